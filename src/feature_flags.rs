@@ -56,6 +56,19 @@
 //! dfdx = { version = "...", features = ["numpy"] }
 //! ```
 //!
+//! # "f64"
+//!
+//! Enables `f64` tensor ops on the [Cpu](crate::tensor::Cpu) device, so that modules like
+//! [Linear](crate::nn::Linear) can be used with `f64` parameters.
+//!
+//! This is not enabled by default because it makes the dtype of untyped float
+//! literals and tensors ambiguous, so they have to be annotated with `f32` or `f64`.
+//!
+//! Example:
+//! ```toml
+//! dfdx = { version = "...", features = ["f64"] }
+//! ```
+//!
 //! # "nightly"
 //!
 //! Enables using all features that currently require the nightly rust compiler.
@@ -4,8 +4,12 @@
 use std::collections::HashMap;
 use std::{boxed::Box, vec::Vec};
 
+use crate::optim::{GradientUpdate, ParamUpdater, UnusedTensors};
+use crate::shapes::{Dtype, HasShape, Rank0, Shape};
 use crate::tensor::storage_traits::{AllocGrad, DeviceStorage};
-use crate::unique_id::{HasUniqueId, UniqueId};
+use crate::tensor::Tensor;
+use crate::tensor_ops::{Device, SumTo, TryAdd, TryMul};
+use crate::unique_id::{unique_id, HasUniqueId, UniqueId};
 
 /// A generic container for keeping variable sized arrays associated with a [UniqueId].
 ///
@@ -23,7 +27,7 @@ use crate::unique_id::{HasUniqueId, UniqueId};
 /// of that trait is used to downcast the box to the expected value.
 #[derive(Debug, Default)]
 pub struct Gradients {
-    gradient_by_id: HashMap<UniqueId, Box<dyn std::any::Any>>,
+    gradient_by_id: HashMap<UniqueId, Box<dyn GradientBuffer>>,
 }
 
 impl Gradients {
@@ -43,7 +47,7 @@ impl Gradients {
     {
         if !self.gradient_by_id.contains_key(t.id()) {
             let grad = t.try_alloc_grad()?;
-            self.gradient_by_id.insert(*t.id(), Box::new(grad));
+            self.gradient_by_id.insert(*t.id(), t.erase_grad(grad));
         }
         Ok(())
     }
@@ -57,7 +61,7 @@ impl Gradients {
     {
         self.gradient_by_id
             .remove_entry(t.id())
-            .map(|e| *e.1.downcast().unwrap())
+            .map(|e| *e.1.into_any().downcast().unwrap())
     }
 
     /// Returns a mutable reference to the data associated with `t`.
@@ -70,6 +74,7 @@ impl Gradients {
         self.gradient_by_id
             .get_mut(t.id())
             .unwrap()
+            .as_any_mut()
             .downcast_mut()
             .unwrap()
     }
@@ -87,11 +92,77 @@ impl Gradients {
         self.gradient_by_id
             .get(t.id())
             .unwrap()
-            .as_ref()
+            .as_any()
             .downcast_ref()
             .unwrap()
     }
 
+    /// Sets the gradients of `model`'s parameters to zero in place, keeping their allocations,
+    /// and drops every other gradient (like the gradients of intermediate values, which
+    /// get new ids every step).
+    ///
+    /// Pass the result to [crate::tensor::Tensor::trace_with()] to reuse these buffers
+    /// in the next backward pass instead of allocating new ones.
+    pub fn zero_params<E: Dtype, D: Device<E>, M: GradientUpdate<D, E>>(&mut self, model: &mut M) {
+        self.try_zero_params(model).unwrap()
+    }
+
+    /// Fallible version of [Gradients::zero_params]
+    pub fn try_zero_params<E: Dtype, D: Device<E>, M: GradientUpdate<D, E>>(
+        &mut self,
+        model: &mut M,
+    ) -> Result<(), D::Err> {
+        let mut zeroer = ParamZeroer {
+            src: self,
+            dst: Default::default(),
+        };
+        model.update(&mut zeroer, &mut Default::default())?;
+        *self = zeroer.dst;
+        Ok(())
+    }
+
+    /// Computes the l2 norm of the gradient of every parameter of `model`, yielding
+    /// `(id, num_elements, l2_norm)`. Useful for debugging, e.g. logging per-parameter
+    /// gradient norms to find which parameter has exploding gradients. Compare the id
+    /// against [HasUniqueId::id()] of a tensor to find its gradient.
+    ///
+    /// Parameters without a gradient are skipped.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let mut model: Linear<2, 3> = BuildModule::build(&dev);
+    /// let x: Tensor<Rank1<2>, f32, _> = dev.tensor([3.0, 4.0]);
+    /// let grads = model.forward(x.trace()).sum().backward();
+    /// for (id, numel, norm) in grads.norms(&mut model) {
+    ///     println!("{id:?}: {numel} elements, norm {norm}");
+    /// }
+    /// ```
+    pub fn norms<E: Dtype, D: Device<E>, M: GradientUpdate<D, E>>(
+        &self,
+        model: &mut M,
+    ) -> impl Iterator<Item = (UniqueId, usize, E)> {
+        self.try_norms(model).unwrap()
+    }
+
+    /// Fallible version of [Gradients::norms]
+    pub fn try_norms<E: Dtype, D: Device<E>, M: GradientUpdate<D, E>>(
+        &self,
+        model: &mut M,
+    ) -> Result<impl Iterator<Item = (UniqueId, usize, E)>, D::Err> {
+        let mut norms = ParamNorms {
+            grads: self,
+            norms: Vec::new(),
+        };
+        model.update(&mut norms, &mut Default::default())?;
+        Ok(norms.norms.into_iter())
+    }
+
+    /// Returns `true` if there is a gradient for `t`.
+    pub(crate) fn contains<T: HasUniqueId>(&self, t: &T) -> bool {
+        self.gradient_by_id.contains_key(t.id())
+    }
+
     /// Borrows a pair of a gradients `(&mut L, &R)`.
     /// `l` is the gradient to update, and `r` is the gradient to backprop.
     ///
@@ -134,6 +205,199 @@ impl Gradients {
     }
 }
 
+/// Moves the gradients of every parameter it visits from `src` to `dst`, zeroing them.
+struct ParamZeroer<'a> {
+    src: &'a mut Gradients,
+    dst: Gradients,
+}
+
+impl<'a, E: Dtype, D: Device<E>> ParamUpdater<D, E> for ParamZeroer<'a> {
+    fn update_param<S: Shape>(
+        &mut self,
+        p: &mut Tensor<S, E, D>,
+        _unused: &mut UnusedTensors,
+    ) -> Result<(), D::Err> {
+        if let Some(mut grad) = self.src.remove(p) {
+            p.device.try_fill_with_zeros(&mut grad)?;
+            self.dst.gradient_by_id.insert(*p.id(), p.erase_grad(grad));
+        }
+        Ok(())
+    }
+}
+
+/// Computes the l2 norm of the gradient of every parameter it visits.
+struct ParamNorms<'a, E> {
+    grads: &'a Gradients,
+    norms: Vec<(UniqueId, usize, E)>,
+}
+
+impl<'a, E: Dtype, D: Device<E>> ParamUpdater<D, E> for ParamNorms<'a, E> {
+    fn update_param<S: Shape>(
+        &mut self,
+        p: &mut Tensor<S, E, D>,
+        _unused: &mut UnusedTensors,
+    ) -> Result<(), D::Err> {
+        if self.grads.contains(p) {
+            let grad = p.device.upgrade(self.grads.get(p).clone());
+            let norm = grad.try_square()?.try_sum::<Rank0, _>()?.try_sqrt()?;
+            let mut buf = [Default::default()];
+            norm.copy_into(&mut buf);
+            self.norms.push((*p.id(), p.shape().num_elements(), buf[0]));
+        }
+        Ok(())
+    }
+}
+
+/// Adds up the squares of the gradients of every parameter it visits.
+struct ParamSumSquares<'a, E: Dtype, D: DeviceStorage> {
+    grads: &'a Gradients,
+    total: Option<Tensor<Rank0, E, D>>,
+}
+
+impl<'a, E: Dtype, D: Device<E>> ParamUpdater<D, E> for ParamSumSquares<'a, E, D> {
+    fn update_param<S: Shape>(
+        &mut self,
+        p: &mut Tensor<S, E, D>,
+        _unused: &mut UnusedTensors,
+    ) -> Result<(), D::Err> {
+        if self.grads.contains(p) {
+            let grad = p.device.upgrade(self.grads.get(p).clone());
+            let sum_squares = grad.try_square()?.try_sum()?;
+            self.total = Some(match self.total.take() {
+                Some(total) => total.try_add(sum_squares)?,
+                None => sum_squares,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Multiplies the gradient of every parameter it visits by `scale`.
+struct ParamScaler<'a, E> {
+    grads: &'a mut Gradients,
+    scale: E,
+}
+
+impl<'a, E: Dtype, D: Device<E>> ParamUpdater<D, E> for ParamScaler<'a, E> {
+    fn update_param<S: Shape>(
+        &mut self,
+        p: &mut Tensor<S, E, D>,
+        _unused: &mut UnusedTensors,
+    ) -> Result<(), D::Err> {
+        if let Some(grad) = self.grads.remove(p) {
+            let grad = p.device.upgrade(grad).try_mul(self.scale)?;
+            self.grads
+                .gradient_by_id
+                .insert(*p.id(), p.erase_grad(grad.storage));
+        }
+        Ok(())
+    }
+}
+
+/// Internal trait - A gradient whose concrete type has been erased, so that [Gradients]
+/// can store gradients of any shape, dtype, and device together.
+pub trait GradientBuffer: std::fmt::Debug {
+    fn as_any(&self) -> &dyn std::any::Any;
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any;
+    fn into_any(self: Box<Self>) -> Box<dyn std::any::Any>;
+
+    /// The number of elements in the gradient.
+    fn num_elements(&self) -> usize;
+
+    /// The sum of the squares of the elements of the gradient.
+    fn sum_squares(&self) -> f32;
+
+    /// Multiplies every element of the gradient by `scale`.
+    fn scale(&mut self, scale: f32);
+}
+
+/// Clips every gradient in `grads` so that their global L2 norm is at most `max_norm`.
+///
+/// The global norm is computed as if every gradient was concatenated into a single vector.
+/// If it is greater than `max_norm`, every gradient is multiplied by `max_norm / norm`.
+///
+/// Returns the global norm from *before* clipping.
+///
+/// Note that `grads` holds the gradients of every tensor that was traced, including
+/// intermediate values. Use [clip_param_grad_norm()] to only clip the gradients of the
+/// parameters of a model.
+///
+/// Similar to pytorch's `torch.nn.utils.clip_grad_norm_`.
+///
+/// ```rust
+/// # use dfdx::{prelude::*, gradients::clip_grad_norm};
+/// # let dev: Cpu = Default::default();
+/// let model: Linear<2, 3> = BuildModule::build(&dev);
+/// let x: Tensor<Rank1<2>, f32, _> = dev.tensor([3.0, 4.0]);
+/// let mut grads = model.forward(x.trace()).sum().backward();
+/// let norm = clip_grad_norm(&mut grads, 1.0);
+/// ```
+pub fn clip_grad_norm(grads: &mut Gradients, max_norm: f32) -> f32 {
+    let norm = grads
+        .gradient_by_id
+        .values()
+        .map(|grad| grad.sum_squares())
+        .sum::<f32>()
+        .sqrt();
+    if norm > max_norm {
+        let scale = max_norm / norm;
+        for grad in grads.gradient_by_id.values_mut() {
+            grad.scale(scale);
+        }
+    }
+    norm
+}
+
+/// Clips the gradients of the parameters of `model` so that their global L2 norm is at most
+/// `max_norm`.
+///
+/// The global norm is computed as if the gradient of every parameter was concatenated into
+/// a single vector. If it is greater than `max_norm`, every one of those gradients is
+/// multiplied by `max_norm / norm`. Gradients of anything else are left as is.
+///
+/// Returns the global norm from *before* clipping.
+///
+/// ```rust
+/// # use dfdx::{prelude::*, gradients::clip_param_grad_norm};
+/// # let dev: Cpu = Default::default();
+/// let mut model: Linear<2, 3> = BuildModule::build(&dev);
+/// let x: Tensor<Rank1<2>, f32, _> = dev.tensor([3.0, 4.0]);
+/// let mut grads = model.forward(x.trace()).sum().backward();
+/// let norm = clip_param_grad_norm(&mut grads, &mut model, 1.0);
+/// ```
+pub fn clip_param_grad_norm<E: Dtype, D: Device<E>, M: GradientUpdate<D, E>>(
+    grads: &mut Gradients,
+    model: &mut M,
+    max_norm: E,
+) -> E {
+    try_clip_param_grad_norm(grads, model, max_norm).unwrap()
+}
+
+/// Fallible version of [clip_param_grad_norm]
+pub fn try_clip_param_grad_norm<E: Dtype, D: Device<E>, M: GradientUpdate<D, E>>(
+    grads: &mut Gradients,
+    model: &mut M,
+    max_norm: E,
+) -> Result<E, D::Err> {
+    let mut sum_squares = ParamSumSquares { grads, total: None };
+    model.update(&mut sum_squares, &mut Default::default())?;
+    let total = match sum_squares.total {
+        Some(total) => total,
+        None => return Ok(Default::default()),
+    };
+    let mut norm = [Default::default()];
+    total.try_sqrt()?.copy_into(&mut norm);
+    let norm = norm[0];
+    if norm > max_norm {
+        let mut scaler = ParamScaler {
+            grads,
+            scale: max_norm / norm,
+        };
+        model.update(&mut scaler, &mut Default::default())?;
+    }
+    Ok(norm)
+}
+
 /// Records gradient computations to execute later.
 ///
 /// The only two things you can do with this are:
@@ -166,7 +430,10 @@ impl Gradients {
 /// This would not be possible if these chain rule operations were inside of GradientTape!
 #[allow(clippy::type_complexity)]
 pub struct GradientTape<D: DeviceStorage> {
-    operations: Vec<Box<dyn FnOnce(&mut Gradients) -> Result<(), D::Err>>>,
+    operations: Vec<(
+        UniqueId,
+        Box<dyn FnOnce(&mut Gradients) -> Result<(), D::Err>>,
+    )>,
     gradients: Gradients,
 }
 
@@ -199,19 +466,32 @@ impl<D: DeviceStorage> GradientTape<D> {
         &mut self,
         operation: F,
     ) {
-        self.operations.push(Box::new(operation));
+        self.operations.push((unique_id(), Box::new(operation)));
     }
 
     /// Compute the [Gradients]! This just runs all the operations on a new [Gradients] struct.
     ///
+    /// Operations are run in the reverse order that they were added in, regardless of the
+    /// order tapes were merged in, so that an operation always runs after the operations
+    /// of the tensors that use its result.
+    ///
     /// Note that this method takes ownership of self, so it can't be called twice!
     pub(crate) fn execute(mut self) -> Result<Gradients, D::Err> {
-        for operation in self.operations.drain(..).rev() {
+        self.operations.sort_by_key(|(k, _)| *k);
+        for (_, operation) in self.operations.drain(..).rev() {
             (operation)(&mut self.gradients)?;
         }
         Ok(self.gradients)
     }
 
+    /// Creates a tape that accumulates into `gradients` instead of allocating new ones.
+    pub(crate) fn with_gradients(gradients: Gradients) -> Self {
+        Self {
+            operations: Vec::new(),
+            gradients,
+        }
+    }
+
     /// Moves all the operations from `other` into self. Leaves `other` empty.
     pub(crate) fn append(&mut self, other: &mut Self) {
         self.gradients
@@ -296,3 +576,140 @@ impl<D: DeviceStorage> Merge<OwnedTape<D>> for OwnedTape<D> {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{shapes::*, tensor::*, tests::*};
+
+    #[test]
+    fn test_clip_grad_norm() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank1<2>, f32, _> = dev.zeros();
+        let b: Tensor<Rank2<2, 2>, f32, _> = dev.zeros();
+        let mut grads: Gradients = Default::default();
+        *grads.get_or_alloc_mut(&a).unwrap() = dev.tensor([3.0, 0.0]).storage;
+        *grads.get_or_alloc_mut(&b).unwrap() = dev.tensor([[0.0, 4.0], [0.0, 0.0]]).storage;
+
+        // norm = sqrt(3^2 + 4^2) = 5 is under the limit, so nothing changes
+        assert_eq!(clip_grad_norm(&mut grads, 10.0), 5.0);
+        assert_eq!(grads.get(&a).array(), [3.0, 0.0]);
+        assert_eq!(grads.get(&b).array(), [[0.0, 4.0], [0.0, 0.0]]);
+
+        assert_eq!(clip_grad_norm(&mut grads, 2.5), 5.0);
+        assert_eq!(grads.get(&a).array(), [1.5, 0.0]);
+        assert_eq!(grads.get(&b).array(), [[0.0, 2.0], [0.0, 0.0]]);
+        assert_eq!(clip_grad_norm(&mut grads, 10.0), 2.5);
+    }
+
+    #[test]
+    fn test_clip_param_grad_norm() {
+        let dev: TestDevice = Default::default();
+        let mut model: crate::nn::Linear<2, 2, f32, _> = crate::nn::BuildModule::build(&dev);
+        let other: Tensor<Rank1<2>, f32, _> = dev.zeros();
+        let mut grads: Gradients = Default::default();
+        *grads.get_or_alloc_mut(&model.bias).unwrap() = dev.tensor([3.0, 0.0]).storage;
+        *grads.get_or_alloc_mut(&model.weight).unwrap() =
+            dev.tensor([[0.0, 4.0], [0.0, 0.0]]).storage;
+        *grads.get_or_alloc_mut(&other).unwrap() = dev.tensor([100.0, 0.0]).storage;
+
+        // norm = sqrt(3^2 + 4^2) = 5 is under the limit, so nothing changes
+        assert_eq!(clip_param_grad_norm(&mut grads, &mut model, 10.0), 5.0);
+        assert_eq!(grads.get(&model.bias).array(), [3.0, 0.0]);
+        assert_eq!(grads.get(&model.weight).array(), [[0.0, 4.0], [0.0, 0.0]]);
+
+        assert_eq!(clip_param_grad_norm(&mut grads, &mut model, 2.5), 5.0);
+        assert_eq!(grads.get(&model.bias).array(), [1.5, 0.0]);
+        assert_eq!(grads.get(&model.weight).array(), [[0.0, 2.0], [0.0, 0.0]]);
+        assert_eq!(clip_param_grad_norm(&mut grads, &mut model, 10.0), 2.5);
+
+        // only the gradients of the parameters count towards the norm
+        assert_eq!(grads.get(&other).array(), [100.0, 0.0]);
+    }
+
+    #[test]
+    fn test_reuse_zeroed_gradients() {
+        use crate::{nn::*, tensor_ops::*};
+        let dev: TestDevice = Default::default();
+        let mut model: Linear<3, 2, f32, _> = BuildModule::build(&dev);
+        let x1: Tensor<Rank2<4, 3>, f32, _> = dev.sample_normal();
+        let x2: Tensor<Rank2<4, 3>, f32, _> = dev.sample_normal();
+
+        let fresh1 = model.forward(x1.trace()).square().mean().backward();
+        let fresh2 = model.forward(x2.trace()).square().mean().backward();
+
+        let mut grads = model.forward(x1.trace()).square().mean().backward();
+        assert_eq!(
+            grads.get(&model.weight).array(),
+            fresh1.get(&model.weight).array()
+        );
+
+        grads.zero_params(&mut model);
+        assert_eq!(grads.get(&model.weight).array(), [[0.0; 3]; 2]);
+        assert_eq!(grads.get(&model.bias).array(), [0.0; 2]);
+        assert_eq!(grads.gradient_by_id.len(), 2);
+        let grads = model
+            .forward(x2.trace_with(grads))
+            .square()
+            .mean()
+            .backward();
+        assert_eq!(
+            grads.get(&model.weight).array(),
+            fresh2.get(&model.weight).array()
+        );
+        assert_eq!(
+            grads.get(&model.bias).array(),
+            fresh2.get(&model.bias).array()
+        );
+    }
+
+    #[test]
+    fn test_reused_gradients_dont_grow() {
+        use crate::{nn::*, tensor_ops::*};
+        let dev: TestDevice = Default::default();
+        let mut model: (Linear<3, 5, f32, _>, ReLU, Linear<5, 2, f32, _>) =
+            BuildModule::build(&dev);
+        let mut grads = Gradients::default();
+        let mut held = std::vec::Vec::new();
+        for _ in 0..5 {
+            let x: Tensor<Rank2<4, 3>, f32, _> = dev.sample_normal();
+            grads = model
+                .forward(x.trace_with(grads))
+                .square()
+                .mean()
+                .backward();
+            grads.zero_params(&mut model);
+            let numel: usize = grads
+                .gradient_by_id
+                .values()
+                .map(|g| g.num_elements())
+                .sum();
+            held.push(numel * std::mem::size_of::<f32>());
+        }
+        assert_eq!(held, [(3 * 5 + 5 + 5 * 2 + 2) * 4; 5]);
+    }
+
+    #[test]
+    fn test_norms_linear() {
+        use crate::{nn::*, tensor_ops::*};
+        let dev: TestDevice = Default::default();
+        let mut model: Linear<5, 2, f32, _> = BuildModule::build(&dev);
+        let x: Tensor<Rank1<5>, f32, _> = dev.sample_normal();
+        let grads = model.forward(x.trace()).sum().backward();
+        let norms: HashMap<UniqueId, (usize, f32)> = grads
+            .norms(&mut model)
+            .map(|(id, numel, norm)| (id, (numel, norm)))
+            .collect();
+
+        let (numel, norm) = norms[model.weight.id()];
+        assert_eq!(numel, 10);
+        let w = grads.get(&model.weight).array();
+        let expected = w.iter().flatten().map(|v| v * v).sum::<f32>().sqrt();
+        assert_close(&norm, &expected);
+
+        // the gradient of the bias is all ones
+        let (numel, norm) = norms[model.bias.id()];
+        assert_eq!(numel, 2);
+        assert_close(&norm, &2.0f32.sqrt());
+    }
+}
@@ -121,6 +121,7 @@ pub mod prelude {
     pub use crate::losses::*;
     pub use crate::nn::*;
     pub use crate::optim::prelude::*;
+    pub use crate::sequential;
     pub use crate::shapes::*;
     pub use crate::tensor::*;
     pub use crate::tensor_ops::*;
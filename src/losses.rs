@@ -1,6 +1,11 @@
 //! Standard loss functions such as [mse_loss()], [cross_entropy_with_logits_loss()], and more.
 
-use crate::{gradients::Tape, shapes::*, tensor::Tensor, tensor_ops::*};
+use crate::{
+    gradients::{Merge, Tape},
+    shapes::*,
+    tensor::{PutTape, SplitTape, Tensor},
+    tensor_ops::*,
+};
 
 /// [Mean Squared Error](https://en.wikipedia.org/wiki/Mean_squared_error).
 /// This computes `(pred - targ).square().mean()`.
@@ -39,7 +44,8 @@ pub fn mae_loss<S: Shape, D: Device<f32>, T: Tape<D>>(
 /// uses absolute error when the error is higher than `beta`, and squared error when the
 /// error is lower than `beta`.
 ///
-/// See [huber_error()]
+/// The errors are averaged over all elements. For the unreduced, elementwise
+/// version see [huber_error()].
 ///
 /// # Example
 /// ```rust
@@ -111,6 +117,41 @@ where
     (logits.log_softmax::<Ax>() * target_probs).mean().negate() * last_axis_numel
 }
 
+/// [Cross entropy loss](https://en.wikipedia.org/wiki/Cross_entropy#Cross-entropy_loss_function_and_logistic_regression)
+/// with class indices as targets.
+/// This computes: `-logits.log_softmax().select(target_ids).mean()`
+///
+/// Equivalent to [cross_entropy_with_logits_loss()] with one hot target probabilities,
+/// but without building the one hot tensor.
+///
+/// # Arguments
+///
+/// - `logits`: The un-normalized output from a model. [log_softmax()] is called **in** this function
+/// - `target_ids`: Index of the target class for every item, with the same shape as
+///   `logits` minus the last axis.
+///
+/// # Example
+/// ```rust
+/// # use dfdx::{prelude::*};
+/// # let dev: Cpu = Default::default();
+/// let logits: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[-1.0, -0.5, 0.0], [1.0, 2.0, 0.5]]);
+/// let target_ids = dev.tensor([2, 0]);
+/// let loss = sparse_cross_entropy_with_logits_loss(logits.traced(), target_ids);
+/// ```
+pub fn sparse_cross_entropy_with_logits_loss<Ax: Axes, S, Idx: Shape, D: Device<f32>, T: Tape<D>>(
+    logits: Tensor<S, f32, D, T>,
+    target_ids: Tensor<Idx, usize, D>,
+) -> Tensor<Rank0, f32, D, T>
+where
+    S: Shape<LastAxis = Ax> + ReduceShape<Ax> + RemoveDimTo<Idx, Idx>,
+{
+    logits
+        .log_softmax::<Ax>()
+        .select(target_ids)
+        .mean()
+        .negate()
+}
+
 /// [KL Divergence loss](https://en.wikipedia.org/wiki/Kullback%E2%80%93Leibler_divergence).
 /// This computes `(target_probs * (target_probs.log() - logits.log_softmax())).sum(-1).mean()`
 ///
@@ -145,6 +186,174 @@ where
         * last_axis_numel
 }
 
+/// How [kl_div_loss()] and [js_div_loss()] reduce the divergence of every distribution,
+/// which has the shape `S`. Implemented by [Mean], [Sum], and [NoReduction].
+pub trait Reduction<S: Shape> {
+    /// The shape of the reduced loss.
+    type Output: Shape;
+
+    fn reduce<D: Device<f32>, T: Tape<D>>(
+        loss: Tensor<S, f32, D, T>,
+    ) -> Tensor<Self::Output, f32, D, T>;
+}
+
+/// Average over all the distributions. This is pytorch's `reduction="batchmean"`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Mean;
+
+/// Sum over all the distributions.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Sum;
+
+/// Keep the divergence of every distribution, like [kl_div()] and [js_div()].
+/// This is pytorch's `reduction="none"`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NoReduction;
+
+impl<S: Shape> Reduction<S> for Mean {
+    type Output = Rank0;
+    fn reduce<D: Device<f32>, T: Tape<D>>(loss: Tensor<S, f32, D, T>) -> Tensor<Rank0, f32, D, T> {
+        loss.mean()
+    }
+}
+
+impl<S: Shape> Reduction<S> for Sum {
+    type Output = Rank0;
+    fn reduce<D: Device<f32>, T: Tape<D>>(loss: Tensor<S, f32, D, T>) -> Tensor<Rank0, f32, D, T> {
+        loss.sum()
+    }
+}
+
+impl<S: Shape> Reduction<S> for NoReduction {
+    type Output = S;
+    fn reduce<D: Device<f32>, T: Tape<D>>(loss: Tensor<S, f32, D, T>) -> Tensor<S, f32, D, T> {
+        loss
+    }
+}
+
+/// [KL Divergence](https://en.wikipedia.org/wiki/Kullback%E2%80%93Leibler_divergence)
+/// of every distribution along the last axis, without any reduction.
+/// This computes `(q * (q.ln() - p_log)).sum(-1)`.
+///
+/// Like pytorch's `kl_div`, the first argument is **log-probabilities** and the second
+/// is probabilities. All values of `q` must be greater than 0.
+/// Gradients flow into both `p_log` and `q`.
+///
+/// # Example
+/// ```rust
+/// # use dfdx::{prelude::*};
+/// # let dev: Cpu = Default::default();
+/// let p_log = dev.tensor([[0.25, 0.75], [0.5, 0.5]]).ln();
+/// let q = dev.tensor([[0.5, 0.5], [0.5, 0.5]]);
+/// let kl: Tensor<Rank1<2>, f32, _, _> = kl_div(p_log.traced(), q);
+/// ```
+pub fn kl_div<Ax: Axes, S, D: Device<f32>, T, R>(
+    p_log: Tensor<S, f32, D, T>,
+    q: Tensor<S, f32, D, R>,
+) -> Tensor<S::Reduced, f32, D, T>
+where
+    S: Shape<LastAxis = Ax> + ReduceShape<Ax>,
+    T: Tape<D> + Merge<R>,
+    R: Tape<D>,
+{
+    // the tapes of both inputs go first, so their ops run after everything below
+    let (p_log, p_tape) = p_log.split_tape();
+    let (q, q_tape) = q.split_tape();
+    let q_ln = q.clone().put_tape(p_tape.merge(q_tape)).ln();
+    ((q_ln - p_log.retaped::<T>()) * q.retaped::<R>()).sum::<_, Ax>()
+}
+
+/// [KL Divergence](https://en.wikipedia.org/wiki/Kullback%E2%80%93Leibler_divergence)
+/// along the last axis, reduced according to `reduction`. See [kl_div()].
+///
+/// The output is [Rank0] for [Mean] and [Sum], and has the shape of [kl_div()]'s output
+/// for [NoReduction].
+///
+/// # Example
+/// ```rust
+/// # use dfdx::{prelude::*};
+/// # let dev: Cpu = Default::default();
+/// let p_log = dev.tensor([[0.25, 0.75], [0.5, 0.5]]).ln();
+/// let q = dev.tensor([[0.5, 0.5], [0.5, 0.5]]);
+/// let loss: Tensor<Rank0, f32, _, _> = kl_div_loss(p_log.clone().traced(), q.clone(), Mean);
+/// let kl: Tensor<Rank1<2>, f32, _, _> = kl_div_loss(p_log.traced(), q, NoReduction);
+/// ```
+pub fn kl_div_loss<Ax: Axes, S, Red, D: Device<f32>, T, R>(
+    p_log: Tensor<S, f32, D, T>,
+    q: Tensor<S, f32, D, R>,
+    _reduction: Red,
+) -> Tensor<Red::Output, f32, D, T>
+where
+    S: Shape<LastAxis = Ax> + ReduceShape<Ax>,
+    Red: Reduction<S::Reduced>,
+    T: Tape<D> + Merge<R>,
+    R: Tape<D>,
+{
+    Red::reduce(kl_div(p_log, q))
+}
+
+/// [Jensen-Shannon Divergence](https://en.wikipedia.org/wiki/Jensen%E2%80%93Shannon_divergence)
+/// of every distribution along the last axis, without any reduction.
+/// This computes `0.5 * (kl(p || m) + kl(q || m))` where `m = 0.5 * (p + q)`.
+///
+/// The arguments are the same as [kl_div()]: `p_log` is **log-probabilities** and `q`
+/// is probabilities. Unlike [kl_div()], the result is symmetric in the two distributions.
+///
+/// # Example
+/// ```rust
+/// # use dfdx::{prelude::*};
+/// # let dev: Cpu = Default::default();
+/// let p_log = dev.tensor([[0.25, 0.75], [0.5, 0.5]]).ln();
+/// let q = dev.tensor([[0.5, 0.5], [0.5, 0.5]]);
+/// let js: Tensor<Rank1<2>, f32, _, _> = js_div(p_log.traced(), q);
+/// ```
+pub fn js_div<Ax: Axes, S, D: Device<f32>, T, R>(
+    p_log: Tensor<S, f32, D, T>,
+    q: Tensor<S, f32, D, R>,
+) -> Tensor<S::Reduced, f32, D, T>
+where
+    S: Shape<LastAxis = Ax> + ReduceShape<Ax>,
+    T: Tape<D> + Merge<R>,
+    R: Tape<D>,
+{
+    // the tapes of both inputs go first, so their ops run after everything below
+    let (p_log, p_tape) = p_log.split_tape();
+    let (q, q_tape) = q.split_tape();
+    let p = p_log.clone().put_tape(p_tape.merge(q_tape)).exp();
+    let m_ln = ((p + q.clone().retaped::<R>()) * 0.5).ln();
+    let kl_pm =
+        (p_log.clone().retaped::<T>() - m_ln.with_empty_tape()) * p_log.retaped::<T>().exp();
+    let kl_qm = (m_ln.negate() + q.clone().retaped::<R>().ln()) * q.retaped::<R>();
+    ((kl_qm + kl_pm) * 0.5).sum::<_, Ax>()
+}
+
+/// [Jensen-Shannon Divergence](https://en.wikipedia.org/wiki/Jensen%E2%80%93Shannon_divergence)
+/// along the last axis, reduced according to `reduction`. See [js_div()].
+///
+/// The output shape works the same as for [kl_div_loss()].
+///
+/// # Example
+/// ```rust
+/// # use dfdx::{prelude::*};
+/// # let dev: Cpu = Default::default();
+/// let p_log = dev.tensor([0.25, 0.75]).ln();
+/// let q = dev.tensor([0.5, 0.5]);
+/// let loss: Tensor<Rank0, f32, _, _> = js_div_loss(p_log.traced(), q, Sum);
+/// ```
+pub fn js_div_loss<Ax: Axes, S, Red, D: Device<f32>, T, R>(
+    p_log: Tensor<S, f32, D, T>,
+    q: Tensor<S, f32, D, R>,
+    _reduction: Red,
+) -> Tensor<Red::Output, f32, D, T>
+where
+    S: Shape<LastAxis = Ax> + ReduceShape<Ax>,
+    Red: Reduction<S::Reduced>,
+    T: Tape<D> + Merge<R>,
+    R: Tape<D>,
+{
+    Red::reduce(js_div(p_log, q))
+}
+
 /// [Binary Cross Entropy](https://en.wikipedia.org/wiki/Cross_entropy#Cross-entropy_loss_function_and_logistic_regression)
 /// With Logits in numerically stable way.
 ///
@@ -243,6 +452,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_sparse_cross_entropy() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 2.0, 3.0], [0.0, 0.0, 0.0]]);
+        let loss = sparse_cross_entropy_with_logits_loss(x.trace(), dev.tensor([2, 1]));
+        // row 0: -log(e^3 / (e^1 + e^2 + e^3)) = 0.40760596, row 1: -log(1 / 3) = 1.0986123
+        let loss_value = loss.array();
+        assert_close(&loss_value, &0.7531091);
+        let g = loss.backward();
+        // (softmax(x) - onehot(targets)) / 2
+        assert_close(
+            &g.get(&x).array(),
+            &[
+                [0.045015287, 0.12236424, -0.16737952],
+                [1.0 / 6.0, -1.0 / 3.0, 1.0 / 6.0],
+            ],
+        );
+
+        let mut targ = [[0.0; 3]; 2];
+        targ[0][2] = 1.0;
+        targ[1][1] = 1.0;
+        let dense = cross_entropy_with_logits_loss(x.trace(), dev.tensor(targ));
+        assert_close(&dense.array(), &loss_value);
+    }
+
     #[test]
     fn test_kl_div() {
         let dev: TestDevice = Default::default();
@@ -275,6 +509,74 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_kl_div_log_probs() {
+        let dev: TestDevice = Default::default();
+        let p_log = dev.tensor([0.2, 0.3, 0.5]).ln();
+        let q = dev.tensor([0.1, 0.6, 0.3]);
+        let loss = kl_div(p_log.trace(), q.trace());
+        // 0.1 * ln(0.1 / 0.2) + 0.6 * ln(0.6 / 0.3) + 0.3 * ln(0.3 / 0.5)
+        assert_close(&loss.array(), &0.1933259);
+        let g = loss.backward();
+        // d/dp_log = -q, d/dq = ln(q / p) + 1
+        assert_close(&g.get(&p_log).array(), &[-0.1, -0.6, -0.3]);
+        assert_close(&g.get(&q).array(), &[0.30685282, 1.6931472, 0.48917437]);
+    }
+
+    #[test]
+    fn test_kl_div_reductions() {
+        let dev: TestDevice = Default::default();
+        let p_log = dev.tensor([[0.2, 0.3, 0.5], [0.1, 0.6, 0.3]]).ln();
+        let q = dev.tensor([[0.1, 0.6, 0.3], [0.1, 0.6, 0.3]]);
+        let kl = kl_div(p_log.clone(), q.clone());
+        assert_close(&kl.array(), &[0.1933259, 0.0]);
+        let loss: Tensor<Rank1<2>, f32, _> = kl_div_loss(p_log.clone(), q.clone(), NoReduction);
+        assert_close(&loss.array(), &[0.1933259, 0.0]);
+        let loss: Tensor<Rank0, f32, _> = kl_div_loss(p_log.clone(), q.clone(), Sum);
+        assert_close(&loss.array(), &0.1933259);
+        let loss: Tensor<Rank0, f32, _, _> = kl_div_loss(p_log.trace(), q, Mean);
+        assert_close(&loss.array(), &(0.1933259 / 2.0));
+        let g = loss.backward();
+        assert_close(
+            &g.get(&p_log).array(),
+            &[[-0.05, -0.3, -0.15], [-0.05, -0.3, -0.15]],
+        );
+    }
+
+    #[test]
+    fn test_js_div() {
+        let dev: TestDevice = Default::default();
+        let p = dev.tensor([0.2, 0.3, 0.5]);
+        let q = dev.tensor([0.1, 0.6, 0.3]);
+        let p_log = p.clone().ln();
+        let js = js_div(p_log.trace(), q.trace());
+        assert_close(&js.array(), &0.046613384);
+        let g = js.backward();
+        // d/dp = 0.5 * ln(p / m), and d/dp_log = p * d/dp
+        assert_close(
+            &g.get(&p_log).array(),
+            &[0.028768208, -0.060819768, 0.05578589],
+        );
+        assert_close(&g.get(&q).array(), &[-0.20273255, 0.14384104, -0.14384104]);
+
+        // symmetric
+        let js_rev = js_div(q.ln(), p);
+        assert_close(&js_rev.array(), &0.046613384);
+    }
+
+    #[test]
+    fn test_js_div_reductions() {
+        let dev: TestDevice = Default::default();
+        let p_log = dev.tensor([[0.2, 0.3, 0.5], [0.1, 0.6, 0.3]]).ln();
+        let q = dev.tensor([[0.1, 0.6, 0.3], [0.1, 0.6, 0.3]]);
+        let loss: Tensor<Rank1<2>, f32, _> = js_div_loss(p_log.clone(), q.clone(), NoReduction);
+        assert_close(&loss.array(), &[0.046613384, 0.0]);
+        let loss: Tensor<Rank0, f32, _> = js_div_loss(p_log.clone(), q.clone(), Sum);
+        assert_close(&loss.array(), &0.046613384);
+        let loss: Tensor<Rank0, f32, _> = js_div_loss(p_log, q, Mean);
+        assert_close(&loss.array(), &(0.046613384 / 2.0));
+    }
+
     #[test]
     fn test_bce() {
         let dev: TestDevice = Default::default();
@@ -378,6 +680,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_huber_loss_regimes() {
+        let dev: TestDevice = Default::default();
+        let x = dev.tensor([0.5, 5.0, -5.0]);
+        let y = dev.zeros();
+
+        let loss = huber_loss(x.trace(), y.clone(), 1.0);
+        assert_close(&loss.array(), &(9.125 / 3.0));
+
+        // unreduced: gradient is the error in the quadratic regime, and
+        // saturates at +/- delta in the linear regime
+        let r = x.trace().huber_error(y, 1.0);
+        assert_eq!(r.array(), [0.125, 4.5, 4.5]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&x).array(), [0.5, 1.0, -1.0]);
+    }
+
     #[test]
     fn test_smooth_l1_loss() {
         let dev: TestDevice = Default::default();
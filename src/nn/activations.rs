@@ -29,7 +29,7 @@ macro_rules! activation_impls {
 }
 
 activation_impls!(ReLU, relu, #[doc="Unit struct that impls [Module] as calling [relu()] on `input`."]);
-activation_impls!(GeLU, gelu, #[doc="Unit struct that impls [Module] as calling [gelu()] on `input`."]);
+activation_impls!(GeLU, gelu, #[doc="Unit struct that impls [Module] as calling [gelu()] on `input`. Uses the tanh approximation of GeLU."]);
 activation_impls!(Sin, sin, #[doc="Unit struct that impls [Module] as calling [sin()] on `input`."]);
 activation_impls!(Cos, cos, #[doc="Unit struct that impls [Module] as calling [cos()] on `input`."]);
 activation_impls!(Ln, ln, #[doc="Unit struct that impls [Module] as calling [ln()] on `input`."]);
@@ -62,6 +62,28 @@ impl<Ax: Axes, S: Shape<LastAxis = Ax> + ReduceShape<Ax>, E: Dtype, D: Device<E>
     }
 }
 
+/// Unit struct that impls [Module] as calling [log_softmax()] on the last axis of `input`.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct LogSoftmax;
+
+impl ZeroSizedModule for LogSoftmax {}
+impl NonMutableModule for LogSoftmax {}
+
+impl<D: Device<E>, E: Dtype> BuildModule<D, E> for LogSoftmax {
+    fn try_build(_: &D) -> Result<Self, <D>::Err> {
+        Ok(Default::default())
+    }
+}
+
+impl<Ax: Axes, S: Shape<LastAxis = Ax> + ReduceShape<Ax>, E: Dtype, D: Device<E>, T: Tape<D>>
+    Module<Tensor<S, E, D, T>> for LogSoftmax
+{
+    type Output = Tensor<S, E, D, T>;
+    fn forward(&self, input: Tensor<S, E, D, T>) -> Self::Output {
+        input.log_softmax::<Ax>()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{nn::ModuleMut, tests::TestDevice};
@@ -86,6 +108,31 @@ mod tests {
         assert_eq!(r1.array(), r2.array());
     }
 
+    #[test]
+    fn test_nn_activations_gelu_zero() {
+        let dev: TestDevice = Default::default();
+        let r = GeLU.forward(dev.tensor(0.0f32));
+        assert_eq!(r.array(), 0.0);
+    }
+
+    #[test]
+    fn test_nn_activations_backward_matches_ops() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<5>, f32, _> = dev.tensor([-2.0, -1.0, 0.0, 1.0, 2.0]);
+
+        let g1 = GeLU.forward(t.trace()).sum().backward();
+        let g2 = gelu(t.trace()).sum().backward();
+        assert_eq!(g1.get(&t).array(), g2.get(&t).array());
+
+        let g1 = Sigmoid.forward(t.trace()).sum().backward();
+        let g2 = sigmoid(t.trace()).sum().backward();
+        assert_eq!(g1.get(&t).array(), g2.get(&t).array());
+
+        let g1 = Tanh.forward(t.trace()).sum().backward();
+        let g2 = tanh(t.trace()).sum().backward();
+        assert_eq!(g1.get(&t).array(), g2.get(&t).array());
+    }
+
     #[test]
     fn test_nn_activations_sin() {
         let dev: TestDevice = Default::default();
@@ -177,4 +224,24 @@ mod tests {
         let r2 = t.softmax::<crate::shapes::Axis<1>>();
         assert_eq!(r1.array(), r2.array());
     }
+
+    #[test]
+    fn test_nn_activations_log_softmax() {
+        let dev: TestDevice = Default::default();
+
+        let t = dev.tensor([-2.0, -1.0, 0.0, 1.0, 2.0]);
+        let r1 = LogSoftmax.forward_mut(t.clone());
+        let r2 = t.log_softmax();
+        assert_eq!(r1.array(), r2.array());
+
+        let t = dev.tensor([[-2.0, -1.0, 0.0], [1.0, 2.0, 3.0]]);
+        let r1 = LogSoftmax.forward_mut(t.clone());
+        let r2 = t.log_softmax::<crate::shapes::Axis<1>>();
+        assert_eq!(r1.array(), r2.array());
+
+        let t = dev.tensor([[[-2.0, -1.0], [0.0, 1.0]], [[2.0, 3.0], [4.0, 5.0]]]);
+        let r1 = LogSoftmax.forward_mut(t.clone());
+        let r2 = t.log_softmax::<crate::shapes::Axis<2>>();
+        assert_eq!(r1.array(), r2.array());
+    }
 }
@@ -112,7 +112,7 @@ mod tests {
     #[test]
     fn test_add_into_2() {
         let dev: TestDevice = Default::default();
-        let m: AddInto<(Linear<2, 5, _>, Linear<3, 5, _>)> = BuildModule::build(&dev);
+        let m: AddInto<(Linear<2, 5, f32, _>, Linear<3, 5, f32, _>)> = BuildModule::build(&dev);
         let _: Tensor<Rank1<5>, _, _, OwnedTape<_>> = m.forward((
             dev.zeros::<Rank1<2>>().traced(),
             dev.zeros::<Rank1<3>>().traced(),
@@ -126,8 +126,11 @@ mod tests {
     #[test]
     fn test_add_into_3() {
         let dev: TestDevice = Default::default();
-        let m: AddInto<(Linear<2, 5, _>, Linear<3, 5, _>, Linear<4, 5, _>)> =
-            BuildModule::build(&dev);
+        let m: AddInto<(
+            Linear<2, 5, f32, _>,
+            Linear<3, 5, f32, _>,
+            Linear<4, 5, f32, _>,
+        )> = BuildModule::build(&dev);
         let _: Tensor<Rank1<5>, _, _, OwnedTape<_>> = m.forward((
             dev.zeros::<Rank1<2>>().traced(),
             dev.zeros::<Rank1<3>>().traced(),
@@ -187,15 +190,16 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::type_complexity)]
     fn test_add_into_6() {
         let dev: TestDevice = Default::default();
         let m: AddInto<(
-            Linear<2, 5, _>,
-            Linear<3, 5, _>,
-            Linear<4, 5, _>,
-            Linear<5, 5, _>,
-            Linear<6, 5, _>,
-            Linear<7, 5, _>,
+            Linear<2, 5, f32, _>,
+            Linear<3, 5, f32, _>,
+            Linear<4, 5, f32, _>,
+            Linear<5, 5, f32, _>,
+            Linear<6, 5, f32, _>,
+            Linear<7, 5, f32, _>,
         )> = BuildModule::build(&dev);
         let _: Tensor<Rank1<5>, _, _, OwnedTape<_>> = m.forward((
             dev.zeros::<Rank1<2>>().traced(),
@@ -218,7 +222,8 @@ mod tests {
     #[test]
     fn test_missing_gradients() {
         let dev: TestDevice = Default::default();
-        let mut model: AddInto<(Linear<5, 3, _>, Linear<5, 3, _>)> = BuildModule::build(&dev);
+        let mut model: AddInto<(Linear<5, 3, f32, _>, Linear<5, 3, f32, _>)> =
+            BuildModule::build(&dev);
         let mut g: SimpleUpdater = Default::default();
 
         // no gradients present
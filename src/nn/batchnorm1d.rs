@@ -0,0 +1,232 @@
+use crate::{gradients::*, optim::*, shapes::*, tensor::*, tensor_ops::*};
+
+use super::{BuildModule, Module, ModuleMut, ResetParams, ToDevice};
+
+/// Batch normalization for sequences/vectors as described in
+/// [Batch Normalization: Accelerating Deep Network Training
+/// by Reducing Internal Covariate Shift](https://arxiv.org/abs/1502.03167)
+///
+/// Generics:
+///
+/// - `C` the size of the channel dimension. For both 2d and 3d tensors this is the 1st
+///   dimension.
+///
+/// # Training vs Inference
+///
+/// BatchNorm1D supports the following cases (see sections below for more details):
+/// 1. **Training**: [ModuleMut] and [OwnedTape] on the input tensor
+/// 2. **Inference**: [Module] and [NoneTape] on the input tensor.
+///
+/// *NOTE: ModuleMut/NoneTape, and Module/OwnedTape will fail to compile.*
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// type Model = BatchNorm1D<3>;
+/// let bn = Model::build_on_device(&dev);
+/// let _ = bn.forward(dev.zeros::<Rank2<4, 3>>());
+/// let _ = bn.forward(dev.zeros::<Rank3<4, 3, 2>>());
+/// ```
+///
+/// ### Training
+/// - Running statistics: updated with momentum
+/// - Normalization: calculated using batch stats
+///
+/// ### Inference
+/// - Running statistics: **not** updated
+/// - Normalization: calculated using running stats
+#[derive(Clone, Debug)]
+pub struct BatchNorm1D<const C: usize, D: Device<f32> = Cpu> {
+    /// Scale for affine transform. Defaults to 1.0
+    pub scale: Tensor<Rank1<C>, f32, D>,
+    /// Bias for affine transform. Defaults to 0.0
+    pub bias: Tensor<Rank1<C>, f32, D>,
+    /// Channel mean that is updated during training. Defaults to 0.0
+    pub running_mean: Tensor<Rank1<C>, f32, D>,
+    /// Channel variance that is updated during training. Defaults to 1.0
+    pub running_var: Tensor<Rank1<C>, f32, D>,
+    /// Added to variance before taking sqrt for numerical stability. Defaults to 1e-5
+    pub epsilon: f32,
+    /// Controls exponential moving average of running stats. Defaults to 0.1
+    ///
+    /// `running_stat * (1.0 - momentum) + stat * momentum`.
+    pub momentum: f32,
+}
+
+impl<const C: usize, D: Device<f32>> BatchNorm1D<C, D> {
+    /// generic forward for inference
+    fn infer_fwd<S: Shape, Ax: Axes>(&self, x: Tensor<S, f32, D>) -> Tensor<S, f32, D>
+    where
+        Rank1<C>: BroadcastShapeTo<S, Ax>,
+    {
+        super::batchnorm2d::infer_fwd(
+            x,
+            &self.running_mean,
+            &self.running_var,
+            &self.scale,
+            &self.bias,
+            self.epsilon,
+        )
+    }
+
+    fn train_fwd<S, T: Tape<D>, Ax: Axes>(
+        &mut self,
+        x: Tensor<S, f32, D, T>,
+    ) -> Tensor<S, f32, D, T>
+    where
+        S: Shape + HasAxes<Ax> + ReduceShapeTo<Rank1<C>, Ax>,
+    {
+        super::batchnorm2d::train_fwd(
+            x,
+            &mut self.running_mean,
+            &mut self.running_var,
+            &self.scale,
+            &self.bias,
+            self.epsilon,
+            self.momentum,
+        )
+    }
+}
+
+impl<B: Dim, const C: usize, D: Device<f32>> Module<Tensor<(B, Const<C>), f32, D, NoneTape>>
+    for BatchNorm1D<C, D>
+{
+    type Output = Tensor<(B, Const<C>), f32, D, NoneTape>;
+
+    /// Inference 2d forward - does **not** update [Self::running_mean] and [Self::running_var]
+    fn forward(&self, x: Tensor<(B, Const<C>), f32, D, NoneTape>) -> Self::Output {
+        self.infer_fwd(x)
+    }
+}
+
+impl<B: Dim, const C: usize, L: Dim, D: Device<f32>>
+    Module<Tensor<(B, Const<C>, L), f32, D, NoneTape>> for BatchNorm1D<C, D>
+{
+    type Output = Tensor<(B, Const<C>, L), f32, D, NoneTape>;
+
+    /// Inference 3d forward - does **not** update [Self::running_mean] and [Self::running_var]
+    fn forward(&self, x: Tensor<(B, Const<C>, L), f32, D, NoneTape>) -> Self::Output {
+        self.infer_fwd(x)
+    }
+}
+
+impl<B: Dim, const C: usize, D: Device<f32>> ModuleMut<Tensor<(B, Const<C>), f32, D, OwnedTape<D>>>
+    for BatchNorm1D<C, D>
+{
+    type Output = Tensor<(B, Const<C>), f32, D, OwnedTape<D>>;
+
+    /// Training 2d forward - updates [Self::running_mean] and [Self::running_var]
+    fn forward_mut(&mut self, x: Tensor<(B, Const<C>), f32, D, OwnedTape<D>>) -> Self::Output {
+        self.train_fwd(x)
+    }
+}
+
+impl<B: Dim, const C: usize, L: Dim, D: Device<f32>>
+    ModuleMut<Tensor<(B, Const<C>, L), f32, D, OwnedTape<D>>> for BatchNorm1D<C, D>
+{
+    type Output = Tensor<(B, Const<C>, L), f32, D, OwnedTape<D>>;
+
+    /// Training 3d forward - updates [Self::running_mean] and [Self::running_var]
+    fn forward_mut(&mut self, x: Tensor<(B, Const<C>, L), f32, D, OwnedTape<D>>) -> Self::Output {
+        self.train_fwd(x)
+    }
+}
+
+impl<const C: usize, D: Device<f32>> BuildModule<D, f32> for BatchNorm1D<C, D> {
+    fn try_build(device: &D) -> Result<Self, D::Err> {
+        Ok(Self {
+            scale: device.try_ones()?,
+            bias: device.try_zeros()?,
+            running_mean: device.try_zeros()?,
+            running_var: device.try_ones()?,
+            epsilon: 1e-5,
+            momentum: 0.1,
+        })
+    }
+}
+
+impl<const C: usize, D: Device<f32>> ResetParams<D, f32> for BatchNorm1D<C, D> {
+    fn try_reset_params(&mut self) -> Result<(), D::Err> {
+        self.scale.try_fill_with_ones()?;
+        self.bias.try_fill_with_zeros()?;
+        self.running_mean.try_fill_with_zeros()?;
+        self.running_var.try_fill_with_ones()?;
+        Ok(())
+    }
+}
+
+impl<const C: usize, D1: Device<f32>, D2: Device<f32>> ToDevice<D2> for BatchNorm1D<C, D1> {
+    type Output = BatchNorm1D<C, D2>;
+    fn to_device(&self, device: &D2) -> Self::Output {
+        BatchNorm1D {
+            scale: self.scale.to_device(device),
+            bias: self.bias.to_device(device),
+            running_mean: self.running_mean.to_device(device),
+            running_var: self.running_var.to_device(device),
+            epsilon: self.epsilon,
+            momentum: self.momentum,
+        }
+    }
+}
+
+impl<const C: usize, D: Device<f32>> GradientUpdate<D, f32> for BatchNorm1D<C, D> {
+    fn update<U>(&mut self, updater: &mut U, unused: &mut UnusedTensors) -> Result<(), <D>::Err>
+    where
+        U: ParamUpdater<D, f32>,
+    {
+        self.scale.update(updater, unused)?;
+        self.bias.update(updater, unused)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::*;
+
+    #[test]
+    fn test_batchnorm1d_2d_forward_mut() {
+        let dev = TestDevice::seed_from_u64(0);
+
+        let x = dev.sample_normal::<Rank2<4, 3>>();
+        let mut bn: BatchNorm1D<3, _> = BuildModule::build(&dev);
+
+        let y = bn.forward_mut(x.trace());
+
+        // each channel is normalized to zero mean & unit variance
+        let y_mean = y.retaped::<NoneTape>().mean::<Rank1<3>, _>();
+        assert_close_with_tolerance(&y_mean.array(), &[0.0; 3], 1e-5);
+        let y_var = y.retaped::<NoneTape>().square().mean::<Rank1<3>, _>();
+        assert_close_with_tolerance(&y_var.array(), &[1.0; 3], 1e-3);
+
+        let g = y.exp().mean().backward();
+        assert_ne!(g.get(&bn.scale).array(), [0.0; 3]);
+        assert_ne!(g.get(&bn.bias).array(), [0.0; 3]);
+    }
+
+    #[test]
+    fn test_batchnorm1d_running_mean_approaches_data_mean() {
+        let dev = TestDevice::seed_from_u64(1);
+
+        let mut bn: BatchNorm1D<3, _> = BuildModule::build(&dev);
+        let offset = dev.tensor([1.0, -2.0, 0.5]);
+
+        for _ in 0..100 {
+            let x = dev.sample_normal::<Rank3<16, 3, 8>>() * 0.1
+                + offset.clone().broadcast::<Rank3<16, 3, 8>, _>();
+            let _ = bn.forward_mut(x.trace());
+        }
+
+        assert_close_with_tolerance(&bn.running_mean.array(), &[1.0, -2.0, 0.5], 1e-2);
+        assert_close_with_tolerance(&bn.running_var.array(), &[0.01; 3], 5e-3);
+
+        // running stats shouldn't be updated during inference
+        let m = bn.running_mean.clone();
+        let v = bn.running_var.clone();
+        let _ = bn.forward(dev.sample_normal::<Rank2<2, 3>>());
+        assert_eq!(bn.running_mean.array(), m.array());
+        assert_eq!(bn.running_var.array(), v.array());
+    }
+}
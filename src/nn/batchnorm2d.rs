@@ -60,55 +60,103 @@ impl<const C: usize, D: Device<f32>> BatchNorm2D<C, D> {
     where
         Rank1<C>: BroadcastShapeTo<S, Ax>,
     {
-        let shape = *x.shape();
-
-        // statistics for normalizing
-        let std = (self.running_var.clone() + self.epsilon).sqrt();
-        let mean = self.running_mean.clone();
-
-        // normalize & affine
-        let x = sub(x, mean.broadcast_like(&shape));
-        let x = div(x, std.broadcast_like(&shape));
-        let x = mul(x, self.scale.clone().broadcast_like(&shape));
-        add(x, self.bias.clone().broadcast_like(&shape))
+        infer_fwd(
+            x,
+            &self.running_mean,
+            &self.running_var,
+            &self.scale,
+            &self.bias,
+            self.epsilon,
+        )
     }
 
-    fn train_fwd<S: Shape, T: Tape<D>, Ax: Axes>(
+    fn train_fwd<S, T: Tape<D>, Ax: Axes>(
         &mut self,
         x: Tensor<S, f32, D, T>,
     ) -> Tensor<S, f32, D, T>
     where
-        S: HasAxes<Ax> + ReduceShapeTo<Rank1<C>, Ax>,
+        S: Shape + HasAxes<Ax> + ReduceShapeTo<Rank1<C>, Ax>,
     {
-        let n = <S as HasAxes<Ax>>::size(x.shape()) as f32;
-        let shape = *x.shape();
+        train_fwd(
+            x,
+            &mut self.running_mean,
+            &mut self.running_var,
+            &self.scale,
+            &self.bias,
+            self.epsilon,
+            self.momentum,
+        )
+    }
+}
+
+/// Batch normalization over the axes `Ax` of `x` for inference, using the running statistics.
+/// This is shared by [BatchNorm1D](super::BatchNorm1D) and [BatchNorm2D].
+pub(super) fn infer_fwd<S: Shape, Ax: Axes, const C: usize, D: Device<f32>>(
+    x: Tensor<S, f32, D>,
+    running_mean: &Tensor<Rank1<C>, f32, D>,
+    running_var: &Tensor<Rank1<C>, f32, D>,
+    scale: &Tensor<Rank1<C>, f32, D>,
+    bias: &Tensor<Rank1<C>, f32, D>,
+    epsilon: f32,
+) -> Tensor<S, f32, D>
+where
+    Rank1<C>: BroadcastShapeTo<S, Ax>,
+{
+    let shape = *x.shape();
 
-        // compute statistics for updating running stats later - on tape
-        let mean_chan = x.retaped::<T>().mean::<Rank1<C>, _>();
+    // statistics for normalizing
+    let std = (running_var.clone() + epsilon).sqrt();
+    let mean = running_mean.clone();
 
-        // update statistics since we are training - off tape
-        self.running_mean = self.running_mean.clone() * (1.0 - self.momentum)
-            + mean_chan.retaped::<NoneTape>() * self.momentum;
+    // normalize & affine
+    let x = sub(x, mean.broadcast_like(&shape));
+    let x = div(x, std.broadcast_like(&shape));
+    let x = mul(x, scale.clone().broadcast_like(&shape));
+    add(x, bias.clone().broadcast_like(&shape))
+}
 
-        let mean = mean_chan.broadcast_like(&shape);
-        let centered = x - mean;
+/// Batch normalization over the axes `Ax` of `x` for training, using the batch statistics and
+/// updating the running statistics. This is shared by [BatchNorm1D](super::BatchNorm1D) and [BatchNorm2D].
+pub(super) fn train_fwd<S, T: Tape<D>, Ax: Axes, const C: usize, D: Device<f32>>(
+    x: Tensor<S, f32, D, T>,
+    running_mean: &mut Tensor<Rank1<C>, f32, D>,
+    running_var: &mut Tensor<Rank1<C>, f32, D>,
+    scale: &Tensor<Rank1<C>, f32, D>,
+    bias: &Tensor<Rank1<C>, f32, D>,
+    epsilon: f32,
+    momentum: f32,
+) -> Tensor<S, f32, D, T>
+where
+    S: Shape + HasAxes<Ax> + ReduceShapeTo<Rank1<C>, Ax>,
+{
+    let n = <S as HasAxes<Ax>>::size(x.shape()) as f32;
+    let shape = *x.shape();
 
-        let var_chan = centered.retaped::<T>().square().mean::<Rank1<C>, _>();
+    // compute statistics for updating running stats later - on tape
+    let mean_chan = x.retaped::<T>().mean::<Rank1<C>, _>();
 
-        // NOTE: uses unbiased variance in running estimate
-        self.running_var = self.running_var.clone() * (1.0 - self.momentum)
-            + var_chan.retaped::<NoneTape>() * (self.momentum * n / (n - 1.0));
+    // update statistics since we are training - off tape
+    *running_mean =
+        running_mean.clone() * (1.0 - momentum) + mean_chan.retaped::<NoneTape>() * momentum;
 
-        // statistics for normalizing - on tape
-        let std = (var_chan + self.epsilon).sqrt().broadcast_like(&shape);
+    let mean = mean_chan.broadcast_like(&shape);
+    let centered = x - mean;
 
-        // record broadcast of scale & bias - on tape
-        let scale = self.scale.retaped::<T>().broadcast_like(&shape);
-        let bias = self.bias.retaped::<T>().broadcast_like(&shape);
+    let var_chan = centered.retaped::<T>().square().mean::<Rank1<C>, _>();
 
-        // normalize & affine - on tape
-        (centered / std) * scale + bias
-    }
+    // NOTE: uses unbiased variance in running estimate
+    *running_var = running_var.clone() * (1.0 - momentum)
+        + var_chan.retaped::<NoneTape>() * (momentum * n / (n - 1.0));
+
+    // statistics for normalizing - on tape
+    let std = (var_chan + epsilon).sqrt().broadcast_like(&shape);
+
+    // record broadcast of scale & bias - on tape
+    let scale = scale.retaped::<T>().broadcast_like(&shape);
+    let bias = bias.retaped::<T>().broadcast_like(&shape);
+
+    // normalize & affine - on tape
+    (centered / std) * scale + bias
 }
 
 impl<const C: usize, H: Dim, W: Dim, D: Device<f32>>
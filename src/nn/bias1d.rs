@@ -0,0 +1,153 @@
+use crate::{gradients::Tape, optim::*, shapes::*, tensor::*, tensor_ops::*};
+
+use super::{BuildModule, Module, ModuleMut, ResetParams, ToDevice};
+
+/// Adds a learnable bias vector to the last axis of the input, i.e. `x + bias`.
+///
+/// Initializes [Self::bias] with zeros.
+///
+/// # Generics
+/// - `M` The size of the last axis of the input.
+/// - `D` The device the parameters are stored on.
+/// - `E` The dtype of the parameters, defaults to `f32`.
+///
+/// # Examples
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// type Model = Bias1D<5>;
+/// let model = Model::build_on_device(&dev);
+/// // single item forward
+/// let _: Tensor<Rank1<5>, f32, _> = model.forward(dev.zeros::<Rank1<5>>());
+/// // batched forward
+/// let _: Tensor<Rank2<10, 5>, f32, _> = model.forward(dev.zeros::<Rank2<10, 5>>());
+/// // batched sequence forward
+/// let _: Tensor<Rank3<10, 3, 5>, f32, _> = model.forward(dev.zeros::<Rank3<10, 3, 5>>());
+/// ```
+#[derive(Debug, Clone)]
+pub struct Bias1D<const M: usize, D: Device<E> = Cpu, E: Dtype = f32> {
+    /// Bias vector, shape (M, )
+    pub bias: Tensor<Rank1<M>, E, D>,
+}
+
+impl<const M: usize, D: Device<E>, E: Dtype> GradientUpdate<D, E> for Bias1D<M, D, E> {
+    fn update<U>(&mut self, updater: &mut U, unused: &mut UnusedTensors) -> Result<(), D::Err>
+    where
+        U: ParamUpdater<D, E>,
+    {
+        self.bias.update(updater, unused)
+    }
+}
+
+impl<const M: usize, D: Device<E>, E: Dtype> BuildModule<D, E> for Bias1D<M, D, E> {
+    fn try_build(device: &D) -> Result<Self, D::Err> {
+        Ok(Self {
+            bias: device.try_zeros()?,
+        })
+    }
+}
+
+impl<const M: usize, D: Device<E>, E: Dtype> ResetParams<D, E> for Bias1D<M, D, E> {
+    fn try_reset_params(&mut self) -> Result<(), D::Err> {
+        self.bias.try_fill_with_zeros()
+    }
+}
+
+impl<const M: usize, D1: Device<E>, D2: Device<E>, E: Dtype> ToDevice<D2> for Bias1D<M, D1, E> {
+    type Output = Bias1D<M, D2, E>;
+    fn to_device(&self, device: &D2) -> Self::Output {
+        Bias1D {
+            bias: self.bias.to_device(device),
+        }
+    }
+}
+
+impl<const M: usize, D: Device<E>, E: Dtype, T: Tape<D>> Module<Tensor<Rank1<M>, E, D, T>>
+    for Bias1D<M, D, E>
+{
+    type Output = Tensor<Rank1<M>, E, D, T>;
+    fn forward(&self, input: Tensor<Rank1<M>, E, D, T>) -> Self::Output {
+        input + self.bias.clone()
+    }
+}
+
+impl<B: Dim, const M: usize, D: Device<E>, E: Dtype, T: Tape<D>>
+    Module<Tensor<(B, Const<M>), E, D, T>> for Bias1D<M, D, E>
+{
+    type Output = Tensor<(B, Const<M>), E, D, T>;
+    fn forward(&self, input: Tensor<(B, Const<M>), E, D, T>) -> Self::Output {
+        self.bias.retaped::<T>().broadcast_like(input.shape()) + input
+    }
+}
+
+impl<B: Dim, S: Dim, const M: usize, D: Device<E>, E: Dtype, T: Tape<D>>
+    Module<Tensor<(B, S, Const<M>), E, D, T>> for Bias1D<M, D, E>
+{
+    type Output = Tensor<(B, S, Const<M>), E, D, T>;
+    fn forward(&self, input: Tensor<(B, S, Const<M>), E, D, T>) -> Self::Output {
+        self.bias.retaped::<T>().broadcast_like(input.shape()) + input
+    }
+}
+
+impl<T, const M: usize, D: Device<E>, E: Dtype> ModuleMut<T> for Bias1D<M, D, E>
+where
+    Self: Module<T>,
+{
+    type Output = <Self as Module<T>>::Output;
+    fn forward_mut(&mut self, input: T) -> Self::Output {
+        self.forward(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{nn::BuildOnDevice, tests::*};
+
+    #[test]
+    fn test_bias1d_initialize() {
+        let dev: TestDevice = Default::default();
+        let mut m = <Bias1D<3>>::build_on_device(&dev);
+        assert_eq!(m.bias.array(), [0.0; 3]);
+        m.bias = dev.tensor([1.0, 2.0, 3.0]);
+        m.reset_params();
+        assert_eq!(m.bias.array(), [0.0; 3]);
+    }
+
+    #[test]
+    fn test_bias1d_forward_backward() {
+        let dev: TestDevice = Default::default();
+        let m: Bias1D<3, _> = Bias1D {
+            bias: dev.tensor([1.0, -2.0, 0.5]),
+        };
+
+        let y = m.forward(dev.tensor([1.0, 1.0, 1.0]).trace());
+        assert_eq!(y.array(), [2.0, -1.0, 1.5]);
+        let g = y.sum().backward();
+        assert_eq!(g.get(&m.bias).array(), [1.0; 3]);
+
+        let y = m.forward(dev.zeros::<Rank2<2, 3>>().trace());
+        assert_eq!(y.array(), [[1.0, -2.0, 0.5]; 2]);
+        let g = y.sum().backward();
+        assert_eq!(g.get(&m.bias).array(), [2.0; 3]);
+
+        let y = m.forward(dev.zeros::<Rank3<4, 2, 3>>().trace());
+        assert_eq!(y.array(), [[[1.0, -2.0, 0.5]; 2]; 4]);
+        let g = y.sum().backward();
+        assert_eq!(g.get(&m.bias).array(), [8.0; 3]);
+    }
+
+    #[test]
+    fn test_bias1d_with_optimizer() {
+        let dev: TestDevice = Default::default();
+        let mut m = <Bias1D<3>>::build_on_device(&dev);
+        let mut opt = Sgd::new(&m, Default::default());
+        let g = m
+            .forward(dev.tensor([[1.0, 2.0, 3.0]; 2]).trace())
+            .square()
+            .mean()
+            .backward();
+        opt.update(&mut m, g).expect("");
+        assert_ne!(m.bias.array(), [0.0; 3]);
+    }
+}
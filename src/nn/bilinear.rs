@@ -0,0 +1,273 @@
+use crate::{gradients::*, optim::*, shapes::*, tensor::*, tensor_ops::*};
+
+use super::module::{BuildModule, Module, ModuleMut, ResetParams, ToDevice};
+
+use num_traits::Float;
+use rand_distr::{uniform::SampleUniform, Uniform};
+
+/// A bilinear transformation of two inputs, `x^T * weight * y + bias`. **Pytorch equivalent**: `nn.Bilinear`
+///
+/// Each of the `O` outputs has its own `(I1, I2)` matrix in [Self::weight].
+/// This is named `BilinearLayer` because [crate::tensor_ops::Bilinear] is the
+/// bilinear upsampling method.
+///
+/// Initializes [Self::weight] and [Self::bias] from a Uniform distribution
+/// between [-1 / sqrt(I1), 1 / sqrt(I1)].
+///
+/// # Generics
+/// - `I1` The size of the first input.
+/// - `I2` The size of the second input.
+/// - `O` The size of the output.
+/// - `D` The device the parameters are stored on.
+/// - `E` The dtype of the parameters, defaults to `f32`.
+///
+/// # Examples
+/// The input is a tuple of the two inputs, both either single items or batches,
+/// with the same type of tape.
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// type Model = BilinearLayer<5, 3, 2>;
+/// let model = Model::build_on_device(&dev);
+/// // single item forward
+/// let _: Tensor<Rank1<2>, f32, _> = model.forward((dev.zeros::<Rank1<5>>(), dev.zeros::<Rank1<3>>()));
+/// // batched forward
+/// let _: Tensor<Rank2<10, 2>, f32, _> =
+///     model.forward((dev.zeros::<Rank2<10, 5>>(), dev.zeros::<Rank2<10, 3>>()));
+/// ```
+#[derive(Debug, Clone)]
+pub struct BilinearLayer<
+    const I1: usize,
+    const I2: usize,
+    const O: usize,
+    D: Device<E> = Cpu,
+    E: Dtype = f32,
+> {
+    /// Weight tensor, shape (O, I1, I2)
+    pub weight: Tensor<Rank3<O, I1, I2>, E, D>,
+
+    /// Bias vector, shape (O, )
+    pub bias: Tensor<Rank1<O>, E, D>,
+}
+
+impl<const I1: usize, const I2: usize, const O: usize, D: Device<E>, E: Dtype> GradientUpdate<D, E>
+    for BilinearLayer<I1, I2, O, D, E>
+{
+    fn update<U>(&mut self, updater: &mut U, unused: &mut UnusedTensors) -> Result<(), D::Err>
+    where
+        U: ParamUpdater<D, E>,
+    {
+        self.weight.update(updater, unused)?;
+        self.bias.update(updater, unused)?;
+        Ok(())
+    }
+}
+
+impl<const I1: usize, const I2: usize, const O: usize, D, E> BuildModule<D, E>
+    for BilinearLayer<I1, I2, O, D, E>
+where
+    D: Device<E>,
+    E: Dtype + Float + SampleUniform,
+{
+    fn try_build(device: &D) -> Result<Self, D::Err> {
+        let bound = E::one() / E::from(I1).unwrap().sqrt();
+        let weight = device.try_sample(Uniform::new(-bound, bound))?;
+        let bias = device.try_sample(Uniform::new(-bound, bound))?;
+        Ok(Self { weight, bias })
+    }
+}
+
+impl<const I1: usize, const I2: usize, const O: usize, D, E> ResetParams<D, E>
+    for BilinearLayer<I1, I2, O, D, E>
+where
+    D: Device<E>,
+    E: Dtype + Float + SampleUniform,
+{
+    fn try_reset_params(&mut self) -> Result<(), D::Err> {
+        let bound = E::one() / E::from(I1).unwrap().sqrt();
+        self.weight
+            .try_fill_with_distr(Uniform::new(-bound, bound))?;
+        self.bias.try_fill_with_distr(Uniform::new(-bound, bound))?;
+        Ok(())
+    }
+}
+
+impl<const I1: usize, const I2: usize, const O: usize, D1, D2, E> ToDevice<D2>
+    for BilinearLayer<I1, I2, O, D1, E>
+where
+    D1: Device<E>,
+    D2: Device<E>,
+    E: Dtype,
+{
+    type Output = BilinearLayer<I1, I2, O, D2, E>;
+    fn to_device(&self, device: &D2) -> Self::Output {
+        BilinearLayer {
+            weight: self.weight.to_device(device),
+            bias: self.bias.to_device(device),
+        }
+    }
+}
+
+impl<const I1: usize, const I2: usize, const O: usize, D: Device<E>, E: Dtype, T>
+    Module<(Tensor<Rank1<I1>, E, D, T>, Tensor<Rank1<I2>, E, D, T>)>
+    for BilinearLayer<I1, I2, O, D, E>
+where
+    T: Tape<D> + Merge<T>,
+{
+    type Output = Tensor<Rank1<O>, E, D, T>;
+
+    /// 1d forward, contracting `y` and then `x` with the broadcasted weight.
+    fn forward(
+        &self,
+        (x, y): (Tensor<Rank1<I1>, E, D, T>, Tensor<Rank1<I2>, E, D, T>),
+    ) -> Self::Output {
+        let wy = self.weight.retaped::<T>() * y.broadcast::<_, Axes2<0, 1>>();
+        let xwy = wy.sum::<_, Axis<2>>() * x.broadcast::<_, Axis<0>>();
+        xwy.sum::<_, Axis<1>>() + self.bias.retaped::<T>()
+    }
+}
+
+impl<B: Dim, const I1: usize, const I2: usize, const O: usize, D: Device<E>, E: Dtype, T>
+    Module<(
+        Tensor<(B, Const<I1>), E, D, T>,
+        Tensor<(B, Const<I2>), E, D, T>,
+    )> for BilinearLayer<I1, I2, O, D, E>
+where
+    T: Tape<D> + Merge<T>,
+{
+    type Output = Tensor<(B, Const<O>), E, D, T>;
+
+    /// Batched forward using [matmul()] of the weight with the transposed `y`.
+    fn forward(
+        &self,
+        (x, y): (
+            Tensor<(B, Const<I1>), E, D, T>,
+            Tensor<(B, Const<I2>), E, D, T>,
+        ),
+    ) -> Self::Output {
+        let batch = x.shape().0;
+        // (O, I1, I2) x (I2, B) -> (O, I1, B)
+        let wy = self.weight.retaped::<T>().matmul(y.permute());
+        let x = x
+            .permute::<_, Axes2<1, 0>>()
+            .broadcast_like::<_, Axis<0>>(wy.shape());
+        let xwy = (wy * x).sum::<_, Axis<1>>().permute::<_, Axes2<1, 0>>();
+        xwy + self.bias.retaped::<T>().broadcast_like(&(batch, Const))
+    }
+}
+
+impl<T, const I1: usize, const I2: usize, const O: usize, D: Device<E>, E: Dtype> ModuleMut<T>
+    for BilinearLayer<I1, I2, O, D, E>
+where
+    Self: Module<T>,
+{
+    type Output = <Self as Module<T>>::Output;
+    fn forward_mut(&mut self, input: T) -> Self::Output {
+        self.forward(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{tests::SimpleUpdater, BuildOnDevice};
+    use crate::{tests::*, unique_id::HasUniqueId};
+
+    #[test]
+    fn test_bilinear_initialize() {
+        let dev: TestDevice = Default::default();
+        let m = BilinearLayer::<100, 3, 2>::build_on_device(&dev);
+        let bound = 1.0 / 100.0f32.sqrt();
+        for v in m.weight.as_vec().into_iter().chain(m.bias.as_vec()) {
+            assert!(-bound <= v && v <= bound && v != 0.0);
+        }
+    }
+
+    #[test]
+    fn test_bilinear_forward_1d() {
+        let dev: TestDevice = Default::default();
+        let m = BilinearLayer::<3, 2, 1>::build_on_device(&dev);
+        let w = m.weight.array()[0];
+        let b = m.bias.array()[0];
+
+        let x = dev.tensor([0.5, -1.0, 2.0]);
+        let y = dev.tensor([1.5, -0.5]);
+        let (xa, ya) = (x.array(), y.array());
+        let r = m.forward((x.trace(), y.trace()));
+
+        let mut expected = b;
+        for i in 0..3 {
+            for j in 0..2 {
+                expected += xa[i] * w[i][j] * ya[j];
+            }
+        }
+        assert_close(&r.array(), &[expected]);
+
+        let g = r.sum().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &w.map(|w_i| w_i[0] * ya[0] + w_i[1] * ya[1]),
+        );
+        assert_close(
+            &g.get(&y).array(),
+            &[0, 1].map(|j| (0..3).map(|i| xa[i] * w[i][j]).sum::<f32>()),
+        );
+        assert_close(
+            &g.get(&m.weight).array(),
+            &[xa.map(|x_i| ya.map(|y_j| x_i * y_j))],
+        );
+        assert_eq!(g.get(&m.bias).array(), [1.0]);
+    }
+
+    #[test]
+    fn test_bilinear_batched_matches_1d() {
+        let dev: TestDevice = Default::default();
+        let m = BilinearLayer::<3, 4, 2>::build_on_device(&dev);
+        let x: Tensor<Rank2<5, 3>, f32, _> = dev.sample_normal();
+        let y: Tensor<Rank2<5, 4>, f32, _> = dev.sample_normal();
+
+        let r = m.forward((x.trace(), y.trace()));
+        let r_array = r.array();
+        let g = r.sum().backward();
+        for (b, r_array_b) in r_array.iter().enumerate() {
+            let x_b = x.clone().select(dev.tensor(b));
+            let y_b = y.clone().select(dev.tensor(b));
+            let r_b = m.forward((x_b.trace(), y_b.trace()));
+            assert_close(&r_b.array(), r_array_b);
+
+            let g_b = r_b.sum().backward();
+            assert_close(&g_b.get(&x_b).array(), &g.get(&x).array()[b]);
+            assert_close(&g_b.get(&y_b).array(), &g.get(&y).array()[b]);
+        }
+    }
+
+    #[test]
+    fn test_bilinear_runtime_batch() {
+        let dev: TestDevice = Default::default();
+        let m = BilinearLayer::<3, 4, 2>::build_on_device(&dev);
+        let x: Tensor<(usize, Const<3>), f32, _> = dev.zeros_like(&(7, Const));
+        let y: Tensor<(usize, Const<4>), f32, _> = dev.zeros_like(&(7, Const));
+        let r = m.forward((x, y));
+        assert_eq!(r.shape(), &(7, Const));
+        assert_eq!(r.as_vec(), m.bias.as_vec().repeat(7));
+    }
+
+    #[test]
+    fn test_bilinear_missing_gradients() {
+        let dev: TestDevice = Default::default();
+        let mut model = BilinearLayer::<3, 2, 2>::build_on_device(&dev);
+        let mut g: SimpleUpdater = Default::default();
+
+        // no gradients present
+        let mut unused = Default::default();
+        model.update(&mut g, &mut unused).unwrap();
+        assert_eq!(&unused.ids, &[*model.weight.id(), *model.bias.id()]);
+
+        g.0.try_alloc_for(&model.weight).unwrap();
+
+        // weight gradient is present
+        let mut unused = Default::default();
+        model.update(&mut g, &mut unused).unwrap();
+        assert_eq!(&unused.ids, &[*model.bias.id()]);
+    }
+}
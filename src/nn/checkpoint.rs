@@ -0,0 +1,173 @@
+use crate::{
+    gradients::{NoneTape, OwnedTape, Tape},
+    optim::*,
+    shapes::*,
+    tensor::{PutTape, SplitTape, Tensor},
+    tensor_ops::Device,
+};
+
+use super::{BuildModule, Module, ModuleMut, ResetParams, ToDevice};
+
+/// Activation checkpointing around `M`: the forward pass of `M` is run **without** a tape,
+/// so none of its intermediate values are kept alive until backward. Instead the backward
+/// pass runs [Module::forward()] of `M` again on the saved input to recompute them, and then
+/// backprops through the recomputed graph.
+///
+/// This trades extra compute for lower peak memory, and results in the same gradients as
+/// using `M` directly.
+///
+/// [ModuleMut::forward_mut()] calls [Module::forward()], since `M` is run twice and should
+/// behave the same both times.
+///
+/// # Generics
+/// - `M`: The module to recompute during backward.
+///
+/// # Examples
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// type Model = Checkpoint<(Linear<5, 10>, ReLU, Linear<10, 2>)>;
+/// let model = Model::build_on_device(&dev);
+/// let x: Tensor<Rank1<5>, f32, _> = dev.zeros();
+/// let y = model.forward(x.trace());
+/// let _ = y.sum().backward();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Checkpoint<M>(pub M);
+
+impl<D: Device<E>, E: Dtype, M: GradientUpdate<D, E>> GradientUpdate<D, E> for Checkpoint<M> {
+    fn update<U>(&mut self, updater: &mut U, unused: &mut UnusedTensors) -> Result<(), D::Err>
+    where
+        U: ParamUpdater<D, E>,
+    {
+        self.0.update(updater, unused)
+    }
+}
+
+impl<D: Device<E>, E: Dtype, M: BuildModule<D, E>> BuildModule<D, E> for Checkpoint<M> {
+    fn try_build(device: &D) -> Result<Self, <D>::Err> {
+        Ok(Self(BuildModule::try_build(device)?))
+    }
+}
+
+impl<D: Device<E>, E: Dtype, M: ResetParams<D, E>> ResetParams<D, E> for Checkpoint<M> {
+    fn try_reset_params(&mut self) -> Result<(), <D>::Err> {
+        self.0.try_reset_params()
+    }
+}
+
+impl<M: ToDevice<D>, D> ToDevice<D> for Checkpoint<M> {
+    type Output = Checkpoint<M::Output>;
+    fn to_device(&self, device: &D) -> Self::Output {
+        Checkpoint(self.0.to_device(device))
+    }
+}
+
+/// Without a tape there is nothing to recompute, so this is just `M`'s forward.
+impl<S: Shape, E: Dtype, D: Device<E>, M> Module<Tensor<S, E, D, NoneTape>> for Checkpoint<M>
+where
+    M: Module<Tensor<S, E, D, NoneTape>>,
+{
+    type Output = M::Output;
+    fn forward(&self, x: Tensor<S, E, D, NoneTape>) -> Self::Output {
+        self.0.forward(x)
+    }
+}
+
+impl<S: Shape, S2: Shape, E: Dtype, D: Device<E>, M> Module<Tensor<S, E, D, OwnedTape<D>>>
+    for Checkpoint<M>
+where
+    M: 'static
+        + Clone
+        + Module<Tensor<S, E, D, NoneTape>, Output = Tensor<S2, E, D, NoneTape>>
+        + Module<Tensor<S, E, D, OwnedTape<D>>, Output = Tensor<S2, E, D, OwnedTape<D>>>,
+{
+    type Output = Tensor<S2, E, D, OwnedTape<D>>;
+    fn forward(&self, x: Tensor<S, E, D, OwnedTape<D>>) -> Self::Output {
+        let (x, mut tape) = x.split_tape();
+        let out = self.0.forward(x.clone());
+        let phantom_out = out.clone();
+        let module = self.0.clone();
+        tape.try_alloc_grad(&x).unwrap();
+        tape.try_alloc_grad(&out).unwrap();
+        tape.add_backward_op(move |grads| {
+            // recompute the forward pass, accumulating into the existing gradients
+            let (y, mut inner) = module
+                .forward(x.trace_with(std::mem::take(grads)))
+                .split_tape();
+            inner.add_backward_op(move |grads| {
+                let (grad_y, grad_out) = grads.mut_and_ref(&y, &phantom_out);
+                *grad_y = grad_out.clone();
+                Ok(())
+            });
+            *grads = inner.0.execute()?;
+            Ok(())
+        });
+        out.put_tape(tape)
+    }
+}
+
+impl<T, M> ModuleMut<T> for Checkpoint<M>
+where
+    Self: Module<T>,
+{
+    type Output = <Self as Module<T>>::Output;
+    fn forward_mut(&mut self, x: T) -> Self::Output {
+        self.forward(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{assert_close, TestDevice};
+    use crate::{
+        nn::{BuildOnDevice, Linear, Tanh},
+        tensor::*,
+        tensor_ops::*,
+    };
+
+    #[test]
+    fn test_checkpoint_same_gradients() {
+        let dev: TestDevice = Default::default();
+        let model = Checkpoint::<Linear<4, 4>>::build_on_device(&dev);
+        let x: Tensor<Rank2<3, 4>, f32, _> = dev.sample_normal();
+
+        let y1 = model.forward(x.trace());
+        let y2 = model.0.forward(x.trace());
+        assert_eq!(y1.array(), y2.array());
+
+        let g1 = y1.square().mean().backward();
+        let g2 = y2.square().mean().backward();
+        assert_close(
+            &g1.get(&model.0.weight).array(),
+            &g2.get(&model.0.weight).array(),
+        );
+        assert_close(
+            &g1.get(&model.0.bias).array(),
+            &g2.get(&model.0.bias).array(),
+        );
+        assert_close(&g1.get(&x).array(), &g2.get(&x).array());
+    }
+
+    #[test]
+    fn test_checkpoint_lower_memory() {
+        let dev: TestDevice = Default::default();
+        type Model = (Linear<4, 64>, Tanh, Linear<64, 64>, Tanh, Linear<64, 4>);
+        let model = Checkpoint::<Model>::build_on_device(&dev);
+        let x: Tensor<Rank2<16, 4>, f32, _> = dev.sample_normal();
+        let before = dev.allocated_bytes();
+
+        let y1 = model.forward(x.trace());
+        let checkpointed = dev.allocated_bytes() - before;
+        let g1 = y1.square().mean().backward();
+        drop(g1);
+
+        let y2 = model.0.forward(x.trace());
+        let plain = dev.allocated_bytes() - before;
+        let g2 = y2.square().mean().backward();
+        drop(g2);
+
+        assert!(checkpointed < plain, "{checkpointed} vs {plain}");
+    }
+}
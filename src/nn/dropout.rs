@@ -147,7 +147,7 @@ impl<S: Shape, E: Dtype, D: Device<E>> ModuleMut<Tensor<S, E, D, OwnedTape<D>>>
 mod tests {
     use crate::{
         shapes::Rank1,
-        tensor::{AsArray, OnesTensor},
+        tensor::{AsArray, OnesTensor, SampleTensor},
         tests::TestDevice,
     };
 
@@ -158,7 +158,7 @@ mod tests {
         let dev: TestDevice = Default::default();
         let mut d1 = Dropout { p: 0.5 };
         let mut d2 = Dropout { p: 0.5 };
-        let t = dev.ones::<Rank1<100>>();
+        let t: Tensor<Rank1<100>, f32, _> = dev.ones();
         let r1 = d1.forward_mut(t.trace());
         let r2 = d2.forward_mut(t.trace());
         let r1_2 = d1.forward_mut(t.trace());
@@ -170,7 +170,7 @@ mod tests {
     fn test_dropout_no_tape() {
         let dev: TestDevice = Default::default();
         let dropout = Dropout { p: 0.5 };
-        let t = dev.ones::<Rank1<100>>();
+        let t: Tensor<Rank1<100>, f32, _> = dev.ones();
         let r = dropout.forward(t.clone());
         assert_eq!(t.array(), r.array());
     }
@@ -179,8 +179,29 @@ mod tests {
     fn test_dropout_tape() {
         let dev: TestDevice = Default::default();
         let mut dropout = Dropout { p: 0.5 };
-        let t = dev.ones::<Rank1<100>>();
+        let t: Tensor<Rank1<100>, f32, _> = dev.ones();
         let r = dropout.forward_mut(t.trace());
         assert_ne!(t.array(), r.array());
     }
+
+    #[test]
+    fn test_dropout_zero_is_identity() {
+        let dev: TestDevice = Default::default();
+        let mut dropout = Dropout { p: 0.0 };
+        let t: Tensor<Rank1<100>, f32, _> = dev.sample_normal();
+        let r = dropout.forward_mut(t.trace());
+        assert_eq!(t.array(), r.array());
+        let g = r.exp().sum().backward();
+        assert_eq!(g.get(&t).array(), t.array().map(f32::exp));
+    }
+
+    #[test]
+    fn test_dropout_preserves_expected_value() {
+        let dev: TestDevice = Default::default();
+        let mut dropout: DropoutOneIn<4> = Default::default();
+        let t = dev.ones::<Rank1<1000>>();
+        let r = dropout.forward_mut(t.trace());
+        let mean = r.array().iter().sum::<f32>() / 1000.0;
+        assert!((mean - 1.0).abs() < 0.1, "{mean}");
+    }
 }
@@ -1,4 +1,10 @@
-use crate::{gradients::Tape, optim::*, shapes::*, tensor::*, tensor_ops::*};
+use crate::{
+    gradients::{Merge, Tape},
+    optim::*,
+    shapes::*,
+    tensor::*,
+    tensor_ops::*,
+};
 
 use super::module::{BuildModule, Module, ModuleMut, ResetParams, ToDevice};
 
@@ -19,17 +25,228 @@ use super::module::{BuildModule, Module, ModuleMut, ResetParams, ToDevice};
 /// # use dfdx::prelude::*;
 /// # let dev: Cpu = Default::default();
 /// let mut model: Embedding<7, 2> = BuildModule::build(&dev);
+/// // single id
+/// let inputs: Tensor<Rank0, usize, _> = dev.zeros();
+/// let _: Tensor<(Const<2>,), f32, _> = model.forward(inputs);
 /// // single sequence of ids
 /// let inputs: Tensor<Rank1<5>, usize, _> = dev.zeros();
 /// let _: Tensor<(Const<5>, Const<2>,), f32, _> = model.forward(inputs);
+/// // sequence of ids with a runtime length
+/// let inputs: Tensor<(usize,), usize, _> = dev.zeros_like(&(8,));
+/// let _: Tensor<(usize, Const<2>), f32, _> = model.forward(inputs);
 /// // batched sequence of ids
 /// let inputs: Tensor<Rank2<10, 5>, usize, _> = dev.zeros();
 /// let _: Tensor<(Const<10>, Const<5>, Const<2>), f32, _> = model.forward(inputs);
 /// ```
+///
+/// # Padding
+/// [Embedding::with_padding_idx] marks one row of [Self::weight] as the padding vector, like
+/// `padding_idx` in pytorch. That row is filled with zeros by [ResetParams], and never receives
+/// any gradient, so optimizers will not update it and it keeps embedding to zeros. Like in
+/// pytorch, a padding row that isn't zero (e.g. from [Embedding::from_weight]) is embedded as is.
+///
+/// The padding index must be less than `VOCAB`.
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let model: Embedding<7, 2> = BuildModule::build(&dev);
+/// let mut model = model.with_padding_idx(0);
+/// model.reset_params();
+/// let y: Tensor<Rank2<3, 2>, f32, _> = model.forward(dev.tensor([0, 1, 0]));
+/// assert_eq!(y.array()[0], [0.0; 2]);
+/// ```
+///
+/// # Scaling gradients by frequency
+/// Setting [Self::scale_grad_by_freq] divides the gradient of each row of [Self::weight]
+/// by the number of times its id appears in the input, like `scale_grad_by_freq` in pytorch.
+/// The forward pass is unchanged. Single ids always appear once, so this only affects sequences.
 #[derive(Debug, Clone)]
 pub struct Embedding<const VOCAB: usize, const DIM: usize, D: Device<f32> = Cpu> {
     /// Transposed weight matrix, shape (I, O)
     pub weight: Tensor<Rank2<VOCAB, DIM>, f32, D>,
+
+    /// Index of the padding row in [Self::weight], if any.
+    padding_idx: Option<usize>,
+
+    /// 1 for every row of [Self::weight], except 0 at [Self::padding_idx].
+    padding_mask: Option<Tensor<Rank1<VOCAB>, f32, D>>,
+
+    /// Whether to divide the gradient of each row of [Self::weight] by the number of
+    /// times it appears in the input.
+    pub scale_grad_by_freq: bool,
+}
+
+impl<const VOCAB: usize, const DIM: usize, D: Device<f32>> Embedding<VOCAB, DIM, D> {
+    /// Creates an [Embedding] from an existing `weight`, e.g. pretrained embeddings.
+    /// There is no padding row and [Self::scale_grad_by_freq] is false.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let model = Embedding::from_weight(dev.tensor([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]));
+    /// let y: Tensor<Rank1<2>, f32, _> = model.forward(dev.tensor(2));
+    /// assert_eq!(y.array(), [5.0, 6.0]);
+    /// ```
+    pub fn from_weight(weight: Tensor<Rank2<VOCAB, DIM>, f32, D>) -> Self {
+        Self {
+            weight,
+            padding_idx: None,
+            padding_mask: None,
+            scale_grad_by_freq: false,
+        }
+    }
+
+    /// Index of the padding row in [Self::weight], if any. See [Embedding::with_padding_idx].
+    pub fn padding_idx(&self) -> Option<usize> {
+        self.padding_idx
+    }
+
+    /// Puts `tape` into a clone of [Self::weight]. If [Self::padding_idx] is set, the gradient of
+    /// the padding row is multiplied by 0 so that no gradient flows back into it.
+    fn try_weight_with_tape<T: Tape<D>>(
+        &self,
+        tape: T,
+    ) -> Result<Tensor<Rank2<VOCAB, DIM>, f32, D, T>, D::Err> {
+        let weight = self.weight.clone().put_tape(tape);
+        match &self.padding_mask {
+            None => Ok(weight),
+            Some(mask) => try_scale_grad(weight, mask.clone().try_broadcast()?),
+        }
+    }
+
+    /// Same as [Self::try_weight_with_tape], but if [Self::scale_grad_by_freq] is set, the gradient
+    /// of each row is divided by the number of times it appears in `ids`.
+    fn try_weight_for_ids<S, T: Tape<D>>(
+        &self,
+        ids: &Tensor<S, usize, D>,
+        tape: T,
+    ) -> Result<Tensor<Rank2<VOCAB, DIM>, f32, D, T>, D::Err>
+    where
+        S: Shape + AppendDim<Const<1>>,
+        Rank2<VOCAB, 1>: ReplaceDimTo<S::Appended, S>,
+    {
+        if !self.scale_grad_by_freq {
+            return self.try_weight_with_tape(tape);
+        }
+        let weight = self.weight.clone().put_tape(tape);
+        let device = &self.weight.device;
+        let ones: Tensor<S::Appended, f32, D> = device.try_ones_like(&ids.shape().append(Const))?;
+        // count each id with a scatter of ones, into a trailing axis of size 1 so that
+        // batched ids use the batched scatter
+        let counts: Tensor<Rank2<VOCAB, 1>, f32, D> = device.try_zeros()?;
+        let counts: Tensor<Rank1<VOCAB>, f32, D> = counts
+            .try_scatter_add(ids.clone(), ones)?
+            .try_reshape_like(&Default::default())?;
+        // rows that don't appear have no gradient, so clamping avoids dividing 0 by 0
+        let mut scale = counts.try_clamp(1.0, f32::INFINITY)?.try_recip()?;
+        if let Some(mask) = &self.padding_mask {
+            scale = scale.try_mul(mask.clone())?;
+        }
+        try_scale_grad(weight, scale.try_broadcast()?)
+    }
+
+    /// Embeds a sequence of ids, and then concatenates per-token `extra` features
+    /// after the embedding vectors along the feature axis. The output feature dimension
+    /// must be `DIM + EXTRA`, and is usually specified with a type annotation.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let model: Embedding<7, 2> = BuildModule::build(&dev);
+    /// let ids: Tensor<Rank1<5>, usize, _> = dev.zeros();
+    /// let extra: Tensor<Rank2<5, 3>, f32, _> = dev.zeros();
+    /// let _: Tensor<Rank2<5, 5>, f32, _> = model.forward_with_extra(ids, extra);
+    /// ```
+    pub fn forward_with_extra<Seq: Dim, Extra: Dim, New: Dim, T, R>(
+        &self,
+        ids: Tensor<(Seq,), usize, D, T>,
+        extra: Tensor<(Seq, Extra), f32, D, R>,
+    ) -> Tensor<(Seq, New), f32, D, T>
+    where
+        T: Tape<D> + Merge<R>,
+        R: Tape<D>,
+    {
+        self.try_forward_with_extra(ids, extra).unwrap()
+    }
+
+    /// Fallible version of [Embedding::forward_with_extra]
+    #[allow(clippy::type_complexity)]
+    pub fn try_forward_with_extra<Seq: Dim, Extra: Dim, New: Dim, T, R>(
+        &self,
+        ids: Tensor<(Seq,), usize, D, T>,
+        extra: Tensor<(Seq, Extra), f32, D, R>,
+    ) -> Result<Tensor<(Seq, New), f32, D, T>, D::Err>
+    where
+        T: Tape<D> + Merge<R>,
+        R: Tape<D>,
+    {
+        let (ids, tape) = ids.split_tape();
+        let weight = self.try_weight_for_ids(&ids, tape)?;
+        let emb = weight.try_gather_rows(ids)?;
+        emb.try_concat::<_, Axis<1>>(extra)
+    }
+
+    /// Fills the row at [Self::padding_idx] with zeros, if it is set.
+    fn try_zero_padding_row(&mut self) -> Result<(), D::Err> {
+        if let Some(mask) = &self.padding_mask {
+            let zeroed = self.weight.clone().try_mul(mask.clone().try_broadcast()?)?;
+            self.weight.storage = zeroed.storage;
+        }
+        Ok(())
+    }
+}
+
+impl<const VOCAB: usize, const DIM: usize, D> Embedding<VOCAB, DIM, D>
+where
+    D: Device<f32> + TensorFromArray<usize, Rank0, usize>,
+{
+    /// Marks the row `padding_idx` of [Self::weight] as the padding vector, see
+    /// [Embedding#padding]. This doesn't change [Self::weight], call [ResetParams::reset_params]
+    /// to fill the padding row with zeros.
+    ///
+    /// **Panics** if `padding_idx` is not less than `VOCAB`.
+    pub fn with_padding_idx(self, padding_idx: usize) -> Self {
+        self.try_with_padding_idx(padding_idx).unwrap()
+    }
+
+    /// Fallible version of [Embedding::with_padding_idx]. Returns an error if `padding_idx`
+    /// is not less than `VOCAB`.
+    pub fn try_with_padding_idx(mut self, padding_idx: usize) -> Result<Self, D::Err> {
+        let idx = self.weight.device.try_tensor(padding_idx)?;
+        let one_hot: Tensor<Rank1<VOCAB>, f32, D> = idx.try_one_hot()?;
+        self.padding_mask = Some(one_hot.try_negate()?.try_add(1.0)?);
+        self.padding_idx = Some(padding_idx);
+        Ok(self)
+    }
+}
+
+/// Multiplies the gradient of `x` by `scale` during backward, without changing the forward pass.
+fn try_scale_grad<S: Shape, D: Device<f32>, T: Tape<D>>(
+    x: Tensor<S, f32, D, T>,
+    scale: Tensor<S, f32, D>,
+) -> Result<Tensor<S, f32, D, T>, D::Err> {
+    let (x, mut tape) = x.split_tape();
+    let out = x.device.upgrade(x.storage.clone());
+    let phantom_out = out.clone();
+    tape.try_alloc_grad(&x)?;
+    tape.try_alloc_grad(&out)?;
+    tape.add_backward_op(move |grads| {
+        let (grad_x, grad_out) = grads.mut_and_ref(&x, &phantom_out);
+        let scaled = x.device.upgrade(grad_out.clone()).try_mul(scale)?;
+        *grad_x = x.device.upgrade(grad_x.clone()).try_add(scaled)?.storage;
+        Ok(())
+    });
+    Ok(out.put_tape(tape))
+}
+
+impl<const VOCAB: usize, const DIM: usize, D: Device<f32>, T: Tape<D>>
+    Module<Tensor<Rank0, usize, D, T>> for Embedding<VOCAB, DIM, D>
+{
+    type Output = Tensor<Rank1<DIM>, f32, D, T>;
+    fn forward(&self, input: Tensor<Rank0, usize, D, T>) -> Self::Output {
+        let (input, tape) = input.split_tape();
+        self.try_weight_with_tape(tape).unwrap().select(input)
+    }
 }
 
 impl<const VOCAB: usize, const DIM: usize, const SEQ: usize, D: Device<f32>, T: Tape<D>>
@@ -38,7 +255,21 @@ impl<const VOCAB: usize, const DIM: usize, const SEQ: usize, D: Device<f32>, T:
     type Output = Tensor<Rank2<SEQ, DIM>, f32, D, T>;
     fn forward(&self, input: Tensor<Rank1<SEQ>, usize, D, T>) -> Self::Output {
         let (input, tape) = input.split_tape();
-        self.weight.clone().put_tape(tape).gather(input)
+        self.try_weight_for_ids(&input, tape)
+            .unwrap()
+            .gather_rows(input)
+    }
+}
+
+impl<const VOCAB: usize, const DIM: usize, D: Device<f32>, T: Tape<D>>
+    Module<Tensor<(usize,), usize, D, T>> for Embedding<VOCAB, DIM, D>
+{
+    type Output = Tensor<(usize, Const<DIM>), f32, D, T>;
+    fn forward(&self, input: Tensor<(usize,), usize, D, T>) -> Self::Output {
+        let (input, tape) = input.split_tape();
+        self.try_weight_for_ids(&input, tape)
+            .unwrap()
+            .gather_rows(input)
     }
 }
 
@@ -54,7 +285,7 @@ impl<
     type Output = Tensor<Rank3<BATCH, SEQ, DIM>, f32, D, T>;
     fn forward(&self, input: Tensor<Rank2<BATCH, SEQ>, usize, D, T>) -> Self::Output {
         let (input, tape) = input.split_tape();
-        self.weight.clone().put_tape(tape).gather(input)
+        self.try_weight_for_ids(&input, tape).unwrap().gather(input)
     }
 }
 
@@ -88,7 +319,7 @@ impl<const VOCAB: usize, const DIM: usize, D: Device<f32>> ResetParams<D, f32>
         let bound: f32 = 1.0 / (VOCAB as f32).sqrt();
         let distr = rand_distr::Uniform::new(-bound, bound);
         self.weight.try_fill_with_distr(distr)?;
-        Ok(())
+        self.try_zero_padding_row()
     }
 }
 
@@ -99,7 +330,7 @@ impl<const VOCAB: usize, const DIM: usize, D: Device<f32>> BuildModule<D, f32>
         let bound: f32 = 1.0 / (VOCAB as f32).sqrt();
         let distr = rand_distr::Uniform::new(-bound, bound);
         let weight = device.try_sample(distr)?;
-        Ok(Self { weight })
+        Ok(Self::from_weight(weight))
     }
 }
 
@@ -110,6 +341,110 @@ impl<const VOCAB: usize, const DIM: usize, D1: Device<f32>, D2: Device<f32>> ToD
     fn to_device(&self, device: &D2) -> Self::Output {
         Embedding {
             weight: self.weight.to_device(device),
+            padding_idx: self.padding_idx,
+            padding_mask: self.padding_mask.as_ref().map(|m| m.to_device(device)),
+            scale_grad_by_freq: self.scale_grad_by_freq,
+        }
+    }
+}
+
+/// An [Embedding] whose weight is also used as the output projection, like the tied
+/// input & output embeddings of a language model.
+///
+/// Calling [Module::forward] with `usize` ids looks up rows of the weight, exactly like
+/// [Embedding]. Calling it with `f32` vectors of size `DIM` multiplies them by the transposed
+/// weight, which produces `VOCAB` logits (this is [super::LinearNoBias] with the same weight).
+///
+/// Since both directions use the one weight tensor, [GradientUpdate] sees a single parameter
+/// whose gradient is the sum of both uses, and [ToDevice] keeps them tied.
+///
+/// [Embedding::with_padding_idx] applies to both directions: the padding row gets no gradient
+/// from the projection either, so it keeps producing a logit of 0 if it is zero.
+///
+/// # Examples
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let embedding: Embedding<7, 2> = BuildModule::build(&dev);
+/// let model = TiedEmbedding { embedding };
+/// let x: Tensor<Rank2<3, 2>, f32, _> = model.forward(dev.tensor([0, 4, 6]));
+/// let _: Tensor<Rank2<3, 7>, f32, _> = model.forward(x);
+/// ```
+#[derive(Debug, Clone)]
+pub struct TiedEmbedding<const VOCAB: usize, const DIM: usize, D: Device<f32> = Cpu> {
+    pub embedding: Embedding<VOCAB, DIM, D>,
+}
+
+impl<S: Shape, const VOCAB: usize, const DIM: usize, D: Device<f32>, T: Tape<D>>
+    Module<Tensor<S, usize, D, T>> for TiedEmbedding<VOCAB, DIM, D>
+where
+    Embedding<VOCAB, DIM, D>: Module<Tensor<S, usize, D, T>>,
+{
+    type Output = <Embedding<VOCAB, DIM, D> as Module<Tensor<S, usize, D, T>>>::Output;
+    fn forward(&self, input: Tensor<S, usize, D, T>) -> Self::Output {
+        self.embedding.forward(input)
+    }
+}
+
+impl<S: Shape, const VOCAB: usize, const DIM: usize, D: Device<f32>, T: Tape<D>>
+    Module<Tensor<S, f32, D, T>> for TiedEmbedding<VOCAB, DIM, D>
+where
+    Tensor<S, f32, D, T>: TryMatMul<Tensor<Rank2<DIM, VOCAB>, f32, D, T>>,
+{
+    type Output = <Tensor<S, f32, D, T> as TryMatMul<Tensor<Rank2<DIM, VOCAB>, f32, D, T>>>::Output;
+    fn forward(&self, input: Tensor<S, f32, D, T>) -> Self::Output {
+        let weight = self.embedding.try_weight_with_tape(T::default()).unwrap();
+        input.matmul(weight.permute())
+    }
+}
+
+impl<T, const VOCAB: usize, const DIM: usize, D: Device<f32>> ModuleMut<T>
+    for TiedEmbedding<VOCAB, DIM, D>
+where
+    Self: Module<T>,
+{
+    type Output = <Self as Module<T>>::Output;
+    fn forward_mut(&mut self, input: T) -> Self::Output {
+        self.forward(input)
+    }
+}
+
+impl<const VOCAB: usize, const DIM: usize, D: Device<f32>> GradientUpdate<D, f32>
+    for TiedEmbedding<VOCAB, DIM, D>
+{
+    fn update<U>(&mut self, updater: &mut U, unused: &mut UnusedTensors) -> Result<(), D::Err>
+    where
+        U: ParamUpdater<D, f32>,
+    {
+        self.embedding.update(updater, unused)
+    }
+}
+
+impl<const VOCAB: usize, const DIM: usize, D: Device<f32>> ResetParams<D, f32>
+    for TiedEmbedding<VOCAB, DIM, D>
+{
+    fn try_reset_params(&mut self) -> Result<(), D::Err> {
+        self.embedding.try_reset_params()
+    }
+}
+
+impl<const VOCAB: usize, const DIM: usize, D: Device<f32>> BuildModule<D, f32>
+    for TiedEmbedding<VOCAB, DIM, D>
+{
+    fn try_build(device: &D) -> Result<Self, D::Err> {
+        Ok(Self {
+            embedding: BuildModule::try_build(device)?,
+        })
+    }
+}
+
+impl<const VOCAB: usize, const DIM: usize, D1: Device<f32>, D2: Device<f32>> ToDevice<D2>
+    for TiedEmbedding<VOCAB, DIM, D1>
+{
+    type Output = TiedEmbedding<VOCAB, DIM, D2>;
+    fn to_device(&self, device: &D2) -> Self::Output {
+        TiedEmbedding {
+            embedding: self.embedding.to_device(device),
         }
     }
 }
@@ -118,7 +453,8 @@ impl<const VOCAB: usize, const DIM: usize, D1: Device<f32>, D2: Device<f32>> ToD
 mod tests {
     use super::*;
     use crate::{
-        nn::{tests::SimpleUpdater, BuildModule},
+        nn::{tests::SimpleUpdater, BuildModule, LinearNoBias},
+        tensor::cpu::CpuError,
         tests::{assert_close, TestDevice},
         unique_id::HasUniqueId,
     };
@@ -138,13 +474,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn embedding_forward_0d() {
+        let dev: TestDevice = Default::default();
+
+        let model = Embedding::from_weight(dev.tensor(W));
+
+        let y: Tensor<Rank1<5>, f32, _, _> = model.forward(dev.tensor(1).trace());
+        assert_eq!(y.array(), W[1]);
+
+        let g = y.sum().backward();
+        assert_eq!(g.get(&model.weight).array(), [[0.0; 5], [1.0; 5]]);
+    }
+
+    #[test]
+    fn test_embedding_from_weight() {
+        let dev: TestDevice = Default::default();
+        let model = Embedding::from_weight(dev.tensor(W));
+        assert_eq!(model.padding_idx(), None);
+        assert!(!model.scale_grad_by_freq);
+
+        let y: Tensor<Rank2<3, 5>, f32, _> = model.forward(dev.tensor([1, 0, 1]));
+        assert_eq!(y.array(), [W[1], W[0], W[1]]);
+    }
+
     #[test]
     fn embedding_forward_1d() {
         let dev: TestDevice = Default::default();
 
-        let model = Embedding {
-            weight: dev.tensor(W),
-        };
+        let model = Embedding::from_weight(dev.tensor(W));
 
         let x = dev.tensor([0, 0, 1]);
         let y = model.forward(x.trace());
@@ -183,9 +541,7 @@ mod tests {
     fn test_forward_2d() {
         let dev: TestDevice = Default::default();
 
-        let model = Embedding {
-            weight: dev.tensor(W),
-        };
+        let model = Embedding::from_weight(dev.tensor(W));
 
         let x = dev.tensor([[0, 0], [0, 1]]);
         let y = model.forward(x.trace());
@@ -225,6 +581,144 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_forward_with_extra() {
+        let dev: TestDevice = Default::default();
+
+        let model = Embedding::from_weight(dev.tensor(W));
+
+        let extra: Tensor<Rank2<3, 2>, f32, _> = dev.sample_normal();
+        let y: Tensor<Rank2<3, 7>, f32, _, _> =
+            model.forward_with_extra(dev.tensor([1, 0, 1]).trace(), extra.trace());
+        let plain = model.forward(dev.tensor([1, 0, 1])).array();
+        let extra_array = extra.array();
+        for (i, row) in y.array().iter().enumerate() {
+            assert_eq!(row[..5], plain[i]);
+            assert_eq!(row[5..], extra_array[i]);
+        }
+
+        let g = y.sum().backward();
+        assert_eq!(g.get(&model.weight).array(), [[1.0; 5], [2.0; 5]]);
+        assert_eq!(g.get(&extra).array(), [[1.0; 2]; 3]);
+    }
+
+    #[test]
+    fn test_forward_runtime_seq_len() {
+        let dev: TestDevice = Default::default();
+
+        let model = Embedding::from_weight(dev.tensor(W));
+
+        let ids = std::vec![1, 0, 1];
+        let mut x: Tensor<(usize,), usize, _> = dev.zeros_like(&(ids.len(),));
+        x.copy_from(&ids);
+        let y = model.forward(x.trace());
+        assert_eq!(y.shape(), &(3, Const));
+        assert_eq!(y.as_vec()[..5], W[1]);
+        assert_eq!(y.as_vec()[5..10], W[0]);
+
+        let g = y.sum().backward();
+        assert_eq!(g.get(&model.weight).array(), [[1.0; 5], [2.0; 5]]);
+    }
+
+    #[test]
+    fn test_embedding_padding_idx_reset() {
+        let dev: TestDevice = Default::default();
+        let model: Embedding<5, 3, _> = BuildModule::build(&dev);
+        let mut model = model.with_padding_idx(2);
+        model.reset_params();
+        let w = model.weight.array();
+        assert_eq!(w[2], [0.0; 3]);
+        for i in [0, 1, 3, 4] {
+            assert_ne!(w[i], [0.0; 3]);
+        }
+    }
+
+    #[test]
+    fn test_embedding_padding_idx_no_gradient() {
+        let dev: TestDevice = Default::default();
+
+        let mut model = Embedding::from_weight(dev.tensor(W)).with_padding_idx(0);
+        model.reset_params();
+        model.weight = dev.tensor([[0.0; 5], W[1]]);
+
+        let y = model.forward(dev.tensor([[0, 1], [0, 0]]).trace());
+        assert_eq!(y.array()[1], [[0.0; 5]; 2]);
+        let g = y.exp().mean().backward();
+        assert_eq!(g.get(&model.weight).array()[0], [0.0; 5]);
+        assert_ne!(g.get(&model.weight).array()[1], [0.0; 5]);
+
+        let mut opt = Sgd::new(&model, Default::default());
+        opt.update(&mut model, g).expect("");
+        assert_eq!(model.weight.array()[0], [0.0; 5]);
+        assert_ne!(model.weight.array()[1], W[1]);
+    }
+
+    #[test]
+    fn test_embedding_padding_idx_out_of_bounds() {
+        let dev: TestDevice = Default::default();
+        let model: Embedding<5, 3, _> = BuildModule::build(&dev);
+        let r = model.clone().try_with_padding_idx(5);
+        assert!(matches!(
+            r,
+            Err(CpuError::IndexOutOfBounds {
+                index: 5,
+                size: 5,
+                ..
+            })
+        ));
+        assert_eq!(model.with_padding_idx(4).padding_idx(), Some(4));
+    }
+
+    #[test]
+    fn test_embedding_padding_idx_with_scale_grad_by_freq() {
+        let dev: TestDevice = Default::default();
+        let mut model = Embedding::from_weight(dev.tensor(W)).with_padding_idx(1);
+        model.scale_grad_by_freq = true;
+        let g = model
+            .forward(dev.tensor([0, 1, 0, 1]).trace())
+            .sum()
+            .backward();
+        assert_eq!(g.get(&model.weight).array(), [[1.0; 5], [0.0; 5]]);
+
+        let mut opt = Sgd::new(&model, Default::default());
+        opt.update(&mut model, g).expect("");
+        assert_eq!(model.weight.array()[1], W[1]);
+    }
+
+    #[test]
+    fn test_embedding_scale_grad_by_freq() {
+        let dev: TestDevice = Default::default();
+
+        let mut model: Embedding<4, 3, _> = BuildModule::build(&dev);
+        let x: Tensor<Rank1<5>, usize, _> = dev.tensor([1, 1, 3, 1, 2]);
+        let w: Tensor<Rank2<5, 3>, f32, _> = dev.sample_normal();
+
+        let y = model.forward(x.trace());
+        let y_array = y.array();
+        let unscaled = (y * w.clone()).sum().backward();
+        let unscaled = unscaled.get(&model.weight).array();
+
+        model.scale_grad_by_freq = true;
+        let y2 = model.forward(x.trace());
+        assert_eq!(y2.array(), y_array);
+        let scaled = (y2 * w).sum().backward();
+        let scaled = scaled.get(&model.weight).array();
+
+        let counts = [1.0, 3.0, 1.0, 1.0];
+        for i in 0..4 {
+            assert_close(&scaled[i], &unscaled[i].map(|g| g / counts[i]));
+        }
+        assert_eq!(scaled[0], [0.0; 3]);
+
+        // batched ids are counted over the whole batch
+        let x: Tensor<Rank2<2, 2>, usize, _> = dev.tensor([[0, 2], [2, 2]]);
+        let g = model.forward(x.trace()).sum().backward();
+        assert_close(
+            &g.get(&model.weight).array(),
+            &[[1.0; 3], [0.0; 3], [1.0; 3], [0.0; 3]],
+        );
+    }
+
     #[test]
     fn test_embedding_missing_gradients() {
         let dev: TestDevice = Default::default();
@@ -244,4 +738,68 @@ mod tests {
         model.update(&mut g, &mut unused).unwrap();
         assert!(unused.is_empty());
     }
+
+    #[test]
+    fn test_tied_embedding_padding_idx_no_gradient() {
+        let dev: TestDevice = Default::default();
+        let model = TiedEmbedding {
+            embedding: Embedding::from_weight(dev.tensor(W)).with_padding_idx(0),
+        };
+        let x: Tensor<Rank2<3, 5>, f32, _> = dev.sample_normal();
+        let logits = model.forward(x.trace());
+        let g = logits.exp().mean().backward();
+        let g = g.get(&model.embedding.weight).array();
+        assert_eq!(g[0], [0.0; 5]);
+        assert!(g[1].iter().all(|&v| v != 0.0));
+    }
+
+    #[test]
+    fn test_tied_embedding_updates_once_with_both_gradients() {
+        let dev: TestDevice = Default::default();
+        let mut model = TiedEmbedding {
+            embedding: Embedding::from_weight(dev.tensor(W)),
+        };
+
+        // the same computation with two separate copies of the weight
+        let emb = model.embedding.clone();
+        let lin = LinearNoBias {
+            weight: dev.tensor(W),
+        };
+        let y = lin.forward(emb.forward(dev.tensor([1, 0, 1]).trace()));
+        let g = y.square().mean().backward();
+        let mut expected = g.get(&emb.weight).array();
+        let from_linear = g.get(&lin.weight).array();
+        let mut expected_weight = W;
+        for i in 0..2 {
+            for j in 0..5 {
+                expected[i][j] += from_linear[i][j];
+                expected_weight[i][j] -= 0.1 * expected[i][j];
+            }
+        }
+
+        let y = model.forward(model.forward(dev.tensor([1, 0, 1]).trace()));
+        let g = y.square().mean().backward();
+        assert_close(&g.get(&model.embedding.weight).array(), &expected);
+
+        let mut opt = Sgd::new(
+            &model,
+            SgdConfig {
+                lr: 0.1,
+                momentum: None,
+                weight_decay: None,
+            },
+        );
+        opt.update(&mut model, g).expect("");
+        assert_close(&model.embedding.weight.array(), &expected_weight);
+
+        // both directions read the updated weight, also after moving devices
+        let model = model.to_device(&dev);
+        let y: Tensor<Rank1<5>, f32, _> = model.forward(dev.tensor(1));
+        assert_close(&y.array(), &expected_weight[1]);
+        let logits = model.forward(dev.tensor([1.0, 0.0, 0.0, 0.0, 0.0]));
+        assert_close(
+            &logits.array(),
+            &[expected_weight[0][0], expected_weight[1][0]],
+        );
+    }
 }
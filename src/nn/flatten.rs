@@ -42,16 +42,91 @@ where
     }
 }
 
-#[cfg(feature = "nightly")]
+/// Reshapes tensors to the constant shape `S`, keeping elements in row-major order.
+///
+/// `Reshape<Rank2<4, 6>>` can act on any tensor with 24 elements.
+///
+/// **Panics** if the input doesn't have the same number of elements as `S`.
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let model: Reshape<Rank2<4, 6>> = Default::default();
+/// let x: Tensor<Rank3<2, 3, 4>, f32, _> = dev.zeros();
+/// let _: Tensor<Rank2<4, 6>, f32, _> = model.forward(x);
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Reshape<S: ConstShape>(pub S);
+
+impl<S: ConstShape> ZeroSizedModule for Reshape<S> {}
+impl<S: ConstShape> NonMutableModule for Reshape<S> {}
+
+impl<S: ConstShape, D: Device<E>, E: Dtype> BuildModule<D, E> for Reshape<S> {
+    fn try_build(_: &D) -> Result<Self, <D>::Err> {
+        Ok(Default::default())
+    }
+}
+
+impl<Src: Shape, Dst: ConstShape, D: Device<E>, E: Dtype, T: Tape<D>> Module<Tensor<Src, E, D, T>>
+    for Reshape<Dst>
+{
+    type Output = Tensor<Dst, E, D, T>;
+    fn forward(&self, input: Tensor<Src, E, D, T>) -> Self::Output {
+        input.reshape_like(&self.0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{nn::ModuleMut, tensor::ZerosTensor, tests::TestDevice};
+    use crate::{
+        nn::ModuleMut,
+        tensor::{AsArray, AsVec, SampleTensor, ZerosTensor},
+        tests::TestDevice,
+    };
 
+    #[cfg(feature = "nightly")]
     #[test]
     fn test_flattens() {
         let dev: TestDevice = Default::default();
         let _: Tensor<Rank1<100>, _, _> = Flatten2D.forward_mut(dev.zeros::<Rank3<10, 5, 2>>());
         let _: Tensor<Rank2<5, 24>, _, _> = Flatten2D.forward_mut(dev.zeros::<Rank4<5, 4, 3, 2>>());
     }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn test_flatten_is_row_major() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 3, 4>, f32, _> = dev.sample_normal();
+        let expected: std::vec::Vec<f32> = t.array().iter().flatten().flatten().copied().collect();
+        let r: Tensor<Rank1<24>, _, _> = Flatten2D.forward(t);
+        assert_eq!(r.as_vec(), expected);
+    }
+
+    #[test]
+    fn test_reshape_is_row_major() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 3, 4>, f32, _> = dev.sample_normal();
+        let expected: std::vec::Vec<f32> = t.array().iter().flatten().flatten().copied().collect();
+        let r: Tensor<Rank2<6, 4>, _, _> = Reshape::default().forward(t);
+        assert_eq!(r.as_vec(), expected);
+    }
+
+    #[test]
+    fn test_reshapes() {
+        let dev: TestDevice = Default::default();
+        let _: Tensor<Rank1<24>, f32, _> =
+            Reshape::default().forward_mut(dev.zeros::<Rank2<4, 6>>());
+        let _: Tensor<Rank3<2, 3, 4>, f32, _> =
+            Reshape::default().forward_mut(dev.zeros::<Rank1<24>>());
+        let _: Tensor<Rank4<1, 2, 3, 4>, f32, _> =
+            Reshape::default().forward_mut(dev.zeros::<Rank2<6, 4>>());
+    }
+
+    #[test]
+    #[should_panic = "ShapeMismatch"]
+    fn test_reshape_wrong_numel() {
+        let dev: TestDevice = Default::default();
+        let _: Tensor<Rank1<25>, f32, _> = Reshape::default().forward(dev.zeros::<Rank2<4, 6>>());
+    }
 }
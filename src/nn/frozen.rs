@@ -0,0 +1,211 @@
+use crate::{
+    gradients::{NoneTape, OwnedTape, Tape},
+    optim::*,
+    shapes::*,
+    tensor::{DeviceStorage, PutTape, SplitTape, Tensor},
+    tensor_ops::Device,
+};
+
+use super::{BuildModule, Module, ModuleMut, ResetParams, ToDevice};
+
+/// Freezes the parameters of `M`, so optimizers will never update them. Useful
+/// for fine-tuning only parts of a model, e.g. keeping a pretrained [super::Embedding] fixed.
+///
+/// [GradientUpdate::update()] is a no-op, and the parameters of `M` are **not**
+/// reported as unused, so optimizers will not return [OptimizerUpdateError::UnusedParams]
+/// for them. [Module::forward()] and [ModuleMut::forward_mut()] run `M` on a tape of its own,
+/// and only the gradient of the input is passed back to the input's tape. So gradients still
+/// flow through `M` into its input, which means `Frozen` can be placed anywhere in a model,
+/// but the gradients of `M`'s parameters are dropped after backward instead of ending up
+/// in the resulting [crate::gradients::Gradients].
+///
+/// [ResetParams], [ToDevice], and saving/loading are passed through to `M`.
+///
+/// # Examples
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let model: (Frozen<Linear<5, 3>>, ReLU, Linear<3, 2>) = BuildModule::build(&dev);
+/// let x: Tensor<Rank1<5>, f32, _> = dev.zeros();
+/// let _: Tensor<Rank1<2>, f32, _> = model.forward(x);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Frozen<M>(pub M);
+
+impl<D: Device<E>, E: Dtype, M> GradientUpdate<D, E> for Frozen<M> {
+    fn update<U>(&mut self, _: &mut U, _: &mut UnusedTensors) -> Result<(), D::Err>
+    where
+        U: ParamUpdater<D, E>,
+    {
+        Ok(())
+    }
+}
+
+impl<D: Device<E>, E: Dtype, M: BuildModule<D, E>> BuildModule<D, E> for Frozen<M> {
+    fn try_build(device: &D) -> Result<Self, <D>::Err> {
+        Ok(Self(BuildModule::try_build(device)?))
+    }
+}
+
+impl<D: Device<E>, E: Dtype, M: ResetParams<D, E>> ResetParams<D, E> for Frozen<M> {
+    fn try_reset_params(&mut self) -> Result<(), <D>::Err> {
+        self.0.try_reset_params()
+    }
+}
+
+impl<M: ToDevice<D>, D> ToDevice<D> for Frozen<M> {
+    type Output = Frozen<M::Output>;
+    fn to_device(&self, device: &D) -> Self::Output {
+        Frozen(self.0.to_device(device))
+    }
+}
+
+/// Runs `f` on `x` with a new tape, and continues the tape of `x` with an operation
+/// that backprops through that tape. Only the gradient of `x` is moved back, so the
+/// gradients of anything else used by `f` are dropped.
+fn forward_detached<S: Shape, S2: Shape, E: Dtype, E2: Dtype, D: DeviceStorage, F>(
+    x: Tensor<S, E, D, OwnedTape<D>>,
+    f: F,
+) -> Tensor<S2, E2, D, OwnedTape<D>>
+where
+    F: FnOnce(Tensor<S, E, D, OwnedTape<D>>) -> Tensor<S2, E2, D, OwnedTape<D>>,
+{
+    let (x, mut tape) = x.split_tape();
+    let (out, mut inner) = f(x.retaped()).split_tape();
+    if out.id == x.id {
+        // `M` didn't do anything, so there is nothing to backprop through
+        return out.put_tape(tape);
+    }
+    let phantom_out = out.clone();
+    tape.try_alloc_grad(&x).unwrap();
+    tape.try_alloc_grad(&out).unwrap();
+    tape.add_backward_op(move |grads| {
+        let grad_x = grads.remove(&x).unwrap();
+        let grad_out = grads.get(&phantom_out).clone();
+        let phantom_x = x.clone();
+        inner.try_alloc_grad(&x)?;
+        inner.add_backward_op(move |inner_grads| {
+            *inner_grads.get_mut(&phantom_x) = grad_x;
+            *inner_grads.get_mut(&phantom_out) = grad_out;
+            Ok(())
+        });
+        let mut inner_grads = inner.0.execute()?;
+        *grads.get_or_alloc_mut(&x)? = inner_grads.remove(&x).unwrap();
+        Ok(())
+    });
+    out.put_tape(tape)
+}
+
+impl<S: Shape, E: Dtype, D: DeviceStorage, M> Module<Tensor<S, E, D, NoneTape>> for Frozen<M>
+where
+    M: Module<Tensor<S, E, D, NoneTape>>,
+{
+    type Output = M::Output;
+    fn forward(&self, x: Tensor<S, E, D, NoneTape>) -> Self::Output {
+        self.0.forward(x)
+    }
+}
+
+impl<S: Shape, S2: Shape, E: Dtype, E2: Dtype, D: DeviceStorage, M>
+    Module<Tensor<S, E, D, OwnedTape<D>>> for Frozen<M>
+where
+    M: Module<Tensor<S, E, D, OwnedTape<D>>, Output = Tensor<S2, E2, D, OwnedTape<D>>>,
+{
+    type Output = Tensor<S2, E2, D, OwnedTape<D>>;
+    fn forward(&self, x: Tensor<S, E, D, OwnedTape<D>>) -> Self::Output {
+        forward_detached(x, |x| self.0.forward(x))
+    }
+}
+
+impl<S: Shape, E: Dtype, D: DeviceStorage, M> ModuleMut<Tensor<S, E, D, NoneTape>> for Frozen<M>
+where
+    M: ModuleMut<Tensor<S, E, D, NoneTape>>,
+{
+    type Output = M::Output;
+    fn forward_mut(&mut self, x: Tensor<S, E, D, NoneTape>) -> Self::Output {
+        self.0.forward_mut(x)
+    }
+}
+
+impl<S: Shape, S2: Shape, E: Dtype, E2: Dtype, D: DeviceStorage, M>
+    ModuleMut<Tensor<S, E, D, OwnedTape<D>>> for Frozen<M>
+where
+    M: ModuleMut<Tensor<S, E, D, OwnedTape<D>>, Output = Tensor<S2, E2, D, OwnedTape<D>>>,
+{
+    type Output = Tensor<S2, E2, D, OwnedTape<D>>;
+    fn forward_mut(&mut self, x: Tensor<S, E, D, OwnedTape<D>>) -> Self::Output {
+        forward_detached(x, |x| self.0.forward_mut(x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestDevice;
+    use crate::{
+        nn::{Embedding, Linear},
+        tensor::*,
+        tensor_ops::*,
+    };
+
+    #[test]
+    fn test_frozen_not_updated() {
+        let dev: TestDevice = Default::default();
+        let mut model: (Frozen<Linear<2, 3, f32, _>>, Linear<3, 2, f32, _>) =
+            BuildModule::build(&dev);
+        let m0 = model.clone();
+
+        let x = dev.sample_normal::<Rank2<4, 2>>();
+        let g = model.forward_mut(x.trace()).square().mean().backward();
+
+        let mut sgd = Sgd::new(&model, Default::default());
+        sgd.update(&mut model, g)
+            .expect("frozen params aren't unused");
+
+        assert_eq!(model.0 .0.weight.array(), m0.0 .0.weight.array());
+        assert_eq!(model.0 .0.bias.array(), m0.0 .0.bias.array());
+        assert_ne!(model.1.weight.array(), m0.1.weight.array());
+        assert_ne!(model.1.bias.array(), m0.1.bias.array());
+    }
+
+    #[test]
+    fn test_frozen_passes_gradients_to_input() {
+        let dev: TestDevice = Default::default();
+        let model: Frozen<Linear<2, 3, f32, _>> = BuildModule::build(&dev);
+        let x = dev.sample_normal::<Rank1<2>>();
+        let g1 = model.forward(x.trace()).sum().backward();
+        let g2 = model.0.forward(x.trace()).sum().backward();
+        assert_eq!(g1.get(&x).array(), g2.get(&x).array());
+    }
+
+    #[test]
+    fn test_frozen_params_have_no_gradients() {
+        let dev: TestDevice = Default::default();
+        let model: (Linear<2, 2, f32, _>, Frozen<Linear<2, 3, f32, _>>) = BuildModule::build(&dev);
+        let x = dev.sample_normal::<Rank1<2>>();
+        let g = model.forward(x.trace()).sum().backward();
+        assert!(!g.contains(&model.1 .0.weight));
+        assert!(!g.contains(&model.1 .0.bias));
+        assert_ne!(g.get(&model.0.weight).array(), [[0.0; 2]; 2]);
+        assert_ne!(g.get(&x).array(), [0.0; 2]);
+    }
+
+    #[test]
+    fn test_frozen_embedding() {
+        let dev: TestDevice = Default::default();
+        let model: (Frozen<Embedding<5, 2, _>>, Linear<2, 1, f32, _>) = BuildModule::build(&dev);
+        let x = dev.tensor([0, 3, 3]);
+        let g = model.forward(x.trace()).sum().backward();
+        assert!(!g.contains(&model.0 .0.weight));
+        assert_ne!(g.get(&model.1.weight).array(), [[0.0; 2]]);
+    }
+
+    #[test]
+    fn test_frozen_reset_params() {
+        let dev: TestDevice = Default::default();
+        let mut model: Frozen<Linear<2, 3, f32, _>> = BuildModule::build(&dev);
+        let w0 = model.0.weight.array();
+        model.reset_params();
+        assert_ne!(model.0.weight.array(), w0);
+    }
+}
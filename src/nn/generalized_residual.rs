@@ -9,6 +9,10 @@ use super::{BuildModule, Module, ModuleMut, ResetParams, ToDevice};
 /// - `F`: The underlying module to do a skip connection around.
 /// - `R`: The underlying residual module
 ///
+/// Unlike [super::Residual], `F` may change the shape of its input, as long as `R`
+/// produces the same output shape. For example, `R` can be a [super::Linear] projection
+/// shortcut when `F` changes the number of features.
+///
 /// # Examples
 /// ```rust
 /// # use dfdx::prelude::*;
@@ -101,7 +105,8 @@ mod tests {
     fn test_reset_generalized_residual() {
         let dev: TestDevice = Default::default();
 
-        let model: GeneralizedResidual<Linear<2, 5, _>, Linear<2, 5, _>> = BuildModule::build(&dev);
+        let model: GeneralizedResidual<Linear<2, 5, f32, _>, Linear<2, 5, f32, _>> =
+            BuildModule::build(&dev);
         assert_ne!(model.f.weight.array(), [[0.0; 2]; 5]);
         assert_ne!(model.f.bias.array(), [0.0; 5]);
         assert_ne!(model.r.weight.array(), [[0.0; 2]; 5]);
@@ -112,7 +117,8 @@ mod tests {
     fn test_generalized_residual_gradients() {
         let dev: TestDevice = Default::default();
 
-        let model: GeneralizedResidual<Linear<2, 2, _>, Linear<2, 2, _>> = BuildModule::build(&dev);
+        let model: GeneralizedResidual<Linear<2, 2, f32, _>, Linear<2, 2, f32, _>> =
+            BuildModule::build(&dev);
 
         let x = dev.sample_normal::<Rank2<4, 2>>();
         let y = model.forward(x.trace());
@@ -127,4 +133,17 @@ mod tests {
         assert_close(&g.get(&model.r.weight).array(), &[[-0.025407, 0.155879]; 2]);
         assert_close(&g.get(&model.r.bias).array(), &[0.5; 2]);
     }
+
+    #[test]
+    fn test_generalized_residual_projection_shortcut() {
+        let dev: TestDevice = Default::default();
+
+        let model: GeneralizedResidual<Linear<2, 5, f32, _>, Linear<2, 5, f32, _>> =
+            BuildModule::build(&dev);
+
+        let x = dev.sample_normal::<Rank2<3, 2>>();
+        let y = model.forward(x.clone());
+        let expected = model.f.forward(x.clone()) + model.r.forward(x);
+        assert_close(&y.array(), &expected.array());
+    }
 }
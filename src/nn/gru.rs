@@ -0,0 +1,353 @@
+use crate::{
+    gradients::{Merge, Tape},
+    optim::*,
+    shapes::*,
+    tensor::*,
+    tensor_ops::*,
+};
+
+use super::module::{BuildModule, Module, ModuleMut, ResetParams, ToDevice};
+
+/// A gated recurrent unit, as described in [Learning Phrase Representations using RNN Encoder-Decoder
+/// for Statistical Machine Translation](https://arxiv.org/abs/1406.1078).
+///
+/// For each element `x` of the input sequence, the hidden state `h` is updated with:
+/// ```text
+/// r = sigmoid(W_ir * x + b_ir + W_hr * h + b_hr)
+/// z = sigmoid(W_iz * x + b_iz + W_hz * h + b_hz)
+/// n = tanh(W_in * x + b_in + r * (W_hn * h + b_hn))
+/// h = (1 - z) * n + z * h
+/// ```
+///
+/// The weights of the reset, update and new gates are stacked along the first axis
+/// of [Self::w_ih], [Self::w_hh], [Self::b_ih] and [Self::b_hh], in that order.
+///
+/// Initializes all parameters from a Uniform distribution between [-1 / sqrt(HIDDEN), 1 / sqrt(HIDDEN)].
+///
+/// # Generics
+/// - `INPUT` The size of each element of the input sequence.
+/// - `HIDDEN` The size of the hidden state.
+///
+/// # Examples
+/// `GRU<5, 3>` takes a sequence of vectors with 5 elements and an initial hidden state with 3 elements,
+/// and returns the hidden state after every step of the sequence, along with the final hidden state.
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// type Model = GRU<5, 3>;
+/// let model = Model::build_on_device(&dev);
+/// let x: Tensor<Rank2<10, 5>, f32, _> = dev.zeros();
+/// let h0: Tensor<Rank1<3>, f32, _> = dev.zeros();
+/// let (out, h): (Tensor<Rank2<10, 3>, f32, _>, Tensor<Rank1<3>, f32, _>) = model.forward((x, h0));
+/// ```
+///
+/// The tape is carried by the output sequence. The final hidden state is returned without a tape,
+/// so it can be passed as the initial state of the next call. To backprop through the final hidden state,
+/// use `out.select_last()`.
+#[derive(Debug, Clone)]
+pub struct GRU<const INPUT: usize, const HIDDEN: usize, D: Device<f32> = Cpu> {
+    /// Input to hidden weights of the reset, update & new gates, shape (3, HIDDEN, INPUT)
+    pub w_ih: Tensor<Rank3<3, HIDDEN, INPUT>, f32, D>,
+
+    /// Hidden to hidden weights of the reset, update & new gates, shape (3, HIDDEN, HIDDEN)
+    pub w_hh: Tensor<Rank3<3, HIDDEN, HIDDEN>, f32, D>,
+
+    /// Input to hidden biases of the reset, update & new gates, shape (3, HIDDEN)
+    pub b_ih: Tensor<Rank2<3, HIDDEN>, f32, D>,
+
+    /// Hidden to hidden biases of the reset, update & new gates, shape (3, HIDDEN)
+    pub b_hh: Tensor<Rank2<3, HIDDEN>, f32, D>,
+}
+
+impl<const I: usize, const H: usize, D: Device<f32>> GradientUpdate<D, f32> for GRU<I, H, D> {
+    fn update<U>(&mut self, updater: &mut U, unused: &mut UnusedTensors) -> Result<(), D::Err>
+    where
+        U: ParamUpdater<D, f32>,
+    {
+        self.w_ih.update(updater, unused)?;
+        self.w_hh.update(updater, unused)?;
+        self.b_ih.update(updater, unused)?;
+        self.b_hh.update(updater, unused)?;
+        Ok(())
+    }
+}
+
+impl<const I: usize, const H: usize, D: Device<f32>> BuildModule<D, f32> for GRU<I, H, D> {
+    fn try_build(device: &D) -> Result<Self, D::Err> {
+        let bound: f32 = 1.0 / (H as f32).sqrt();
+        let distr = rand_distr::Uniform::new(-bound, bound);
+        Ok(Self {
+            w_ih: device.try_sample(distr)?,
+            w_hh: device.try_sample(distr)?,
+            b_ih: device.try_sample(distr)?,
+            b_hh: device.try_sample(distr)?,
+        })
+    }
+}
+
+impl<const I: usize, const H: usize, D: Device<f32>> ResetParams<D, f32> for GRU<I, H, D> {
+    fn try_reset_params(&mut self) -> Result<(), D::Err> {
+        let bound: f32 = 1.0 / (H as f32).sqrt();
+        let distr = rand_distr::Uniform::new(-bound, bound);
+        self.w_ih.try_fill_with_distr(distr)?;
+        self.w_hh.try_fill_with_distr(distr)?;
+        self.b_ih.try_fill_with_distr(distr)?;
+        self.b_hh.try_fill_with_distr(distr)?;
+        Ok(())
+    }
+}
+
+impl<const I: usize, const H: usize, D1: Device<f32>, D2: Device<f32>> ToDevice<D2>
+    for GRU<I, H, D1>
+{
+    type Output = GRU<I, H, D2>;
+    fn to_device(&self, device: &D2) -> Self::Output {
+        GRU {
+            w_ih: self.w_ih.to_device(device),
+            w_hh: self.w_hh.to_device(device),
+            b_ih: self.b_ih.to_device(device),
+            b_hh: self.b_hh.to_device(device),
+        }
+    }
+}
+
+impl<const I: usize, const H: usize, D> GRU<I, H, D>
+where
+    D: Device<f32> + TensorFromArray<usize, Rank0, usize>,
+{
+    /// `W_ig * x + b_ig` for gate `g`
+    fn input_gate<T: Tape<D>>(
+        &self,
+        g: usize,
+        x: Tensor<Rank1<I>, f32, D, T>,
+    ) -> Tensor<Rank1<H>, f32, D, T> {
+        let dev = &self.w_ih.device;
+        let w = self.w_ih.retaped::<T>().select(dev.tensor(g));
+        x.matmul(w.permute()) + self.b_ih.retaped::<T>().select(dev.tensor(g))
+    }
+
+    /// `W_hg * h + b_hg` for gate `g`
+    fn hidden_gate<T: Tape<D>>(
+        &self,
+        g: usize,
+        h: Tensor<Rank1<H>, f32, D, T>,
+    ) -> Tensor<Rank1<H>, f32, D, T> {
+        let dev = &self.w_hh.device;
+        let w = self.w_hh.retaped::<T>().select(dev.tensor(g));
+        h.matmul(w.permute()) + self.b_hh.retaped::<T>().select(dev.tensor(g))
+    }
+}
+
+impl<const SEQ: usize, const I: usize, const H: usize, D, T, R>
+    Module<(
+        Tensor<Rank2<SEQ, I>, f32, D, T>,
+        Tensor<Rank1<H>, f32, D, R>,
+    )> for GRU<I, H, D>
+where
+    D: Device<f32> + TensorFromArray<usize, Rank0, usize>,
+    T: Tape<D> + Merge<R>,
+    R: Tape<D>,
+{
+    type Output = (Tensor<Rank2<SEQ, H>, f32, D, T>, Tensor<Rank1<H>, f32, D>);
+
+    /// Runs the gate equations over every element of the input sequence.
+    fn forward(
+        &self,
+        (x, h0): (
+            Tensor<Rank2<SEQ, I>, f32, D, T>,
+            Tensor<Rank1<H>, f32, D, R>,
+        ),
+    ) -> Self::Output {
+        // Every intermediate that is used more than once is stored without a tape, and its
+        // tape is merged into `tape` as soon as it is created. This keeps the backward operations
+        // in the order they were recorded, so gradients flow through all uses of a value.
+        let (x, tape) = x.split_tape();
+        let (mut h, h_tape) = h0.split_tape();
+        let mut tape = tape.merge(h_tape);
+        let dev = x.device.clone();
+
+        // the hidden state after every step is stacked into the output at the end
+        let mut hs = std::vec::Vec::with_capacity(SEQ);
+        for t in 0..SEQ {
+            let (x_t, x_t_tape) = x.retaped::<T>().select(dev.tensor(t)).split_tape();
+            tape = tape.merge(x_t_tape);
+
+            let r = (self.input_gate(0, x_t.retaped::<T>())
+                + self.hidden_gate(0, h.retaped::<T>()))
+            .sigmoid();
+            let (z, z_tape) = (self.input_gate(1, x_t.retaped::<T>())
+                + self.hidden_gate(1, h.retaped::<T>()))
+            .sigmoid()
+            .split_tape();
+            tape = tape.merge(z_tape);
+            let n = (self.input_gate(2, x_t.retaped::<T>())
+                + r * self.hidden_gate(2, h.retaped::<T>()))
+            .tanh();
+
+            let h_next =
+                (z.retaped::<T>().negate() + 1.0) * n + z.retaped::<T>() * h.retaped::<T>();
+            let (h_next, h_tape) = h_next.split_tape();
+            tape = tape.merge(h_tape);
+            h = h_next;
+            hs.push(h.retaped::<T>());
+        }
+        let mut hs = hs.into_iter();
+        let (out, out_tape) = stack(std::array::from_fn(|_| hs.next().unwrap())).split_tape();
+
+        (out.put_tape(tape.merge(out_tape)), h)
+    }
+}
+
+impl<T, const I: usize, const H: usize, D: Device<f32>> ModuleMut<T> for GRU<I, H, D>
+where
+    Self: Module<T>,
+{
+    type Output = <Self as Module<T>>::Output;
+    fn forward_mut(&mut self, input: T) -> Self::Output {
+        self.forward(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::tests::SimpleUpdater;
+    use crate::tests::{assert_close, assert_close_with_tolerance, TestDevice};
+    use crate::unique_id::HasUniqueId;
+
+    const W_IH: [[[f32; 2]; 3]; 3] = [
+        [[0.1, -0.2], [0.3, 0.4], [-0.5, 0.6]],
+        [[0.7, 0.8], [-0.9, 0.1], [0.2, -0.3]],
+        [[0.4, -0.5], [0.6, 0.7], [-0.8, 0.9]],
+    ];
+    const W_HH: [[[f32; 3]; 3]; 3] = [
+        [[0.1, 0.2, -0.3], [0.4, -0.5, 0.6], [0.7, 0.8, -0.9]],
+        [[-0.1, 0.3, 0.5], [0.2, 0.4, -0.6], [0.8, -0.7, 0.1]],
+        [[0.3, -0.2, 0.1], [-0.4, 0.5, 0.6], [0.9, 0.1, -0.2]],
+    ];
+    const B_IH: [[f32; 3]; 3] = [[0.1, 0.2, 0.3], [-0.1, -0.2, -0.3], [0.5, -0.5, 0.0]];
+    const B_HH: [[f32; 3]; 3] = [[0.0, 0.1, -0.1], [0.2, 0.0, 0.3], [-0.4, 0.4, 0.2]];
+
+    fn sigmoid(x: f32) -> f32 {
+        1.0 / (1.0 + (-x).exp())
+    }
+
+    #[test]
+    fn test_gru_single_step() {
+        let dev: TestDevice = Default::default();
+        let m: GRU<2, 3, _> = GRU {
+            w_ih: dev.tensor(W_IH),
+            w_hh: dev.tensor(W_HH),
+            b_ih: dev.tensor(B_IH),
+            b_hh: dev.tensor(B_HH),
+        };
+        let x = [0.5, -1.0];
+        let h0 = [0.2, -0.3, 0.4];
+        let (out, h) = m.forward((dev.tensor([x]), dev.tensor(h0)));
+
+        // gate pre-activations, computed by hand
+        let mut gi = [[0.0; 3]; 3];
+        let mut gh = [[0.0; 3]; 3];
+        for g in 0..3 {
+            for j in 0..3 {
+                gi[g][j] = B_IH[g][j] + W_IH[g][j][0] * x[0] + W_IH[g][j][1] * x[1];
+                gh[g][j] = B_HH[g][j];
+                for k in 0..3 {
+                    gh[g][j] += W_HH[g][j][k] * h0[k];
+                }
+            }
+        }
+        let mut expected = [0.0; 3];
+        for j in 0..3 {
+            let r = sigmoid(gi[0][j] + gh[0][j]);
+            let z = sigmoid(gi[1][j] + gh[1][j]);
+            let n = (gi[2][j] + r * gh[2][j]).tanh();
+            expected[j] = (1.0 - z) * n + z * h0[j];
+        }
+
+        assert_close(&h.array(), &expected);
+        assert_close(&out.array(), &[expected]);
+    }
+
+    #[test]
+    fn test_gru_sequence_matches_steps() {
+        let dev: TestDevice = Default::default();
+        let m: GRU<2, 3, _> = BuildModule::build(&dev);
+        let x: Tensor<Rank2<4, 2>, f32, _> = dev.sample_normal();
+        let h0: Tensor<Rank1<3>, f32, _> = dev.sample_normal();
+        let (out, h) = m.forward((x.clone(), h0.clone()));
+        let out = out.array();
+        assert_eq!(out[3], h.array());
+
+        let x = x.array();
+        let mut h = h0;
+        for t in 0..4 {
+            let (o, h_next) = m.forward((dev.tensor([x[t]]), h));
+            assert_close(&o.array()[0], &out[t]);
+            h = h_next;
+        }
+    }
+
+    #[test]
+    fn test_gru_backward() {
+        let dev: TestDevice = Default::default();
+        let mut m: GRU<2, 3, _> = BuildModule::build(&dev);
+        let x: Tensor<Rank2<4, 2>, f32, _> = dev.sample_normal();
+        let h0: Tensor<Rank1<3>, f32, _> = dev.sample_normal();
+        let (out, _) = m.forward((x.trace(), h0.clone()));
+        let g = out.square().mean().backward();
+
+        assert_ne!(g.get(&x).array(), [[0.0; 2]; 4]);
+        assert_ne!(g.get(&m.w_ih).array(), [[[0.0; 2]; 3]; 3]);
+        assert_ne!(g.get(&m.w_hh).array(), [[[0.0; 3]; 3]; 3]);
+        assert_ne!(g.get(&m.b_ih).array(), [[0.0; 3]; 3]);
+        assert_ne!(g.get(&m.b_hh).array(), [[0.0; 3]; 3]);
+
+        let mut updater = SimpleUpdater(g);
+        let mut unused = Default::default();
+        m.update(&mut updater, &mut unused).unwrap();
+        assert!(unused.is_empty());
+
+        let mut unused = Default::default();
+        m.update(&mut SimpleUpdater::default(), &mut unused)
+            .unwrap();
+        assert_eq!(
+            &unused.ids,
+            &[*m.w_ih.id(), *m.w_hh.id(), *m.b_ih.id(), *m.b_hh.id()]
+        );
+    }
+
+    #[test]
+    fn test_gru_backward_matches_finite_differences() {
+        let dev: TestDevice = Default::default();
+        let m: GRU<2, 3, _> = GRU {
+            w_ih: dev.tensor(W_IH),
+            w_hh: dev.tensor(W_HH),
+            b_ih: dev.tensor(B_IH),
+            b_hh: dev.tensor(B_HH),
+        };
+        let x = dev.tensor([[0.5, -1.0], [0.3, 0.2], [-0.7, 0.9]]);
+        let h0 = dev.tensor([0.2, -0.3, 0.4]);
+        let loss = |m: &GRU<2, 3, TestDevice>| {
+            let (out, _) = m.forward((x.clone(), h0.clone()));
+            out.square().sum::<Rank0, _>().array()
+        };
+        // gradients have to flow through every step of the sequence
+        let (out, _) = m.forward((x.trace(), h0.clone()));
+        let g = out.square().sum().backward();
+        let grad = g.get(&m.w_hh).array();
+        let w = m.w_hh.array();
+        for (a, b, c) in [(0, 0, 0), (1, 2, 1), (2, 1, 2), (2, 0, 1)] {
+            let mut wp = w;
+            wp[a][b][c] += 1e-2;
+            let mut wm = w;
+            wm[a][b][c] -= 1e-2;
+            let mut mp = m.clone();
+            mp.w_hh = dev.tensor(wp);
+            let mut mm = m.clone();
+            mm.w_hh = dev.tensor(wm);
+            let fd: f32 = (loss(&mp) - loss(&mm)) / 2e-2;
+            assert_close_with_tolerance(&grad[a][b][c], &fd, 1e-3);
+        }
+    }
+}
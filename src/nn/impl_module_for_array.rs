@@ -0,0 +1,115 @@
+use crate::{optim::*, shapes::*, tensor_ops::Device};
+
+use super::module::{BuildModule, Module, ModuleMut, ResetParams, ToDevice};
+
+/// A stack of `N` modules of the same type, applied in sequence. Each element has
+/// its own parameters, unlike applying the same module `N` times.
+///
+/// Only modules whose output is the same type as their input can be stacked.
+/// See also [super::Repeated], which stores the modules in a [Vec].
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let model: [(Linear<5, 5>, ReLU); 3] = BuildModule::build(&dev);
+/// let x: Tensor<Rank1<5>, f32, _> = dev.zeros();
+/// let _: Tensor<Rank1<5>, f32, _> = model.forward(x);
+/// ```
+impl<D: Device<E>, E: Dtype, M: GradientUpdate<D, E>, const N: usize> GradientUpdate<D, E>
+    for [M; N]
+{
+    fn update<U>(&mut self, updater: &mut U, unused: &mut UnusedTensors) -> Result<(), D::Err>
+    where
+        U: ParamUpdater<D, E>,
+    {
+        for m in self.iter_mut() {
+            m.update(updater, unused)?;
+        }
+        Ok(())
+    }
+}
+
+impl<D: Device<E>, E: Dtype, M: BuildModule<D, E>, const N: usize> BuildModule<D, E> for [M; N] {
+    fn try_build(device: &D) -> Result<Self, <D>::Err> {
+        let mut modules = std::vec::Vec::with_capacity(N);
+        for _ in 0..N {
+            modules.push(BuildModule::try_build(device)?);
+        }
+        match modules.try_into() {
+            Ok(modules) => Ok(modules),
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
+impl<D: Device<E>, E: Dtype, M: ResetParams<D, E>, const N: usize> ResetParams<D, E> for [M; N] {
+    fn try_reset_params(&mut self) -> Result<(), <D>::Err> {
+        for m in self.iter_mut() {
+            m.try_reset_params()?;
+        }
+        Ok(())
+    }
+}
+
+impl<M: ToDevice<D>, D, const N: usize> ToDevice<D> for [M; N] {
+    type Output = [M::Output; N];
+    fn to_device(&self, device: &D) -> Self::Output {
+        std::array::from_fn(|i| self[i].to_device(device))
+    }
+}
+
+impl<T, M: Module<T, Output = T>, const N: usize> Module<T> for [M; N] {
+    type Output = T;
+    fn forward(&self, mut x: T) -> Self::Output {
+        for m in self.iter() {
+            x = m.forward(x);
+        }
+        x
+    }
+}
+
+impl<T, M: ModuleMut<T, Output = T>, const N: usize> ModuleMut<T> for [M; N] {
+    type Output = T;
+    fn forward_mut(&mut self, mut x: T) -> Self::Output {
+        for m in self.iter_mut() {
+            x = m.forward_mut(x);
+        }
+        x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestDevice;
+    use crate::{nn::Linear, tensor::*, tensor_ops::*};
+
+    #[test]
+    fn test_array_independent_params() {
+        let dev: TestDevice = Default::default();
+        let mut model: [Linear<3, 3, f32, _>; 2] = BuildModule::build(&dev);
+        assert_ne!(model[0].weight.array(), model[1].weight.array());
+        assert_ne!(model[0].weight.id, model[1].weight.id);
+
+        let x: Tensor<Rank1<3>, f32, _> = dev.tensor([1.0, 2.0, 3.0]);
+        let y = model[1].forward(model[0].forward(x.clone()));
+        assert_eq!(model.forward(x.clone()).array(), y.array());
+        assert_eq!(model.forward_mut(x).array(), y.array());
+    }
+
+    #[test]
+    fn test_array_update() {
+        let dev: TestDevice = Default::default();
+        let mut model: [Linear<2, 2, f32, _>; 2] = BuildModule::build(&dev);
+        let x: Tensor<Rank2<3, 2>, f32, _> = dev.sample_normal();
+
+        let mut sgd = Sgd::new(&model, Default::default());
+        let m0 = model.clone();
+        let g = model.forward(x.trace()).square().mean().backward();
+        sgd.update(&mut model, g).expect("");
+        for i in 0..2 {
+            assert_ne!(model[i].weight.array(), m0[i].weight.array());
+            assert_ne!(model[i].bias.array(), m0[i].bias.array());
+        }
+    }
+}
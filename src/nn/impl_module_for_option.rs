@@ -0,0 +1,122 @@
+use crate::{optim::*, shapes::*, tensor_ops::Device};
+
+use super::module::{BuildModule, Module, ModuleMut, ResetParams, ToDevice};
+
+/// An optional layer: `Some(m)` forwards through `m`, and `None` is the identity.
+/// Since the type is the same either way, this can be used to toggle layers in
+/// configurable architectures at runtime.
+///
+/// [BuildModule] builds `Some`. Only modules whose output is the same type as their
+/// input can be optional.
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let mut model: (Linear<5, 5>, Option<Dropout>) = BuildModule::build(&dev);
+/// model.1 = None;
+/// let x: Tensor<Rank1<5>, f32, _> = dev.zeros();
+/// let _: Tensor<Rank1<5>, f32, _> = model.forward(x);
+/// ```
+impl<D: Device<E>, E: Dtype, M: GradientUpdate<D, E>> GradientUpdate<D, E> for Option<M> {
+    fn update<U>(&mut self, updater: &mut U, unused: &mut UnusedTensors) -> Result<(), D::Err>
+    where
+        U: ParamUpdater<D, E>,
+    {
+        match self {
+            Some(m) => m.update(updater, unused),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<D: Device<E>, E: Dtype, M: BuildModule<D, E>> BuildModule<D, E> for Option<M> {
+    fn try_build(device: &D) -> Result<Self, <D>::Err> {
+        Ok(Some(BuildModule::try_build(device)?))
+    }
+}
+
+impl<D: Device<E>, E: Dtype, M: ResetParams<D, E>> ResetParams<D, E> for Option<M> {
+    fn try_reset_params(&mut self) -> Result<(), <D>::Err> {
+        match self {
+            Some(m) => m.try_reset_params(),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<M: ToDevice<D>, D> ToDevice<D> for Option<M> {
+    type Output = Option<M::Output>;
+    fn to_device(&self, device: &D) -> Self::Output {
+        self.as_ref().map(|m| m.to_device(device))
+    }
+}
+
+impl<T, M: Module<T, Output = T>> Module<T> for Option<M> {
+    type Output = T;
+    fn forward(&self, x: T) -> Self::Output {
+        match self {
+            Some(m) => m.forward(x),
+            None => x,
+        }
+    }
+}
+
+impl<T, M: ModuleMut<T, Output = T>> ModuleMut<T> for Option<M> {
+    type Output = T;
+    fn forward_mut(&mut self, x: T) -> Self::Output {
+        match self {
+            Some(m) => m.forward_mut(x),
+            None => x,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestDevice;
+    use crate::{nn::Linear, tensor::*, tensor_ops::*};
+
+    #[test]
+    fn test_option_none_is_identity() {
+        let dev: TestDevice = Default::default();
+        let mut model: Option<Linear<3, 3, f32, _>> = BuildModule::build(&dev);
+        let x: Tensor<Rank1<3>, f32, _> = dev.tensor([1.0, 2.0, 3.0]);
+
+        let y = model.forward(x.clone());
+        assert_eq!(
+            y.array(),
+            model.as_ref().unwrap().forward(x.clone()).array()
+        );
+        assert_ne!(y.array(), x.array());
+
+        model = None;
+        assert_eq!(model.forward(x.clone()).array(), x.array());
+        assert_eq!(model.forward_mut(x.clone()).array(), x.array());
+        let g = model.forward(x.trace()).sum().backward();
+        assert_eq!(g.get(&x).array(), [1.0; 3]);
+    }
+
+    #[test]
+    fn test_option_update() {
+        let dev: TestDevice = Default::default();
+        let mut model: (Linear<2, 2, f32, _>, Option<Linear<2, 2, f32, _>>) =
+            BuildModule::build(&dev);
+        let x: Tensor<Rank2<3, 2>, f32, _> = dev.sample_normal();
+
+        let mut sgd = Sgd::new(&model, Default::default());
+        let m0 = model.clone();
+        let g = model.forward(x.trace()).square().mean().backward();
+        sgd.update(&mut model, g).expect("");
+        assert_ne!(
+            model.1.as_ref().unwrap().weight.array(),
+            m0.1.unwrap().weight.array()
+        );
+
+        model.1 = None;
+        let m0 = model.clone();
+        let g = model.forward(x.trace()).square().mean().backward();
+        sgd.update(&mut model, g).expect("");
+        assert_ne!(model.0.weight.array(), m0.0.weight.array());
+    }
+}
@@ -85,7 +85,8 @@ macro_rules! tuple_impls {
         > ModuleMut<Input> for ($($name,)+) {
             type Output = $last ::Output;
 
-            /// Calls forward sequentially on each module in the tuple.
+            /// Calls [ModuleMut::forward_mut()] sequentially on each module in the tuple,
+            /// so stateful modules (like dropout or batchnorm) run in training mode.
             fn forward_mut(&mut self, x: Input) -> Self::Output {
                 $(let x = self.$idx.forward_mut(x);)+
                 x
@@ -121,7 +122,7 @@ mod tests {
     #[test]
     fn test_2_tuple_update() {
         let dev: TestDevice = Default::default();
-        let mut model: (Linear<2, 3, _>, Linear<3, 4, _>) = BuildModule::build(&dev);
+        let mut model: (Linear<2, 3, f32, _>, Linear<3, 4, f32, _>) = BuildModule::build(&dev);
         assert_ne!(model.0.weight.array(), [[0.0; 2]; 3]);
         assert_ne!(model.0.bias.array(), [0.0; 3]);
         assert_ne!(model.1.weight.array(), [[0.0; 3]; 4]);
@@ -248,6 +249,42 @@ mod tests {
         assert_eq!(y.array(), [1.0, 1.0, 1.0, 1.0, 1.0, 1.0]);
     }
 
+    /// A module that adds 1.0 in [Module::forward], and 2.0 in [ModuleMut::forward_mut],
+    /// counting how many times the mutable path was taken.
+    #[derive(Debug, Default, Clone)]
+    struct Stateful {
+        num_mut_calls: usize,
+    }
+    impl Module<Tensor<Rank1<2>, f32, Cpu>> for Stateful {
+        type Output = Tensor<Rank1<2>, f32, Cpu>;
+        fn forward(&self, input: Tensor<Rank1<2>, f32, Cpu>) -> Self::Output {
+            input + 1.0
+        }
+    }
+    impl ModuleMut<Tensor<Rank1<2>, f32, Cpu>> for Stateful {
+        type Output = Tensor<Rank1<2>, f32, Cpu>;
+        fn forward_mut(&mut self, input: Tensor<Rank1<2>, f32, Cpu>) -> Self::Output {
+            self.num_mut_calls += 1;
+            input + 2.0
+        }
+    }
+
+    #[test]
+    fn test_tuple_forward_mut_calls_sub_module_forward_mut() {
+        let dev: Cpu = Default::default();
+        let mut model: (Stateful, ReLU, Stateful) = Default::default();
+
+        let y = model.forward(dev.zeros());
+        assert_eq!(y.array(), [2.0, 2.0]);
+        assert_eq!(model.0.num_mut_calls, 0);
+        assert_eq!(model.2.num_mut_calls, 0);
+
+        let y = model.forward_mut(dev.zeros());
+        assert_eq!(y.array(), [4.0, 4.0]);
+        assert_eq!(model.0.num_mut_calls, 1);
+        assert_eq!(model.2.num_mut_calls, 1);
+    }
+
     #[test]
     fn test_tuple_missing_gradients() {
         let dev: TestDevice = Default::default();
@@ -0,0 +1,108 @@
+use crate::{optim::*, shapes::*, tensor_ops::Device};
+
+use super::module::{BuildModule, Module, NonMutableModule, ResetParams, ToDevice};
+
+/// Wraps a function or closure `F` so it can be used as a layer. [Module::forward()]
+/// calls `F` on the input.
+///
+/// This has no parameters, so building, resetting, updating, and moving it to
+/// a different device are all no-ops. `F` should not capture any tensors, since
+/// they will not be moved by [ToDevice] or updated by optimizers.
+///
+/// [BuildModule] is only available when `F` implements [Default], which closures
+/// do not, so models containing a closure have to be constructed by hand.
+///
+/// # Examples
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let linear: Linear<5, 2> = BuildModule::build(&dev);
+/// let model = (linear, Lambda(|x: Tensor<Rank1<2>, f32, Cpu>| x * 2.0));
+/// let _: Tensor<Rank1<2>, f32, _> = model.forward(dev.zeros::<Rank1<5>>());
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Lambda<F>(pub F);
+
+impl<F> NonMutableModule for Lambda<F> {}
+
+impl<D: Device<E>, E: Dtype, F: Default> BuildModule<D, E> for Lambda<F> {
+    fn try_build(_: &D) -> Result<Self, <D>::Err> {
+        Ok(Default::default())
+    }
+}
+
+impl<D: Device<E>, E: Dtype, F> ResetParams<D, E> for Lambda<F> {
+    fn try_reset_params(&mut self) -> Result<(), <D>::Err> {
+        Ok(())
+    }
+}
+
+impl<D: Device<E>, E: Dtype, F> GradientUpdate<D, E> for Lambda<F> {
+    fn update<U>(&mut self, _: &mut U, _: &mut UnusedTensors) -> Result<(), <D>::Err>
+    where
+        U: ParamUpdater<D, E>,
+    {
+        Ok(())
+    }
+}
+
+impl<F: Clone, D> ToDevice<D> for Lambda<F> {
+    type Output = Self;
+    fn to_device(&self, _device: &D) -> Self {
+        self.clone()
+    }
+}
+
+impl<T, O, F: Fn(T) -> O> Module<T> for Lambda<F> {
+    type Output = O;
+    fn forward(&self, x: T) -> Self::Output {
+        (self.0)(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{BuildOnDevice, Linear, ModuleMut};
+    use crate::{gradients::OwnedTape, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_lambda_doubles_linear() {
+        let dev: TestDevice = Default::default();
+        let linear = Linear::<3, 2>::build_on_device(&dev);
+        let mut model = (
+            linear.clone(),
+            Lambda(|x: Tensor<Rank1<2>, f32, _, OwnedTape<_>>| x * 2.0),
+        );
+
+        let x = dev.tensor([1.0, -2.0, 0.5]);
+        let y = model.forward(x.trace());
+        let y_array = y.array();
+        let expected = linear.forward(x.trace());
+        assert_close(&y_array, &expected.array().map(|v| v * 2.0));
+
+        let g = y.sum().backward();
+        let g_expected = expected.sum().backward();
+        assert_close(
+            &g.get(&model.0.weight).array(),
+            &g_expected
+                .get(&linear.weight)
+                .array()
+                .map(|r| r.map(|v| v * 2.0)),
+        );
+        assert_close(&g.get(&model.0.bias).array(), &[2.0; 2]);
+
+        assert_eq!(model.forward_mut(x.trace()).array(), y_array);
+    }
+
+    #[test]
+    fn test_lambda_fn_item() {
+        fn negate<S: Shape, D: Device<f32>>(x: Tensor<S, f32, D>) -> Tensor<S, f32, D> {
+            -x
+        }
+        let dev: TestDevice = Default::default();
+        let model = Lambda(negate);
+        let y = model.forward(dev.tensor([1.0, -2.0]));
+        assert_eq!(y.array(), [-1.0, 2.0]);
+    }
+}
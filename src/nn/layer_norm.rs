@@ -177,6 +177,33 @@ mod tests {
         assert_close(&g.get(&m.beta).array(), &[0.2; 5]);
     }
 
+    #[test]
+    fn test_layer_norm_2d_backward() {
+        let dev: TestDevice = Default::default();
+        let m: LayerNorm1D<3, _> = BuildModule::build(&dev);
+        let x = dev.tensor([[1.0, 2.0, 3.0], [-1.0, 0.0, 4.0]]);
+        let r = m.forward(x.trace());
+        assert_close(
+            &r.array(),
+            &[
+                [-1.2247357, 0.0, 1.2247357],
+                [-0.9258191, -0.46290955, 1.3887287],
+            ],
+        );
+        let g = (r * dev.tensor([[1.0, -2.0, 0.5], [4.0, 5.0, 6.0]]))
+            .sum()
+            .backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[
+                [1.122679, -2.2453487, 1.1226698],
+                [-0.13226058, 0.16532448, -0.033063904],
+            ],
+        );
+        assert_close(&g.get(&m.gamma).array(), &[-4.928012, -2.3145478, 8.94474]);
+        assert_close(&g.get(&m.beta).array(), &[5.0, 3.0, 6.5]);
+    }
+
     #[test]
     fn test_layer_norm_missing_gradients() {
         let dev: TestDevice = Default::default();
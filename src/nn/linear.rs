@@ -2,6 +2,9 @@ use crate::{gradients::Tape, optim::*, shapes::*, tensor::*, tensor_ops::*};
 
 use super::module::{BuildModule, Module, ModuleMut, ResetParams, ToDevice};
 
+use num_traits::Float;
+use rand_distr::{uniform::SampleUniform, Distribution, Normal, StandardNormal, Uniform};
+
 /// A linear transformation of the form `weight * x + bias`, where `weight` is a matrix, `x` is a vector or matrix,
 /// and `bias` is a vector.
 ///
@@ -11,6 +14,8 @@ use super::module::{BuildModule, Module, ModuleMut, ResetParams, ToDevice};
 /// # Generics
 /// - `I` The "input" size of vectors & matrices.
 /// - `O` The "output" size of vectors & matrices.
+/// - `E` The dtype of the parameters, defaults to `f32`.
+/// - `D` The device the parameters are stored on, defaults to [Cpu].
 ///
 /// # Examples
 /// `Linear<5, 2>` can act on vectors with 5 elements, and results in vectors with 2 elements.
@@ -23,20 +28,25 @@ use super::module::{BuildModule, Module, ModuleMut, ResetParams, ToDevice};
 /// let _: Tensor<Rank1<2>, f32, _> = model.forward(dev.zeros::<Rank1<5>>());
 /// // batched forward
 /// let _: Tensor<Rank2<10, 2>, f32, _> = model.forward(dev.zeros::<Rank2<10, 5>>());
+/// // batched forward with a runtime batch size
+/// let x: Tensor<(usize, Const<5>), f32, _> = dev.zeros_like(&(10, Const));
+/// let _: Tensor<(usize, Const<2>), f32, _> = model.forward(x);
 /// ```
 #[derive(Debug, Clone)]
-pub struct Linear<const I: usize, const O: usize, D: Device<f32> = Cpu> {
+pub struct Linear<const I: usize, const O: usize, E: Dtype = f32, D: Device<E> = Cpu> {
     /// Transposed weight matrix, shape (I, O)
-    pub weight: Tensor<Rank2<O, I>, f32, D>,
+    pub weight: Tensor<Rank2<O, I>, E, D>,
 
     /// Bias vector, shape (O, )
-    pub bias: Tensor<Rank1<O>, f32, D>,
+    pub bias: Tensor<Rank1<O>, E, D>,
 }
 
-impl<const I: usize, const O: usize, D: Device<f32>> GradientUpdate<D, f32> for Linear<I, O, D> {
+impl<const I: usize, const O: usize, E: Dtype, D: Device<E>> GradientUpdate<D, E>
+    for Linear<I, O, E, D>
+{
     fn update<U>(&mut self, updater: &mut U, unused: &mut UnusedTensors) -> Result<(), D::Err>
     where
-        U: ParamUpdater<D, f32>,
+        U: ParamUpdater<D, E>,
     {
         self.weight.update(updater, unused)?;
         self.bias.update(updater, unused)?;
@@ -44,30 +54,137 @@ impl<const I: usize, const O: usize, D: Device<f32>> GradientUpdate<D, f32> for
     }
 }
 
-impl<const I: usize, const O: usize, D: Device<f32>> BuildModule<D, f32> for Linear<I, O, D> {
+impl<const I: usize, const O: usize, E: Dtype + Float + SampleUniform, D: Device<E>>
+    BuildModule<D, E> for Linear<I, O, E, D>
+{
     fn try_build(device: &D) -> Result<Self, D::Err> {
-        let bound: f32 = 1.0 / (I as f32).sqrt();
-        let distr = rand_distr::Uniform::new(-bound, bound);
-        let weight = device.try_sample(distr)?;
-        let bias = device.try_sample(distr)?;
+        let bound = E::one() / E::from(I).unwrap().sqrt();
+        let weight = device.try_sample(Uniform::new(-bound, bound))?;
+        let bias = device.try_sample(Uniform::new(-bound, bound))?;
         Ok(Self { weight, bias })
     }
 }
 
-impl<const I: usize, const O: usize, D: Device<f32>> ResetParams<D, f32> for Linear<I, O, D> {
+impl<const I: usize, const O: usize, E: Dtype + Float + SampleUniform, D: Device<E>>
+    ResetParams<D, E> for Linear<I, O, E, D>
+{
     fn try_reset_params(&mut self) -> Result<(), D::Err> {
-        let bound: f32 = 1.0 / (I as f32).sqrt();
-        let distr = rand_distr::Uniform::new(-bound, bound);
-        self.weight.try_fill_with_distr(distr)?;
-        self.bias.try_fill_with_distr(distr)?;
+        let bound = E::one() / E::from(I).unwrap().sqrt();
+        self.weight
+            .try_fill_with_distr(Uniform::new(-bound, bound))?;
+        self.bias.try_fill_with_distr(Uniform::new(-bound, bound))?;
         Ok(())
     }
 }
 
-impl<const I: usize, const O: usize, D1: Device<f32>, D2: Device<f32>> ToDevice<D2>
-    for Linear<I, O, D1>
+impl<const I: usize, const O: usize, E: Dtype, D: Device<E>> Linear<I, O, E, D> {
+    /// Creates a [Linear] from an existing `weight` of shape (O, I) and `bias` of shape (O, ),
+    /// e.g. pretrained parameters.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let model = Linear::from_weights(dev.tensor([[1.0, 2.0, 3.0]]), dev.tensor([0.5]));
+    /// let y: Tensor<Rank1<1>, f32, _> = model.forward(dev.tensor([1.0, 1.0, 1.0]));
+    /// assert_eq!(y.array(), [6.5]);
+    /// ```
+    pub fn from_weights(weight: Tensor<Rank2<O, I>, E, D>, bias: Tensor<Rank1<O>, E, D>) -> Self {
+        Self { weight, bias }
+    }
+}
+
+/// How to initialize the weight of a [Linear]. The bias is always initialized
+/// from a Uniform distribution between [-1 / sqrt(I), 1 / sqrt(I)].
+///
+/// See [Linear::build_with_init()] and [Linear::reset_params_with()].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum InitMethod {
+    /// Uniform distribution between [-1 / sqrt(I), 1 / sqrt(I)]. This is what
+    /// [BuildModule] and [ResetParams] use.
+    #[default]
+    Default,
+
+    /// [Kaiming/He](https://arxiv.org/abs/1502.01852) uniform initialization for ReLU networks:
+    /// Uniform distribution between [-sqrt(6 / I), sqrt(6 / I)].
+    KaimingUniform,
+
+    /// [Kaiming/He](https://arxiv.org/abs/1502.01852) normal initialization for ReLU networks:
+    /// Normal distribution with mean 0 and std `sqrt(2 / I)`.
+    KaimingNormal,
+
+    /// [Xavier/Glorot](http://proceedings.mlr.press/v9/glorot10a.html) uniform initialization
+    /// for tanh networks: Uniform distribution between [-sqrt(6 / (I + O)), sqrt(6 / (I + O))].
+    XavierUniform,
+}
+
+impl<const I: usize, const O: usize, E: Dtype + Float + SampleUniform, D: Device<E>>
+    Linear<I, O, E, D>
+where
+    StandardNormal: Distribution<E>,
+{
+    /// Builds a [Linear] whose weight is initialized with `method`.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let model: Linear<5, 2> = Linear::build_with_init(&dev, InitMethod::KaimingNormal);
+    /// ```
+    pub fn build_with_init(device: &D, method: InitMethod) -> Self {
+        Self::try_build_with_init(device, method).unwrap()
+    }
+
+    /// Fallible version of [Linear::build_with_init]
+    pub fn try_build_with_init(device: &D, method: InitMethod) -> Result<Self, D::Err> {
+        let mut linear = Self {
+            weight: device.try_zeros()?,
+            bias: device.try_zeros()?,
+        };
+        linear.try_reset_params_with(method)?;
+        Ok(linear)
+    }
+
+    /// Re-initializes the parameters, with the weight initialized using `method`.
+    pub fn reset_params_with(&mut self, method: InitMethod) {
+        self.try_reset_params_with(method).unwrap()
+    }
+
+    /// Fallible version of [Linear::reset_params_with]
+    pub fn try_reset_params_with(&mut self, method: InitMethod) -> Result<(), D::Err> {
+        let fan_in = E::from(I).unwrap();
+        let fan_out = E::from(O).unwrap();
+        let six = E::from(6.0).unwrap();
+        match method {
+            InitMethod::Default => {
+                let bound = E::one() / fan_in.sqrt();
+                self.weight
+                    .try_fill_with_distr(Uniform::new(-bound, bound))?;
+            }
+            InitMethod::KaimingUniform => {
+                let bound = (six / fan_in).sqrt();
+                self.weight
+                    .try_fill_with_distr(Uniform::new(-bound, bound))?;
+            }
+            InitMethod::KaimingNormal => {
+                let std = (E::from(2.0).unwrap() / fan_in).sqrt();
+                self.weight
+                    .try_fill_with_distr(Normal::new(E::zero(), std).unwrap())?;
+            }
+            InitMethod::XavierUniform => {
+                let bound = (six / (fan_in + fan_out)).sqrt();
+                self.weight
+                    .try_fill_with_distr(Uniform::new(-bound, bound))?;
+            }
+        }
+        let bound = E::one() / fan_in.sqrt();
+        self.bias.try_fill_with_distr(Uniform::new(-bound, bound))?;
+        Ok(())
+    }
+}
+
+impl<const I: usize, const O: usize, E: Dtype, D1: Device<E>, D2: Device<E>> ToDevice<D2>
+    for Linear<I, O, E, D1>
 {
-    type Output = Linear<I, O, D2>;
+    type Output = Linear<I, O, E, D2>;
     fn to_device(&self, device: &D2) -> Self::Output {
         Linear {
             weight: self.weight.to_device(device),
@@ -76,22 +193,24 @@ impl<const I: usize, const O: usize, D1: Device<f32>, D2: Device<f32>> ToDevice<
     }
 }
 
-impl<const I: usize, const O: usize, D: Device<f32>, T> Module<T> for Linear<I, O, D>
+impl<const I: usize, const O: usize, E: Dtype, D: Device<E>, T> Module<T> for Linear<I, O, E, D>
 where
-    T: SplitTape + TryMatMul<Tensor<Rank2<I, O>, f32, D, T::Tape>>,
+    T: SplitTape
+        + TryMatMulBias<Tensor<Rank2<I, O>, E, D, T::Tape>, Tensor<Rank1<O>, E, D, T::Tape>>,
     T::Tape: Tape<D>,
-    for<'a> Bias1D<'a, O, D>: Module<T::Output, Output = T::Output>,
 {
     type Output = T::Output;
 
-    /// 1d forward using [matmul()] and [add()].
+    /// 1d forward using [matmul_bias()].
     fn forward(&self, x: T) -> Self::Output {
-        let o = x.matmul(self.weight.retaped::<T::Tape>().permute());
-        Bias1D { beta: &self.bias }.forward(o)
+        x.matmul_bias(
+            self.weight.retaped::<T::Tape>().permute(),
+            self.bias.retaped::<T::Tape>(),
+        )
     }
 }
 
-impl<T, const I: usize, const O: usize, D: Device<f32>> ModuleMut<T> for Linear<I, O, D>
+impl<T, const I: usize, const O: usize, E: Dtype, D: Device<E>> ModuleMut<T> for Linear<I, O, E, D>
 where
     Self: Module<T>,
 {
@@ -101,35 +220,95 @@ where
     }
 }
 
-#[derive(Clone, Debug)]
-struct Bias1D<'a, const M: usize, D: Device<f32> = Cpu> {
-    beta: &'a Tensor<Rank1<M>, f32, D>,
+/// A linear transformation of the form `weight * x`, where `weight` is a matrix and `x` is a vector or matrix.
+/// This is [Linear] without the additive bias term.
+///
+/// Initializes [Self::weight] from a Uniform distribution
+/// between [-1 / sqrt(I), 1 / sqrt(I)].
+///
+/// # Generics
+/// - `I` The "input" size of vectors & matrices.
+/// - `O` The "output" size of vectors & matrices.
+///
+/// # Examples
+/// `LinearNoBias<5, 2>` can act on vectors with 5 elements, and results in vectors with 2 elements.
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// type Model = LinearNoBias<5, 2>;
+/// let model = Model::build_on_device(&dev);
+/// // single item forward
+/// let _: Tensor<Rank1<2>, f32, _> = model.forward(dev.zeros::<Rank1<5>>());
+/// // batched forward
+/// let _: Tensor<Rank2<10, 2>, f32, _> = model.forward(dev.zeros::<Rank2<10, 5>>());
+/// ```
+#[derive(Debug, Clone)]
+pub struct LinearNoBias<const I: usize, const O: usize, D: Device<f32> = Cpu> {
+    /// Transposed weight matrix, shape (I, O)
+    pub weight: Tensor<Rank2<O, I>, f32, D>,
 }
 
-impl<'a, const M: usize, D: Device<f32>, T: Tape<D>> Module<Tensor<Rank1<M>, f32, D, T>>
-    for Bias1D<'a, M, D>
+impl<const I: usize, const O: usize, D: Device<f32>> GradientUpdate<D, f32>
+    for LinearNoBias<I, O, D>
 {
-    type Output = Tensor<Rank1<M>, f32, D, T>;
-    fn forward(&self, input: Tensor<Rank1<M>, f32, D, T>) -> Self::Output {
-        input + self.beta.clone()
+    fn update<U>(&mut self, updater: &mut U, unused: &mut UnusedTensors) -> Result<(), D::Err>
+    where
+        U: ParamUpdater<D, f32>,
+    {
+        self.weight.update(updater, unused)?;
+        Ok(())
+    }
+}
+
+impl<const I: usize, const O: usize, D: Device<f32>> BuildModule<D, f32> for LinearNoBias<I, O, D> {
+    fn try_build(device: &D) -> Result<Self, D::Err> {
+        let bound: f32 = 1.0 / (I as f32).sqrt();
+        let distr = rand_distr::Uniform::new(-bound, bound);
+        let weight = device.try_sample(distr)?;
+        Ok(Self { weight })
+    }
+}
+
+impl<const I: usize, const O: usize, D: Device<f32>> ResetParams<D, f32> for LinearNoBias<I, O, D> {
+    fn try_reset_params(&mut self) -> Result<(), D::Err> {
+        let bound: f32 = 1.0 / (I as f32).sqrt();
+        let distr = rand_distr::Uniform::new(-bound, bound);
+        self.weight.try_fill_with_distr(distr)?;
+        Ok(())
     }
 }
 
-impl<'a, B: Dim, const M: usize, D: Device<f32>, T: Tape<D>>
-    Module<Tensor<(B, Const<M>), f32, D, T>> for Bias1D<'a, M, D>
+impl<const I: usize, const O: usize, D1: Device<f32>, D2: Device<f32>> ToDevice<D2>
+    for LinearNoBias<I, O, D1>
+{
+    type Output = LinearNoBias<I, O, D2>;
+    fn to_device(&self, device: &D2) -> Self::Output {
+        LinearNoBias {
+            weight: self.weight.to_device(device),
+        }
+    }
+}
+
+impl<const I: usize, const O: usize, D: Device<f32>, T> Module<T> for LinearNoBias<I, O, D>
+where
+    T: SplitTape + TryMatMul<Tensor<Rank2<I, O>, f32, D, T::Tape>>,
+    T::Tape: Tape<D>,
 {
-    type Output = Tensor<(B, Const<M>), f32, D, T>;
-    fn forward(&self, input: Tensor<(B, Const<M>), f32, D, T>) -> Self::Output {
-        self.beta.retaped::<T>().broadcast_like(input.shape()) + input
+    type Output = T::Output;
+
+    /// 1d forward using [matmul()].
+    fn forward(&self, x: T) -> Self::Output {
+        x.matmul(self.weight.retaped::<T::Tape>().permute())
     }
 }
 
-impl<'a, B: Dim, S: Dim, const M: usize, D: Device<f32>, T: Tape<D>>
-    Module<Tensor<(B, S, Const<M>), f32, D, T>> for Bias1D<'a, M, D>
+impl<T, const I: usize, const O: usize, D: Device<f32>> ModuleMut<T> for LinearNoBias<I, O, D>
+where
+    Self: Module<T>,
 {
-    type Output = Tensor<(B, S, Const<M>), f32, D, T>;
-    fn forward(&self, input: Tensor<(B, S, Const<M>), f32, D, T>) -> Self::Output {
-        self.beta.retaped::<T>().broadcast_like(input.shape()) + input
+    type Output = <Self as Module<T>>::Output;
+    fn forward_mut(&mut self, input: T) -> Self::Output {
+        self.forward(input)
     }
 }
 
@@ -151,12 +330,12 @@ mod tests {
         use super::super::module::OnDevice;
 
         let cuda: Cuda = Default::default();
-        let _: Linear<1, 1, _> = BuildModule::build(&cuda);
+        let _: Linear<1, 1, f32, _> = BuildModule::build(&cuda);
         let _: OnDevice<Linear<1, 1>, Cuda> = BuildModule::build(&cuda);
         let _: OnDevice<(Linear<1, 2>, Linear<2, 1>), Cuda> = BuildModule::build(&cuda);
 
-        let _: Linear<1, 1, Cuda> = Linear::<1, 1>::build_on_device(&cuda);
-        let _: Linear<1, 1, _> = Linear::<1, 1>::build_on_device(&cuda);
+        let _: Linear<1, 1, f32, Cuda> = Linear::<1, 1>::build_on_device(&cuda);
+        let _: Linear<1, 1, f32, _> = Linear::<1, 1>::build_on_device(&cuda);
         let _ = Linear::<1, 1>::build_on_device(&cuda);
     }
 
@@ -173,6 +352,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_linear_init_methods() {
+        let dev: TestDevice = Default::default();
+
+        let m = Linear::<500, 400, f32, _>::build_with_init(&dev, InitMethod::KaimingNormal);
+        let w = m.weight.as_vec();
+        let n = w.len() as f32;
+        let mean = w.iter().sum::<f32>() / n;
+        let std = (w.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / n).sqrt();
+        let expected = (2.0f32 / 500.0).sqrt();
+        assert!(mean.abs() < 1e-3, "{mean}");
+        assert!(
+            (std - expected).abs() < 0.02 * expected,
+            "{std} vs {expected}"
+        );
+
+        let mut m = Linear::<100, 50, f32, _>::build_with_init(&dev, InitMethod::XavierUniform);
+        let bound = (6.0f32 / 150.0).sqrt();
+        assert!(m.weight.as_vec().iter().all(|v| v.abs() <= bound));
+
+        m.reset_params_with(InitMethod::KaimingUniform);
+        let bound = (6.0f32 / 100.0).sqrt();
+        let w = m.weight.as_vec();
+        assert!(w.iter().all(|v| v.abs() <= bound));
+        assert!(w.iter().any(|v| v.abs() > 1.0 / 100.0f32.sqrt()));
+    }
+
     #[test]
     fn test_forward_1d() {
         let dev: TestDevice = Default::default();
@@ -197,6 +403,17 @@ mod tests {
         assert_close(&g.get(&model.bias).array(), &[-0.93430865, 0.08624211]);
     }
 
+    #[test]
+    fn test_linear_from_weights() {
+        let dev: TestDevice = Default::default();
+        let model = Linear::from_weights(dev.tensor(W), dev.tensor(B));
+        assert_eq!(model.weight.array(), W);
+        assert_eq!(model.bias.array(), B);
+
+        let x = dev.tensor([-0.8808001f32, 2.4185333, 2.2478335, 0.0565211, 2.031299]);
+        assert_close(&model.forward(x).array(), &[-0.93430865, 0.08624211]);
+    }
+
     #[test]
     fn test_forward_2d() {
         let dev: TestDevice = Default::default();
@@ -232,6 +449,39 @@ mod tests {
         assert_close(&g.get(&model.bias).array(), &[0.7679174, -0.31687993]);
     }
 
+    #[test]
+    fn test_forward_runtime_batch() {
+        let dev: TestDevice = Default::default();
+
+        let model = Linear {
+            weight: dev.tensor(W),
+            bias: dev.tensor(B),
+        };
+
+        let x: Tensor<Rank2<3, 5>, f32, _> = dev.sample_normal();
+        let mut x_rt: Tensor<(usize, Const<5>), f32, _> = dev.zeros_like(&(3, Const));
+        x_rt.copy_from(&x.as_vec());
+
+        let y = model.forward(x.trace());
+        let y_rt = model.forward(x_rt.trace());
+        assert_eq!(y_rt.shape(), &(3, Const));
+        assert_eq!(y_rt.as_vec(), y.as_vec());
+
+        let g = y.square().mean().backward();
+        let g_rt = y_rt.square().mean().backward();
+        assert_eq!(
+            g_rt.get(&model.weight).array(),
+            g.get(&model.weight).array()
+        );
+        assert_eq!(g_rt.get(&model.bias).array(), g.get(&model.bias).array());
+        assert_eq!(g_rt.get(&x_rt).as_vec(), g.get(&x).as_vec());
+
+        // runtime batch & sequence dimensions
+        let x: Tensor<(usize, usize, Const<5>), f32, _> = dev.zeros_like(&(2, 4, Const));
+        let y = model.forward(x);
+        assert_eq!(y.shape(), &(2, 4, Const::<2>));
+    }
+
     #[test]
     fn test_forward_3d() {
         let dev: TestDevice = Default::default();
@@ -272,11 +522,39 @@ mod tests {
         assert_close(&g.get(&model.bias).array(), &[0.40265593, -0.2874091]);
     }
 
+    #[test]
+    fn test_forward_fused_matches_unfused() {
+        let dev: TestDevice = Default::default();
+
+        let model: Linear<64, 32, f32, _> = BuildModule::build(&dev);
+        let x: Tensor<Rank2<16, 64>, f32, _> = dev.sample_normal();
+
+        let fused = model.forward(x.trace());
+        let unfused = x.trace().matmul(model.weight.trace().permute());
+        let unfused = crate::nn::Bias1D {
+            bias: model.bias.clone(),
+        }
+        .forward(unfused);
+        assert_close(&fused.array(), &unfused.array());
+
+        let g_fused = fused.square().mean().backward();
+        let g_unfused = unfused.square().mean().backward();
+        assert_close(
+            &g_fused.get(&model.weight).array(),
+            &g_unfused.get(&model.weight).array(),
+        );
+        assert_close(
+            &g_fused.get(&model.bias).array(),
+            &g_unfused.get(&model.bias).array(),
+        );
+        assert_close(&g_fused.get(&x).array(), &g_unfused.get(&x).array());
+    }
+
     #[test]
     fn test_linear_missing_gradients() {
         let dev: TestDevice = Default::default();
 
-        let mut model: Linear<5, 3, _> = BuildModule::build(&dev);
+        let mut model: Linear<5, 3, f32, _> = BuildModule::build(&dev);
         let mut g: SimpleUpdater = Default::default();
 
         // no gradients present
@@ -299,4 +577,96 @@ mod tests {
         model.update(&mut g, &mut unused).unwrap();
         assert!(unused.is_empty());
     }
+
+    #[cfg(feature = "f64")]
+    #[test]
+    fn test_linear_f64_forward_backward() {
+        let dev: Cpu = Default::default();
+
+        let model: Linear<5, 2, f64, Cpu> = Linear {
+            weight: dev.tensor(W.map(|r| r.map(|w| w as f64))),
+            bias: dev.tensor(B.map(|b| b as f64)),
+        };
+
+        let x = dev.tensor([-0.8808001f64, 2.4185333, 2.2478335, 0.0565211, 2.031299]);
+        let y = model.forward(x.trace());
+        assert_close(&y.array().map(|v| v as f32), &[-0.93430865, 0.08624211]);
+
+        // same gradients as `test_forward_1d`, which reduces with `mean` instead of `sum`
+        let g = y.square().sum().backward();
+        assert_close(
+            &g.get(&model.weight)
+                .array()
+                .map(|r| r.map(|v| v as f32 / 2.0)),
+            &[
+                [0.82293916, -2.2596567, -2.1001704, -0.05280815, -1.8978603],
+                [-0.07596206, 0.20857942, 0.19385791, 0.004874499, 0.17518352],
+            ],
+        );
+        assert_close(
+            &g.get(&model.bias).array().map(|v| v as f32 / 2.0),
+            &[-0.93430865, 0.08624211],
+        );
+
+        let _: Linear<5, 2, f64, Cpu> = BuildModule::build(&dev);
+    }
+
+    #[test]
+    fn test_linear_no_bias_forward_2d() {
+        let dev: TestDevice = Default::default();
+
+        let model = LinearNoBias {
+            weight: dev.tensor(W),
+        };
+
+        let x = dev.tensor([
+            [-1.9468665, 1.4611785, -1.6698982, 1.408863, 1.3425643],
+            [-1.3399831, 3.0510678, -0.17936817, -0.04943254, -0.8052705],
+            [-0.8291412, 0.07691376, -0.26538327, 0.90017676, -1.8790455],
+        ]);
+        let y = model.forward(x.trace());
+        assert_close(
+            &y.array(),
+            &[
+                [1.0149013, 0.27786547],
+                [-0.38199905, 0.14271596],
+                [0.5412404, -0.4990702],
+            ],
+        );
+
+        let g = y.square().mean().backward();
+        assert_close(
+            &g.get(&model.weight).array(),
+            &[
+                [-0.63758993, 0.11969196, -0.5899665, 0.6453174, 0.21772248],
+                [
+                    -0.10613476,
+                    0.26768726,
+                    -0.11905362,
+                    -0.021610612,
+                    0.3986343,
+                ],
+            ],
+        );
+    }
+
+    #[test]
+    fn test_linear_no_bias_missing_gradients() {
+        let dev: TestDevice = Default::default();
+
+        let mut model: LinearNoBias<5, 3, _> = BuildModule::build(&dev);
+        let mut g: SimpleUpdater = Default::default();
+
+        // no gradients present
+        let mut unused = Default::default();
+        model.update(&mut g, &mut unused).unwrap();
+        assert_eq!(&unused.ids, &[*model.weight.id()]);
+
+        g.0.try_alloc_for(&model.weight).unwrap();
+
+        // weight gradient is present
+        let mut unused = Default::default();
+        model.update(&mut g, &mut unused).unwrap();
+        assert!(unused.is_empty());
+    }
 }
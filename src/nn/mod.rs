@@ -19,6 +19,7 @@
 //! Here is a list of existing modules that have different behavior in these
 //! two functions:
 //!
+//! - [BatchNorm1D]
 //! - [BatchNorm2D]
 //! - [DropoutOneIn]
 //! - [Dropout]
@@ -36,8 +37,8 @@
 //!
 //! Here, the return type of [BuildOnDevice] depends on the device you pass in.
 //!
-//! For example, when using device [Cpu], the type is `Linear<5, 2, Cpu>`, or when using
-//! a `Cuda` device, the type is `Linear<5, 2, Cuda>`.
+//! For example, when using device [Cpu], the type is `Linear<5, 2, f32, Cpu>`, or when using
+//! a `Cuda` device, the type is `Linear<5, 2, f32, Cuda>`.
 //!
 //! Alternatively, you can use [BuildModule], which requires device specific model definitions:
 //!
@@ -45,7 +46,7 @@
 //! # use dfdx::prelude::*;
 //! type Dev = Cpu;
 //! let dev: Dev = Default::default();
-//! let model: Linear<5, 2, Dev> = BuildModule::build(&dev);
+//! let model: Linear<5, 2, f32, Dev> = BuildModule::build(&dev);
 //! ```
 //!
 //! # Resetting parameters
@@ -70,6 +71,13 @@
 //! type Mlp = (Linear<5, 3>, ReLU, Linear<3, 2>);
 //! ```
 //!
+//! The [crate::sequential!] macro writes out the tuple for you, and nests the tuples
+//! when there are more than 6 modules:
+//! ```rust
+//! # use dfdx::prelude::*;
+//! type Mlp = sequential!(Linear<5, 3>, ReLU, Linear<3, 2>);
+//! ```
+//!
 //! Here's a more complex feedforward network that takes vectors of 5 elements and maps them to 2 elements.
 //! ```rust
 //! # use dfdx::prelude::*;
@@ -103,34 +111,60 @@
 
 mod activations;
 mod add_into;
+mod batchnorm1d;
 mod batchnorm2d;
+mod bias1d;
+mod bilinear;
+mod checkpoint;
 mod conv;
 mod dropout;
 mod embedding;
 mod flatten;
+mod frozen;
 mod generalized_residual;
+mod gru;
+mod impl_module_for_array;
+mod impl_module_for_option;
 mod impl_module_for_tuples;
+mod lambda;
 mod layer_norm;
 mod linear;
 mod module;
 mod pool2d;
 mod pool_global;
+mod positional_encoding;
+mod prelu;
+#[cfg(feature = "std")]
+mod profiled;
 mod repeated;
 mod residual;
+mod sequential;
 mod split_into;
 mod transformer;
 
 pub use activations::*;
 pub use add_into::*;
+pub use batchnorm1d::*;
 pub use batchnorm2d::*;
+pub use bias1d::*;
+pub use bilinear::*;
+pub use checkpoint::*;
 pub use dropout::*;
 pub use embedding::*;
+pub use flatten::Reshape;
+pub use frozen::*;
 pub use generalized_residual::*;
+pub use gru::*;
 pub use impl_module_for_tuples::*;
+pub use lambda::*;
 pub use layer_norm::*;
 pub use linear::*;
 pub use module::*;
 pub use pool_global::*;
+pub use positional_encoding::*;
+pub use prelu::*;
+#[cfg(feature = "std")]
+pub use profiled::*;
 pub use repeated::*;
 pub use residual::*;
 pub use split_into::*;
@@ -10,6 +10,30 @@ use zip::{result::ZipResult, ZipArchive, ZipWriter};
 impl<T: ZeroSizedModule> SaveToNpz for T {}
 impl<T: ZeroSizedModule> LoadFromNpz for T {}
 
+impl<const C: usize, D: Device<f32>> SaveToNpz for BatchNorm1D<C, D> {
+    fn write<W: Write + Seek>(&self, p: &str, w: &mut zip::ZipWriter<W>) -> ZipResult<()> {
+        self.scale.write_to_npz(w, format!("{p}scale.npy"))?;
+        self.bias.write_to_npz(w, format!("{p}bias.npy"))?;
+        self.running_mean
+            .write_to_npz(w, format!("{p}running_mean.npy"))?;
+        self.running_var
+            .write_to_npz(w, format!("{p}running_var.npy"))?;
+        Ok(())
+    }
+}
+
+impl<const C: usize, D: Device<f32>> LoadFromNpz for BatchNorm1D<C, D> {
+    fn read<R: Read + Seek>(&mut self, p: &str, r: &mut ZipArchive<R>) -> Result<(), NpzError> {
+        self.scale.read_from_npz(r, format!("{p}scale.npy"))?;
+        self.bias.read_from_npz(r, format!("{p}bias.npy"))?;
+        self.running_mean
+            .read_from_npz(r, format!("{p}running_mean.npy"))?;
+        self.running_var
+            .read_from_npz(r, format!("{p}running_var.npy"))?;
+        Ok(())
+    }
+}
+
 impl<const C: usize, D: Device<f32>> SaveToNpz for BatchNorm2D<C, D> {
     fn write<W: Write + Seek>(&self, p: &str, w: &mut zip::ZipWriter<W>) -> ZipResult<()> {
         self.scale.write_to_npz(w, format!("{p}scale.npy"))?;
@@ -34,6 +58,20 @@ impl<const C: usize, D: Device<f32>> LoadFromNpz for BatchNorm2D<C, D> {
     }
 }
 
+impl<const M: usize, D: Device<f32>> SaveToNpz for Bias1D<M, D> {
+    fn write<W: Write + Seek>(&self, p: &str, w: &mut zip::ZipWriter<W>) -> ZipResult<()> {
+        self.bias.write_to_npz(w, format!("{p}bias.npy"))?;
+        Ok(())
+    }
+}
+
+impl<const M: usize, D: Device<f32>> LoadFromNpz for Bias1D<M, D> {
+    fn read<R: Read + Seek>(&mut self, p: &str, r: &mut ZipArchive<R>) -> Result<(), NpzError> {
+        self.bias.read_from_npz(r, format!("{p}bias.npy"))?;
+        Ok(())
+    }
+}
+
 #[cfg(feature = "nightly")]
 impl<
         const I: usize,
@@ -68,6 +106,81 @@ impl<
     }
 }
 
+impl<M: SaveToNpz> SaveToNpz for Frozen<M> {
+    fn write<W: Write + Seek>(&self, p: &str, w: &mut ZipWriter<W>) -> ZipResult<()> {
+        self.0.write(p, w)
+    }
+}
+
+impl<M: LoadFromNpz> LoadFromNpz for Frozen<M> {
+    fn read<R: Read + Seek>(&mut self, p: &str, r: &mut ZipArchive<R>) -> Result<(), NpzError> {
+        self.0.read(p, r)
+    }
+}
+
+impl<M: SaveToNpz> SaveToNpz for Checkpoint<M> {
+    fn write<W: Write + Seek>(&self, p: &str, w: &mut ZipWriter<W>) -> ZipResult<()> {
+        self.0.write(p, w)
+    }
+}
+
+impl<M: LoadFromNpz> LoadFromNpz for Checkpoint<M> {
+    fn read<R: Read + Seek>(&mut self, p: &str, r: &mut ZipArchive<R>) -> Result<(), NpzError> {
+        self.0.read(p, r)
+    }
+}
+
+impl<M: SaveToNpz> SaveToNpz for Profiled<M> {
+    fn write<W: Write + Seek>(&self, p: &str, w: &mut ZipWriter<W>) -> ZipResult<()> {
+        self.0.write(p, w)
+    }
+}
+
+impl<M: LoadFromNpz> LoadFromNpz for Profiled<M> {
+    fn read<R: Read + Seek>(&mut self, p: &str, r: &mut ZipArchive<R>) -> Result<(), NpzError> {
+        self.0.read(p, r)
+    }
+}
+
+impl<F> SaveToNpz for Lambda<F> {}
+impl<F> LoadFromNpz for Lambda<F> {}
+
+impl<M: SaveToNpz> SaveToNpz for Option<M> {
+    fn write<W: Write + Seek>(&self, p: &str, w: &mut ZipWriter<W>) -> ZipResult<()> {
+        match self {
+            Some(m) => m.write(p, w),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<M: LoadFromNpz> LoadFromNpz for Option<M> {
+    fn read<R: Read + Seek>(&mut self, p: &str, r: &mut ZipArchive<R>) -> Result<(), NpzError> {
+        match self {
+            Some(m) => m.read(p, r),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<M: SaveToNpz, const N: usize> SaveToNpz for [M; N] {
+    fn write<W: Write + Seek>(&self, p: &str, w: &mut ZipWriter<W>) -> ZipResult<()> {
+        for (i, m) in self.iter().enumerate() {
+            m.write(&format!("{p}{i}."), w)?;
+        }
+        Ok(())
+    }
+}
+
+impl<M: LoadFromNpz, const N: usize> LoadFromNpz for [M; N] {
+    fn read<R: Read + Seek>(&mut self, p: &str, r: &mut ZipArchive<R>) -> Result<(), NpzError> {
+        for (i, m) in self.iter_mut().enumerate() {
+            m.read(&format!("{p}{i}."), r)?;
+        }
+        Ok(())
+    }
+}
+
 impl<F: SaveToNpz, R: SaveToNpz> SaveToNpz for GeneralizedResidual<F, R> {
     fn write<W: Write + Seek>(&self, p: &str, w: &mut ZipWriter<W>) -> ZipResult<()> {
         self.f.write(&format!("{p}.f"), w)?;
@@ -82,6 +195,32 @@ impl<F: LoadFromNpz, R: LoadFromNpz> LoadFromNpz for GeneralizedResidual<F, R> {
     }
 }
 
+impl<const V: usize, const M: usize, D: Device<f32>> SaveToNpz for Embedding<V, M, D> {
+    fn write<W: Write + Seek>(&self, p: &str, w: &mut ZipWriter<W>) -> ZipResult<()> {
+        self.weight.write_to_npz(w, format!("{p}weight.npy"))?;
+        Ok(())
+    }
+}
+
+impl<const V: usize, const M: usize, D: Device<f32>> LoadFromNpz for Embedding<V, M, D> {
+    fn read<R: Read + Seek>(&mut self, p: &str, r: &mut ZipArchive<R>) -> Result<(), NpzError> {
+        self.weight.read_from_npz(r, format!("{p}weight.npy"))?;
+        Ok(())
+    }
+}
+
+impl<const V: usize, const M: usize, D: Device<f32>> SaveToNpz for TiedEmbedding<V, M, D> {
+    fn write<W: Write + Seek>(&self, p: &str, w: &mut ZipWriter<W>) -> ZipResult<()> {
+        self.embedding.write(p, w)
+    }
+}
+
+impl<const V: usize, const M: usize, D: Device<f32>> LoadFromNpz for TiedEmbedding<V, M, D> {
+    fn read<R: Read + Seek>(&mut self, p: &str, r: &mut ZipArchive<R>) -> Result<(), NpzError> {
+        self.embedding.read(p, r)
+    }
+}
+
 impl<const M: usize, D: Device<f32>> SaveToNpz for LayerNorm1D<M, D> {
     fn write<W: Write + Seek>(&self, p: &str, w: &mut ZipWriter<W>) -> ZipResult<()> {
         self.gamma.write_to_npz(w, format!("{p}gamma.npy"))?;
@@ -98,7 +237,25 @@ impl<const M: usize, D: Device<f32>> LoadFromNpz for LayerNorm1D<M, D> {
     }
 }
 
-impl<const I: usize, const O: usize, D: Device<f32>> SaveToNpz for Linear<I, O, D> {
+impl<const I: usize, const O: usize, D: Device<f32>> SaveToNpz for Linear<I, O, f32, D> {
+    fn write<W: Write + Seek>(&self, p: &str, w: &mut ZipWriter<W>) -> ZipResult<()> {
+        self.weight.write_to_npz(w, format!("{p}weight.npy"))?;
+        self.bias.write_to_npz(w, format!("{p}bias.npy"))?;
+        Ok(())
+    }
+}
+
+impl<const I: usize, const O: usize, D: Device<f32>> LoadFromNpz for Linear<I, O, f32, D> {
+    fn read<R: Read + Seek>(&mut self, p: &str, r: &mut ZipArchive<R>) -> Result<(), NpzError> {
+        self.weight.read_from_npz(r, format!("{p}weight.npy"))?;
+        self.bias.read_from_npz(r, format!("{p}bias.npy"))?;
+        Ok(())
+    }
+}
+
+impl<const I1: usize, const I2: usize, const O: usize, D: Device<f32>> SaveToNpz
+    for BilinearLayer<I1, I2, O, D>
+{
     fn write<W: Write + Seek>(&self, p: &str, w: &mut ZipWriter<W>) -> ZipResult<()> {
         self.weight.write_to_npz(w, format!("{p}weight.npy"))?;
         self.bias.write_to_npz(w, format!("{p}bias.npy"))?;
@@ -106,7 +263,9 @@ impl<const I: usize, const O: usize, D: Device<f32>> SaveToNpz for Linear<I, O,
     }
 }
 
-impl<const I: usize, const O: usize, D: Device<f32>> LoadFromNpz for Linear<I, O, D> {
+impl<const I1: usize, const I2: usize, const O: usize, D: Device<f32>> LoadFromNpz
+    for BilinearLayer<I1, I2, O, D>
+{
     fn read<R: Read + Seek>(&mut self, p: &str, r: &mut ZipArchive<R>) -> Result<(), NpzError> {
         self.weight.read_from_npz(r, format!("{p}weight.npy"))?;
         self.bias.read_from_npz(r, format!("{p}bias.npy"))?;
@@ -114,6 +273,52 @@ impl<const I: usize, const O: usize, D: Device<f32>> LoadFromNpz for Linear<I, O
     }
 }
 
+impl<const I: usize, const O: usize, D: Device<f32>> SaveToNpz for LinearNoBias<I, O, D> {
+    fn write<W: Write + Seek>(&self, p: &str, w: &mut ZipWriter<W>) -> ZipResult<()> {
+        self.weight.write_to_npz(w, format!("{p}weight.npy"))?;
+        Ok(())
+    }
+}
+
+impl<const I: usize, const O: usize, D: Device<f32>> LoadFromNpz for LinearNoBias<I, O, D> {
+    fn read<R: Read + Seek>(&mut self, p: &str, r: &mut ZipArchive<R>) -> Result<(), NpzError> {
+        self.weight.read_from_npz(r, format!("{p}weight.npy"))?;
+        Ok(())
+    }
+}
+
+impl<const S: usize, const M: usize, D: Device<f32>> SaveToNpz
+    for SinusoidalPositionalEncoding<S, M, D>
+{
+    fn write<W: Write + Seek>(&self, p: &str, w: &mut ZipWriter<W>) -> ZipResult<()> {
+        self.encoding.write_to_npz(w, format!("{p}encoding.npy"))?;
+        Ok(())
+    }
+}
+
+impl<const S: usize, const M: usize, D: Device<f32>> LoadFromNpz
+    for SinusoidalPositionalEncoding<S, M, D>
+{
+    fn read<R: Read + Seek>(&mut self, p: &str, r: &mut ZipArchive<R>) -> Result<(), NpzError> {
+        self.encoding.read_from_npz(r, format!("{p}encoding.npy"))?;
+        Ok(())
+    }
+}
+
+impl<D: Device<f32>> SaveToNpz for PReLU<D> {
+    fn write<W: Write + Seek>(&self, p: &str, w: &mut ZipWriter<W>) -> ZipResult<()> {
+        self.a.write_to_npz(w, format!("{p}a.npy"))?;
+        Ok(())
+    }
+}
+
+impl<D: Device<f32>> LoadFromNpz for PReLU<D> {
+    fn read<R: Read + Seek>(&mut self, p: &str, r: &mut ZipArchive<R>) -> Result<(), NpzError> {
+        self.a.read_from_npz(r, format!("{p}a.npy"))?;
+        Ok(())
+    }
+}
+
 macro_rules! tuple_npz_impl {
     ([$($name:ident),+], [$($idx:tt),+]) => {
 impl<$($name: SaveToNpz),+> SaveToNpz for ($($name,)+) {
@@ -334,7 +539,7 @@ impl<
 mod tests {
     use crate::{
         shapes::*,
-        tensor::{AsArray, SampleTensor, Tensor},
+        tensor::{numpy::NpyError, AsArray, AsVec, SampleTensor, Tensor},
         tensor_ops::Device,
         tests::TestDevice,
     };
@@ -365,6 +570,31 @@ mod tests {
         assert_eq!(loaded.forward(x).array(), y.array());
     }
 
+    #[test]
+    fn test_batchnorm1d_save_load() {
+        let dev: TestDevice = Default::default();
+        type Model = BatchNorm1D<3>;
+
+        let x = dev.sample_normal::<Rank3<4, 3, 5>>();
+        let file = NamedTempFile::new().expect("failed to create tempfile");
+
+        let mut saved = Model::build_on_device(&dev);
+        let mut loaded = Model::build_on_device(&dev);
+
+        saved.running_mean.fill_with_distr(Standard);
+        saved.running_var.fill_with_distr(Standard);
+        saved.scale.fill_with_distr(Standard);
+        saved.bias.fill_with_distr(Standard);
+        let y = saved.forward(x.clone());
+
+        assert_ne!(loaded.forward(x.clone()).array(), y.array());
+
+        saved.save(file.path()).expect("");
+        loaded.load(file.path()).expect("");
+
+        assert_eq!(loaded.forward(x).array(), y.array());
+    }
+
     #[test]
     fn test_batchnorm2d_save_load() {
         let dev: TestDevice = Default::default();
@@ -390,6 +620,28 @@ mod tests {
         assert_eq!(loaded.forward(x).array(), y.array());
     }
 
+    #[test]
+    fn test_save_load_bias1d() {
+        let dev: TestDevice = Default::default();
+        type Model = Bias1D<5>;
+
+        let x = dev.sample_normal::<Rank2<2, 5>>();
+        let file = NamedTempFile::new().expect("failed to create tempfile");
+
+        let mut saved = Model::build_on_device(&dev);
+        let mut loaded = Model::build_on_device(&dev);
+
+        saved.bias.fill_with_distr(Standard);
+        let y = saved.forward(x.clone());
+
+        assert_ne!(loaded.forward(x.clone()).array(), y.array());
+
+        saved.save(file.path()).expect("");
+        loaded.load(file.path()).expect("");
+
+        assert_eq!(loaded.forward(x).array(), y.array());
+    }
+
     #[cfg(feature = "nightly")]
     #[test]
     fn test_save_load_conv() {
@@ -414,6 +666,65 @@ mod tests {
         test_save_load::<Rank1<5>, f32, TestDevice, (T, T)>(&dev);
     }
 
+    #[test]
+    fn test_save_load_linear_identical_weights() {
+        let dev: TestDevice = Default::default();
+        let file = NamedTempFile::new().expect("failed to create tempfile");
+
+        let saved = Linear::<5, 2>::build_on_device(&dev);
+        let mut loaded = Linear::<5, 2>::build_on_device(&dev);
+        saved.save(file.path()).expect("");
+        loaded.load(file.path()).expect("");
+
+        let to_bits =
+            |t: std::vec::Vec<f32>| t.iter().map(|x| x.to_bits()).collect::<std::vec::Vec<_>>();
+        assert_eq!(
+            to_bits(loaded.weight.as_vec()),
+            to_bits(saved.weight.as_vec())
+        );
+        assert_eq!(to_bits(loaded.bias.as_vec()), to_bits(saved.bias.as_vec()));
+    }
+
+    #[test]
+    fn test_load_mismatched_shape() {
+        let dev: TestDevice = Default::default();
+        let file = NamedTempFile::new().expect("failed to create tempfile");
+
+        Linear::<5, 2>::build_on_device(&dev)
+            .save(file.path())
+            .expect("");
+        let err = Linear::<5, 3>::build_on_device(&dev)
+            .load(file.path())
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            NpzError::Npy(NpyError::ShapeMismatch { ref expected, ref found })
+            if expected == &[3, 5] && found == &[2, 5]
+        ));
+    }
+
+    #[test]
+    fn test_save_load_embedding() {
+        let dev: TestDevice = Default::default();
+        let file = NamedTempFile::new().expect("failed to create tempfile");
+
+        let saved = Embedding::<7, 3>::build_on_device(&dev);
+        let mut loaded = Embedding::<7, 3>::build_on_device(&dev);
+        assert_ne!(loaded.weight.array(), saved.weight.array());
+        saved.save(file.path()).expect("");
+        loaded.load(file.path()).expect("");
+        assert_eq!(loaded.weight.array(), saved.weight.array());
+
+        let saved = TiedEmbedding::<7, 3>::build_on_device(&dev);
+        let mut loaded = TiedEmbedding::<7, 3>::build_on_device(&dev);
+        saved.save(file.path()).expect("");
+        loaded.load(file.path()).expect("");
+        assert_eq!(
+            loaded.embedding.weight.array(),
+            saved.embedding.weight.array()
+        );
+    }
+
     #[test]
     fn test_save_load_tuple() {
         let dev: TestDevice = Default::default();
@@ -455,6 +766,14 @@ mod tests {
         test_save_load::<Rank1<3>, f32, TestDevice, (T, T)>(&dev);
     }
 
+    #[test]
+    fn test_save_load_array() {
+        type T = [Linear<3, 3>; 4];
+        let dev: TestDevice = Default::default();
+        test_save_load::<Rank1<3>, f32, TestDevice, T>(&dev);
+        test_save_load::<Rank1<3>, f32, TestDevice, (T, T)>(&dev);
+    }
+
     #[test]
     fn test_save_load_residual() {
         type T = Residual<Linear<5, 5>>;
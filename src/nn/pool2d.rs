@@ -67,7 +67,7 @@ impl_pools!(MinPool2D, ConstMinPool2D);
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{shapes::*, tensor::*, tests::TestDevice};
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::TestDevice};
 
     #[test]
     fn test_max_forward_3d_sizes() {
@@ -191,4 +191,29 @@ mod tests {
         let _: Tensor<Rank3<1, 6, 6>, _, _> = <(A, A)>::default().forward(x.clone());
         let _: Tensor<Rank3<1, 8, 8>, _, _> = <(A, A, B)>::default().forward(x.clone());
     }
+
+    #[test]
+    fn test_max_backward_concentrates_at_max() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<1, 2, 4>, f32, _> =
+            dev.tensor([[[1.0, 3.0, -1.0, -2.0], [2.0, 0.5, -4.0, -3.0]]]);
+        let y = MaxPool2D::<2, 2>::default().forward(x.trace());
+        assert_eq!(y.array(), [[[3.0, -1.0]]]);
+        let g = y.sum().backward();
+        assert_eq!(
+            g.get(&x).array(),
+            [[[0.0, 1.0, 1.0, 0.0], [0.0, 0.0, 0.0, 0.0]]]
+        );
+    }
+
+    #[test]
+    fn test_avg_backward_distributes_evenly() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank4<2, 1, 2, 2>, f32, _> =
+            dev.tensor([[[[1.0, 3.0], [2.0, 0.5]]], [[[-1.0, -2.0], [-4.0, -3.0]]]]);
+        let y = AvgPool2D::<2, 2>::default().forward(x.trace());
+        assert_eq!(y.array(), [[[[1.625]]], [[[-2.5]]]]);
+        let g = y.sum().backward();
+        assert_eq!(g.get(&x).array(), [[[[0.25; 2]; 2]]; 2]);
+    }
 }
@@ -0,0 +1,179 @@
+use crate::{gradients::Tape, optim::*, shapes::*, tensor::*, tensor_ops::*};
+
+use super::module::{BuildModule, Module, ModuleMut, ResetParams, ToDevice};
+
+/// Adds the sinusoidal positional encodings from
+/// [Attention Is All You Need](https://arxiv.org/abs/1706.03762) to its input.
+///
+/// The encoding table is computed when the module is built:
+/// - `encoding[pos][2i] = sin(pos / 10000^(2i / DIM))`
+/// - `encoding[pos][2i + 1] = cos(pos / 10000^(2i / DIM))`
+///
+/// [Self::encoding] is not a parameter, so it is skipped by [GradientUpdate] and never
+/// changed by optimizers, but it is still moved by [ToDevice]. [ResetParams] recomputes it.
+///
+/// # Generics
+/// - `SEQ` The length of the sequences.
+/// - `DIM` The size of each item in the sequence, usually the embedding size.
+///
+/// # Examples
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let model: SinusoidalPositionalEncoding<5, 4> = BuildModule::build(&dev);
+/// // single sequence
+/// let _: Tensor<Rank2<5, 4>, f32, _> = model.forward(dev.zeros::<Rank2<5, 4>>());
+/// // batched sequences
+/// let _: Tensor<Rank3<3, 5, 4>, f32, _> = model.forward(dev.zeros::<Rank3<3, 5, 4>>());
+/// ```
+#[derive(Debug, Clone)]
+pub struct SinusoidalPositionalEncoding<const SEQ: usize, const DIM: usize, D: Device<f32> = Cpu> {
+    /// The encoding table, shape (SEQ, DIM)
+    pub encoding: Tensor<Rank2<SEQ, DIM>, f32, D>,
+}
+
+/// Computes the (SEQ, DIM) table of sinusoidal encodings in row-major order.
+fn sinusoidal_table<const SEQ: usize, const DIM: usize>() -> std::vec::Vec<f32> {
+    let mut table = std::vec::Vec::with_capacity(SEQ * DIM);
+    for pos in 0..SEQ {
+        for i in 0..DIM {
+            let freq = 10000f64.powf(-((i - i % 2) as f64) / DIM as f64);
+            let angle = pos as f64 * freq;
+            table.push(if i % 2 == 0 { angle.sin() } else { angle.cos() } as f32);
+        }
+    }
+    table
+}
+
+impl<const SEQ: usize, const DIM: usize, D: Device<f32>> BuildModule<D, f32>
+    for SinusoidalPositionalEncoding<SEQ, DIM, D>
+{
+    fn try_build(device: &D) -> Result<Self, D::Err> {
+        let encoding =
+            device.try_tensor_from_vec(sinusoidal_table::<SEQ, DIM>(), (Const, Const))?;
+        Ok(Self { encoding })
+    }
+}
+
+impl<const SEQ: usize, const DIM: usize, D: Device<f32>> ResetParams<D, f32>
+    for SinusoidalPositionalEncoding<SEQ, DIM, D>
+{
+    fn try_reset_params(&mut self) -> Result<(), D::Err> {
+        self.encoding.copy_from(&sinusoidal_table::<SEQ, DIM>());
+        Ok(())
+    }
+}
+
+impl<const SEQ: usize, const DIM: usize, D: Device<f32>> GradientUpdate<D, f32>
+    for SinusoidalPositionalEncoding<SEQ, DIM, D>
+{
+    fn update<U>(&mut self, _: &mut U, _: &mut UnusedTensors) -> Result<(), D::Err>
+    where
+        U: ParamUpdater<D, f32>,
+    {
+        Ok(())
+    }
+}
+
+impl<const SEQ: usize, const DIM: usize, D1: Device<f32>, D2: Device<f32>> ToDevice<D2>
+    for SinusoidalPositionalEncoding<SEQ, DIM, D1>
+{
+    type Output = SinusoidalPositionalEncoding<SEQ, DIM, D2>;
+    fn to_device(&self, device: &D2) -> Self::Output {
+        SinusoidalPositionalEncoding {
+            encoding: self.encoding.to_device(device),
+        }
+    }
+}
+
+impl<const SEQ: usize, const DIM: usize, D: Device<f32>, T: Tape<D>>
+    Module<Tensor<Rank2<SEQ, DIM>, f32, D, T>> for SinusoidalPositionalEncoding<SEQ, DIM, D>
+{
+    type Output = Tensor<Rank2<SEQ, DIM>, f32, D, T>;
+    fn forward(&self, x: Tensor<Rank2<SEQ, DIM>, f32, D, T>) -> Self::Output {
+        x + self.encoding.clone()
+    }
+}
+
+impl<B: Dim, const SEQ: usize, const DIM: usize, D: Device<f32>, T: Tape<D>>
+    Module<Tensor<(B, Const<SEQ>, Const<DIM>), f32, D, T>>
+    for SinusoidalPositionalEncoding<SEQ, DIM, D>
+{
+    type Output = Tensor<(B, Const<SEQ>, Const<DIM>), f32, D, T>;
+    fn forward(&self, x: Tensor<(B, Const<SEQ>, Const<DIM>), f32, D, T>) -> Self::Output {
+        let shape = *x.shape();
+        x + self.encoding.clone().broadcast_like(&shape)
+    }
+}
+
+impl<T, const SEQ: usize, const DIM: usize, D: Device<f32>> ModuleMut<T>
+    for SinusoidalPositionalEncoding<SEQ, DIM, D>
+where
+    Self: Module<T>,
+{
+    type Output = <Self as Module<T>>::Output;
+    fn forward_mut(&mut self, input: T) -> Self::Output {
+        self.forward(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::nn::{tests::SimpleUpdater, BuildOnDevice};
+    use crate::tests::*;
+
+    #[test]
+    fn test_sinusoidal_encoding_table() {
+        let dev: TestDevice = Default::default();
+        let m = SinusoidalPositionalEncoding::<3, 4>::build_on_device(&dev);
+        let e = m.encoding.array();
+        assert_eq!(e[0], [0.0, 1.0, 0.0, 1.0]);
+        assert_close(
+            &e[1],
+            &[1f32.sin(), 1f32.cos(), 0.01f32.sin(), 0.01f32.cos()],
+        );
+        assert_close(
+            &e[2],
+            &[2f32.sin(), 2f32.cos(), 0.02f32.sin(), 0.02f32.cos()],
+        );
+    }
+
+    #[test]
+    fn test_sinusoidal_encoding_forward() {
+        let dev: TestDevice = Default::default();
+        let m = SinusoidalPositionalEncoding::<3, 4>::build_on_device(&dev);
+        let e = m.encoding.array();
+
+        let x: Tensor<Rank2<3, 4>, f32, _> = dev.sample_normal();
+        let y = m.forward(x.trace());
+        let (x_array, y_array) = (x.array(), y.array());
+        assert_close(
+            &y_array,
+            &[0, 1, 2].map(|p| [0, 1, 2, 3].map(|i| x_array[p][i] + e[p][i])),
+        );
+        let g = y.exp().sum().backward();
+        assert_close(&g.get(&x).array(), &y_array.map(|r| r.map(f32::exp)));
+
+        let x: Tensor<Rank3<2, 3, 4>, f32, _> = dev.sample_normal();
+        let y = m.forward(x.clone());
+        let (x_array, y_array) = (x.array(), y.array());
+        for b in 0..2 {
+            assert_eq!(m.forward(dev.tensor(x_array[b])).array(), y_array[b]);
+        }
+    }
+
+    #[test]
+    fn test_sinusoidal_encoding_not_updated() {
+        let dev: TestDevice = Default::default();
+        let mut m = SinusoidalPositionalEncoding::<3, 4>::build_on_device(&dev);
+        let mut g: SimpleUpdater = Default::default();
+        g.0.try_alloc_for(&m.encoding).unwrap();
+
+        let mut unused = Default::default();
+        m.update(&mut g, &mut unused).unwrap();
+        assert!(unused.ids.is_empty());
+        // the gradient was left in place instead of being consumed
+        assert_eq!(g.0.get(&m.encoding).array(), [[0.0; 4]; 3]);
+    }
+}
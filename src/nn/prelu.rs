@@ -0,0 +1,112 @@
+use crate::{gradients::Tape, optim::*, shapes::*, tensor::*, tensor_ops::*};
+
+use super::{BuildModule, Module, ModuleMut, ResetParams, ToDevice};
+
+/// Parametric ReLU as described in [Delving Deep into Rectifiers](https://arxiv.org/abs/1502.01852).
+/// Computes `max(0, x) + a * min(0, x)`, where [Self::a] is a single learnable slope
+/// shared across all elements of the input.
+///
+/// Initializes [Self::a] to `0.25`.
+///
+/// # Examples
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// type Model = PReLU;
+/// let model = Model::build_on_device(&dev);
+/// let y = model.forward(dev.tensor([-2.0, -1.0, 0.0, 1.0, 2.0]));
+/// assert_eq!(y.array(), [-0.5, -0.25, 0.0, 1.0, 2.0]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct PReLU<D: Device<f32> = Cpu> {
+    /// The slope for negative inputs, shape ()
+    pub a: Tensor<Rank0, f32, D>,
+}
+
+impl<D: Device<f32>> BuildModule<D, f32> for PReLU<D> {
+    fn try_build(device: &D) -> Result<Self, D::Err> {
+        let mut a: Tensor<Rank0, f32, D> = device.try_zeros()?;
+        a.copy_from(&[0.25]);
+        Ok(Self { a })
+    }
+}
+
+impl<D: Device<f32>> ResetParams<D, f32> for PReLU<D> {
+    fn try_reset_params(&mut self) -> Result<(), D::Err> {
+        self.a.copy_from(&[0.25]);
+        Ok(())
+    }
+}
+
+impl<D1: Device<f32>, D2: Device<f32>> ToDevice<D2> for PReLU<D1> {
+    type Output = PReLU<D2>;
+    fn to_device(&self, device: &D2) -> Self::Output {
+        PReLU {
+            a: self.a.to_device(device),
+        }
+    }
+}
+
+impl<D: Device<f32>> GradientUpdate<D, f32> for PReLU<D> {
+    fn update<U>(&mut self, updater: &mut U, unused: &mut UnusedTensors) -> Result<(), <D>::Err>
+    where
+        U: ParamUpdater<D, f32>,
+    {
+        self.a.update(updater, unused)
+    }
+}
+
+impl<S: Shape, D: Device<f32>, T: Tape<D>> Module<Tensor<S, f32, D, T>> for PReLU<D> {
+    type Output = Tensor<S, f32, D, T>;
+    fn forward(&self, x: Tensor<S, f32, D, T>) -> Self::Output {
+        let shape = *x.shape();
+        let pos = x.with_empty_tape().relu();
+        let neg = x.negate().relu().negate();
+        neg * self.a.retaped::<T>().broadcast_like(&shape) + pos
+    }
+}
+
+impl<T, D: Device<f32>> ModuleMut<T> for PReLU<D>
+where
+    Self: Module<T>,
+{
+    type Output = <Self as Module<T>>::Output;
+    fn forward_mut(&mut self, input: T) -> Self::Output {
+        self.forward(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::{assert_close, TestDevice};
+
+    #[test]
+    fn test_prelu_forward_backward() {
+        let dev: TestDevice = Default::default();
+        let model: PReLU<_> = BuildModule::build(&dev);
+        assert_eq!(model.a.array(), 0.25);
+
+        let x = dev.tensor([[-2.0, -1.0, 0.0], [1.0, 2.0, -4.0]]);
+        let y = model.forward(x.trace());
+        assert_eq!(y.array(), [[-0.5, -0.25, 0.0], [1.0, 2.0, -1.0]]);
+
+        let g = y.sum().backward();
+        assert_eq!(g.get(&model.a).array(), -7.0);
+        assert_eq!(g.get(&x).array(), [[0.25, 0.25, 0.0], [1.0, 1.0, 0.25]]);
+    }
+
+    #[test]
+    fn test_prelu_reset_and_update() {
+        let dev: TestDevice = Default::default();
+        let mut model: PReLU<_> = BuildModule::build(&dev);
+
+        let g = model.forward(dev.tensor([-1.0]).trace()).sum().backward();
+        let mut sgd = Sgd::new(&model, Default::default());
+        sgd.update(&mut model, g).unwrap();
+        assert_close(&model.a.array(), &0.26);
+
+        model.reset_params();
+        assert_eq!(model.a.array(), 0.25);
+    }
+}
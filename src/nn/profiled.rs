@@ -0,0 +1,189 @@
+use crate::{optim::*, shapes::*, tensor_ops::Device};
+
+use super::{BuildModule, Module, ModuleMut, ResetParams, ToDevice};
+
+use std::{
+    cell::{Cell, RefCell},
+    collections::VecDeque,
+    time::Duration,
+    time::Instant,
+    vec::Vec,
+};
+
+/// The most [ForwardTiming]s kept on a thread. Once there are more, the oldest ones are dropped.
+pub const MAX_FORWARD_TIMINGS: usize = 1024;
+
+std::thread_local! {
+    static RECORD_FORWARD_TIMINGS: Cell<bool> = const { Cell::new(false) };
+    static FORWARD_TIMINGS: RefCell<VecDeque<ForwardTiming>> =
+        const { RefCell::new(VecDeque::new()) };
+}
+
+/// How long a single forward of a [Profiled] module took.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ForwardTiming {
+    /// The type name of the wrapped module.
+    pub name: &'static str,
+    /// Wall clock duration of the forward call.
+    pub duration: Duration,
+}
+
+/// Turns recording of [ForwardTiming]s on or off for the current thread. Recording is
+/// off by default, so [Profiled] modules don't do anything until this is called with `true`.
+pub fn record_forward_timings(enabled: bool) {
+    RECORD_FORWARD_TIMINGS.with(|record| record.set(enabled));
+}
+
+/// Removes and returns the [ForwardTiming]s recorded on the current thread, in the order
+/// the forward calls **finished**. This means a [Profiled] module nested inside of another one
+/// comes before the outer one.
+///
+/// Only the last [MAX_FORWARD_TIMINGS] recordings are kept.
+pub fn take_forward_timings() -> Vec<ForwardTiming> {
+    FORWARD_TIMINGS.with(|timings| std::mem::take(&mut *timings.borrow_mut()).into())
+}
+
+/// Measures the wall clock time of every [Module::forward()] and [ModuleMut::forward_mut()]
+/// of `M`, and records it on the current thread. Turn recording on with
+/// [record_forward_timings()], and get the recordings with [take_forward_timings()].
+///
+/// The input and output are passed through unchanged. Only modules that are wrapped
+/// are timed, so there is no cost for the rest of the model.
+///
+/// Note that cuda kernels are launched asynchronously, so on [crate::tensor::Cuda] this
+/// measures how long it took to launch the kernels, not to run them.
+///
+/// # Examples
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// type Model = (Profiled<Linear<5, 10>>, ReLU, Profiled<Linear<10, 2>>);
+/// let model = Model::build_on_device(&dev);
+/// record_forward_timings(true);
+/// let _ = model.forward(dev.zeros::<Rank1<5>>());
+/// let timings = take_forward_timings();
+/// assert_eq!(timings.len(), 2);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Profiled<M>(pub M);
+
+fn start() -> Option<Instant> {
+    RECORD_FORWARD_TIMINGS
+        .with(|record| record.get())
+        .then(Instant::now)
+}
+
+fn record<M>(start: Option<Instant>) {
+    if let Some(start) = start {
+        let timing = ForwardTiming {
+            name: std::any::type_name::<M>(),
+            duration: start.elapsed(),
+        };
+        FORWARD_TIMINGS.with(|timings| {
+            let mut timings = timings.borrow_mut();
+            if timings.len() == MAX_FORWARD_TIMINGS {
+                timings.pop_front();
+            }
+            timings.push_back(timing);
+        });
+    }
+}
+
+impl<D: Device<E>, E: Dtype, M: GradientUpdate<D, E>> GradientUpdate<D, E> for Profiled<M> {
+    fn update<U>(&mut self, updater: &mut U, unused: &mut UnusedTensors) -> Result<(), D::Err>
+    where
+        U: ParamUpdater<D, E>,
+    {
+        self.0.update(updater, unused)
+    }
+}
+
+impl<D: Device<E>, E: Dtype, M: BuildModule<D, E>> BuildModule<D, E> for Profiled<M> {
+    fn try_build(device: &D) -> Result<Self, <D>::Err> {
+        Ok(Self(BuildModule::try_build(device)?))
+    }
+}
+
+impl<D: Device<E>, E: Dtype, M: ResetParams<D, E>> ResetParams<D, E> for Profiled<M> {
+    fn try_reset_params(&mut self) -> Result<(), <D>::Err> {
+        self.0.try_reset_params()
+    }
+}
+
+impl<M: ToDevice<D>, D> ToDevice<D> for Profiled<M> {
+    type Output = Profiled<M::Output>;
+    fn to_device(&self, device: &D) -> Self::Output {
+        Profiled(self.0.to_device(device))
+    }
+}
+
+impl<T, M: Module<T>> Module<T> for Profiled<M> {
+    type Output = M::Output;
+    fn forward(&self, x: T) -> Self::Output {
+        let start = start();
+        let y = self.0.forward(x);
+        record::<M>(start);
+        y
+    }
+}
+
+impl<T, M: ModuleMut<T>> ModuleMut<T> for Profiled<M> {
+    type Output = M::Output;
+    fn forward_mut(&mut self, x: T) -> Self::Output {
+        let start = start();
+        let y = self.0.forward_mut(x);
+        record::<M>(start);
+        y
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestDevice;
+    use crate::{
+        nn::{BuildOnDevice, Linear, ReLU},
+        tensor::*,
+    };
+
+    #[test]
+    fn test_profiled_two_layers() {
+        let dev: TestDevice = Default::default();
+        type Model = (Profiled<Linear<4, 8>>, ReLU, Profiled<Linear<8, 2>>);
+        let mut model = Model::build_on_device(&dev);
+        record_forward_timings(true);
+        let _ = take_forward_timings();
+
+        let x: Tensor<Rank2<3, 4>, f32, _> = dev.sample_normal();
+        let y = model.forward(x.clone());
+        let timings = take_forward_timings();
+        assert_eq!(timings.len(), 2);
+        assert!(timings.iter().all(|t| t.name.contains("Linear")));
+        assert!(take_forward_timings().is_empty());
+
+        // same result as without profiling
+        let plain = (model.0 .0.clone(), ReLU, model.2 .0.clone());
+        assert_eq!(plain.forward(x.clone()).array(), y.array());
+
+        let _ = model.forward_mut(x.clone());
+        assert_eq!(take_forward_timings().len(), 2);
+
+        record_forward_timings(false);
+        let _ = model.forward(x);
+        assert!(take_forward_timings().is_empty());
+    }
+
+    #[test]
+    fn test_profiled_keeps_last_timings() {
+        let dev: TestDevice = Default::default();
+        let model: Profiled<ReLU> = Default::default();
+        let x: Tensor<Rank1<3>, f32, _> = dev.zeros();
+        record_forward_timings(true);
+        let _ = take_forward_timings();
+        for _ in 0..MAX_FORWARD_TIMINGS + 10 {
+            let _ = model.forward(x.clone());
+        }
+        record_forward_timings(false);
+        assert_eq!(take_forward_timings().len(), MAX_FORWARD_TIMINGS);
+    }
+}
@@ -74,7 +74,7 @@ mod tests {
     #[test]
     fn test_residual_reset() {
         let dev: TestDevice = Default::default();
-        let model: Residual<Linear<2, 5, _>> = BuildModule::build(&dev);
+        let model: Residual<Linear<2, 5, f32, _>> = BuildModule::build(&dev);
         assert_ne!(model.0.weight.array(), [[0.0; 2]; 5]);
         assert_ne!(model.0.bias.array(), [0.0; 5]);
     }
@@ -83,7 +83,7 @@ mod tests {
     fn test_residual_gradients() {
         let dev: TestDevice = Default::default();
 
-        let model: Residual<Linear<2, 2, _>> = BuildModule::build(&dev);
+        let model: Residual<Linear<2, 2, f32, _>> = BuildModule::build(&dev);
 
         let x = dev.sample_normal::<Rank2<4, 2>>();
         let y = model.forward(x.trace());
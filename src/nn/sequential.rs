@@ -0,0 +1,95 @@
+/// Builds a sequential model out of a list of modules, without writing out the nested tuples.
+///
+/// `sequential!(A, B, C)` is the tuple `(A, B, C)`. Since tuples only implement [crate::nn::Module]
+/// for up to 6 elements, longer lists are nested, so `sequential!(M1, ..., M8)`
+/// expands to `(M1, M2, M3, M4, M5, (M6, M7, M8))`. A single module expands to itself.
+///
+/// In type position, it creates the model type:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// type Mlp = sequential!(Linear<5, 3>, ReLU, Linear<3, 1>);
+/// let mlp = Mlp::build_on_device(&dev);
+/// let y: Tensor<Rank1<1>, f32, _> = mlp.forward(dev.zeros::<Rank1<5>>());
+/// ```
+///
+/// Prefixing the list with `values:` nests already constructed modules the same way:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// type Mlp = sequential!(Linear<5, 3>, ReLU, Linear<3, 1>);
+/// let l1: Linear<5, 3> = BuildModule::build(&dev);
+/// let l2: Linear<3, 1> = BuildModule::build(&dev);
+/// let mlp: Mlp = sequential!(values: l1, ReLU, l2);
+/// ```
+#[macro_export]
+macro_rules! sequential {
+    (values: $m1:expr, $m2:expr, $m3:expr, $m4:expr, $m5:expr, $m6:expr, $($rest:expr),+ $(,)?) => {
+        ($m1, $m2, $m3, $m4, $m5, $crate::sequential!(values: $m6, $($rest),+))
+    };
+    (values: $m:expr $(,)?) => {
+        $m
+    };
+    (values: $($m:expr),+ $(,)?) => {
+        ($($m, )+)
+    };
+    ($m1:ty, $m2:ty, $m3:ty, $m4:ty, $m5:ty, $m6:ty, $($rest:ty),+ $(,)?) => {
+        ($m1, $m2, $m3, $m4, $m5, $crate::sequential!($m6, $($rest),+))
+    };
+    ($m:ty $(,)?) => {
+        $m
+    };
+    ($($m:ty),+ $(,)?) => {
+        ($($m, )+)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{nn::*, shapes::*, tensor::*, tests::TestDevice};
+
+    #[test]
+    fn test_sequential_3_layers() {
+        let dev: TestDevice = Default::default();
+        type Model = sequential!(Linear<5, 3>, ReLU, Linear<3, 1>);
+        let model = Model::build_on_device(&dev);
+        let _: &(Linear<5, 3, f32, _>, ReLU, Linear<3, 1, f32, _>) = &model;
+
+        let y = model.forward(dev.sample_normal::<Rank1<5>>());
+        assert_eq!(y.shape(), &(Const::<1>,));
+        let y = model.forward(dev.sample_normal::<Rank2<4, 5>>());
+        assert_eq!(y.shape(), &(Const::<4>, Const::<1>));
+    }
+
+    #[test]
+    fn test_sequential_nests_long_lists() {
+        let dev: TestDevice = Default::default();
+        type Model = sequential!(
+            Linear<2, 3>,
+            ReLU,
+            Linear<3, 4>,
+            ReLU,
+            Linear<4, 5>,
+            ReLU,
+            Linear<5, 6>,
+            Tanh,
+        );
+        let model = Model::build_on_device(&dev);
+        let _: &(_, _, _, _, _, (ReLU, Linear<5, 6, f32, _>, Tanh)) = &model;
+        let y = model.forward(dev.sample_normal::<Rank2<3, 2>>());
+        assert_eq!(y.shape(), &(Const::<3>, Const::<6>));
+    }
+
+    #[test]
+    fn test_sequential_values() {
+        let dev: TestDevice = Default::default();
+        let l1: Linear<5, 3, f32, _> = BuildModule::build(&dev);
+        let l2: Linear<3, 1, f32, _> = BuildModule::build(&dev);
+        let model: sequential!(Linear<5, 3, f32, _>, ReLU, Linear<3, 1, f32, _>) =
+            sequential!(values: l1.clone(), ReLU, l2.clone());
+
+        let x = dev.sample_normal::<Rank1<5>>();
+        let expected = l2.forward(l1.forward(x.clone()).relu());
+        assert_eq!(model.forward(x).array(), expected.array());
+    }
+}
@@ -125,7 +125,8 @@ pub struct TransformerDecoderBlock<
     pub norm3: LayerNorm1D<MODEL_DIM, D>,
 }
 
-type FF<const M: usize, const F: usize, D> = Residual<(Linear<M, F, D>, ReLU, Linear<F, M, D>)>;
+type FF<const M: usize, const F: usize, D> =
+    Residual<(Linear<M, F, f32, D>, ReLU, Linear<F, M, f32, D>)>;
 
 impl<const M: usize, const N: usize, const F: usize, D: Device<f32>> BuildModule<D, f32>
     for TransformerDecoderBlock<M, N, F, D>
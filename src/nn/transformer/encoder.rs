@@ -51,7 +51,8 @@ pub struct TransformerEncoderBlock<
     pub norm2: LayerNorm1D<MODEL_DIM, D>,
 }
 
-type FF<const M: usize, const F: usize, D> = Residual<(Linear<M, F, D>, ReLU, Linear<F, M, D>)>;
+type FF<const M: usize, const F: usize, D> =
+    Residual<(Linear<M, F, f32, D>, ReLU, Linear<F, M, f32, D>)>;
 
 impl<const M: usize, const H: usize, const F: usize, D: Device<f32>> BuildModule<D, f32>
     for TransformerEncoderBlock<M, H, F, D>
@@ -17,6 +17,11 @@ use crate::{gradients::Tape, shapes::*, Assert, ConstTrue};
 /// - `MultiHeadAttention<8, 2>` is an attention layer with 2 heads and 8 token, key and value dims.
 /// - `MultiHeadAttention<8, 2, 6, 4>` is an attention layer with the key and value dimension different
 ///   than the embed dimension
+///
+/// Inputs can be a single tensor for self attention, a `(query, key, value)` tuple for cross
+/// attention, or a `(query, key, value, mask)` tuple. The mask has shape `(S1, S2)` and is added
+/// to the attention weights before the softmax, the same as a float `attn_mask` in pytorch.
+/// Use `f32::NEG_INFINITY` to prevent a query from attending to a key.
 /// TODO: Doctests fail for some reason
 #[derive(Debug, Clone)]
 pub struct MultiHeadAttention<
@@ -26,10 +31,10 @@ pub struct MultiHeadAttention<
     const V_DIM: usize = EMBED_DIM,
     D: Device<f32> = Cpu,
 > {
-    pub w_q: Linear<EMBED_DIM, K_DIM, D>,
-    pub w_k: Linear<EMBED_DIM, K_DIM, D>,
-    pub w_v: Linear<EMBED_DIM, V_DIM, D>,
-    pub w_o: Linear<V_DIM, EMBED_DIM, D>,
+    pub w_q: Linear<EMBED_DIM, K_DIM, f32, D>,
+    pub w_k: Linear<EMBED_DIM, K_DIM, f32, D>,
+    pub w_v: Linear<EMBED_DIM, V_DIM, f32, D>,
+    pub w_o: Linear<V_DIM, EMBED_DIM, f32, D>,
 }
 
 impl<const M: usize, const H: usize, const K: usize, const V: usize, D: Device<f32>>
@@ -90,6 +95,98 @@ where
     }
 }
 
+#[cfg(feature = "nightly")]
+impl<const M: usize, const H: usize, const K: usize, const V: usize, D: Device<f32>>
+    MultiHeadAttention<M, H, K, V, D>
+{
+    /// Attention of `q` over `k` and `v`. If there is a `mask`, it is added to the attention
+    /// weights before the softmax.
+    fn attend<const S1: usize, const S2: usize, T: Tape<D>>(
+        &self,
+        q: Tensor<Rank2<S1, M>, f32, D, T>,
+        k: Tensor<Rank2<S2, M>, f32, D>,
+        v: Tensor<Rank2<S2, M>, f32, D>,
+        mask: Option<Tensor<Rank2<S1, S2>, f32, D>>,
+    ) -> Tensor<Rank2<S1, M>, f32, D, T>
+    where
+        Assert<{ S1 * K == S1 * H * (K / H) }>: ConstTrue,
+        Assert<{ S2 * K == S2 * H * (K / H) }>: ConstTrue,
+        Assert<{ S2 * V == S2 * H * (V / H) }>: ConstTrue,
+        Assert<{ S1 * H * (V / H) == S1 * V }>: ConstTrue,
+    {
+        let v: Tensor<Rank2<S2, V>, _, _, _> = self.w_v.forward(v.retaped::<T>());
+        let v = v.reshape::<Rank3<S2, H, { V / H }>>();
+        let v = v.permute::<Rank3<H, S2, { V / H }>, _>();
+
+        let k: Tensor<Rank2<S2, K>, _, _, _> = self.w_k.forward(k.retaped::<T>());
+        let k = k.reshape::<Rank3<S2, H, { K / H }>>();
+        let k = k.permute::<Rank3<H, { K / H }, S2>, _>();
+
+        let q: Tensor<Rank2<S1, K>, _, _, _> = self.w_q.forward(q);
+        let q = q.reshape::<Rank3<S1, H, { K / H }>>();
+        let q = q.permute::<Rank3<H, S1, { K / H }>, _>();
+
+        // Get weights
+        let scalar: f32 = 1.0 / ((K / H) as f32).sqrt();
+        let weights: Tensor<Rank3<H, S1, S2>, _, _, _> = q.matmul(k) * scalar;
+        let weights = match mask {
+            Some(mask) => weights + mask.broadcast(),
+            None => weights,
+        };
+        let weights = weights.softmax::<Axis<2>>();
+
+        // Get new tokens
+        let tokens: Tensor<Rank3<H, S1, { V / H }>, _, _, _> = weights.matmul(v);
+        let tokens = tokens.permute::<Rank3<S1, H, { V / H }>, _>();
+        let tokens = tokens.reshape::<Rank2<S1, V>>();
+
+        self.w_o.forward(tokens)
+    }
+
+    /// Batched version of [Self::attend]. The same `mask` is used for every batch item.
+    fn attend_batched<const B: usize, const S1: usize, const S2: usize, T: Tape<D>>(
+        &self,
+        q: Tensor<Rank3<B, S1, M>, f32, D, T>,
+        k: Tensor<Rank3<B, S2, M>, f32, D>,
+        v: Tensor<Rank3<B, S2, M>, f32, D>,
+        mask: Option<Tensor<Rank2<S1, S2>, f32, D>>,
+    ) -> Tensor<Rank3<B, S1, M>, f32, D, T>
+    where
+        Assert<{ B * S1 * K == B * S1 * H * (K / H) }>: ConstTrue,
+        Assert<{ B * S2 * K == B * S2 * H * (K / H) }>: ConstTrue,
+        Assert<{ B * S2 * V == B * S2 * H * (V / H) }>: ConstTrue,
+        Assert<{ B * S1 * H * (V / H) == B * S1 * V }>: ConstTrue,
+    {
+        let v: Tensor<Rank3<B, S2, V>, _, _, _> = self.w_v.forward(v.retaped::<T>());
+        let v = v.reshape::<Rank4<B, S2, H, { V / H }>>();
+        let v = v.permute::<Rank4<B, H, S2, { V / H }>, _>();
+
+        let k: Tensor<Rank3<B, S2, K>, _, _, _> = self.w_k.forward(k.retaped::<T>());
+        let k = k.reshape::<Rank4<B, S2, H, { K / H }>>();
+        let k = k.permute::<Rank4<B, H, { K / H }, S2>, _>();
+
+        let q: Tensor<Rank3<B, S1, K>, _, _, _> = self.w_q.forward(q);
+        let q = q.reshape::<Rank4<B, S1, H, { K / H }>>();
+        let q = q.permute::<Rank4<B, H, S1, { K / H }>, _>();
+
+        // Get weights
+        let scalar: f32 = 1.0 / ((K / H) as f32).sqrt();
+        let weights: Tensor<Rank4<B, H, S1, S2>, _, _, _> = q.matmul(k) * scalar;
+        let weights = match mask {
+            Some(mask) => weights + mask.broadcast(),
+            None => weights,
+        };
+        let weights = weights.softmax::<Axis<3>>();
+
+        // Get new tokens
+        let tokens: Tensor<Rank4<B, H, S1, { V / H }>, _, _, _> = weights.matmul(v);
+        let tokens = tokens.permute::<Rank4<B, S1, H, { V / H }>, _>();
+        let tokens = tokens.reshape::<Rank3<B, S1, V>>();
+
+        self.w_o.forward(tokens)
+    }
+}
+
 #[cfg(feature = "nightly")]
 impl<
         const M: usize,
@@ -105,6 +202,7 @@ impl<
         Tensor<Rank2<S1, M>, f32, D, T>,
         Tensor<Rank2<S2, M>, f32, D>,
         Tensor<Rank2<S2, M>, f32, D>,
+        Tensor<Rank2<S1, S2>, f32, D>,
     )> for MultiHeadAttention<M, H, K, V, D>
 where
     Assert<{ S1 * K == S1 * H * (K / H) }>: ConstTrue,
@@ -114,38 +212,96 @@ where
 {
     type Output = Tensor<Rank2<S1, M>, f32, D, T>;
 
-    /// Encoder-Decoder style self attention where one set of tensors is used for values and keys, and another is used for queries
+    /// Encoder-Decoder style self attention where one set of tensors is used for values and keys, and another is used for queries.
+    /// `mask` is added to the attention weights before the softmax.
     fn forward(
         &self,
-        (q, k, v): (
+        (q, k, v, mask): (
             Tensor<Rank2<S1, M>, f32, D, T>,
             Tensor<Rank2<S2, M>, f32, D>,
             Tensor<Rank2<S2, M>, f32, D>,
+            Tensor<Rank2<S1, S2>, f32, D>,
         ),
     ) -> Self::Output {
-        let v: Tensor<Rank2<S2, V>, _, _, _> = self.w_v.forward(v.retaped::<T>());
-        let v = v.reshape::<Rank3<S2, H, { V / H }>>();
-        let v = v.permute::<Rank3<H, S2, { V / H }>, _>();
-
-        let k: Tensor<Rank2<S2, K>, _, _, _> = self.w_k.forward(k.retaped::<T>());
-        let k = k.reshape::<Rank3<S2, H, { K / H }>>();
-        let k = k.permute::<Rank3<H, { K / H }, S2>, _>();
+        self.attend(q, k, v, Some(mask))
+    }
+}
 
-        let q: Tensor<Rank2<S1, K>, _, _, _> = self.w_q.forward(q);
-        let q = q.reshape::<Rank3<S1, H, { K / H }>>();
-        let q = q.permute::<Rank3<H, S1, { K / H }>, _>();
+#[cfg(feature = "nightly")]
+impl<
+        const M: usize,
+        const H: usize,
+        const K: usize,
+        const V: usize,
+        D: Device<f32>,
+        const B: usize,
+        const S1: usize,
+        const S2: usize,
+        T: Tape<D>,
+    >
+    Module<(
+        Tensor<Rank3<B, S1, M>, f32, D, T>,
+        Tensor<Rank3<B, S2, M>, f32, D>,
+        Tensor<Rank3<B, S2, M>, f32, D>,
+        Tensor<Rank2<S1, S2>, f32, D>,
+    )> for MultiHeadAttention<M, H, K, V, D>
+where
+    Assert<{ B * S1 * K == B * S1 * H * (K / H) }>: ConstTrue,
+    Assert<{ B * S2 * K == B * S2 * H * (K / H) }>: ConstTrue,
+    Assert<{ B * S2 * V == B * S2 * H * (V / H) }>: ConstTrue,
+    Assert<{ B * S1 * H * (V / H) == B * S1 * V }>: ConstTrue,
+{
+    type Output = Tensor<Rank3<B, S1, M>, f32, D, T>;
 
-        // Get weights
-        let scalar: f32 = 1.0 / ((K / H) as f32).sqrt();
-        let weights: Tensor<Rank3<H, S1, S2>, _, _, _> = q.matmul(k) * scalar;
-        let weights = weights.softmax::<Axis<2>>();
+    /// Batched Encoder-Decoder style self attention where one set of tensors is used for values and keys, and another is used for queries.
+    /// `mask` is added to the attention weights of every batch item before the softmax.
+    fn forward(
+        &self,
+        (q, k, v, mask): (
+            Tensor<Rank3<B, S1, M>, f32, D, T>,
+            Tensor<Rank3<B, S2, M>, f32, D>,
+            Tensor<Rank3<B, S2, M>, f32, D>,
+            Tensor<Rank2<S1, S2>, f32, D>,
+        ),
+    ) -> Self::Output {
+        self.attend_batched(q, k, v, Some(mask))
+    }
+}
 
-        // Get new tokens
-        let tokens: Tensor<Rank3<H, S1, { V / H }>, _, _, _> = weights.matmul(v);
-        let tokens = tokens.permute::<Rank3<S1, H, { V / H }>, _>();
-        let tokens = tokens.reshape::<Rank2<S1, V>>();
+#[cfg(feature = "nightly")]
+impl<
+        const M: usize,
+        const H: usize,
+        const K: usize,
+        const V: usize,
+        D: Device<f32>,
+        const S1: usize,
+        const S2: usize,
+        T: Tape<D>,
+    >
+    Module<(
+        Tensor<Rank2<S1, M>, f32, D, T>,
+        Tensor<Rank2<S2, M>, f32, D>,
+        Tensor<Rank2<S2, M>, f32, D>,
+    )> for MultiHeadAttention<M, H, K, V, D>
+where
+    Assert<{ S1 * K == S1 * H * (K / H) }>: ConstTrue,
+    Assert<{ S2 * K == S2 * H * (K / H) }>: ConstTrue,
+    Assert<{ S2 * V == S2 * H * (V / H) }>: ConstTrue,
+    Assert<{ S1 * H * (V / H) == S1 * V }>: ConstTrue,
+{
+    type Output = Tensor<Rank2<S1, M>, f32, D, T>;
 
-        self.w_o.forward(tokens)
+    /// Encoder-Decoder style self attention where one set of tensors is used for values and keys, and another is used for queries
+    fn forward(
+        &self,
+        (q, k, v): (
+            Tensor<Rank2<S1, M>, f32, D, T>,
+            Tensor<Rank2<S2, M>, f32, D>,
+            Tensor<Rank2<S2, M>, f32, D>,
+        ),
+    ) -> Self::Output {
+        self.attend(q, k, v, None)
     }
 }
 
@@ -183,29 +339,7 @@ where
             Tensor<Rank3<B, S2, M>, f32, D>,
         ),
     ) -> Self::Output {
-        let v: Tensor<Rank3<B, S2, V>, _, _, _> = self.w_v.forward(v.retaped::<T>());
-        let v = v.reshape::<Rank4<B, S2, H, { V / H }>>();
-        let v = v.permute::<Rank4<B, H, S2, { V / H }>, _>();
-
-        let k: Tensor<Rank3<B, S2, K>, _, _, _> = self.w_k.forward(k.retaped::<T>());
-        let k = k.reshape::<Rank4<B, S2, H, { K / H }>>();
-        let k = k.permute::<Rank4<B, H, { K / H }, S2>, _>();
-
-        let q: Tensor<Rank3<B, S1, K>, _, _, _> = self.w_q.forward(q);
-        let q = q.reshape::<Rank4<B, S1, H, { K / H }>>();
-        let q = q.permute::<Rank4<B, H, S1, { K / H }>, _>();
-
-        // Get weights
-        let scalar: f32 = 1.0 / ((K / H) as f32).sqrt();
-        let weights: Tensor<Rank4<B, H, S1, S2>, _, _, _> = q.matmul(k) * scalar;
-        let weights = weights.softmax::<Axis<3>>();
-
-        // Get new tokens
-        let tokens: Tensor<Rank4<B, H, S1, { V / H }>, _, _, _> = weights.matmul(v);
-        let tokens = tokens.permute::<Rank4<B, S1, H, { V / H }>, _>();
-        let tokens = tokens.reshape::<Rank3<B, S1, V>>();
-
-        self.w_o.forward(tokens)
+        self.attend_batched(q, k, v, None)
     }
 }
 
@@ -333,6 +467,63 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_mha_single_head_backward() {
+        let dev: TestDevice = Default::default();
+
+        let mha = MultiHeadAttention::<4, 1>::build_on_device(&dev);
+
+        let q = dev.sample_normal::<Rank2<2, 4>>();
+        let k = dev.sample_normal::<Rank2<3, 4>>();
+        let v = dev.sample_normal::<Rank2<3, 4>>();
+
+        // with a single head, attention is just the composition of the projections
+        let qp = mha.w_q.forward(q.trace());
+        let kp = mha.w_k.forward(k.trace());
+        let vp = mha.w_v.forward(v.trace());
+        let weights = (qp.matmul(kp.permute()) * 0.5).softmax::<Axis<1>>();
+        let y_expected = mha.w_o.forward(weights.matmul(vp));
+        let y_expected_array = y_expected.array();
+        let g_expected = y_expected.square().mean().backward();
+
+        let y = mha.forward((q.trace(), k.clone(), v.clone()));
+        assert_close(&y.array(), &y_expected_array);
+        let g = y.square().mean().backward();
+
+        assert_close(&g.get(&q).array(), &g_expected.get(&q).array());
+        assert_close(&g.get(&k).array(), &g_expected.get(&k).array());
+        assert_close(&g.get(&v).array(), &g_expected.get(&v).array());
+        for w in [
+            &mha.w_q.weight,
+            &mha.w_k.weight,
+            &mha.w_v.weight,
+            &mha.w_o.weight,
+        ] {
+            assert_close(&g.get(w).array(), &g_expected.get(w).array());
+        }
+    }
+
+    #[test]
+    fn test_mha_mask() {
+        let dev: TestDevice = Default::default();
+
+        let mha = MultiHeadAttention::<4, 2>::build_on_device(&dev);
+
+        let x = dev.sample_normal::<Rank2<3, 4>>();
+        let inf = f32::NEG_INFINITY;
+        let causal = dev.tensor([[0.0, inf, inf], [0.0, 0.0, inf], [0.0, 0.0, 0.0]]);
+        let y = mha.forward((x.clone(), x.clone(), x.clone(), causal));
+
+        // the first token can only attend to itself
+        let x0 = x.clone().select(dev.tensor(0));
+        let y0 = mha.w_o.forward(mha.w_v.forward(x0));
+        assert_close(&y.array()[0], &y0.array());
+
+        // a zero mask is the same as no mask
+        let y = mha.forward((x.clone(), x.clone(), x.clone(), dev.zeros()));
+        assert_close(&y.array(), &mha.forward((x.clone(), x.clone(), x)).array());
+    }
+
     #[test]
     fn test_backward_updates_all() {
         let dev: TestDevice = Default::default();
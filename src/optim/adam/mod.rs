@@ -11,7 +11,10 @@ use crate::{
     tensor::DeviceStorage,
 };
 
-use super::{GradientUpdate, Optimizer, OptimizerUpdateError, ParamUpdater, WeightDecay};
+use super::{
+    GradientUpdate, LearningRate, Optimizer, OptimizerUpdateError, ParamGroups, ParamUpdater,
+    WeightDecay,
+};
 
 /// Configuration of hyperparameters for [Adam].
 ///
@@ -51,6 +54,64 @@ impl Default for AdamConfig<f32> {
     }
 }
 
+/// Configuration of hyperparameters for [AdamW]. This is [AdamConfig] with
+/// [WeightDecay::Decoupled], so each step does
+/// `param -= lr * (m_hat / (sqrt(v_hat) + eps) + weight_decay * param)`.
+///
+/// ```rust
+/// # use dfdx::{prelude::*, optim::*};
+/// # type Model = Tensor<Rank0, f32, Cpu>;
+/// # let dev: Cpu = Default::default();
+/// # let model: Model = dev.zeros();
+/// let mut opt: AdamW<Model> = AdamW::new(&model, AdamWConfig {
+///     lr: 1e-2,
+///     betas: [0.9, 0.95],
+///     eps: 1e-8,
+///     weight_decay: 1e-1,
+/// }.into());
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct AdamWConfig<E> {
+    /// Learning rate. Defaults to `1e-3`.
+    pub lr: E,
+
+    /// Betas from Adam paper. Defaults to `[0.9, 0.999]`.
+    pub betas: [E; 2],
+
+    /// Epsilon for numerical stability. Defaults to `1e-8`.
+    pub eps: E,
+
+    /// Decoupled weight decay. Defaults to `1e-2`.
+    pub weight_decay: E,
+}
+
+impl Default for AdamWConfig<f32> {
+    fn default() -> Self {
+        Self {
+            lr: 1e-3,
+            betas: [0.9, 0.999],
+            eps: 1e-8,
+            weight_decay: 1e-2,
+        }
+    }
+}
+
+impl<E> From<AdamWConfig<E>> for AdamConfig<E> {
+    fn from(cfg: AdamWConfig<E>) -> Self {
+        Self {
+            lr: cfg.lr,
+            betas: cfg.betas,
+            eps: cfg.eps,
+            weight_decay: Some(WeightDecay::Decoupled(cfg.weight_decay)),
+        }
+    }
+}
+
+/// Adam with decoupled weight decay from
+/// [Decoupled Weight Decay Regularization](https://arxiv.org/abs/1711.05101).
+/// Construct it from an [AdamWConfig], see [AdamWConfig] for an example.
+pub type AdamW<M, E = f32> = Adam<M, E>;
+
 /// An implementation of the Adam optimizer from
 /// [Adam: A Method for Stochastic Optimization](https://arxiv.org/abs/1412.6980)
 ///
@@ -74,6 +135,9 @@ pub struct Adam<M, E: Dtype = f32> {
     /// Hyperparameter configuration
     pub cfg: AdamConfig<E>,
 
+    /// Per parameter overrides of [Self::cfg]
+    pub param_groups: ParamGroups<E>,
+
     t: i32,
     gradients: Gradients,
     moment1: Gradients,
@@ -87,6 +151,7 @@ impl<M, E: Dtype> Adam<M, E> {
     pub fn new(_model: &M, cfg: AdamConfig<E>) -> Self {
         Self {
             cfg,
+            param_groups: Default::default(),
             t: 0,
             gradients: Default::default(),
             moment1: Default::default(),
@@ -118,10 +183,12 @@ impl<M, D: DeviceStorage + AdamKernel<E>, E: Dtype> ParamUpdater<D, E> for Adam<
         match g {
             None => unused.add(p),
             Some(g) => {
+                let mut cfg = self.cfg;
+                self.param_groups
+                    .apply(p, &mut cfg.lr, &mut cfg.weight_decay);
                 let m_t = self.moment1.get_or_alloc_mut(p)?;
                 let v_t = self.moment2.get_or_alloc_mut(p)?;
-                p.device
-                    .update(self.t, &self.cfg, &mut p.storage, m_t, v_t, g)?;
+                p.device.update(self.t, &cfg, &mut p.storage, m_t, v_t, g)?;
             }
         }
         Ok(())
@@ -147,6 +214,16 @@ where
     }
 }
 
+impl<M, E: Dtype> LearningRate<E> for Adam<M, E> {
+    fn lr(&self) -> E {
+        self.cfg.lr
+    }
+
+    fn set_lr(&mut self, lr: E) {
+        self.cfg.lr = lr;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -278,4 +355,23 @@ mod tests {
             assert_close(&t.array(), e);
         }
     }
+
+    #[test]
+    fn test_adamw_one_step() {
+        let dev: TestDevice = Default::default();
+        let mut t: Tensor<Rank1<2>, f32, _> = dev.tensor([1.0, -2.0]);
+        let cfg = AdamWConfig {
+            lr: 0.1,
+            betas: [0.9, 0.999],
+            eps: 1e-8,
+            weight_decay: 0.1,
+        };
+        let mut opt: AdamW<_> = AdamW::new(&t, cfg.into());
+
+        // grad is 2 * t, so after bias correction m_hat / sqrt(v_hat) is sign(grad)
+        let gradients = t.trace().square().sum().backward();
+        opt.update(&mut t, gradients).expect("");
+        // 1.0 - 0.1 * (1.0 + 0.1 * 1.0), -2.0 - 0.1 * (-1.0 + 0.1 * -2.0)
+        assert_close(&t.array(), &[0.89, -1.88]);
+    }
 }
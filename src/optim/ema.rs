@@ -0,0 +1,149 @@
+use std::{any::Any, boxed::Box, vec::Vec};
+
+use super::{GradientUpdate, ParamUpdater, UnusedTensors};
+use crate::{
+    shapes::Shape,
+    tensor::{Tensor, ToDevice},
+    tensor_ops::{Device, TryAdd, TryMul},
+};
+
+/// Keeps an exponential moving average of the parameters of a module,
+/// which is often more stable than the module itself for evaluation.
+///
+/// Every call to [Ema::update()] does `shadow = decay * shadow + (1 - decay) * param`
+/// for each parameter of the module, in the same order as [GradientUpdate] visits them.
+///
+/// ```rust
+/// # use dfdx::{prelude::*, optim::*};
+/// # let dev: Cpu = Default::default();
+/// type Model = (Linear<5, 3>, ReLU, Linear<3, 1>);
+/// let model = Model::build_on_device(&dev);
+/// let mut ema = Ema::new(&model);
+/// // -- snip optimizer step --
+/// ema.update(&model, 0.999);
+/// let averaged: Model = ema.into_inner();
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ema<M> {
+    /// The averaged copy of the module.
+    pub shadow: M,
+}
+
+impl<M: Clone> Ema<M> {
+    /// Starts the average at the current parameters of `model`.
+    pub fn new(model: &M) -> Self {
+        Self {
+            shadow: model.clone(),
+        }
+    }
+
+    /// Moves every parameter of [Self::shadow] towards the matching parameter
+    /// of `model`. `decay` is usually close to 1.
+    pub fn update<D: Device<f32>>(&mut self, model: &M, decay: f32)
+    where
+        M: GradientUpdate<D, f32>,
+    {
+        self.try_update(model, decay).unwrap()
+    }
+
+    /// Fallible version of [Ema::update]
+    pub fn try_update<D: Device<f32>>(&mut self, model: &M, decay: f32) -> Result<(), D::Err>
+    where
+        M: GradientUpdate<D, f32>,
+    {
+        let mut unused = Default::default();
+        let mut collector = Collector { params: Vec::new() };
+        model.clone().update(&mut collector, &mut unused)?;
+        let mut averager = Averager {
+            params: collector.params.into_iter(),
+            decay,
+        };
+        self.shadow.update(&mut averager, &mut unused)
+    }
+
+    /// Returns the averaged module.
+    pub fn into_inner(self) -> M {
+        self.shadow
+    }
+}
+
+impl<M: ToDevice<D>, D> ToDevice<D> for Ema<M> {
+    type Output = Ema<M::Output>;
+    fn to_device(&self, device: &D) -> Self::Output {
+        Ema {
+            shadow: self.shadow.to_device(device),
+        }
+    }
+}
+
+/// Collects the parameters of a module in visiting order.
+struct Collector {
+    params: Vec<Box<dyn Any>>,
+}
+
+impl<D: Device<f32>> ParamUpdater<D, f32> for Collector {
+    fn update_param<S: Shape>(
+        &mut self,
+        p: &mut Tensor<S, f32, D>,
+        _unused: &mut UnusedTensors,
+    ) -> Result<(), D::Err> {
+        self.params.push(Box::new(p.clone()));
+        Ok(())
+    }
+}
+
+/// Averages each parameter with the collected parameter at the same position.
+struct Averager<I> {
+    params: I,
+    decay: f32,
+}
+
+impl<D: Device<f32>, I: Iterator<Item = Box<dyn Any>>> ParamUpdater<D, f32> for Averager<I> {
+    fn update_param<S: Shape>(
+        &mut self,
+        p: &mut Tensor<S, f32, D>,
+        _unused: &mut UnusedTensors,
+    ) -> Result<(), D::Err> {
+        let param: Box<Tensor<S, f32, D>> = self
+            .params
+            .next()
+            .and_then(|param| param.downcast().ok())
+            .expect("model and shadow have different parameters");
+        let avg = p
+            .clone()
+            .try_mul(self.decay)?
+            .try_add((*param).try_mul(1.0 - self.decay)?)?;
+        // keep the id of `p`, only replace its values
+        p.storage = avg.storage;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{nn::*, shapes::*, tensor::*, tests::*};
+
+    #[test]
+    fn test_ema_linear_closed_form() {
+        let dev: TestDevice = Default::default();
+        let mut model: Linear<2, 2, f32, _> = BuildModule::build(&dev);
+        let w0 = model.weight.array();
+        let b0 = model.bias.array();
+
+        let decay = 0.9;
+        let mut ema = Ema::new(&model);
+        for step in 1..=3 {
+            model.weight = dev.ones::<Rank2<2, 2>>() * step as f32;
+            model.bias = dev.ones::<Rank1<2>>() * step as f32;
+            ema.update(&model, decay);
+        }
+
+        // decay^3 * x0 + (1 - decay) * (decay^2 * 1 + decay * 2 + 3)
+        let steps = (1.0 - decay) * (decay * decay + decay * 2.0 + 3.0);
+        let d3 = decay * decay * decay;
+        let ema = ema.into_inner();
+        assert_close(&ema.weight.array(), &w0.map(|r| r.map(|w| d3 * w + steps)));
+        assert_close(&ema.bias.array(), &b0.map(|b| d3 * b + steps));
+    }
+}
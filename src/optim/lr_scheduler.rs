@@ -0,0 +1,279 @@
+use num_traits::Float;
+
+use super::{Optimizer, OptimizerUpdateError};
+use crate::{gradients::Gradients, shapes::Dtype, tensor::DeviceStorage};
+
+/// Something with a learning rate that can be read and modified, such as
+/// [super::Sgd], [super::Adam], and [super::RMSprop].
+pub trait LearningRate<E> {
+    /// The current learning rate.
+    fn lr(&self) -> E;
+
+    /// Overwrites the current learning rate.
+    fn set_lr(&mut self, lr: E);
+}
+
+/// Adjusts the learning rate of a wrapped optimizer based on the number of
+/// times [LrScheduler::step()] has been called (the epoch).
+///
+/// Schedulers also implement [Optimizer], so they can be used in place of the
+/// optimizer they wrap:
+///
+/// ```rust
+/// # use dfdx::{prelude::*, optim::*};
+/// # let dev: Cpu = Default::default();
+/// # type Model = Linear<5, 2>;
+/// let mut model = Model::build_on_device(&dev);
+/// let opt: Sgd<Model> = Sgd::new(&model, Default::default());
+/// let mut sched = StepLR::new(opt, 10, 0.1);
+/// for _epoch in 0..3 {
+/// #   let gradients = model.forward(dev.zeros::<Rank1<5>>().trace()).mean().backward();
+///     // -- snip training loop --
+///     sched.update(&mut model, gradients).expect("");
+///     sched.step();
+/// }
+/// ```
+pub trait LrScheduler<E> {
+    /// Advances the epoch by one and updates the learning rate of the wrapped optimizer.
+    fn step(&mut self);
+
+    /// The current learning rate.
+    fn lr(&self) -> E;
+
+    /// The number of times [LrScheduler::step()] has been called.
+    fn epoch(&self) -> usize;
+}
+
+/// Multiplies the learning rate by `gamma` every `step_size` epochs.
+///
+/// `lr = base_lr * gamma ^ (epoch / step_size)`
+///
+/// Based on [pytorch's StepLR](https://pytorch.org/docs/stable/generated/torch.optim.lr_scheduler.StepLR.html)
+#[derive(Debug)]
+pub struct StepLR<O, E = f32> {
+    /// The wrapped optimizer
+    pub opt: O,
+    /// Learning rate at epoch 0
+    pub base_lr: E,
+    /// Number of epochs between each decay
+    pub step_size: usize,
+    /// Multiplicative decay factor
+    pub gamma: E,
+    epoch: usize,
+}
+
+impl<O: LearningRate<E>, E: Copy> StepLR<O, E> {
+    /// Wraps `opt`, using its current learning rate as [StepLR::base_lr].
+    pub fn new(opt: O, step_size: usize, gamma: E) -> Self {
+        assert!(step_size > 0, "step_size must be greater than 0");
+        Self {
+            base_lr: opt.lr(),
+            opt,
+            step_size,
+            gamma,
+            epoch: 0,
+        }
+    }
+}
+
+impl<O: LearningRate<E>, E: Dtype + Float> LrScheduler<E> for StepLR<O, E> {
+    fn step(&mut self) {
+        self.epoch += 1;
+        let num_decays = (self.epoch / self.step_size) as i32;
+        self.opt.set_lr(self.base_lr * self.gamma.powi(num_decays));
+    }
+
+    fn lr(&self) -> E {
+        self.opt.lr()
+    }
+
+    fn epoch(&self) -> usize {
+        self.epoch
+    }
+}
+
+/// Multiplies the learning rate by `gamma` every epoch.
+///
+/// `lr = base_lr * gamma ^ epoch`
+///
+/// Based on [pytorch's ExponentialLR](https://pytorch.org/docs/stable/generated/torch.optim.lr_scheduler.ExponentialLR.html)
+#[derive(Debug)]
+pub struct ExponentialLR<O, E = f32> {
+    /// The wrapped optimizer
+    pub opt: O,
+    /// Learning rate at epoch 0
+    pub base_lr: E,
+    /// Multiplicative decay factor
+    pub gamma: E,
+    epoch: usize,
+}
+
+impl<O: LearningRate<E>, E: Copy> ExponentialLR<O, E> {
+    /// Wraps `opt`, using its current learning rate as [ExponentialLR::base_lr].
+    pub fn new(opt: O, gamma: E) -> Self {
+        Self {
+            base_lr: opt.lr(),
+            opt,
+            gamma,
+            epoch: 0,
+        }
+    }
+}
+
+impl<O: LearningRate<E>, E: Dtype + Float> LrScheduler<E> for ExponentialLR<O, E> {
+    fn step(&mut self) {
+        self.epoch += 1;
+        self.opt
+            .set_lr(self.base_lr * self.gamma.powi(self.epoch as i32));
+    }
+
+    fn lr(&self) -> E {
+        self.opt.lr()
+    }
+
+    fn epoch(&self) -> usize {
+        self.epoch
+    }
+}
+
+/// Anneals the learning rate from `base_lr` down to `eta_min` over `t_max` epochs
+/// following half a cosine period, and back up again over the next `t_max` epochs.
+///
+/// `lr = eta_min + (base_lr - eta_min) * (1 + cos(pi * epoch / t_max)) / 2`
+///
+/// Based on [pytorch's CosineAnnealingLR](https://pytorch.org/docs/stable/generated/torch.optim.lr_scheduler.CosineAnnealingLR.html)
+#[derive(Debug)]
+pub struct CosineAnnealingLR<O, E = f32> {
+    /// The wrapped optimizer
+    pub opt: O,
+    /// Learning rate at epoch 0
+    pub base_lr: E,
+    /// Number of epochs to go from `base_lr` to `eta_min`
+    pub t_max: usize,
+    /// Minimum learning rate
+    pub eta_min: E,
+    epoch: usize,
+}
+
+impl<O: LearningRate<E>, E: Copy> CosineAnnealingLR<O, E> {
+    /// Wraps `opt`, using its current learning rate as [CosineAnnealingLR::base_lr].
+    pub fn new(opt: O, t_max: usize, eta_min: E) -> Self {
+        assert!(t_max > 0, "t_max must be greater than 0");
+        Self {
+            base_lr: opt.lr(),
+            opt,
+            t_max,
+            eta_min,
+            epoch: 0,
+        }
+    }
+}
+
+impl<O: LearningRate<E>, E: Dtype + Float> LrScheduler<E> for CosineAnnealingLR<O, E> {
+    fn step(&mut self) {
+        self.epoch += 1;
+        let progress = E::from(self.epoch).unwrap() / E::from(self.t_max).unwrap();
+        let cos = (E::from(core::f64::consts::PI).unwrap() * progress).cos();
+        let half = E::from(0.5).unwrap();
+        self.opt
+            .set_lr(self.eta_min + (self.base_lr - self.eta_min) * (E::one() + cos) * half);
+    }
+
+    fn lr(&self) -> E {
+        self.opt.lr()
+    }
+
+    fn epoch(&self) -> usize {
+        self.epoch
+    }
+}
+
+macro_rules! forward_optimizer {
+    ($Sched:ident) => {
+        impl<M, D: DeviceStorage, E: Dtype, O: Optimizer<M, D, E>> Optimizer<M, D, E>
+            for $Sched<O, E>
+        {
+            fn update(
+                &mut self,
+                module: &mut M,
+                gradients: Gradients,
+            ) -> Result<(), OptimizerUpdateError<D>> {
+                self.opt.update(module, gradients)
+            }
+        }
+    };
+}
+
+forward_optimizer!(StepLR);
+forward_optimizer!(ExponentialLR);
+forward_optimizer!(CosineAnnealingLR);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::optim::*;
+    use crate::tensor::Tensor;
+    use crate::tests::{assert_close, TestDevice};
+
+    type Model = Tensor<crate::shapes::Rank1<3>, f32, TestDevice>;
+
+    fn sgd(lr: f32) -> Sgd<Model> {
+        let dev: TestDevice = Default::default();
+        let model: Model = crate::tensor::ZerosTensor::zeros(&dev);
+        Sgd::new(
+            &model,
+            SgdConfig {
+                lr,
+                momentum: None,
+                weight_decay: None,
+            },
+        )
+    }
+
+    fn lrs<S: LrScheduler<f32>, const N: usize>(mut sched: S) -> [f32; N] {
+        std::array::from_fn(|i| {
+            if i > 0 {
+                sched.step();
+            }
+            sched.lr()
+        })
+    }
+
+    #[test]
+    fn test_step_lr() {
+        let sched = StepLR::new(sgd(1.0), 2, 0.5);
+        assert_close(&lrs(sched), &[1.0, 1.0, 0.5, 0.5, 0.25, 0.25, 0.125]);
+    }
+
+    #[test]
+    fn test_exponential_lr() {
+        let sched = ExponentialLR::new(sgd(0.1), 0.9);
+        let expected: [f32; 6] = std::array::from_fn(|e| 0.1 * 0.9f32.powi(e as i32));
+        assert_close(&lrs(sched), &expected);
+    }
+
+    #[test]
+    fn test_cosine_annealing_lr() {
+        let sched = CosineAnnealingLR::new(sgd(1.0), 4, 0.2);
+        let expected: [f32; 9] = std::array::from_fn(|e| {
+            0.2 + 0.8 * (1.0 + (core::f32::consts::PI * e as f32 / 4.0).cos()) / 2.0
+        });
+        let actual = lrs(sched);
+        assert_close(&actual, &expected);
+        assert_close(&[actual[2], actual[4], actual[8]], &[0.6, 0.2, 1.0]);
+    }
+
+    #[test]
+    fn test_scheduler_sets_optimizer_lr() {
+        let dev: TestDevice = Default::default();
+        let model: Model = crate::tensor::ZerosTensor::zeros(&dev);
+        let mut sched = ExponentialLR::new(Adam::new(&model, Default::default()), 0.5);
+        sched.step();
+        assert_eq!(sched.epoch(), 1);
+        assert_eq!(sched.opt.cfg.lr, 5e-4);
+
+        let mut sched = StepLR::new(RMSprop::new(&model, Default::default()), 1, 0.1);
+        sched.step();
+        assert_close(&[sched.opt.cfg.lr], &[1e-3]);
+    }
+}
@@ -1,4 +1,4 @@
-//! Optimizers such as [Sgd], [Adam], and [RMSprop] that can optimize neural networks.
+//! Optimizers such as [Sgd], [Adam], [AdamW], and [RMSprop] that can optimize neural networks.
 //!
 //! # Initializing
 //!
@@ -6,6 +6,7 @@
 //! all the relevant parameters through the corresponding config object:
 //! - [Sgd::new()] with [SgdConfig]
 //! - [Adam::new()] with [AdamConfig]
+//! - [AdamW] with [AdamWConfig]
 //! - [RMSprop::new()] with [RMSpropConfig]
 //!
 //! # Updating network parameters
@@ -26,15 +27,36 @@
 //! let gradients: Gradients = loss.backward();
 //! opt.update(&mut model, gradients);
 //! ```
+//!
+//! # Learning rate schedules
+//!
+//! The learning rate of any optimizer implementing [LearningRate] can be decayed over time
+//! by wrapping it in a [LrScheduler] such as [StepLR], [ExponentialLR], or [CosineAnnealingLR],
+//! and calling [LrScheduler::step()] once per epoch.
+//!
+//! # Parameter groups
+//!
+//! Parts of a model can use a different learning rate or weight decay than the rest
+//! by adding them to a [ParamGroup] through the optimizer's [ParamGroups].
+//!
+//! # Exponential moving average
+//!
+//! [Ema] keeps a moving average of a model's parameters, updated after each optimizer step.
 
 mod adam;
+mod ema;
+mod lr_scheduler;
 mod optimizer;
+mod param_groups;
 mod rmsprop;
 mod sgd;
 
-pub use adam::{Adam, AdamConfig};
+pub use adam::{Adam, AdamConfig, AdamW, AdamWConfig};
+pub use ema::Ema;
+pub use lr_scheduler::{CosineAnnealingLR, ExponentialLR, LearningRate, LrScheduler, StepLR};
 pub use optimizer::{GradientUpdate, Optimizer, OptimizerUpdateError, ParamUpdater, UnusedTensors};
 pub use optimizer::{Momentum, WeightDecay};
+pub use param_groups::{ParamGroup, ParamGroups};
 pub use rmsprop::{RMSprop, RMSpropConfig};
 pub use sgd::{Sgd, SgdConfig};
 
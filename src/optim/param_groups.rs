@@ -0,0 +1,221 @@
+use std::collections::HashMap;
+use std::vec::Vec;
+
+use super::{GradientUpdate, ParamUpdater, UnusedTensors, WeightDecay};
+use crate::{
+    shapes::{Dtype, Shape},
+    tensor::{DeviceStorage, Tensor},
+    unique_id::{HasUniqueId, UniqueId},
+};
+
+/// Hyperparameters that override the optimizer's config for a group of parameters.
+/// `None` keeps the value from the optimizer's config.
+///
+/// To disable weight decay for a group, use a weight decay of `0.0`.
+#[derive(Debug, Clone, Copy)]
+pub struct ParamGroup<E> {
+    /// Learning rate for this group.
+    pub lr: Option<E>,
+
+    /// Weight decay for this group.
+    pub weight_decay: Option<WeightDecay<E>>,
+}
+
+impl<E> Default for ParamGroup<E> {
+    fn default() -> Self {
+        Self {
+            lr: None,
+            weight_decay: None,
+        }
+    }
+}
+
+/// Assigns parameters to [ParamGroup]s, so they can be updated with different
+/// hyperparameters. Available on [super::Sgd], [super::Adam], and [super::RMSprop]
+/// as the `param_groups` field.
+///
+/// Parameters that aren't in any group use the optimizer's config. If a parameter is
+/// added to multiple groups, the last one wins.
+///
+/// ```rust
+/// # use dfdx::{prelude::*, optim::*};
+/// # let dev: Cpu = Default::default();
+/// type Model = (Embedding<10, 4>, Linear<4, 2>);
+/// let mut model = Model::build_on_device(&dev);
+/// let mut opt: Sgd<Model> = Sgd::new(&model, Default::default());
+/// opt.param_groups.add(&mut model.0, ParamGroup {
+///     lr: Some(1e-1),
+///     weight_decay: Some(WeightDecay::L2(0.0)),
+/// });
+/// ```
+///
+/// Note that [super::LrScheduler]s only change the optimizer's learning rate, not the
+/// learning rates of groups.
+#[derive(Debug, Clone)]
+pub struct ParamGroups<E> {
+    groups: Vec<ParamGroup<E>>,
+    members: HashMap<UniqueId, usize>,
+}
+
+impl<E> Default for ParamGroups<E> {
+    fn default() -> Self {
+        Self {
+            groups: Default::default(),
+            members: Default::default(),
+        }
+    }
+}
+
+impl<E: Dtype> ParamGroups<E> {
+    /// Adds all of the parameters in `module` to a new group.
+    ///
+    /// This only reads the ids of the parameters; `module` is mutable
+    /// because that is what [GradientUpdate] requires.
+    pub fn add<D: DeviceStorage, M: GradientUpdate<D, E>>(
+        &mut self,
+        module: &mut M,
+        group: ParamGroup<E>,
+    ) {
+        let mut registrar = Registrar {
+            members: &mut self.members,
+            group: self.groups.len(),
+        };
+        let mut unused = Default::default();
+        module
+            .update(&mut registrar, &mut unused)
+            .unwrap_or_else(|_| unreachable!());
+        self.groups.push(group);
+    }
+
+    /// The group that `t` was added to, if any.
+    pub fn get<T: HasUniqueId>(&self, t: &T) -> Option<&ParamGroup<E>> {
+        self.members.get(t.id()).map(|&i| &self.groups[i])
+    }
+
+    /// Overwrites `lr` and `weight_decay` with the values of `t`'s group.
+    pub(super) fn apply<T: HasUniqueId>(
+        &self,
+        t: &T,
+        lr: &mut E,
+        weight_decay: &mut Option<WeightDecay<E>>,
+    ) {
+        if let Some(group) = self.get(t) {
+            if let Some(group_lr) = group.lr {
+                *lr = group_lr;
+            }
+            if let Some(group_wd) = group.weight_decay {
+                *weight_decay = Some(group_wd);
+            }
+        }
+    }
+}
+
+/// Records the ids of all parameters it visits as members of `group`.
+struct Registrar<'a> {
+    members: &'a mut HashMap<UniqueId, usize>,
+    group: usize,
+}
+
+impl<'a, D: DeviceStorage, E: Dtype> ParamUpdater<D, E> for Registrar<'a> {
+    fn update_param<S: Shape>(
+        &mut self,
+        p: &mut Tensor<S, E, D>,
+        _unused: &mut UnusedTensors,
+    ) -> Result<(), D::Err> {
+        self.members.insert(*p.id(), self.group);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{nn::*, optim::*, shapes::*, tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_param_groups_different_lrs() {
+        let dev: TestDevice = Default::default();
+        type Model = (Embedding<5, 3>, Linear<3, 2>);
+        let mut model = Model::build_on_device(&dev);
+        let mut opt: Sgd<Model> = Sgd::new(
+            &model,
+            SgdConfig {
+                lr: 1e-2,
+                momentum: None,
+                weight_decay: None,
+            },
+        );
+        opt.param_groups.add(
+            &mut model.0,
+            ParamGroup {
+                lr: Some(1e-1),
+                ..Default::default()
+            },
+        );
+        opt.param_groups.add(
+            &mut model.1,
+            ParamGroup {
+                lr: Some(1e-3),
+                ..Default::default()
+            },
+        );
+
+        let x: Tensor<Rank1<3>, usize, _> = dev.tensor([0, 2, 4]);
+        let y = model.forward(x.trace());
+        let g = y.square().mean().backward();
+        let g_emb = g.get(&model.0.weight).array();
+        let g_weight = g.get(&model.1.weight).array();
+        let g_bias = g.get(&model.1.bias).array();
+
+        let old = model.clone();
+        opt.update(&mut model, g).expect("");
+
+        assert_close(
+            &(old.0.weight - model.0.weight.clone()).array(),
+            &g_emb.map(|r| r.map(|g| g * 1e-1)),
+        );
+        assert_close(
+            &(old.1.weight - model.1.weight.clone()).array(),
+            &g_weight.map(|r| r.map(|g| g * 1e-3)),
+        );
+        assert_close(
+            &(old.1.bias - model.1.bias.clone()).array(),
+            &g_bias.map(|g| g * 1e-3),
+        );
+    }
+
+    #[test]
+    fn test_param_groups_weight_decay_override() {
+        let dev: TestDevice = Default::default();
+        let mut t: Tensor<Rank1<3>, f32, _> = dev.ones();
+        let mut opt = Sgd::new(
+            &t,
+            SgdConfig {
+                lr: 1.0,
+                momentum: None,
+                weight_decay: Some(WeightDecay::Decoupled(0.5)),
+            },
+        );
+        let mut grouped: Tensor<Rank1<3>, f32, _> = dev.ones();
+        opt.param_groups.add(
+            &mut grouped,
+            ParamGroup {
+                lr: None,
+                weight_decay: Some(WeightDecay::L2(0.0)),
+            },
+        );
+        assert!(opt.param_groups.get(&t).is_none());
+        assert!(opt.param_groups.get(&grouped).is_some());
+
+        // `grouped` is a different tensor, so `t` still uses the optimizer's weight decay
+        let g = t.trace().sum().backward();
+        opt.update(&mut t, g).expect("");
+        assert_eq!(t.array(), [-0.5; 3]);
+
+        let mut opt2 = Sgd::new(&grouped, opt.cfg);
+        opt2.param_groups = opt.param_groups.clone();
+        let g = grouped.trace().sum().backward();
+        opt2.update(&mut grouped, g).expect("");
+        assert_eq!(grouped.array(), [0.0; 3]);
+    }
+}
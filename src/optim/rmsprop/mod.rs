@@ -12,7 +12,8 @@ use crate::{
 };
 
 use super::{
-    GradientUpdate, Optimizer, OptimizerUpdateError, ParamUpdater, UnusedTensors, WeightDecay,
+    GradientUpdate, LearningRate, Optimizer, OptimizerUpdateError, ParamGroups, ParamUpdater,
+    UnusedTensors, WeightDecay,
 };
 
 /// Configuration of hyperparameters for [RMSprop].
@@ -83,6 +84,9 @@ pub struct RMSprop<M, E: Dtype = f32> {
     /// Hyperparameter configuration
     pub cfg: RMSpropConfig<E>,
 
+    /// Per parameter overrides of [Self::cfg]
+    pub param_groups: ParamGroups<E>,
+
     step: usize,
     momentums: Gradients,
     square_avg: Gradients,
@@ -97,6 +101,7 @@ impl<M, E: Dtype> RMSprop<M, E> {
     pub fn new(_model: &M, cfg: RMSpropConfig<E>) -> Self {
         Self {
             cfg,
+            param_groups: Default::default(),
             step: 0,
             momentums: Default::default(),
             square_avg: Default::default(),
@@ -137,7 +142,10 @@ impl<M, D: RMSpropKernel<f32> + OneFillStorage<f32>> ParamUpdater<D, f32> for RM
                     p.device.try_fill_with_ones(sa)?;
                 }
 
-                p.device.update(&self.cfg, &mut p.storage, m, sa, ga, g)?;
+                let mut cfg = self.cfg;
+                self.param_groups
+                    .apply(p, &mut cfg.lr, &mut cfg.weight_decay);
+                p.device.update(&cfg, &mut p.storage, m, sa, ga, g)?;
             }
         }
         Ok(())
@@ -164,6 +172,16 @@ where
     }
 }
 
+impl<M, E: Dtype> LearningRate<E> for RMSprop<M, E> {
+    fn lr(&self) -> E {
+        self.cfg.lr
+    }
+
+    fn set_lr(&mut self, lr: E) {
+        self.cfg.lr = lr;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -9,7 +9,7 @@ use crate::gradients::Gradients;
 use crate::shapes::{Dtype, Shape};
 use crate::tensor::{DeviceStorage, Tensor};
 
-use super::optimizer::*;
+use super::{optimizer::*, LearningRate, ParamGroups};
 
 /// Configuration of hyperparameters for [Sgd].
 ///
@@ -93,6 +93,9 @@ impl Default for SgdConfig<f32> {
 /// [Decoupled Weight Decay Regularization](https://arxiv.org/abs/1711.05101)
 /// Both L2 weight_decay and decoupled weight_decay are available.
 ///
+/// The whole update (`param -= lr * grad` plus weight decay & momentum) is fused into
+/// a single kernel per parameter tensor.
+///
 /// # Example Usage
 ///
 /// ```rust
@@ -113,6 +116,9 @@ pub struct Sgd<M, E: Dtype = f32> {
     /// Hyperparameter configuration
     pub cfg: SgdConfig<E>,
 
+    /// Per parameter overrides of [Self::cfg]
+    pub param_groups: ParamGroups<E>,
+
     velocity: Gradients,
     gradients: Gradients,
 
@@ -124,6 +130,7 @@ impl<M, E: Dtype> Sgd<M, E> {
     pub fn new(_model: &M, cfg: SgdConfig<E>) -> Self {
         Self {
             cfg,
+            param_groups: Default::default(),
             velocity: Default::default(),
             gradients: Default::default(),
             marker: PhantomData,
@@ -151,8 +158,11 @@ impl<M, D: SgdKernel<E>, E: Dtype> ParamUpdater<D, E> for Sgd<M, E> {
         match g {
             None => unused.add(p),
             Some(g) => {
+                let mut cfg = self.cfg;
+                self.param_groups
+                    .apply(p, &mut cfg.lr, &mut cfg.weight_decay);
                 let v = self.velocity.get_or_alloc_mut(p)?;
-                p.device.update(&self.cfg, &mut p.storage, v, g)?;
+                p.device.update(&cfg, &mut p.storage, v, g)?;
             }
         }
         Ok(())
@@ -177,6 +187,16 @@ where
     }
 }
 
+impl<M, E: Dtype> LearningRate<E> for Sgd<M, E> {
+    fn lr(&self) -> E {
+        self.cfg.lr
+    }
+
+    fn set_lr(&mut self, lr: E) {
+        self.cfg.lr = lr;
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -411,4 +431,31 @@ mod tests {
             assert_close(&t.array(), e);
         }
     }
+
+    #[test]
+    fn test_sgd_fused_matches_unfused() {
+        let dev: TestDevice = Default::default();
+        let lr = 1e-2;
+        let wd = 1e-4;
+
+        let mut param = dev.sample_normal::<Rank2<256, 256>>();
+        let grad = dev.sample_normal::<Rank2<256, 256>>();
+
+        // unfused reference: velocity starts at 0, so v = g + wd * p
+        let v = grad.clone() + param.clone() * wd;
+        let expected = param.clone() - v * lr;
+
+        let mut sgd = Sgd::new(
+            &param,
+            SgdConfig {
+                lr,
+                momentum: Some(Momentum::Classic(0.9)),
+                weight_decay: Some(WeightDecay::L2(wd)),
+            },
+        );
+        let gradients = (param.trace() * grad).sum().backward();
+        sgd.update(&mut param, gradients).expect("");
+
+        assert_close(&param.array(), &expected.array());
+    }
 }
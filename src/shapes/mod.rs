@@ -11,7 +11,7 @@ pub(crate) use broadcasts::{
     BroadcastShapeTo, BroadcastStridesTo, ReduceShape, ReduceShapeTo, ReduceStridesTo,
 };
 pub(crate) use permutes::{PermuteShapeTo, PermuteStridesTo};
-pub(crate) use replace_dim::{RemoveDimTo, ReplaceDimTo};
+pub(crate) use replace_dim::{AppendDim, PrependDim, RemoveDimTo, ReplaceDimTo, ResizeDimTo};
 
 #[allow(unused_imports)]
 pub(crate) use same_numel::HasSameNumelAs;
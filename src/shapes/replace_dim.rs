@@ -60,6 +60,82 @@ pub trait ReplaceDimTo<Dst: Shape, Idx: Shape>: Shape {
     }
 }
 
+/// Marker for shapes that are the same as `Dst` except for the size of axis `Ax`
+pub trait ResizeDimTo<Dst: Shape, Ax: Axes<Array = [isize; 1]>>: Shape {
+    #[inline]
+    fn resize(&self, size: usize) -> Dst {
+        let ax = Ax::as_array()[0] as usize;
+        let src_dims = self.concrete();
+        let mut dst_dims: Dst::Concrete = Default::default();
+        for i in 0..Dst::NUM_DIMS {
+            dst_dims[i] = if i == ax { size } else { src_dims[i] };
+        }
+        Dst::from_concrete(&dst_dims).unwrap()
+    }
+}
+
+/// Marker for shapes that can have a new dimension added after their last one
+pub trait AppendDim<New: Dim>: Shape {
+    type Appended: Shape;
+
+    #[inline]
+    fn append(&self, new: New) -> Self::Appended {
+        let src_dims = self.concrete();
+        let mut dst_dims: <Self::Appended as Shape>::Concrete = Default::default();
+        for i in 0..Self::NUM_DIMS {
+            dst_dims[i] = src_dims[i];
+        }
+        dst_dims[Self::NUM_DIMS] = new.size();
+        Self::Appended::from_concrete(&dst_dims).unwrap()
+    }
+}
+
+macro_rules! append {
+    ($($DimVars:tt),*) => {
+impl<$($DimVars: Dim, )* New: Dim> AppendDim<New> for ($($DimVars, )*) {
+    type Appended = ($($DimVars, )* New, );
+}
+    };
+}
+
+append!();
+append!(D1);
+append!(D1, D2);
+append!(D1, D2, D3);
+append!(D1, D2, D3, D4);
+append!(D1, D2, D3, D4, D5);
+
+/// Marker for shapes that can have a new dimension added before their first one
+pub trait PrependDim<New: Dim>: Shape {
+    type Prepended: Shape;
+
+    #[inline]
+    fn prepend(&self, new: New) -> Self::Prepended {
+        let src_dims = self.concrete();
+        let mut dst_dims: <Self::Prepended as Shape>::Concrete = Default::default();
+        dst_dims[0] = new.size();
+        for i in 0..Self::NUM_DIMS {
+            dst_dims[i + 1] = src_dims[i];
+        }
+        Self::Prepended::from_concrete(&dst_dims).unwrap()
+    }
+}
+
+macro_rules! prepend {
+    ($($DimVars:tt),*) => {
+impl<$($DimVars: Dim, )* New: Dim> PrependDim<New> for ($($DimVars, )*) {
+    type Prepended = (New, $($DimVars, )*);
+}
+    };
+}
+
+prepend!();
+prepend!(D1);
+prepend!(D1, D2);
+prepend!(D1, D2, D3);
+prepend!(D1, D2, D3, D4);
+prepend!(D1, D2, D3, D4, D5);
+
 macro_rules! replace {
     (($($DimVars:tt),*), $Ax:ty, $Dst:ty, $Idx:ty) => {
 impl<$($DimVars: Dim, )* New: Dim> ReplaceDimTo<$Dst, $Idx> for ($($DimVars, )*) {
@@ -111,3 +187,23 @@ impl<Batch: Dim, Seq: Dim, S1: Dim, S2: Dim> ReplaceDimTo<(Batch, Seq, S2), (Bat
 {
     type Ax = Axis<0>;
 }
+
+macro_rules! resize {
+    (($($DimVars:tt),*), $Ax:ty, $Dst:ty) => {
+impl<$($DimVars: Dim, )* New: Dim> ResizeDimTo<$Dst, $Ax> for ($($DimVars, )*) {}
+    };
+}
+
+resize!((D1), Axis<0>, (New,));
+
+resize!((D1, D2), Axis<0>, (New, D2));
+resize!((D1, D2), Axis<1>, (D1, New));
+
+resize!((D1, D2, D3), Axis<0>, (New, D2, D3));
+resize!((D1, D2, D3), Axis<1>, (D1, New, D3));
+resize!((D1, D2, D3), Axis<2>, (D1, D2, New));
+
+resize!((D1, D2, D3, D4), Axis<0>, (New, D2, D3, D4));
+resize!((D1, D2, D3, D4), Axis<1>, (D1, New, D3, D4));
+resize!((D1, D2, D3, D4), Axis<2>, (D1, D2, New, D4));
+resize!((D1, D2, D3, D4), Axis<3>, (D1, D2, D3, New));
@@ -0,0 +1,147 @@
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+/// Counts the bytes of the buffers a device has allocated for tensors and gradients.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Allocations(Arc<AtomicUsize>);
+
+impl Allocations {
+    /// The total number of bytes of all tracked buffers that are still alive.
+    pub(crate) fn num_bytes(&self) -> usize {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    fn track(&self, num_bytes: usize) -> Allocation {
+        self.0.fetch_add(num_bytes, Ordering::Relaxed);
+        Allocation {
+            num_bytes,
+            allocations: self.clone(),
+        }
+    }
+}
+
+/// The bytes of a single buffer, which are removed from [Allocations] on drop.
+#[derive(Debug)]
+struct Allocation {
+    num_bytes: usize,
+    allocations: Allocations,
+}
+
+impl Drop for Allocation {
+    fn drop(&mut self) {
+        self.allocations
+            .0
+            .fetch_sub(self.num_bytes, Ordering::Relaxed);
+    }
+}
+
+/// A buffer of device memory, like a `Vec` or a `CudaSlice`.
+pub(crate) trait NumBytes {
+    fn num_bytes(&self) -> usize;
+}
+
+impl<E> NumBytes for std::vec::Vec<E> {
+    fn num_bytes(&self) -> usize {
+        self.len() * std::mem::size_of::<E>()
+    }
+}
+
+#[cfg(feature = "cuda")]
+impl<E> NumBytes for cudarc::driver::CudaSlice<E> {
+    fn num_bytes(&self) -> usize {
+        cudarc::driver::CudaSlice::num_bytes(self)
+    }
+}
+
+/// A buffer that can be counted by a device's [Allocations]. Buffers start out untracked,
+/// and are tracked when they end up in a tensor or gradient (see [Tracked::track]).
+///
+/// Clones of a tracked buffer are tracked too, so the copies made by `Arc::make_mut`
+/// when writing to a shared buffer are counted.
+#[derive(Debug)]
+pub(crate) struct Tracked<B> {
+    buf: B,
+    allocation: Option<Allocation>,
+}
+
+impl<B: NumBytes> Tracked<B> {
+    /// Adds this buffer to `allocations`, unless it is already tracked.
+    pub(crate) fn track(&mut self, allocations: &Allocations) {
+        if self.allocation.is_none() {
+            self.allocation = Some(allocations.track(self.buf.num_bytes()));
+        }
+    }
+
+    /// Returns the buffer, removing it from the [Allocations] that track it.
+    #[cfg(feature = "cuda")]
+    pub(crate) fn into_inner(self) -> B {
+        self.buf
+    }
+}
+
+impl<B> From<B> for Tracked<B> {
+    fn from(buf: B) -> Self {
+        Self {
+            buf,
+            allocation: None,
+        }
+    }
+}
+
+impl<B: Clone> Clone for Tracked<B> {
+    fn clone(&self) -> Self {
+        Self {
+            buf: self.buf.clone(),
+            allocation: self
+                .allocation
+                .as_ref()
+                .map(|a| a.allocations.track(a.num_bytes)),
+        }
+    }
+}
+
+impl<B> Deref for Tracked<B> {
+    type Target = B;
+    fn deref(&self) -> &Self::Target {
+        &self.buf
+    }
+}
+
+impl<B> DerefMut for Tracked<B> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.buf
+    }
+}
+
+#[cfg(feature = "cuda")]
+mod cuda {
+    use super::Tracked;
+    use cudarc::driver::{sys::CUdeviceptr, AsKernelParam, CudaSlice, DevicePtr, DevicePtrMut};
+
+    impl<T> DevicePtr<T> for Tracked<CudaSlice<T>> {
+        fn device_ptr(&self) -> &CUdeviceptr {
+            self.buf.device_ptr()
+        }
+    }
+
+    impl<T> DevicePtrMut<T> for Tracked<CudaSlice<T>> {
+        fn device_ptr_mut(&mut self) -> &mut CUdeviceptr {
+            self.buf.device_ptr_mut()
+        }
+    }
+
+    unsafe impl<T> AsKernelParam for &Tracked<CudaSlice<T>> {
+        #[inline(always)]
+        fn as_kernel_param(&self) -> *mut std::ffi::c_void {
+            self.buf.device_ptr() as *const CUdeviceptr as *mut std::ffi::c_void
+        }
+    }
+
+    unsafe impl<T> AsKernelParam for &mut Tracked<CudaSlice<T>> {
+        #[inline(always)]
+        fn as_kernel_param(&self) -> *mut std::ffi::c_void {
+            self.buf.device_ptr() as *const CUdeviceptr as *mut std::ffi::c_void
+        }
+    }
+}
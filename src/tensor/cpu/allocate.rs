@@ -22,7 +22,7 @@ impl<S: Shape, E: Default + Clone> StridedArray<S, E> {
         let mut data: Vec<E> = Vec::new();
         data.try_reserve(numel).map_err(|_| CpuError::OutOfMemory)?;
         data.resize(numel, elem);
-        let data = Arc::new(data);
+        let data = Arc::new(data.into());
         Ok(StridedArray {
             data,
             shape,
@@ -38,7 +38,7 @@ impl<S: Shape, E: Default + Clone> StridedArray<S, E> {
         let mut data: Vec<E> = Vec::new();
         data.try_reserve(numel).map_err(|_| CpuError::OutOfMemory)?;
         data.resize(numel, elem);
-        let data = Arc::new(data);
+        let data = Arc::new(data.into());
         Ok(StridedArray {
             data,
             shape,
@@ -120,6 +120,27 @@ impl<E: Unit> CopySlice<E> for Cpu {
     }
 }
 
+impl<E: Unit> TensorFromVec<E> for Cpu {
+    fn try_tensor_from_vec<S: Shape>(
+        &self,
+        src: Vec<E>,
+        shape: S,
+    ) -> Result<Tensor<S, E, Self>, Self::Err> {
+        let expected = shape.num_elements();
+        if src.len() != expected {
+            return Err(CpuError::WrongNumElements {
+                expected,
+                found: src.len(),
+            });
+        }
+        Ok(self.upgrade(StridedArray {
+            data: Arc::new(src.into()),
+            shape,
+            strides: shape.strides(),
+        }))
+    }
+}
+
 impl<E: Unit> TensorFromArray<E, Rank0, E> for Cpu {
     fn try_tensor(&self, src: E) -> Result<Tensor<Rank0, E, Self>, Self::Err> {
         let mut storage: StridedArray<_, E> = StridedArray::new(Default::default())?;
@@ -1,7 +1,10 @@
-use crate::shapes::{Dtype, HasDtype, HasShape, HasUnitType, Shape, Unit};
-use crate::tensor::storage_traits::*;
+use crate::shapes::{Dtype, HasDtype, HasShape, HasUnitType, Rank0, Shape, Unit};
+use crate::tensor::{storage_traits::*, Allocations, Tensor, Tracked};
+use crate::tensor_ops::{SumTo, TryMul};
+use crate::unique_id::unique_id;
 use rand::{rngs::StdRng, Rng, SeedableRng};
 use std::{
+    any::Any,
     sync::{Arc, Mutex},
     vec::Vec,
 };
@@ -14,12 +17,14 @@ use std::{
 #[derive(Clone, Debug)]
 pub struct Cpu {
     pub(crate) rng: Arc<Mutex<StdRng>>,
+    pub(crate) allocations: Allocations,
 }
 
 impl Default for Cpu {
     fn default() -> Self {
         Self {
             rng: Arc::new(Mutex::new(StdRng::seed_from_u64(0))),
+            allocations: Default::default(),
         }
     }
 }
@@ -29,6 +34,7 @@ impl Cpu {
     pub fn seed_from_u64(seed: u64) -> Self {
         Self {
             rng: Arc::new(Mutex::new(StdRng::seed_from_u64(seed))),
+            allocations: Default::default(),
         }
     }
 }
@@ -36,7 +42,7 @@ impl Cpu {
 /// The storage for the cpu device
 #[derive(Debug, Clone)]
 pub struct StridedArray<S: Shape, E> {
-    pub(crate) data: Arc<Vec<E>>,
+    pub(crate) data: Arc<Tracked<Vec<E>>>,
     pub(crate) shape: S,
     pub(crate) strides: S::Concrete,
 }
@@ -45,12 +51,68 @@ pub struct StridedArray<S: Shape, E> {
 pub enum CpuError {
     /// Device is out of memory
     OutOfMemory,
+    /// An index tensor contained a value that is out of bounds for the axis it indexes
+    IndexOutOfBounds {
+        axis: usize,
+        index: usize,
+        size: usize,
+    },
+    /// The number of elements given does not match the number of elements of the shape
+    WrongNumElements { expected: usize, found: usize },
+    /// A reshape was requested between shapes with different numbers of elements
+    ShapeMismatch { src: usize, dst: usize },
+    /// An axis can't be split into the requested number of equally sized chunks
+    UnevenSplit { size: usize, chunks: usize },
+    /// Two tensors that must agree on the size of an axis have different sizes for it
+    DimMismatch {
+        axis: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// The top `k` values were requested from an axis that is smaller than `k`, or
+    /// the output shape doesn't have size `k` on that axis
+    InvalidTopK { k: usize, size: usize },
+    /// The axes to transpose are not both axes of the tensor, or the output shape
+    /// can't hold the transposed shape
+    InvalidTranspose { axes: [usize; 2], num_dims: usize },
 }
 
 impl std::fmt::Display for CpuError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::OutOfMemory => f.write_str("CpuError::OutOfMemory"),
+            Self::IndexOutOfBounds { axis, index, size } => write!(
+                f,
+                "CpuError::IndexOutOfBounds {{ axis: {axis}, index: {index}, size: {size} }}"
+            ),
+            Self::WrongNumElements { expected, found } => write!(
+                f,
+                "CpuError::WrongNumElements {{ expected: {expected}, found: {found} }}"
+            ),
+            Self::ShapeMismatch { src, dst } => {
+                write!(f, "CpuError::ShapeMismatch {{ src: {src}, dst: {dst} }}")
+            }
+            Self::UnevenSplit { size, chunks } => {
+                write!(
+                    f,
+                    "CpuError::UnevenSplit {{ size: {size}, chunks: {chunks} }}"
+                )
+            }
+            Self::DimMismatch {
+                axis,
+                expected,
+                found,
+            } => write!(
+                f,
+                "CpuError::DimMismatch {{ axis: {axis}, expected: {expected}, found: {found} }}"
+            ),
+            Self::InvalidTopK { k, size } => {
+                write!(f, "CpuError::InvalidTopK {{ k: {k}, size: {size} }}")
+            }
+            Self::InvalidTranspose { axes, num_dims } => write!(
+                f,
+                "CpuError::InvalidTranspose {{ axes: {axes:?}, num_dims: {num_dims} }}"
+            ),
         }
     }
 }
@@ -85,10 +147,73 @@ impl DeviceStorage for Cpu {
         &self,
         storage: &Self::Storage<S, E>,
     ) -> Result<Self::Storage<S, E>, Self::Err> {
-        StridedArray::try_new_like(storage, Default::default())
+        let mut grad = StridedArray::try_new_like(storage, Default::default())?;
+        Arc::make_mut(&mut grad.data).track(&self.allocations);
+        Ok(grad)
     }
 
     fn random_u64(&self) -> u64 {
         self.rng.lock().unwrap().gen()
     }
+
+    fn storage_byte_size<S: Shape, E: Unit>(storage: &Self::Storage<S, E>) -> usize {
+        storage.data.len() * std::mem::size_of::<E>()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.allocations.num_bytes()
+    }
+
+    fn grad_sum_squares<S: Shape, E: Dtype>(
+        &self,
+        grad: &Self::Storage<S, E>,
+    ) -> Result<f32, Self::Err> {
+        let grad: &dyn Any = grad;
+        if let Some(grad) = grad.downcast_ref::<StridedArray<S, f32>>() {
+            let mut sum = [0.0];
+            let grad = self.upgrade(grad.clone());
+            grad.try_square()?
+                .try_sum::<Rank0, _>()?
+                .copy_into(&mut sum);
+            return Ok(sum[0]);
+        }
+        #[cfg(feature = "f64")]
+        if let Some(grad) = grad.downcast_ref::<StridedArray<S, f64>>() {
+            let mut sum = [0.0];
+            let grad = self.upgrade(grad.clone());
+            grad.try_square()?
+                .try_sum::<Rank0, _>()?
+                .copy_into(&mut sum);
+            return Ok(sum[0] as f32);
+        }
+        Ok(0.0)
+    }
+
+    fn grad_scale<S: Shape, E: Dtype>(
+        &self,
+        grad: &mut Self::Storage<S, E>,
+        scale: f32,
+    ) -> Result<(), Self::Err> {
+        let grad: &mut dyn Any = grad;
+        if let Some(grad) = grad.downcast_mut::<StridedArray<S, f32>>() {
+            *grad = self.upgrade(grad.clone()).try_mul(scale)?.storage;
+        }
+        #[cfg(feature = "f64")]
+        if let Some(grad) = grad.downcast_mut::<StridedArray<S, f64>>() {
+            *grad = self.upgrade(grad.clone()).try_mul(scale as f64)?.storage;
+        }
+        Ok(())
+    }
+
+    fn upgrade<S: Shape, E: Unit>(&self, mut storage: Self::Storage<S, E>) -> Tensor<S, E, Self> {
+        if let Some(data) = Arc::get_mut(&mut storage.data) {
+            data.track(&self.allocations);
+        }
+        Tensor {
+            id: unique_id(),
+            storage,
+            device: self.clone(),
+            tape: Default::default(),
+        }
+    }
 }
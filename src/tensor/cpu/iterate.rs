@@ -1,5 +1,6 @@
-use super::device::StridedArray;
-use crate::shapes::{BroadcastStridesTo, Shape};
+use super::device::{Cpu, StridedArray};
+use crate::shapes::{BroadcastStridesTo, Shape, Unit};
+use crate::tensor::{storage_traits::AsVec, Tensor, Tracked};
 use std::sync::Arc;
 use std::vec::Vec;
 
@@ -65,22 +66,22 @@ impl<S: Shape> NdIndex<S> {
 }
 
 pub(crate) struct StridedRefIter<'a, S: Shape, E> {
-    data: &'a Vec<E>,
+    data: &'a [E],
     index: NdIndex<S>,
 }
 
 pub(crate) struct StridedMutIter<'a, S: Shape, E> {
-    data: &'a mut Vec<E>,
+    data: &'a mut [E],
     index: NdIndex<S>,
 }
 
 pub(crate) struct StridedRefIndexIter<'a, S: Shape, E> {
-    data: &'a Vec<E>,
+    data: &'a [E],
     index: NdIndex<S>,
 }
 
 pub(crate) struct StridedMutIndexIter<'a, S: Shape, E> {
-    data: &'a mut Vec<E>,
+    data: &'a mut [E],
     index: NdIndex<S>,
 }
 
@@ -95,28 +96,28 @@ impl<S: Shape, E: Clone> StridedArray<S, E> {
 
     pub(crate) fn iter(&self) -> StridedRefIter<S, E> {
         StridedRefIter {
-            data: self.data.as_ref(),
+            data: self.data.as_slice(),
             index: NdIndex::new(self.shape, self.strides),
         }
     }
 
     pub(crate) fn iter_mut(&mut self) -> StridedMutIter<S, E> {
         StridedMutIter {
-            data: std::sync::Arc::make_mut(&mut self.data),
+            data: std::sync::Arc::make_mut(&mut self.data).as_mut_slice(),
             index: NdIndex::new(self.shape, self.strides),
         }
     }
 
     pub(crate) fn iter_with_index(&self) -> StridedRefIndexIter<S, E> {
         StridedRefIndexIter {
-            data: self.data.as_ref(),
+            data: self.data.as_slice(),
             index: NdIndex::new(self.shape, self.strides),
         }
     }
 
     pub(crate) fn iter_mut_with_index(&mut self) -> StridedMutIndexIter<S, E> {
         StridedMutIndexIter {
-            data: std::sync::Arc::make_mut(&mut self.data),
+            data: std::sync::Arc::make_mut(&mut self.data).as_mut_slice(),
             index: NdIndex::new(self.shape, self.strides),
         }
     }
@@ -128,7 +129,7 @@ impl<S: Shape, E: Clone> StridedArray<S, E> {
         S: BroadcastStridesTo<Dst, Axes>,
     {
         StridedRefIter {
-            data: self.data.as_ref(),
+            data: self.data.as_slice(),
             index: NdIndex::new(*dst, self.shape.broadcast_strides(self.strides)),
         }
     }
@@ -138,7 +139,7 @@ impl<S: Shape, E: Clone> StridedArray<S, E> {
         S: BroadcastStridesTo<Dst, Axes>,
     {
         StridedMutIter {
-            data: Arc::make_mut(&mut self.data),
+            data: Arc::make_mut(&mut self.data).as_mut_slice(),
             index: NdIndex::new(*dst, self.shape.broadcast_strides(self.strides)),
         }
     }
@@ -187,16 +188,63 @@ impl<'q, S: Shape, E> LendingIterator for StridedMutIndexIter<'q, S, E> {
     }
 }
 
+impl<S: Shape, E: Unit, T> Tensor<S, E, Cpu, T> {
+    /// Iterates over the elements of the tensor in row-major order, without
+    /// allocating a [Vec] like [AsVec::as_vec] does.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// assert_eq!(t.iter().sum::<f32>(), 21.0);
+    /// ```
+    ///
+    /// Cuda tensors must be moved to the cpu with [crate::tensor::ToDevice] first.
+    pub fn iter(&self) -> impl Iterator<Item = &E> + '_ {
+        let data = self.storage.data.as_ref();
+        let mut index = NdIndex::new(self.storage.shape, self.storage.strides);
+        std::iter::from_fn(move || index.get_with_idx().map(|(i, _)| &data[i]))
+    }
+
+    /// Mutably iterates over the elements of the tensor in row-major order.
+    /// The tensor keeps its [crate::unique_id::UniqueId].
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let mut t: Tensor<Rank1<3>, f32, _> = dev.zeros();
+    /// for (i, x) in t.iter_mut().enumerate() {
+    ///     *x = i as f32;
+    /// }
+    /// assert_eq!(t.array(), [0.0, 1.0, 2.0]);
+    /// ```
+    ///
+    /// **Note** Broadcasted tensors are copied into a contiguous buffer first.
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, E> {
+        let storage = &mut self.storage;
+        let strides = storage.shape.strides();
+        if storage.strides != strides {
+            let mut data: Tracked<Vec<E>> = storage.as_vec().into();
+            data.track(&self.device.allocations);
+            storage.data = Arc::new(data);
+            storage.strides = strides;
+        }
+        Arc::make_mut(&mut storage.data).iter_mut()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::shapes::{Rank0, Rank1, Rank2, Rank3};
+    use crate::tensor::{AsArray, OnesTensor, SampleTensor, TensorFromArray};
+    use crate::tensor_ops::{BroadcastTo, SumTo};
 
     use super::*;
 
     #[test]
     fn test_0d_contiguous_iter() {
         let s: StridedArray<Rank0, f32> = StridedArray {
-            data: Arc::new([0.0].to_vec()),
+            data: Arc::new([0.0].to_vec().into()),
             shape: (),
             strides: ().strides(),
         };
@@ -209,7 +257,7 @@ mod tests {
     fn test_1d_contiguous_iter() {
         let shape = Default::default();
         let s: StridedArray<Rank1<3>, f32> = StridedArray {
-            data: Arc::new([0.0, 1.0, 2.0].to_vec()),
+            data: Arc::new([0.0, 1.0, 2.0].to_vec().into()),
             shape,
             strides: shape.strides(),
         };
@@ -224,7 +272,7 @@ mod tests {
     fn test_2d_contiguous_iter() {
         let shape = Default::default();
         let s: StridedArray<Rank2<2, 3>, f32> = StridedArray {
-            data: Arc::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0].to_vec()),
+            data: Arc::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0].to_vec().into()),
             shape,
             strides: shape.strides(),
         };
@@ -241,7 +289,7 @@ mod tests {
     #[test]
     fn test_2d_broadcasted_0_iter() {
         let s: StridedArray<Rank2<2, 3>, f32> = StridedArray {
-            data: Arc::new([1.0, 0.0, -1.0].to_vec()),
+            data: Arc::new([1.0, 0.0, -1.0].to_vec().into()),
             shape: Default::default(),
             strides: [0, 1],
         };
@@ -258,7 +306,7 @@ mod tests {
     #[test]
     fn test_2d_broadcasted_1_iter() {
         let s: StridedArray<Rank2<2, 3>, f32> = StridedArray {
-            data: Arc::new([1.0, -1.0].to_vec()),
+            data: Arc::new([1.0, -1.0].to_vec().into()),
             shape: Default::default(),
             strides: [1, 0],
         };
@@ -275,7 +323,7 @@ mod tests {
     #[test]
     fn test_2d_permuted_iter() {
         let s: StridedArray<Rank2<3, 2>, f32> = StridedArray {
-            data: Arc::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0].to_vec()),
+            data: Arc::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0].to_vec().into()),
             shape: Default::default(),
             strides: [1, 3],
         };
@@ -292,7 +340,7 @@ mod tests {
     #[test]
     fn test_3d_broadcasted_iter() {
         let s: StridedArray<Rank3<3, 1, 2>, f32> = StridedArray {
-            data: Arc::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0].to_vec()),
+            data: Arc::new([1.0, 2.0, 3.0, 4.0, 5.0, 6.0].to_vec().into()),
             shape: Default::default(),
             strides: [2, 0, 1],
         };
@@ -305,4 +353,37 @@ mod tests {
         assert_eq!(i.next(), Some(&6.0));
         assert!(i.next().is_none());
     }
+
+    #[test]
+    fn test_tensor_iter_sum() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank2<2, 3>, f32, _> = dev.sample_normal();
+        let total: f32 = t.iter().sum();
+        assert_eq!(total, t.clone().sum::<Rank0, _>().array());
+        assert_eq!(t.iter().copied().collect::<Vec<_>>(), t.as_vec());
+    }
+
+    #[test]
+    fn test_tensor_iter_mut() {
+        let dev: Cpu = Default::default();
+        let mut t: Tensor<Rank2<2, 3>, f32, _> = dev.ones();
+        let id = t.id;
+        for x in t.iter_mut() {
+            *x *= 2.0;
+        }
+        assert_eq!(t.id, id);
+        assert_eq!(t.array(), [[2.0; 3]; 2]);
+    }
+
+    #[test]
+    fn test_tensor_iter_mut_broadcasted() {
+        let dev: Cpu = Default::default();
+        let a: Tensor<Rank1<3>, f32, _> = dev.tensor([1.0, 2.0, 3.0]);
+        let mut b: Tensor<Rank2<2, 3>, f32, _> = a.clone().broadcast();
+        for (i, x) in b.iter_mut().enumerate() {
+            *x += i as f32;
+        }
+        assert_eq!(b.array(), [[1.0, 3.0, 5.0], [4.0, 6.0, 8.0]]);
+        assert_eq!(a.array(), [1.0, 2.0, 3.0]);
+    }
 }
@@ -5,12 +5,13 @@ use crate::{
     tensor::{
         cpu::{Cpu, StridedArray},
         storage_traits::*,
-        Tensor,
+        Tensor, Tracked,
     },
 };
 
 use super::{Cuda, CudaArray, CudaError};
 
+use cudarc::driver::{CudaSlice, ValidAsZeroBits};
 use rand::Rng;
 use std::{sync::Arc, vec::Vec};
 
@@ -22,7 +23,9 @@ impl Cuda {
     ) -> Result<Tensor<S, E, Self>, CudaError> {
         let data = self
             .dev
-            .take_async(Arc::try_unwrap(t_cpu.storage.data).unwrap())?;
+            .take_async(Arc::try_unwrap(t_cpu.storage.data).unwrap().into_inner())?;
+        let mut data: Tracked<CudaSlice<E>> = data.into();
+        data.track(&self.allocations);
         let storage = CudaArray {
             data: Arc::new(data),
             shape: t_cpu.storage.shape,
@@ -46,15 +49,16 @@ where
     }
 }
 
-impl<E: Unit> ZeroFillStorage<E> for Cuda {
+impl<E: Unit + ValidAsZeroBits> ZeroFillStorage<E> for Cuda {
     fn try_fill_with_zeros<S: Shape>(
         &self,
         storage: &mut Self::Storage<S, E>,
     ) -> Result<(), Self::Err> {
-        self.dev.copy_into_async(
-            std::vec![Default::default(); storage.data.len()],
-            Arc::make_mut(&mut storage.data),
-        )?;
+        // memsets on the device instead of copying zeros from the host
+        let mut data: Tracked<CudaSlice<E>> =
+            self.dev.alloc_zeros_async(storage.data.len())?.into();
+        data.track(&self.allocations);
+        storage.data = Arc::new(data);
         Ok(())
     }
 }
@@ -138,6 +142,16 @@ where
     }
 }
 
+impl<E: Unit> TensorFromVec<E> for Cuda {
+    fn try_tensor_from_vec<S: Shape>(
+        &self,
+        src: Vec<E>,
+        shape: S,
+    ) -> Result<Tensor<S, E, Self>, Self::Err> {
+        self.take_cpu_tensor(self.cpu.try_tensor_from_vec(src, shape)?)
+    }
+}
+
 impl<S: Shape, E: Unit> AsArray for CudaArray<S, E>
 where
     StridedArray<S, E>: AsArray,
@@ -145,7 +159,7 @@ where
     type Array = <StridedArray<S, E> as AsArray>::Array;
     fn array(&self) -> Self::Array {
         let a = StridedArray {
-            data: Arc::new(self.as_vec()),
+            data: Arc::new(self.as_vec().into()),
             shape: self.shape,
             strides: self.strides,
         };
@@ -1,12 +1,15 @@
-use crate::shapes::{Dtype, HasDtype, HasShape, HasUnitType, Shape, Unit};
+use crate::shapes::{Dtype, HasDtype, HasShape, HasUnitType, Rank0, Shape, Unit};
 use crate::tensor::cpu::{Cpu, CpuError};
-use crate::tensor::storage_traits::{DeviceStorage, HasErr};
+use crate::tensor::storage_traits::{CopySlice, DeviceStorage, HasErr};
+use crate::tensor::{Allocations, Tensor, Tracked};
+use crate::tensor_ops::{SumTo, TryMul};
+use crate::unique_id::unique_id;
 
 use cudarc::{
     cublas::{result::CublasError, CudaBlas},
     driver::{result::DriverError, BuildError, CudaDevice, CudaDeviceBuilder, CudaSlice},
 };
-use std::sync::Arc;
+use std::{any::Any, sync::Arc};
 
 #[derive(Debug)]
 pub enum CudaError {
@@ -45,6 +48,7 @@ pub struct Cuda {
     pub(crate) cpu: Cpu,
     pub(crate) dev: Arc<CudaDevice>,
     pub(crate) blas: Arc<CudaBlas>,
+    pub(crate) allocations: Allocations,
 }
 
 impl Default for Cuda {
@@ -68,13 +72,18 @@ impl Cuda {
         let cpu = Cpu::seed_from_u64(seed);
         let dev = CudaDeviceBuilder::new(ordinal).build()?;
         let blas = Arc::new(CudaBlas::new(dev.clone())?);
-        Ok(Self { cpu, dev, blas })
+        Ok(Self {
+            cpu,
+            dev,
+            blas,
+            allocations: Default::default(),
+        })
     }
 }
 
 #[derive(Debug, Clone)]
 pub struct CudaArray<S: Shape, E> {
-    pub(crate) data: Arc<CudaSlice<E>>,
+    pub(crate) data: Arc<Tracked<CudaSlice<E>>>,
     pub(crate) shape: S,
     pub(crate) strides: S::Concrete,
 }
@@ -114,14 +123,68 @@ impl DeviceStorage for Cuda {
     ) -> Result<Self::Storage<S, E>, Self::Err> {
         let numel = storage.shape.num_elements();
         let strides: S::Concrete = storage.strides;
-        Ok(Self::Storage {
-            data: Arc::new(self.dev.take_async(std::vec![Default::default(); numel])?),
+        let mut grad = Self::Storage {
+            data: Arc::new(
+                self.dev
+                    .take_async(std::vec![Default::default(); numel])?
+                    .into(),
+            ),
             shape: storage.shape,
             strides,
-        })
+        };
+        Arc::make_mut(&mut grad.data).track(&self.allocations);
+        Ok(grad)
     }
 
     fn random_u64(&self) -> u64 {
         self.cpu.random_u64()
     }
+
+    fn storage_byte_size<S: Shape, E: Unit>(storage: &Self::Storage<S, E>) -> usize {
+        storage.data.len() * std::mem::size_of::<E>()
+    }
+
+    fn allocated_bytes(&self) -> usize {
+        self.allocations.num_bytes()
+    }
+
+    fn grad_sum_squares<S: Shape, E: Dtype>(
+        &self,
+        grad: &Self::Storage<S, E>,
+    ) -> Result<f32, Self::Err> {
+        let grad: &dyn Any = grad;
+        if let Some(grad) = grad.downcast_ref::<CudaArray<S, f32>>() {
+            let mut sum = [0.0];
+            let grad = self.upgrade(grad.clone());
+            grad.try_square()?
+                .try_sum::<Rank0, _>()?
+                .copy_into(&mut sum);
+            return Ok(sum[0]);
+        }
+        Ok(0.0)
+    }
+
+    fn grad_scale<S: Shape, E: Dtype>(
+        &self,
+        grad: &mut Self::Storage<S, E>,
+        scale: f32,
+    ) -> Result<(), Self::Err> {
+        let grad: &mut dyn Any = grad;
+        if let Some(grad) = grad.downcast_mut::<CudaArray<S, f32>>() {
+            *grad = self.upgrade(grad.clone()).try_mul(scale)?.storage;
+        }
+        Ok(())
+    }
+
+    fn upgrade<S: Shape, E: Unit>(&self, mut storage: Self::Storage<S, E>) -> Tensor<S, E, Self> {
+        if let Some(data) = Arc::get_mut(&mut storage.data) {
+            data.track(&self.allocations);
+        }
+        Tensor {
+            id: unique_id(),
+            storage,
+            device: self.clone(),
+            tape: Default::default(),
+        }
+    }
 }
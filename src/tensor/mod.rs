@@ -31,6 +31,16 @@
 //! let t = dev.tensor([1.0, 2.0, 3.0]);
 //! ```
 //!
+//! ### From vecs with a runtime shape
+//!
+//! See [TensorFromVec].
+//!
+//! ```rust
+//! # use dfdx::prelude::*;
+//! # let dev: Cpu = Default::default();
+//! let t: Tensor<(usize,), f32, _> = dev.tensor_from_vec(vec![1.0, 2.0, 3.0], (3,));
+//! ```
+//!
 //! ### Filled with 0s or 1s
 //!
 //! See [ZerosTensor] and [OnesTensor].
@@ -103,6 +113,27 @@
 //! let t = t.traced(); // takes ownership of t
 //! ```
 //!
+//! # Inference without gradients
+//!
+//! Tensors without a tape never record backward operations or allocate gradients, so
+//! running a model on them is the equivalent of pytorch's `no_grad`. Use [Tensor::detach] or
+//! [Tensor::detached] to remove the tape from a tensor that already has one.
+//!
+//! ```rust
+//! # use dfdx::prelude::*;
+//! # let dev: Cpu = Default::default();
+//! let t: Tensor<Rank1<5>, f32, _> = dev.zeros();
+//! let t: Tensor<Rank1<5>, f32, _, NoneTape> = t.traced().detached();
+//! ```
+//!
+//! Since nothing is recorded, calling `backward()` on such a tensor **fails to compile**:
+//! ```compile_fail
+//! # use dfdx::prelude::*;
+//! # let dev: Cpu = Default::default();
+//! let t: Tensor<Rank1<5>, f32, _> = dev.zeros();
+//! let _ = t.traced().detached().sum().backward();
+//! ```
+//!
 //! # Serialization using numpy
 //!
 //! See [Tensor::save_to_npy] and [Tensor::load_from_npy].
@@ -110,6 +141,7 @@
 //! You can also use [Tensor::write_to_npz] and [Tensor::read_from_npz] when working with
 //! zip archives.
 
+mod allocations;
 pub(crate) mod cpu;
 mod tensor_impls;
 
@@ -121,6 +153,7 @@ pub(crate) mod numpy;
 
 pub(crate) mod storage_traits;
 
+pub(crate) use allocations::{Allocations, Tracked};
 pub(crate) use storage_traits::{OneFillStorage, ZeroFillStorage};
 
 pub use cpu::{Cpu, CpuError};
@@ -128,7 +161,7 @@ pub use cpu::{Cpu, CpuError};
 #[cfg(feature = "cuda")]
 pub use cuda::{Cuda, CudaError};
 
-pub use storage_traits::{AsArray, AsVec, CopySlice, TensorFromArray};
+pub use storage_traits::{AsArray, AsVec, CopySlice, TensorFromArray, TensorFromVec};
 pub use storage_traits::{DeviceStorage, HasErr};
 pub use storage_traits::{OnesTensor, SampleTensor, ZerosTensor};
 
@@ -140,8 +173,10 @@ pub use tensor_impls::{Tensor0D, Tensor1D, Tensor2D, Tensor3D, Tensor4D, Tensor5
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::gradients::NoneTape;
     use crate::shapes::*;
-    use crate::tests::TestDevice;
+    use crate::tensor_ops::*;
+    use crate::tests::{assert_close, TestDevice};
     use crate::unique_id::{unique_id, UniqueId};
     use std::collections::HashSet;
 
@@ -192,6 +227,30 @@ mod tests {
         assert_eq!(t3.id, t1_id);
     }
 
+    #[test]
+    fn test_detached_forward() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank1<3>, f32, _> = dev.tensor([1.0, 2.0, 3.0]);
+        let traced = x.trace().exp().sum();
+        let detached: Tensor<Rank0, f32, _, NoneTape> = x.trace().detached().exp().sum();
+        assert_eq!(traced.array(), detached.array());
+        assert_ne!(x.trace().detach().id, x.id);
+    }
+
+    #[test]
+    fn test_detached_stops_gradients() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank1<3>, f32, _> = dev.tensor([1.0, 2.0, 3.0]);
+        let y = x.trace().exp();
+        let c = y.detach();
+        // without detaching this would be 2 * exp(2x)
+        let g = (y * c).sum().backward();
+        assert_close(
+            &g.get(&x).array(),
+            &[1.0f32, 2.0, 3.0].map(|v| v.exp() * v.exp()),
+        );
+    }
+
     #[test]
     fn test_zeros() {
         let dev: TestDevice = Default::default();
@@ -223,6 +282,90 @@ mod tests {
         assert_eq!(t.array(), [[1.0, 2.0], [3.0, 4.0]]);
     }
 
+    #[test]
+    fn test_tensor_from_vec() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<(usize, usize), f32, _> =
+            dev.tensor_from_vec(std::vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0], (2, 3));
+        assert_eq!(t.shape(), &(2, 3));
+        assert_eq!(t.as_vec(), [1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    }
+
+    #[test]
+    fn test_tensor_from_vec_wrong_len() {
+        let dev: Cpu = Default::default();
+        let r = dev.try_tensor_from_vec(std::vec![1.0f32, 2.0, 3.0], (2, 2));
+        assert!(matches!(
+            r,
+            Err(CpuError::WrongNumElements {
+                expected: 4,
+                found: 3
+            })
+        ));
+    }
+
+    #[test]
+    fn test_copy_from_preserves_id() {
+        let dev: TestDevice = Default::default();
+        let mut t: Tensor<Rank2<2, 3>, f32, _> = dev.sample_normal();
+        let id = t.id;
+        let saved = t.as_vec();
+        t.copy_from(&[0.0; 6]);
+        assert_eq!(t.array(), [[0.0; 3]; 2]);
+        t.copy_from(&saved);
+        assert_eq!(t.as_vec(), saved);
+        assert_eq!(t.id, id);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_copy_from_wrong_len() {
+        let dev: TestDevice = Default::default();
+        let mut t: Tensor<Rank1<3>, f32, _> = dev.zeros();
+        t.copy_from(&[1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_to_device_usize_and_bool() {
+        let dev: TestDevice = Default::default();
+        let cpu: Cpu = Default::default();
+        let idx: Tensor<Rank1<3>, usize, Cpu> = cpu.tensor([2, 0, 2]);
+        let idx = idx.to_device(&dev);
+        assert_eq!(idx.array(), [2, 0, 2]);
+
+        let t: Tensor<Rank1<3>, f32, _> = dev.tensor([1.0, 2.0, 3.0]);
+        let r = t.trace().gather(idx);
+        assert_eq!(r.array(), [3.0, 1.0, 3.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [1.0, 0.0, 2.0]);
+
+        let mask: Tensor<Rank1<2>, bool, Cpu> = cpu.tensor([true, false]);
+        assert_eq!(mask.to_device(&dev).array(), [true, false]);
+    }
+
+    #[test]
+    fn test_to_cpu() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 3>, f32, _> = dev.sample_normal();
+        let c: Tensor<Rank2<2, 3>, f32, Cpu> = t.trace().to_cpu();
+        assert_eq!(c.as_vec(), t.as_vec());
+
+        let mask: Tensor<Rank1<2>, bool, _> = dev.tensor([true, false]);
+        assert_eq!(mask.to_cpu().array(), [true, false]);
+    }
+
+    #[cfg(feature = "cuda")]
+    #[test]
+    fn test_cuda_to_cpu() {
+        let cpu: Cpu = Default::default();
+        let cuda: Cuda = Default::default();
+        let data = std::vec![1.0f32, -2.0, 3.0, 4.5, 0.0, 6.0];
+        let t: Tensor<Rank2<2, 3>, f32, Cpu> = cpu.tensor_from_vec(data.clone(), (Const, Const));
+        let t = t.to_cuda(&cuda);
+        assert_eq!(t.to_cpu().as_vec(), data);
+        assert_eq!(t.to_cpu().to_cuda(&cuda).as_vec(), data);
+    }
+
     #[test]
     fn fuzz_test_rand() {
         let dev: TestDevice = Default::default();
@@ -237,4 +380,58 @@ mod tests {
         let dev: TestDevice = Default::default();
         let _: Tensor<Rank1<1000>, f32, _> = dev.sample_normal();
     }
+
+    #[test]
+    fn test_allocated_bytes() {
+        let dev: Cpu = Default::default();
+        assert_eq!(dev.allocated_bytes(), 0);
+
+        let a: Tensor<Rank2<100, 100>, f32, _> = dev.zeros();
+        assert_eq!(a.byte_size(), 100 * 100 * 4);
+        assert_eq!(dev.allocated_bytes(), 100 * 100 * 4);
+
+        // clones & broadcasts share the same buffer
+        let b = a.clone();
+        let c: Tensor<Rank3<2, 100, 100>, f32, _> = a.clone().broadcast();
+        assert_eq!(c.byte_size(), 100 * 100 * 4);
+        assert_eq!(dev.allocated_bytes(), 100 * 100 * 4);
+
+        let d: Tensor<Rank1<10>, usize, _> = dev.zeros();
+        assert_eq!(d.byte_size(), 10 * std::mem::size_of::<usize>());
+        assert_eq!(dev.allocated_bytes(), 100 * 100 * 4 + d.byte_size());
+
+        drop(a);
+        drop(b);
+        drop(d);
+        assert_eq!(dev.allocated_bytes(), 100 * 100 * 4);
+        drop(c);
+        assert_eq!(dev.allocated_bytes(), 0);
+    }
+
+    #[test]
+    fn test_allocated_bytes_gradients() {
+        let dev: Cpu = Default::default();
+        let t: Tensor<Rank1<5>, f32, _> = dev.zeros();
+        let g = t.trace().sum().backward();
+        // `t`, and the gradients of `t` and the sum
+        assert_eq!(dev.allocated_bytes(), 5 * 4 + 5 * 4 + 4);
+        drop(g);
+        assert_eq!(dev.allocated_bytes(), 5 * 4);
+    }
+
+    #[test]
+    fn test_allocated_bytes_clone_then_write() {
+        let dev: Cpu = Default::default();
+        let a: Tensor<Rank1<5>, f32, _> = dev.zeros();
+        let mut b = a.clone();
+        assert_eq!(dev.allocated_bytes(), 5 * 4);
+
+        // writing to a shared buffer copies it
+        b.copy_from(&[1.0; 5]);
+        assert_eq!(dev.allocated_bytes(), 2 * 5 * 4);
+        drop(a);
+        assert_eq!(dev.allocated_bytes(), 5 * 4);
+        drop(b);
+        assert_eq!(dev.allocated_bytes(), 0);
+    }
 }
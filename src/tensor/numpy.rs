@@ -152,9 +152,23 @@ fn read_header<R: Read, E: NumpyDtype>(r: &mut R, shape: Vec<usize>) -> Result<E
 
     // shape
     i = expect(&header, i, b"'shape': (")?;
-    let shape_str = to_shape_str(shape);
-    i = expect(&header, i, shape_str.as_bytes())?;
-    expect(&header, i, b"), }")?;
+    let shape_len = header[i..]
+        .iter()
+        .position(|&c| c == b')')
+        .ok_or(NpyError::InvalidShape)?;
+    let found = String::from_utf8(header[i..i + shape_len].to_vec())?
+        .split(',')
+        .map(|d| d.trim())
+        .filter(|d| !d.is_empty())
+        .map(|d| d.parse::<usize>().map_err(|_| NpyError::InvalidShape))
+        .collect::<Result<Vec<usize>, _>>()?;
+    if found != shape {
+        return Err(NpyError::ShapeMismatch {
+            expected: shape,
+            found,
+        });
+    }
+    expect(&header, i + shape_len, b"), }")?;
 
     Ok(endian)
 }
@@ -259,6 +273,15 @@ pub enum NpyError {
 
     /// Unexpected alignment for [Endian].
     InvalidAlignment,
+
+    /// The shape in the header could not be parsed.
+    InvalidShape,
+
+    /// The shape of the stored array is different from the shape of the tensor.
+    ShapeMismatch {
+        expected: Vec<usize>,
+        found: Vec<usize>,
+    },
 }
 
 impl std::fmt::Display for NpyError {
@@ -277,6 +300,10 @@ impl std::fmt::Display for NpyError {
                 "error while parsing: expected {expected_str} found {found_str}"
             ),
             NpyError::InvalidAlignment => write!(fmt, "invalid alignment"),
+            NpyError::InvalidShape => write!(fmt, "invalid shape"),
+            NpyError::ShapeMismatch { expected, found } => {
+                write!(fmt, "shape mismatch: expected {expected:?} found {found:?}")
+            }
         }
     }
 }
@@ -514,4 +541,27 @@ mod tests {
             .load_from_npy(file.path())
             .expect_err("");
     }
+
+    #[test]
+    fn test_load_shape_mismatch() {
+        let dev: TestDevice = Default::default();
+        let file = NamedTempFile::new().expect("failed to create tempfile");
+        dev.tensor([[0.0f32; 3]; 2])
+            .save_to_npy(file.path())
+            .expect("Saving failed");
+
+        let err = dev
+            .tensor([[0.0f32; 2]; 3])
+            .load_from_npy(file.path())
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            NpyError::ShapeMismatch { ref expected, ref found }
+            if expected == &[3, 2] && found == &[2, 3]
+        ));
+        assert_eq!(
+            err.to_string(),
+            "shape mismatch: expected [3, 2] found [2, 3]"
+        );
+    }
 }
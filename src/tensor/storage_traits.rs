@@ -1,7 +1,9 @@
 use rand::distributions::Distribution;
 use rand_distr::{Standard, StandardNormal};
+use std::{any::Any, boxed::Box};
 
 use crate::{
+    gradients::GradientBuffer,
     shapes::{ConstShape, Dtype, HasShape, HasUnitType, Shape, Unit},
     unique_id::unique_id,
 };
@@ -32,6 +34,54 @@ pub trait DeviceStorage: 'static + Default + Clone + HasErr {
         storage: &Self::Storage<S, E>,
     ) -> Result<Self::Storage<S, E>, Self::Err>;
 
+    /// The number of bytes of the buffer that backs `storage`. Broadcasted
+    /// storages report the size of the buffer they were broadcasted from.
+    ///
+    /// Defaults to the number of elements of the shape times the size of `E`.
+    fn storage_byte_size<S: Shape, E: Unit>(storage: &Self::Storage<S, E>) -> usize {
+        storage.shape().num_elements() * std::mem::size_of::<E>()
+    }
+
+    /// The total number of bytes allocated by this device for tensors and gradients
+    /// that are still alive. Devices that don't track their allocations return 0.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// let dev: Cpu = Default::default();
+    /// let before = dev.allocated_bytes();
+    /// let t: Tensor<Rank2<100, 100>, f32, _> = dev.zeros();
+    /// assert_eq!(dev.allocated_bytes() - before, t.byte_size());
+    /// drop(t);
+    /// assert_eq!(dev.allocated_bytes(), before);
+    /// ```
+    fn allocated_bytes(&self) -> usize {
+        0
+    }
+
+    /// The sum of the squares of the elements of `grad`. Used to compute the norm of
+    /// gradients whose dtype has been erased, see [crate::gradients::clip_grad_norm()].
+    ///
+    /// Defaults to 0, so gradients on devices that don't override this don't count
+    /// towards the norm.
+    fn grad_sum_squares<S: Shape, E: Dtype>(
+        &self,
+        _grad: &Self::Storage<S, E>,
+    ) -> Result<f32, Self::Err> {
+        Ok(0.0)
+    }
+
+    /// Multiplies every element of `grad` by `scale`. Used to clip gradients whose dtype
+    /// has been erased, see [crate::gradients::clip_grad_norm()].
+    ///
+    /// Defaults to leaving `grad` as is.
+    fn grad_scale<S: Shape, E: Dtype>(
+        &self,
+        _grad: &mut Self::Storage<S, E>,
+        _scale: f32,
+    ) -> Result<(), Self::Err> {
+        Ok(())
+    }
+
     /// Upgrades the device storage into a tensor
     fn upgrade<S: Shape, E: Unit>(&self, storage: Self::Storage<S, E>) -> Tensor<S, E, Self> {
         Tensor {
@@ -47,6 +97,7 @@ pub trait DeviceStorage: 'static + Default + Clone + HasErr {
 pub trait AllocGrad: HasErr {
     type Gradient: 'static;
     fn try_alloc_grad(&self) -> Result<Self::Gradient, Self::Err>;
+    fn erase_grad(&self, grad: Self::Gradient) -> Box<dyn GradientBuffer>;
 }
 
 impl<S: Shape, E: Dtype, D: DeviceStorage, T> AllocGrad for Tensor<S, E, D, T> {
@@ -54,6 +105,46 @@ impl<S: Shape, E: Dtype, D: DeviceStorage, T> AllocGrad for Tensor<S, E, D, T> {
     fn try_alloc_grad(&self) -> Result<Self::Gradient, D::Err> {
         self.device.try_alloc_grad(&self.storage)
     }
+    fn erase_grad(&self, grad: Self::Gradient) -> Box<dyn GradientBuffer> {
+        Box::new(DeviceGradient::<S, E, D> {
+            storage: grad,
+            device: self.device.clone(),
+        })
+    }
+}
+
+/// A gradient that can be stored in [crate::gradients::Gradients] after its type
+/// has been erased.
+struct DeviceGradient<S: Shape, E: Unit, D: DeviceStorage> {
+    storage: D::Storage<S, E>,
+    device: D,
+}
+
+impl<S: Shape, E: Unit, D: DeviceStorage> std::fmt::Debug for DeviceGradient<S, E, D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.storage.fmt(f)
+    }
+}
+
+impl<S: Shape, E: Dtype, D: DeviceStorage> GradientBuffer for DeviceGradient<S, E, D> {
+    fn as_any(&self) -> &dyn Any {
+        &self.storage
+    }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        &mut self.storage
+    }
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        Box::new(self.storage)
+    }
+    fn num_elements(&self) -> usize {
+        self.storage.shape().num_elements()
+    }
+    fn sum_squares(&self) -> f32 {
+        self.device.grad_sum_squares(&self.storage).unwrap()
+    }
+    fn scale(&mut self, scale: f32) {
+        self.device.grad_scale(&mut self.storage, scale).unwrap()
+    }
 }
 
 /// Enables copying data into and out of tensors
@@ -63,7 +154,12 @@ pub trait CopySlice<E: Unit>: DeviceStorage {
 }
 
 impl<S: Shape, E: Unit, D: CopySlice<E>, T> Tensor<S, E, D, T> {
-    /// Copy data from a slice - **panics** if there are not enough elements in the slice.
+    /// Copy data from a slice - **panics** if the length of the slice is not
+    /// the number of elements in the tensor.
+    ///
+    /// The data is written into the existing storage, so the tensor keeps its id. This
+    /// is the reverse of [crate::tensor::AsVec::as_vec()], and can be used to load parameters
+    /// of an existing module in place.
     ///
     /// ```rust
     /// # use dfdx::prelude::*;
@@ -74,10 +170,16 @@ impl<S: Shape, E: Unit, D: CopySlice<E>, T> Tensor<S, E, D, T> {
     /// assert_eq!(t.array(), [[1.0, 2.0], [3.0, 4.0]]);
     /// ```
     pub fn copy_from(&mut self, src: &[E]) {
+        assert_eq!(
+            src.len(),
+            self.shape().num_elements(),
+            "Slice length does not match number of elements in tensor"
+        );
         D::copy_from(self, src);
     }
 
-    /// Copy data into a slice - **panics** if there are not enough elements in the tensor.
+    /// Copy data into a slice - **panics** if the length of the slice is not
+    /// the number of elements in the tensor.
     ///
     /// ```rust
     /// # use dfdx::prelude::*;
@@ -88,6 +190,11 @@ impl<S: Shape, E: Unit, D: CopySlice<E>, T> Tensor<S, E, D, T> {
     /// assert_eq!(data, [1.0, 2.0, 3.0, 4.0]);
     /// ```
     pub fn copy_into(&self, dst: &mut [E]) {
+        assert_eq!(
+            dst.len(),
+            self.shape().num_elements(),
+            "Slice length does not match number of elements in tensor"
+        );
         D::copy_into(self, dst);
     }
 }
@@ -249,6 +356,29 @@ pub trait TensorFromArray<Src, S: Shape, E: Unit>: DeviceStorage {
     fn try_tensor(&self, src: Src) -> Result<Tensor<S, E, Self>, Self::Err>;
 }
 
+/// Construct tensors from a [std::vec::Vec] and a runtime shape
+pub trait TensorFromVec<E: Unit>: DeviceStorage {
+    /// Create a tensor with shape `shape` from `src`, which is in row major order.
+    ///
+    /// **Panics** if `src.len()` is not `shape.num_elements()`.
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<(usize, usize), f32, _> = dev.tensor_from_vec(vec![1.0, 2.0, 3.0, 4.0], (2, 2));
+    /// assert_eq!(t.as_vec(), [1.0, 2.0, 3.0, 4.0]);
+    /// ```
+    fn tensor_from_vec<S: Shape>(&self, src: std::vec::Vec<E>, shape: S) -> Tensor<S, E, Self> {
+        self.try_tensor_from_vec(src, shape).unwrap()
+    }
+    /// Fallible version of [TensorFromVec::tensor_from_vec]. Returns an error
+    /// if `src.len()` is not `shape.num_elements()`.
+    fn try_tensor_from_vec<S: Shape>(
+        &self,
+        src: std::vec::Vec<E>,
+        shape: S,
+    ) -> Result<Tensor<S, E, Self>, Self::Err>;
+}
+
 /// Convert tensors to rust arrays
 pub trait AsArray {
     type Array: std::fmt::Debug + PartialEq;
@@ -1,11 +1,12 @@
 use rand::distributions::Distribution;
+use std::boxed::Box;
 
 use super::storage_traits::{CopySlice, DeviceStorage, HasErr, ZerosTensor};
 use super::{Cpu, OneFillStorage, SampleTensor, ZeroFillStorage};
 use crate::{
-    gradients::{NoneTape, OwnedTape, Tape},
+    gradients::{GradientTape, Gradients, NoneTape, OwnedTape, Tape},
     shapes::*,
-    unique_id::{HasUniqueId, UniqueId},
+    unique_id::{unique_id, HasUniqueId, UniqueId},
 };
 
 /// The single tensor struct that stores nd arrays and tapes.
@@ -65,6 +66,21 @@ impl<S: Shape, E: Unit, D: DeviceStorage, T> HasErr for Tensor<S, E, D, T> {
     type Err = D::Err;
 }
 
+impl<S: Shape, E: Unit, D: DeviceStorage, T> Tensor<S, E, D, T> {
+    /// The number of bytes of the buffer that backs this tensor. See [DeviceStorage::allocated_bytes]
+    /// for the total over all tensors of a device.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<2, 3>, f32, _> = dev.zeros();
+    /// assert_eq!(t.byte_size(), 2 * 3 * 4);
+    /// ```
+    pub fn byte_size(&self) -> usize {
+        D::storage_byte_size(&self.storage)
+    }
+}
+
 impl<S: Shape, E: Dtype, D: DeviceStorage> Tensor<S, E, D, NoneTape> {
     /// Clone and put a [OwnedTape] into the tensor
     pub fn trace(&self) -> Tensor<S, E, D, OwnedTape<D>> {
@@ -74,6 +90,28 @@ impl<S: Shape, E: Dtype, D: DeviceStorage> Tensor<S, E, D, NoneTape> {
     pub fn traced(self) -> Tensor<S, E, D, OwnedTape<D>> {
         self.put_tape(Default::default())
     }
+    /// Clone and put a [OwnedTape] that reuses `gradients` into the tensor. See [Tensor::traced_with].
+    pub fn trace_with(&self, gradients: Gradients) -> Tensor<S, E, D, OwnedTape<D>> {
+        self.clone().traced_with(gradients)
+    }
+    /// Put a [OwnedTape] into the tensor that accumulates into `gradients`.
+    ///
+    /// Gradients that are already present in `gradients` are reused instead of allocated,
+    /// and the backward pass **adds** to them. Call [Gradients::zero_params()] first to get
+    /// the same result as [Tensor::traced], and to drop the gradients of intermediate values:
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let mut model: Linear<3, 2> = BuildModule::build(&dev);
+    /// let x: Tensor<Rank1<3>, f32, _> = dev.tensor([1.0, 2.0, 3.0]);
+    /// let mut grads = model.forward(x.trace()).square().sum().backward();
+    /// grads.zero_params(&mut model);
+    /// let grads = model.forward(x.trace_with(grads)).square().sum().backward();
+    /// ```
+    pub fn traced_with(self, gradients: Gradients) -> Tensor<S, E, D, OwnedTape<D>> {
+        self.put_tape(OwnedTape(Box::new(GradientTape::with_gradients(gradients))))
+    }
 }
 
 impl<S: Shape, E: Dtype, D: DeviceStorage, T: Tape<D>> Tensor<S, E, D, T> {
@@ -86,6 +124,30 @@ impl<S: Shape, E: Dtype, D: DeviceStorage, T: Tape<D>> Tensor<S, E, D, T> {
             tape: Default::default(),
         }
     }
+
+    /// Clone and remove the tape from the tensor. See [Tensor::detached].
+    pub fn detach(&self) -> Tensor<S, E, D, NoneTape> {
+        Tensor {
+            id: unique_id(),
+            storage: self.storage.clone(),
+            device: self.device.clone(),
+            tape: NoneTape,
+        }
+    }
+
+    /// Remove the tape from the tensor. Operations applied to the result are not
+    /// recorded, and no gradients are allocated for them.
+    ///
+    /// The result gets a new [UniqueId], so gradients will not flow back through it,
+    /// and it can be combined with the original tensor.
+    pub fn detached(self) -> Tensor<S, E, D, NoneTape> {
+        Tensor {
+            id: unique_id(),
+            storage: self.storage,
+            device: self.device,
+            tape: NoneTape,
+        }
+    }
 }
 
 /// Put a tape of type `T` into the tensor
@@ -200,9 +262,9 @@ impl<S: Shape, E: Unit, D: SampleTensor<E>, T> Tensor<S, E, D, T> {
 /// use dfdx::prelude::*;
 ///
 /// struct MLP<D: Device<f32>> {
-///     l1: Linear<5, 10, D>,
+///     l1: Linear<5, 10, f32, D>,
 ///     a1: ReLU,
-///     l2: Linear<10, 1, D>,
+///     l2: Linear<10, 1, f32, D>,
 /// }
 ///
 /// // Need two device types to allow converting from one device to another
@@ -249,9 +311,11 @@ pub type OnCuda<M> = OnDevice<M, crate::prelude::Cuda>;
 /// Equivalent to `OnDevice<M, Cpu>`
 pub type OnCpu<M> = OnDevice<M, Cpu>;
 
+/// Copies the data of any tensor to another device, including `usize` index tensors and
+/// `bool` masks, so they can be moved alongside parameters. The tape is not copied.
 impl<
         S: Shape,
-        E: Dtype + Unit,
+        E: Unit,
         T,
         D1: DeviceStorage + ZerosTensor<E> + CopySlice<E>,
         D2: DeviceStorage + ZerosTensor<E> + CopySlice<E>,
@@ -268,6 +332,25 @@ impl<
     }
 }
 
+impl<S: Shape, E: Unit, D: DeviceStorage + ZerosTensor<E> + CopySlice<E>, T> Tensor<S, E, D, T> {
+    /// Copies the data into a new tensor on a [Cpu], no matter which device this tensor is on.
+    /// This is useful for inspecting tensors on other devices. The tape is not copied.
+    ///
+    /// Unlike [ToDevice::to_device()], this does not need a device to be passed in.
+    pub fn to_cpu(&self) -> Tensor<S, E, Cpu> {
+        self.to_device(&Cpu::default())
+    }
+
+    /// Copies the data into a new tensor on `device`. The tape is not copied.
+    #[cfg(feature = "cuda")]
+    pub fn to_cuda(&self, device: &crate::tensor::Cuda) -> Tensor<S, E, crate::tensor::Cuda>
+    where
+        Cpu: ZerosTensor<E>,
+    {
+        self.to_device(device)
+    }
+}
+
 pub type Tensor0D<Tape = NoneTape> = Tensor<Rank0, f32, Cpu, Tape>;
 pub type Tensor1D<const M: usize, Tape = NoneTape> = Tensor<Rank1<M>, f32, Cpu, Tape>;
 pub type Tensor2D<const M: usize, const N: usize, Tape = NoneTape> =
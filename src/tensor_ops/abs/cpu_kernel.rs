@@ -1,14 +1,14 @@
 use crate::tensor_ops::cpu_kernels::UnaryDerivative;
 
-impl UnaryDerivative<f32> for super::AbsKernelOp {
+impl<F: num_traits::Float> UnaryDerivative<F> for super::AbsKernelOp {
     #[inline(always)]
-    fn f(&self, x: &f32) -> f32 {
+    fn f(&self, x: &F) -> F {
         x.abs()
     }
     #[inline(always)]
-    fn df(&self, x: &f32) -> f32 {
-        if x == &0.0 {
-            0.0
+    fn df(&self, x: &F) -> F {
+        if x == &F::zero() {
+            F::zero()
         } else {
             x.signum()
         }
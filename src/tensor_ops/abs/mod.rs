@@ -52,4 +52,12 @@ mod tests {
         let g = r.mean().backward();
         assert_eq!(g.get(&x).array(), [-0.2, -0.2, 0.0, 0.2, 0.2]);
     }
+
+    #[test]
+    fn test_abs_subgradient() {
+        let dev: TestDevice = Default::default();
+        let x = dev.tensor([-3.0, 0.0, 3.0]);
+        let g = x.trace().abs().sum().backward();
+        assert_eq!(g.get(&x).array(), [-1.0, 0.0, 1.0]);
+    }
 }
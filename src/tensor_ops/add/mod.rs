@@ -171,7 +171,7 @@ mod tests {
     #[test]
     fn test_scalar_add_0d() {
         let dev: TestDevice = Default::default();
-        let x = dev.tensor(0.0);
+        let x: Tensor<Rank0, f32, _> = dev.tensor(0.0);
         let r = x.trace() + 1.0;
         assert_eq!(r.array(), 1.0);
         let g = r.exp().backward();
@@ -181,7 +181,7 @@ mod tests {
     #[test]
     fn test_scalar_add_1d() {
         let dev: TestDevice = Default::default();
-        let x = dev.tensor([0.0, 1.0, 2.0]);
+        let x: Tensor<Rank1<3>, f32, _> = dev.tensor([0.0, 1.0, 2.0]);
         let r = x.trace() + 0.5;
         assert_eq!(r.array(), [0.5, 1.5, 2.5]);
         let g = r.exp().sum().backward();
@@ -191,7 +191,7 @@ mod tests {
     #[test]
     fn test_scalar_add_2d() {
         let dev: TestDevice = Default::default();
-        let x = dev.tensor([[0.0; 2]; 3]);
+        let x: Tensor<Rank2<3, 2>, f32, _> = dev.tensor([[0.0; 2]; 3]);
         let r = x.trace() + 0.5;
         assert_eq!(r.array(), [[0.5; 2]; 3]);
         let g = r.exp().sum().backward();
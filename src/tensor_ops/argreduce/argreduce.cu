@@ -0,0 +1,75 @@
+#include "cuda_utils.cuh"
+
+// Each thread handles one output element, scanning along axis `ax` of `inp`.
+// Returns the offset into `inp` of the first element along that axis.
+__device__ unsigned int argreduce_offset(
+    unsigned int out_i,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t *dims,
+    const size_t *strides
+) {
+    unsigned int offset = 0;
+    for (int d = num_dims - 1; d >= 0; d--) {
+        if (d == ax) {
+            continue;
+        }
+        offset += (out_i % dims[d]) * strides[d];
+        out_i /= dims[d];
+    }
+    return offset;
+}
+
+extern "C" __global__ void argmax_forward(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t *dims,
+    const float *inp,
+    const size_t *strides,
+    size_t *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int offset = argreduce_offset(i, num_dims, ax, dims, strides);
+    size_t best_i = 0;
+    float best = inp[offset];
+    for (size_t k = 1; k < dims[ax]; k++) {
+        float v = inp[offset + k * strides[ax]];
+        if (v > best) {
+            best = v;
+            best_i = k;
+        }
+    }
+    out[i] = best_i;
+}
+
+extern "C" __global__ void argmin_forward(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t *dims,
+    const float *inp,
+    const size_t *strides,
+    size_t *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int offset = argreduce_offset(i, num_dims, ax, dims, strides);
+    size_t best_i = 0;
+    float best = inp[offset];
+    for (size_t k = 1; k < dims[ax]; k++) {
+        float v = inp[offset + k * strides[ax]];
+        if (v < best) {
+            best = v;
+            best_i = k;
+        }
+    }
+    out[i] = best_i;
+}
@@ -0,0 +1,57 @@
+use crate::{
+    shapes::{Axes, Dtype, ReduceShapeTo, Shape},
+    tensor::cpu::{Cpu, LendingIterator, StridedArray},
+};
+
+impl Cpu {
+    /// Finds the index along `Ax` of the first value that `better` prefers over all others.
+    fn argreduce<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>, E: Dtype>(
+        &self,
+        dst: Dst,
+        inp: &StridedArray<Src, E>,
+        better: impl Fn(E, E) -> bool,
+    ) -> Result<StridedArray<Dst, usize>, <Self as crate::tensor::HasErr>::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>,
+    {
+        let ax = Ax::as_array()[0] as usize;
+        let mut out: StridedArray<Dst, usize> = StridedArray::new(dst)?;
+        let mut best: StridedArray<Dst, E> = StridedArray::new(dst)?;
+        let mut out_iter = out.iter_mut_as(&inp.shape);
+        let mut best_iter = best.iter_mut_as(&inp.shape);
+        let mut inp_iter = inp.iter_with_index();
+        while let Some(((o, b), (v, i_inp))) =
+            out_iter.next().zip(best_iter.next()).zip(inp_iter.next())
+        {
+            if i_inp[ax] == 0 || better(*v, *b) {
+                *o = i_inp[ax];
+                *b = *v;
+            }
+        }
+        Ok(out)
+    }
+}
+
+impl<E: Dtype> super::ArgReduceKernel<E> for Cpu {
+    fn argmax<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, E>,
+    ) -> Result<Self::Storage<Dst, usize>, Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>,
+    {
+        self.argreduce(dst, inp, |v, best| v > best)
+    }
+
+    fn argmin<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, E>,
+    ) -> Result<Self::Storage<Dst, usize>, Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>,
+    {
+        self.argreduce(dst, inp, |v, best| v < best)
+    }
+}
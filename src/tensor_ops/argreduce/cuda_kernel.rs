@@ -0,0 +1,77 @@
+use crate::{
+    shapes::{Axes, ReduceShapeTo, Shape},
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{CudaSlice, LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/argreduce.ptx"));
+const MODULE_NAME: &str = "argreduce";
+const ARGMAX_FN_NAME: &str = "argmax_forward";
+const ARGMIN_FN_NAME: &str = "argmin_forward";
+const ALL_FN_NAMES: [&str; 2] = [ARGMAX_FN_NAME, ARGMIN_FN_NAME];
+
+impl Cuda {
+    fn argreduce<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        fn_name: &str,
+        dst: Dst,
+        inp: &CudaArray<Src, f32>,
+    ) -> Result<CudaArray<Dst, usize>, <Self as crate::tensor::HasErr>::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>,
+    {
+        if !self.dev.has_func(MODULE_NAME, fn_name) {
+            self.dev
+                .load_ptx(PTX_SRC.into(), MODULE_NAME, &ALL_FN_NAMES)?;
+        }
+
+        let numel = dst.num_elements();
+        let mut storage = self.dev.alloc_zeros_async::<usize>(numel)?;
+
+        let dims: CudaSlice<usize> = self.dev.take_async(inp.shape.concrete().into())?;
+        let strides: CudaSlice<usize> = self.dev.take_async(inp.strides.into())?;
+
+        let fwd_fn = self.dev.get_func(MODULE_NAME, fn_name).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,                      // const size_t numel,
+            Src::NUM_DIMS,              // const size_t num_dims,
+            Ax::as_array()[0] as usize, // const size_t ax,
+            &dims,                      // const size_t *dims,
+            inp.data.as_ref(),          // const float *inp,
+            &strides,                   // const size_t *strides,
+            &mut storage,               // size_t *out
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+        Ok(CudaArray {
+            data: Arc::new(storage.into()),
+            shape: dst,
+            strides: dst.strides(),
+        })
+    }
+}
+
+impl super::ArgReduceKernel<f32> for Cuda {
+    fn argmax<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, f32>,
+    ) -> Result<Self::Storage<Dst, usize>, Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>,
+    {
+        self.argreduce(ARGMAX_FN_NAME, dst, inp)
+    }
+
+    fn argmin<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, f32>,
+    ) -> Result<Self::Storage<Dst, usize>, Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>,
+    {
+        self.argreduce(ARGMIN_FN_NAME, dst, inp)
+    }
+}
@@ -0,0 +1,149 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{shapes::*, tensor::*};
+
+pub trait ArgReduceKernel<E: Dtype>: DeviceStorage {
+    fn argmax<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, E>,
+    ) -> Result<Self::Storage<Dst, usize>, Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>;
+    fn argmin<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, E>,
+    ) -> Result<Self::Storage<Dst, usize>, Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>;
+}
+
+/// Reduces a single axis to the index of its maximum or minimum value.
+///
+/// These are not differentiable, so the output is a [usize] tensor without a tape.
+/// If there are multiple equal extreme values, the index of the first one is returned.
+pub trait ArgReduceTo<D: DeviceStorage>: HasErr + HasShape {
+    /// Index of the max value along axis `Ax`. **Pytorch equivalent**: `t.argmax(Ax)`
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 3.0, 2.0], [5.0, 0.0, 4.0]]);
+    /// let r = t.clone().argmax::<Rank1<2>, _>(); // or `argmax::<_, Axis<1>>()`
+    /// assert_eq!(r.array(), [1, 0]);
+    ///
+    /// let r = t.argmax::<Rank1<3>, _>();
+    /// assert_eq!(r.array(), [1, 0, 1]);
+    /// ```
+    fn argmax<Dst: Shape, Ax: Axes<Array = [isize; 1]>>(self) -> Tensor<Dst, usize, D>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_argmax().unwrap()
+    }
+    /// Fallible version of [ArgReduceTo::argmax]
+    fn try_argmax<Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        self,
+    ) -> Result<Tensor<Dst, usize, D>, Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>;
+
+    /// Index of the min value along axis `Ax`. **Pytorch equivalent**: `t.argmin(Ax)`
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 3.0, 2.0], [5.0, 0.0, 4.0]]);
+    /// let r = t.argmin::<Rank1<2>, _>();
+    /// assert_eq!(r.array(), [0, 1]);
+    /// ```
+    fn argmin<Dst: Shape, Ax: Axes<Array = [isize; 1]>>(self) -> Tensor<Dst, usize, D>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_argmin().unwrap()
+    }
+    /// Fallible version of [ArgReduceTo::argmin]
+    fn try_argmin<Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        self,
+    ) -> Result<Tensor<Dst, usize, D>, Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: ArgReduceKernel<E>, T> ArgReduceTo<D> for Tensor<S, E, D, T> {
+    fn try_argmax<Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        self,
+    ) -> Result<Tensor<Dst, usize, D>, Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        let dst: Dst = self.shape().reduced();
+        let storage = self.device.argmax(dst, &self.storage)?;
+        Ok(self.device.upgrade(storage))
+    }
+
+    fn try_argmin<Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        self,
+    ) -> Result<Tensor<Dst, usize, D>, Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        let dst: Dst = self.shape().reduced();
+        let storage = self.device.argmin(dst, &self.storage)?;
+        Ok(self.device.upgrade(storage))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor_ops::*;
+    use crate::tests::TestDevice;
+
+    #[test]
+    fn test_argmax_axis_1() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 3.0, 2.0], [5.0, 0.0, 4.0]]);
+        let r: Tensor<Rank1<2>, usize, _> = t.argmax();
+        assert_eq!(r.array(), [1, 0]);
+    }
+
+    #[test]
+    fn test_argmin_axis_0() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 3.0, 2.0], [5.0, 0.0, 4.0]]);
+        let r: Tensor<Rank1<3>, usize, _> = t.argmin();
+        assert_eq!(r.array(), [0, 1, 0]);
+    }
+
+    #[test]
+    fn test_argreduce_3d_and_ties() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 3, 2>, f32, _> = dev.tensor([
+            [[0.0, 1.0], [2.0, 1.0], [2.0, -1.0]],
+            [[-3.0, 4.0], [-3.0, 5.0], [7.0, 5.0]],
+        ]);
+        let r = t.clone().argmax::<Rank2<2, 2>, _>();
+        assert_eq!(r.array(), [[1, 0], [2, 1]]);
+        let r = t.argmin::<Rank2<2, 2>, _>();
+        assert_eq!(r.array(), [[0, 2], [0, 0]]);
+    }
+
+    #[test]
+    fn test_argmax_broadcasted_and_traced() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<3>, f32, _> = dev.tensor([1.0, -2.0, 3.0]);
+        let r = t
+            .trace()
+            .broadcast::<Rank2<2, 3>, _>()
+            .argmax::<Rank1<2>, _>();
+        assert_eq!(r.array(), [2, 2]);
+        let r = t.broadcast::<Rank2<3, 4>, _>().argmin::<Rank1<4>, _>();
+        assert_eq!(r.array(), [1; 4]);
+    }
+}
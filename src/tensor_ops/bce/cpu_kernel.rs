@@ -1,16 +1,16 @@
 use crate::tensor_ops::cpu_kernels::BinaryDerivative;
 
-impl BinaryDerivative<f32> for super::BCEKernelOp {
+impl<F: num_traits::Float> BinaryDerivative<F> for super::BCEKernelOp {
     #[inline(always)]
-    fn f(&self, logit: &f32, prob: &f32) -> f32 {
-        logit.max(0.0) - logit * prob + (1.0 + (-logit.abs()).exp()).ln()
+    fn f(&self, logit: &F, prob: &F) -> F {
+        logit.max(F::zero()) - *logit * *prob + (F::one() + (-logit.abs()).exp()).ln()
     }
     #[inline(always)]
-    fn dfdx(&self, logit: &f32, prob: &f32) -> f32 {
-        1.0 - prob - (1.0 + logit.exp()).recip()
+    fn dfdx(&self, logit: &F, prob: &F) -> F {
+        F::one() - *prob - (F::one() + logit.exp()).recip()
     }
     #[inline(always)]
-    fn dfdy(&self, logit: &f32, _: &f32) -> f32 {
-        -logit
+    fn dfdy(&self, logit: &F, _: &F) -> F {
+        -*logit
     }
 }
@@ -0,0 +1,29 @@
+#include "cuda_utils.cuh"
+
+// One thread per element of `inp`. `dims` are the dims of `inp`, and
+// `out_strides` are the strides of `out` broadcasted to `inp`, so reduced
+// axes have a stride of 0.
+#define BOOL_REDUCE_OP(NAME, OUT_TYPE, REDUCE) \
+extern "C" __global__ void NAME( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const bool *inp, \
+    const size_t *inp_strides, \
+    const size_t *out_strides, \
+    OUT_TYPE *out \
+) { \
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x; \
+    if (i >= numel) { \
+        return; \
+    } \
+\
+    bool x = inp[get_strided_index(i, num_dims, dims, inp_strides)]; \
+    OUT_TYPE *o = out + get_strided_index(i, num_dims, dims, out_strides); \
+    REDUCE \
+}
+
+// all writers of `any` and `all` write the same value, so no atomics are needed
+BOOL_REDUCE_OP(bool_reduce_any, bool, if (x) { *o = true; });
+BOOL_REDUCE_OP(bool_reduce_all, bool, if (!x) { *o = false; });
+BOOL_REDUCE_OP(bool_reduce_count_nonzero, size_t, if (x) { atomicAdd((unsigned long long *)o, 1ULL); });
@@ -0,0 +1,57 @@
+use crate::{
+    shapes::{Axes, ReduceShapeTo, Shape},
+    tensor::cpu::{Cpu, LendingIterator, StridedArray},
+};
+
+impl super::BoolReduceKernel for Cpu {
+    fn any<Src, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, bool>,
+    ) -> Result<Self::Storage<Dst, bool>, Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>,
+    {
+        let mut out: StridedArray<Dst, bool> = StridedArray::try_new_with(dst, false)?;
+        let mut out_iter = out.iter_mut_as(&inp.shape);
+        let mut inp_iter = inp.iter();
+        while let Some((o, i)) = out_iter.next().zip(inp_iter.next()) {
+            *o |= *i;
+        }
+        Ok(out)
+    }
+
+    fn all<Src, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, bool>,
+    ) -> Result<Self::Storage<Dst, bool>, Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>,
+    {
+        let mut out: StridedArray<Dst, bool> = StridedArray::try_new_with(dst, true)?;
+        let mut out_iter = out.iter_mut_as(&inp.shape);
+        let mut inp_iter = inp.iter();
+        while let Some((o, i)) = out_iter.next().zip(inp_iter.next()) {
+            *o &= *i;
+        }
+        Ok(out)
+    }
+
+    fn count_nonzero<Src, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, bool>,
+    ) -> Result<Self::Storage<Dst, usize>, Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>,
+    {
+        let mut out: StridedArray<Dst, usize> = StridedArray::new(dst)?;
+        let mut out_iter = out.iter_mut_as(&inp.shape);
+        let mut inp_iter = inp.iter();
+        while let Some((o, i)) = out_iter.next().zip(inp_iter.next()) {
+            *o += *i as usize;
+        }
+        Ok(out)
+    }
+}
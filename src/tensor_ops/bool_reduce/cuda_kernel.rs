@@ -0,0 +1,95 @@
+use crate::{
+    shapes::{Axes, BroadcastStridesTo, ReduceShapeTo, Shape},
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{CudaSlice, LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/bool_reduce.ptx"));
+const MODULE_NAME: &str = "bool_reduce";
+const ANY_FN_NAME: &str = "bool_reduce_any";
+const ALL_FN_NAME: &str = "bool_reduce_all";
+const COUNT_FN_NAME: &str = "bool_reduce_count_nonzero";
+const ALL_FN_NAMES: [&str; 3] = [ANY_FN_NAME, ALL_FN_NAME, COUNT_FN_NAME];
+
+impl Cuda {
+    fn bool_reduce<Src: Shape, Dst: Shape, Ax: Axes, E: Clone + Unpin>(
+        &self,
+        fn_name: &str,
+        dst: Dst,
+        inp: &CudaArray<Src, bool>,
+        init: E,
+    ) -> Result<CudaArray<Dst, E>, <Self as crate::tensor::HasErr>::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>,
+    {
+        if !self.dev.has_func(MODULE_NAME, fn_name) {
+            self.dev
+                .load_ptx(PTX_SRC.into(), MODULE_NAME, &ALL_FN_NAMES)?;
+        }
+
+        // TODO: modify this to be `self.dev.alloc_zeros_async(numel)?` once cudarc implements
+        // ValidAsZeroBits for bool
+        let mut storage = self.dev.take_async(std::vec![init; dst.num_elements()])?;
+
+        let numel = inp.shape.num_elements();
+        let out_strides: Src::Concrete =
+            BroadcastStridesTo::<Src, Ax>::broadcast_strides(&dst, dst.strides());
+        let dims: CudaSlice<usize> = self.dev.take_async(inp.shape.concrete().into())?;
+        let inp_strides: CudaSlice<usize> = self.dev.take_async(inp.strides.into())?;
+        let out_strides: CudaSlice<usize> = self.dev.take_async(out_strides.into())?;
+
+        let fwd_fn = self.dev.get_func(MODULE_NAME, fn_name).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,             // const size_t numel,
+            Src::NUM_DIMS,     // const size_t num_dims,
+            &dims,             // const size_t *dims,
+            inp.data.as_ref(), // const bool *inp,
+            &inp_strides,      // const size_t *inp_strides,
+            &out_strides,      // const size_t *out_strides,
+            &mut storage,      // OUT_TYPE *out
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+        Ok(CudaArray {
+            data: Arc::new(storage.into()),
+            shape: dst,
+            strides: dst.strides(),
+        })
+    }
+}
+
+impl super::BoolReduceKernel for Cuda {
+    fn any<Src, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, bool>,
+    ) -> Result<Self::Storage<Dst, bool>, Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>,
+    {
+        self.bool_reduce(ANY_FN_NAME, dst, inp, false)
+    }
+
+    fn all<Src, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, bool>,
+    ) -> Result<Self::Storage<Dst, bool>, Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>,
+    {
+        self.bool_reduce(ALL_FN_NAME, dst, inp, true)
+    }
+
+    fn count_nonzero<Src, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, bool>,
+    ) -> Result<Self::Storage<Dst, usize>, Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>,
+    {
+        self.bool_reduce(COUNT_FN_NAME, dst, inp, 0usize)
+    }
+}
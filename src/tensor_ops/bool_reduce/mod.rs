@@ -0,0 +1,179 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{shapes::*, tensor::*};
+
+pub trait BoolReduceKernel: DeviceStorage {
+    fn any<Src, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, bool>,
+    ) -> Result<Self::Storage<Dst, bool>, Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>;
+    fn all<Src, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, bool>,
+    ) -> Result<Self::Storage<Dst, bool>, Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>;
+    fn count_nonzero<Src, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, bool>,
+    ) -> Result<Self::Storage<Dst, usize>, Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>;
+}
+
+/// Reductions of boolean tensors along multiple axes.
+///
+/// These are not differentiable, so they are only implemented for tensors without a tape.
+/// Reducing an empty axis gives `false` for [BoolReduceTo::any], `true` for
+/// [BoolReduceTo::all], and `0` for [BoolReduceTo::count_nonzero].
+pub trait BoolReduceTo<D: DeviceStorage>: HasErr + HasShape {
+    /// Whether any value along `Ax` is `true`. **Pytorch equivalent**: `t.any(Axes)`
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[true, false, false], [false, false, false]]);
+    /// let r = t.clone().any::<Rank1<2>, _>(); // or `any::<_, Axis<1>>()`
+    /// assert_eq!(r.array(), [true, false]);
+    /// let r = t.any::<Rank0, _>();
+    /// assert_eq!(r.array(), true);
+    /// ```
+    fn any<Dst: Shape, Ax: Axes>(self) -> Tensor<Dst, bool, D>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_any().unwrap()
+    }
+    /// Fallible version of [BoolReduceTo::any]
+    fn try_any<Dst: Shape, Ax: Axes>(self) -> Result<Tensor<Dst, bool, D>, Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>;
+
+    /// Whether all values along `Ax` are `true`. **Pytorch equivalent**: `t.all(Axes)`
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[true, true, true], [true, false, true]]);
+    /// let r = t.all::<Rank1<2>, _>(); // or `all::<_, Axis<1>>()`
+    /// assert_eq!(r.array(), [true, false]);
+    /// ```
+    fn all<Dst: Shape, Ax: Axes>(self) -> Tensor<Dst, bool, D>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_all().unwrap()
+    }
+    /// Fallible version of [BoolReduceTo::all]
+    fn try_all<Dst: Shape, Ax: Axes>(self) -> Result<Tensor<Dst, bool, D>, Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>;
+
+    /// The number of `true` values along `Ax`. **Pytorch equivalent**: `t.count_nonzero(Axes)`
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[true, true, true], [true, false, true]]);
+    /// let r = t.clone().count_nonzero::<Rank1<3>, _>(); // or `count_nonzero::<_, Axis<0>>()`
+    /// assert_eq!(r.array(), [2, 1, 2]);
+    /// let r = t.count_nonzero::<Rank0, _>();
+    /// assert_eq!(r.array(), 5);
+    /// ```
+    fn count_nonzero<Dst: Shape, Ax: Axes>(self) -> Tensor<Dst, usize, D>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_count_nonzero().unwrap()
+    }
+    /// Fallible version of [BoolReduceTo::count_nonzero]
+    fn try_count_nonzero<Dst: Shape, Ax: Axes>(self) -> Result<Tensor<Dst, usize, D>, Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>;
+}
+
+impl<S: Shape, D: BoolReduceKernel> BoolReduceTo<D> for Tensor<S, bool, D> {
+    fn try_any<Dst: Shape, Ax: Axes>(self) -> Result<Tensor<Dst, bool, D>, Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        let dst: Dst = self.shape().reduced();
+        let storage = self.device.any(dst, &self.storage)?;
+        Ok(self.device.upgrade(storage))
+    }
+
+    fn try_all<Dst: Shape, Ax: Axes>(self) -> Result<Tensor<Dst, bool, D>, Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        let dst: Dst = self.shape().reduced();
+        let storage = self.device.all(dst, &self.storage)?;
+        Ok(self.device.upgrade(storage))
+    }
+
+    fn try_count_nonzero<Dst: Shape, Ax: Axes>(self) -> Result<Tensor<Dst, usize, D>, Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        let dst: Dst = self.shape().reduced();
+        let storage = self.device.count_nonzero(dst, &self.storage)?;
+        Ok(self.device.upgrade(storage))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor_ops::*;
+    use crate::tests::TestDevice;
+
+    #[test]
+    fn test_bool_reduce_1d() {
+        let dev: TestDevice = Default::default();
+        let t = dev.tensor([true, false, true]);
+        assert!(t.clone().any::<Rank0, _>().array());
+        assert!(!t.clone().all::<Rank0, _>().array());
+        assert_eq!(t.count_nonzero::<Rank0, _>().array(), 2);
+    }
+
+    #[test]
+    fn test_bool_reduce_3d() {
+        let dev: TestDevice = Default::default();
+        let t = dev.tensor([
+            [[true, false], [false, false], [true, true]],
+            [[false, false], [true, true], [true, false]],
+        ]);
+        assert_eq!(
+            t.clone().any::<Rank2<2, 3>, _>().array(),
+            [[true, false, true], [false, true, true]]
+        );
+        assert_eq!(
+            t.clone().all::<Rank2<2, 3>, _>().array(),
+            [[false, false, true], [false, true, false]]
+        );
+        assert_eq!(t.clone().count_nonzero::<Rank1<3>, _>().array(), [1, 2, 3]);
+        assert_eq!(
+            t.clone().count_nonzero::<Rank2<3, 2>, _>().array(),
+            [[1, 0], [1, 1], [2, 1]]
+        );
+        assert_eq!(t.clone().all::<_, Axes2<1, 2>>().array(), [false, false]);
+        assert_eq!(t.any::<_, Axes2<1, 2>>().array(), [true, true]);
+    }
+
+    #[test]
+    fn test_bool_reduce_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let t = dev.tensor([true, false, true]);
+        let b: Tensor<Rank2<4, 3>, bool, _> = t.broadcast();
+        assert_eq!(b.clone().count_nonzero::<Rank1<3>, _>().array(), [4, 0, 4]);
+        assert_eq!(b.all::<Rank1<3>, _>().array(), [true, false, true]);
+    }
+}
@@ -46,7 +46,7 @@ impl Cuda {
         );
         unsafe { fwd_fn.launch_async(cfg, params) }?;
         Ok(CudaArray {
-            data: Arc::new(storage),
+            data: Arc::new(storage.into()),
             shape,
             strides,
         })
@@ -78,7 +78,7 @@ impl BooleanKernel for Cuda {
         unsafe { fwd_fn.launch_async(cfg, params) }?;
 
         Ok(CudaArray {
-            data: Arc::new(storage),
+            data: Arc::new(storage.into()),
             shape: inp.shape,
             strides: inp.strides,
         })
@@ -3,7 +3,7 @@ use crate::{
     tensor::cpu::{Cpu, StridedArray},
 };
 
-impl<E: Dtype> super::BroadcastKernel<E> for Cpu {
+impl<E: Unit> super::BroadcastKernel<E> for Cpu {
     fn forward<Src: Shape, Dst: Shape, Ax: Axes>(
         &self,
         dst: Dst,
@@ -26,6 +26,7 @@ impl<E: Dtype> super::BroadcastKernel<E> for Cpu {
     ) -> Result<(), Self::Err>
     where
         Src: BroadcastShapeTo<Dst, Ax>,
+        E: Dtype,
     {
         debug_assert_eq!(grad_out.data.len(), grad_inp.data.len());
         for (i, o) in grad_inp.buf_iter_mut().zip(grad_out.buf_iter()) {
@@ -6,7 +6,7 @@ use std::sync::Arc;
 
 const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/broadcast_to.ptx"));
 
-impl<E: Dtype> super::BroadcastKernel<E> for Cuda {
+impl<E: Unit> super::BroadcastKernel<E> for Cuda {
     fn forward<Src: Shape, Dst: Shape, Ax: Axes>(
         &self,
         dst: Dst,
@@ -28,6 +28,7 @@ impl<E: Dtype> super::BroadcastKernel<E> for Cuda {
     ) -> Result<(), Self::Err>
     where
         Src: BroadcastShapeTo<Dst, Ax>,
+        E: Dtype,
     {
         if !self.dev.has_func("broadcast_to", "sum") {
             self.dev
@@ -5,7 +5,7 @@ mod cuda_kernel;
 
 use crate::{gradients::Tape, shapes::*, tensor::*};
 
-pub trait BroadcastKernel<E: Dtype>: DeviceStorage {
+pub trait BroadcastKernel<E: Unit>: DeviceStorage {
     fn forward<Src: Shape, Dst: Shape, Ax: Axes>(
         &self,
         dst: Dst,
@@ -19,7 +19,8 @@ pub trait BroadcastKernel<E: Dtype>: DeviceStorage {
         grad_out: &Self::Storage<Dst, E>,
     ) -> Result<(), Self::Err>
     where
-        Src: BroadcastShapeTo<Dst, Ax>;
+        Src: BroadcastShapeTo<Dst, Ax>,
+        E: Dtype;
 }
 
 /// Broadcast self into a new shape.
@@ -58,6 +59,31 @@ pub trait BroadcastTo: HasErr + HasShape {
     {
         self.try_broadcast_like(dst).unwrap()
     }
+    /// Broadcast into a runtime shape `dst`. The backward sums the gradient over
+    /// the broadcasted axes.
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank1<3>, f32, _> = dev.zeros();
+    /// let b: Tensor<(usize, Const<3>), f32, _> = a.broadcast_to((5, Const));
+    /// assert_eq!(b.shape(), &(5, Const));
+    /// ```
+    fn broadcast_to<Dst: Shape, Ax: Axes>(self, dst: Dst) -> Self::WithShape<Dst>
+    where
+        Self::Shape: BroadcastShapeTo<Dst, Ax>,
+    {
+        self.try_broadcast_like(&dst).unwrap()
+    }
+    /// Fallible version of [BroadcastTo::broadcast_to]
+    fn try_broadcast_to<Dst: Shape, Ax: Axes>(
+        self,
+        dst: Dst,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: BroadcastShapeTo<Dst, Ax>,
+    {
+        self.try_broadcast_like(&dst)
+    }
     /// fallible version of [BroadcastTo::broadcast_like]
     fn try_broadcast_like<Dst: Shape, Ax: Axes>(
         self,
@@ -88,6 +114,22 @@ impl<S: Shape, E: Dtype, D: BroadcastKernel<E>, T: Tape<D>> BroadcastTo for Tens
     }
 }
 
+/// Boolean tensors, such as the condition of [crate::tensor_ops::ChooseFrom], can be broadcast
+/// too. Since they have no gradients, they cannot be on a tape.
+impl<S: Shape, D: BroadcastKernel<bool>> BroadcastTo for Tensor<S, bool, D> {
+    fn try_broadcast_like<Dst: Shape, Ax: Axes>(
+        self,
+        dst: &Dst,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: BroadcastShapeTo<Dst, Ax>,
+    {
+        Ok(self
+            .device
+            .upgrade(self.device.forward(*dst, &self.storage)?))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -153,4 +195,15 @@ mod tests {
         g.get(&a).array().assert_close(&a_grad.array(), 1e-4);
         g.get(&b).array().assert_close(&b_grad.array(), 1e-4);
     }
+
+    #[test]
+    fn test_broadcast_to_runtime_shape() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor([1.0, 2.0, 3.0]);
+        let b: Tensor<(usize, Const<3>), f32, _, _> = a.trace().broadcast_to((5, Const));
+        assert_eq!(b.shape(), &(5, Const));
+        assert_eq!(b.as_vec(), [1.0, 2.0, 3.0].repeat(5));
+        let g = b.sum().backward();
+        assert_eq!(g.get(&a).array(), [5.0; 3]);
+    }
 }
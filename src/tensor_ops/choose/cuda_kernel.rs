@@ -51,7 +51,7 @@ impl ChooseKernel<f32> for Cuda {
         );
         unsafe { fwd_fn.launch_async(cfg, params) }?;
         Ok(CudaArray {
-            data: Arc::new(storage),
+            data: Arc::new(storage.into()),
             shape,
             strides,
         })
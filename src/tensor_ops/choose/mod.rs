@@ -27,6 +27,20 @@ pub trait ChooseKernel<E: Dtype>: DeviceStorage {
 }
 
 /// Choose values from two tensors using a boolean mask. Equivalent to `torch.where` from pytorch.
+///
+/// The gradient of the output flows to `lhs` where the mask is true, and to `rhs` where it
+/// is false.
+///
+/// The mask must have the same shape as `lhs` and `rhs`, but can be broadcast to it first:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let cond: Tensor<Rank1<3>, bool, _> = dev.tensor([true, false, true]);
+/// let a: Tensor<Rank2<2, 3>, f32, _> = dev.ones();
+/// let b: Tensor<Rank2<2, 3>, f32, _> = dev.zeros();
+/// let r = cond.broadcast().choose(a, b);
+/// assert_eq!(r.array(), [[1.0, 0.0, 1.0]; 2]);
+/// ```
 pub trait ChooseFrom<Lhs, Rhs>: HasErr {
     type Output;
 
@@ -120,6 +134,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_choose_broadcasted_cond_backward() {
+        let dev: TestDevice = Default::default();
+        let cond: Tensor<Rank1<4>, bool, _> = dev.tensor([true, false, false, true]);
+        let a: Tensor<Rank2<2, 4>, f32, _> = dev.sample_normal();
+        let b: Tensor<Rank2<2, 4>, f32, _> = dev.sample_normal();
+        let r = cond.broadcast().choose(a.trace(), b.trace());
+
+        let a_array = a.array();
+        let b_array = b.array();
+        assert_eq!(
+            r.array(),
+            [0, 1].map(|i| [a_array[i][0], b_array[i][1], b_array[i][2], a_array[i][3]])
+        );
+        let g = r.sum().backward();
+        assert_eq!(g.get(&a).array(), [[1.0, 0.0, 0.0, 1.0]; 2]);
+        assert_eq!(g.get(&b).array(), [[0.0, 1.0, 1.0, 0.0]; 2]);
+    }
+
     #[test]
     fn test_choose_2d_backward() {
         let dev: TestDevice = Default::default();
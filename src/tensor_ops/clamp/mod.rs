@@ -6,6 +6,8 @@ mod cuda_kernel;
 use super::ops::{try_unary_op, UnaryKernel};
 use crate::{gradients::Tape, shapes::*, tensor::Tensor};
 
+use num_traits::Float;
+
 #[repr(C)]
 #[derive(Debug, Clone, Copy)]
 pub struct ClampKernelOp<E> {
@@ -15,6 +17,10 @@ pub struct ClampKernelOp<E> {
 
 /// Clamp all elements between the provided min and max values.
 ///
+/// Gradients only flow through elements that were within `[min, max]`, so saturated
+/// elements get a gradient of zero. See [Tensor::clamp_min] and [Tensor::clamp_max]
+/// to only clamp one side.
+///
 /// Example:
 /// ```rust
 /// # use dfdx::prelude::*;
@@ -42,6 +48,42 @@ impl<S: Shape, E: Dtype, D: UnaryKernel<ClampKernelOp<E>, E>, T: Tape<D>> Tensor
     }
 }
 
+impl<S: Shape, E: Dtype + Float, D: UnaryKernel<ClampKernelOp<E>, E>, T: Tape<D>>
+    Tensor<S, E, D, T>
+{
+    /// Clamps all elements to be at least `min`.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([-1.0, 0.0, 1.0]);
+    /// assert_eq!(t.clamp_min(0.0).array(), [0.0, 0.0, 1.0]);
+    /// ```
+    pub fn clamp_min(self, min: E) -> Self {
+        self.try_clamp_min(min).unwrap()
+    }
+    /// See [Tensor::clamp_min]
+    pub fn try_clamp_min(self, min: E) -> Result<Self, D::Err> {
+        self.try_clamp(min, E::infinity())
+    }
+
+    /// Clamps all elements to be at most `max`.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([-1.0, 0.0, 1.0]);
+    /// assert_eq!(t.clamp_max(0.0).array(), [-1.0, 0.0, 0.0]);
+    /// ```
+    pub fn clamp_max(self, max: E) -> Self {
+        self.try_clamp_max(max).unwrap()
+    }
+    /// See [Tensor::clamp_max]
+    pub fn try_clamp_max(self, max: E) -> Result<Self, D::Err> {
+        self.try_clamp(E::neg_infinity(), max)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{tensor::*, tensor_ops::*, tests::*};
@@ -58,4 +100,30 @@ mod tests {
             &[[0.06131324, 0.16666667, 0.45304698], [0.0; 3]],
         );
     }
+
+    #[test]
+    fn test_clamp_saturated_grads() {
+        let dev: TestDevice = Default::default();
+        let t = dev.tensor([-2.0, 0.5, 3.0]);
+        let r = t.trace().clamp(-1.0, 1.0);
+        assert_eq!(r.array(), [-1.0, 0.5, 1.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_clamp_min_max() {
+        let dev: TestDevice = Default::default();
+        let t = dev.tensor([-2.0, 0.5, 3.0]);
+
+        let r = t.trace().clamp_min(0.0);
+        assert_eq!(r.array(), [0.0, 0.5, 3.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [0.0, 1.0, 1.0]);
+
+        let r = t.trace().clamp_max(0.0);
+        assert_eq!(r.array(), [-2.0, 0.0, 0.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [1.0, 0.0, 0.0]);
+    }
 }
@@ -0,0 +1,43 @@
+#include "cuda_utils.cuh"
+
+extern "C" __global__ void concat_forward(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const float *inp,
+    const size_t *inp_strides,
+    const size_t out_offset,
+    float *out,
+    const size_t *out_strides
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int inp_i = get_strided_index(i, num_dims, dims, inp_strides);
+    unsigned int out_i = out_offset + get_strided_index(i, num_dims, dims, out_strides);
+
+    out[out_i] = inp[inp_i];
+}
+
+extern "C" __global__ void concat_backward(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    float *grad_inp,
+    const size_t *inp_strides,
+    const size_t out_offset,
+    const float *grad_out,
+    const size_t *out_strides
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int inp_i = get_strided_index(i, num_dims, dims, inp_strides);
+    unsigned int out_i = out_offset + get_strided_index(i, num_dims, dims, out_strides);
+
+    grad_inp[inp_i] += grad_out[out_i];
+}
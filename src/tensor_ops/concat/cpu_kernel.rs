@@ -0,0 +1,69 @@
+use crate::shapes::{Axes, Dtype, ResizeDimTo, Shape};
+use crate::tensor::cpu::{Cpu, LendingIterator, StridedArray};
+
+impl<E: Dtype> super::ConcatKernel<E> for Cpu {
+    fn forward<A, B, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        dst: Dst,
+        lhs: &Self::Storage<A, E>,
+        rhs: &Self::Storage<B, E>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>
+    where
+        A: ResizeDimTo<Dst, Ax>,
+        B: ResizeDimTo<Dst, Ax>,
+    {
+        let ax = Ax::as_array()[0] as usize;
+        let split = lhs.shape.concrete()[ax];
+        let mut out = StridedArray::new(dst)?;
+        let mut out_iter = out.iter_mut_with_index();
+        while let Some((o, i_out)) = out_iter.next() {
+            if i_out[ax] < split {
+                let mut i_lhs: A::Concrete = Default::default();
+                for j in 0..A::NUM_DIMS {
+                    i_lhs[j] = i_out[j];
+                }
+                *o = lhs[i_lhs];
+            } else {
+                let mut i_rhs: B::Concrete = Default::default();
+                for j in 0..B::NUM_DIMS {
+                    i_rhs[j] = i_out[j];
+                }
+                i_rhs[ax] -= split;
+                *o = rhs[i_rhs];
+            }
+        }
+        Ok(out)
+    }
+
+    fn backward<A, B, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        grad_lhs: &mut Self::Storage<A, E>,
+        grad_rhs: &mut Self::Storage<B, E>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>
+    where
+        A: ResizeDimTo<Dst, Ax>,
+        B: ResizeDimTo<Dst, Ax>,
+    {
+        let ax = Ax::as_array()[0] as usize;
+        let split = grad_lhs.shape.concrete()[ax];
+        let mut out_iter = grad_out.iter_with_index();
+        while let Some((o, i_out)) = out_iter.next() {
+            if i_out[ax] < split {
+                let mut i_lhs: A::Concrete = Default::default();
+                for j in 0..A::NUM_DIMS {
+                    i_lhs[j] = i_out[j];
+                }
+                grad_lhs[i_lhs] += *o;
+            } else {
+                let mut i_rhs: B::Concrete = Default::default();
+                for j in 0..B::NUM_DIMS {
+                    i_rhs[j] = i_out[j];
+                }
+                i_rhs[ax] -= split;
+                grad_rhs[i_rhs] += *o;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{
+    shapes::{Axes, ResizeDimTo, Shape},
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{CudaSlice, LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/concat.ptx"));
+const MODULE_NAME: &str = "concat";
+const FWD_FN_NAME: &str = "concat_forward";
+const BWD_FN_NAME: &str = "concat_backward";
+const ALL_FN_NAMES: [&str; 2] = [FWD_FN_NAME, BWD_FN_NAME];
+
+impl super::ConcatKernel<f32> for Cuda {
+    fn forward<A, B, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        dst: Dst,
+        lhs: &Self::Storage<A, f32>,
+        rhs: &Self::Storage<B, f32>,
+    ) -> Result<Self::Storage<Dst, f32>, Self::Err>
+    where
+        A: ResizeDimTo<Dst, Ax>,
+        B: ResizeDimTo<Dst, Ax>,
+    {
+        if !self.dev.has_func(MODULE_NAME, FWD_FN_NAME) {
+            self.dev
+                .load_ptx(PTX_SRC.into(), MODULE_NAME, &ALL_FN_NAMES)?;
+        }
+
+        let ax = Ax::as_array()[0] as usize;
+        let strides = dst.strides();
+        let mut storage = self.dev.alloc_zeros_async::<f32>(dst.num_elements())?;
+        let out_strides: CudaSlice<usize> = self.dev.take_async(strides.into())?;
+
+        // copy lhs into the start of the axis
+        let numel = lhs.shape.num_elements();
+        let lhs_dims: CudaSlice<usize> = self.dev.take_async(lhs.shape.concrete().into())?;
+        let lhs_strides: CudaSlice<usize> = self.dev.take_async(lhs.strides.into())?;
+        let fwd_fn = self.dev.get_func(MODULE_NAME, FWD_FN_NAME).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,             // const size_t numel,
+            Dst::NUM_DIMS,     // const size_t num_dims,
+            &lhs_dims,         // const size_t *dims,
+            lhs.data.as_ref(), // const float *inp,
+            &lhs_strides,      // const size_t *inp_strides,
+            0usize,            // const size_t out_offset,
+            &mut storage,      // float *out,
+            &out_strides,      // const size_t *out_strides
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+
+        // and rhs right after it
+        let numel = rhs.shape.num_elements();
+        let rhs_dims: CudaSlice<usize> = self.dev.take_async(rhs.shape.concrete().into())?;
+        let rhs_strides: CudaSlice<usize> = self.dev.take_async(rhs.strides.into())?;
+        let fwd_fn = self.dev.get_func(MODULE_NAME, FWD_FN_NAME).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,                                  // const size_t numel,
+            Dst::NUM_DIMS,                          // const size_t num_dims,
+            &rhs_dims,                              // const size_t *dims,
+            rhs.data.as_ref(),                      // const float *inp,
+            &rhs_strides,                           // const size_t *inp_strides,
+            lhs.shape.concrete()[ax] * strides[ax], // const size_t out_offset,
+            &mut storage,                           // float *out,
+            &out_strides,                           // const size_t *out_strides
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+
+        Ok(CudaArray {
+            data: Arc::new(storage.into()),
+            shape: dst,
+            strides,
+        })
+    }
+
+    fn backward<A, B, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        grad_lhs: &mut Self::Storage<A, f32>,
+        grad_rhs: &mut Self::Storage<B, f32>,
+        grad_out: &Self::Storage<Dst, f32>,
+    ) -> Result<(), Self::Err>
+    where
+        A: ResizeDimTo<Dst, Ax>,
+        B: ResizeDimTo<Dst, Ax>,
+    {
+        let ax = Ax::as_array()[0] as usize;
+        let out_strides: CudaSlice<usize> = self.dev.take_async(grad_out.strides.into())?;
+
+        let numel = grad_lhs.shape.num_elements();
+        let lhs_dims: CudaSlice<usize> = self.dev.take_async(grad_lhs.shape.concrete().into())?;
+        let lhs_strides: CudaSlice<usize> = self.dev.take_async(grad_lhs.strides.into())?;
+        let bwd_fn = self.dev.get_func(MODULE_NAME, BWD_FN_NAME).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,                             // const size_t numel,
+            Dst::NUM_DIMS,                     // const size_t num_dims,
+            &lhs_dims,                         // const size_t *dims,
+            Arc::make_mut(&mut grad_lhs.data), // float *grad_inp,
+            &lhs_strides,                      // const size_t *inp_strides,
+            0usize,                            // const size_t out_offset,
+            grad_out.data.as_ref(),            // const float *grad_out,
+            &out_strides,                      // const size_t *out_strides
+        );
+        unsafe { bwd_fn.launch_async(cfg, params) }?;
+
+        let numel = grad_rhs.shape.num_elements();
+        let rhs_dims: CudaSlice<usize> = self.dev.take_async(grad_rhs.shape.concrete().into())?;
+        let rhs_strides: CudaSlice<usize> = self.dev.take_async(grad_rhs.strides.into())?;
+        let bwd_fn = self.dev.get_func(MODULE_NAME, BWD_FN_NAME).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,                                                // const size_t numel,
+            Dst::NUM_DIMS,                                        // const size_t num_dims,
+            &rhs_dims,                                            // const size_t *dims,
+            Arc::make_mut(&mut grad_rhs.data),                    // float *grad_inp,
+            &rhs_strides,                                         // const size_t *inp_strides,
+            grad_lhs.shape.concrete()[ax] * grad_out.strides[ax], // const size_t out_offset,
+            grad_out.data.as_ref(),                               // const float *grad_out,
+            &out_strides,                                         // const size_t *out_strides
+        );
+        unsafe { bwd_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+}
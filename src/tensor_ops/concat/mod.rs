@@ -0,0 +1,183 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{
+    gradients::{Merge, Tape},
+    shapes::*,
+    tensor::*,
+};
+
+pub trait ConcatKernel<E: Dtype>: DeviceStorage {
+    fn forward<A, B, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        dst: Dst,
+        lhs: &Self::Storage<A, E>,
+        rhs: &Self::Storage<B, E>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>
+    where
+        A: ResizeDimTo<Dst, Ax>,
+        B: ResizeDimTo<Dst, Ax>;
+    fn backward<A, B, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        grad_lhs: &mut Self::Storage<A, E>,
+        grad_rhs: &mut Self::Storage<B, E>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>
+    where
+        A: ResizeDimTo<Dst, Ax>,
+        B: ResizeDimTo<Dst, Ax>;
+}
+
+/// Concatenate two tensors along a single axis. Equivalent to `torch.cat` from pytorch.
+pub trait TryConcat<Rhs: HasShape>: HasErr + HasShape {
+    /// Concatenates `self` and `rhs` along axis `Ax`. All other dimensions must be the same,
+    /// and the size of axis `Ax` in `Dst` is the sum of the two sizes.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank2<2, 3>, f32, _> = dev.zeros();
+    /// let b: Tensor<Rank2<2, 4>, f32, _> = dev.zeros();
+    /// let _: Tensor<Rank2<2, 7>, f32, _> = a.clone().concat(b);
+    ///
+    /// // concatenating along the 0th axis
+    /// let _ = a.clone().concat::<Rank2<4, 3>, _>(a.clone());
+    ///
+    /// // runtime dimensions are added together, but need the axis to be specified
+    /// let c: Tensor<(usize, Const<3>), f32, _> = dev.zeros_like(&(5, Const));
+    /// let d: Tensor<(usize, Const<3>), f32, _> = a.concat::<_, Axis<0>>(c);
+    /// assert_eq!(d.shape(), &(7, Const));
+    /// ```
+    fn concat<Dst: Shape, Ax: Axes<Array = [isize; 1]>>(self, rhs: Rhs) -> Self::WithShape<Dst>
+    where
+        Self::Shape: ResizeDimTo<Dst, Ax>,
+        Rhs::Shape: ResizeDimTo<Dst, Ax>,
+    {
+        self.try_concat(rhs).unwrap()
+    }
+
+    /// Fallible version of [TryConcat::concat]
+    fn try_concat<Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        self,
+        rhs: Rhs,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ResizeDimTo<Dst, Ax>,
+        Rhs::Shape: ResizeDimTo<Dst, Ax>;
+}
+
+impl<A: Shape, B: Shape, E: Dtype, D: ConcatKernel<E>, T: Tape<D> + Merge<R>, R: Tape<D>>
+    TryConcat<Tensor<B, E, D, R>> for Tensor<A, E, D, T>
+{
+    fn try_concat<Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        self,
+        rhs: Tensor<B, E, D, R>,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        A: ResizeDimTo<Dst, Ax>,
+        B: ResizeDimTo<Dst, Ax>,
+    {
+        let ax = Ax::as_array()[0] as usize;
+        let (lhs_dims, rhs_dims) = (self.shape().concrete(), rhs.shape().concrete());
+        for i in 0..A::NUM_DIMS {
+            if i != ax {
+                assert_eq!(lhs_dims[i], rhs_dims[i]);
+            }
+        }
+        let dst: Dst = self.shape().resize(lhs_dims[ax] + rhs_dims[ax]);
+
+        let (lhs, ltape) = self.split_tape();
+        let (rhs, rtape) = rhs.split_tape();
+        let mut tape = ltape.merge(rtape);
+        let storage = lhs.device.forward(dst, &lhs.storage, &rhs.storage)?;
+        let out = lhs.device.upgrade(storage);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&lhs)?;
+        tape.try_alloc_grad(&rhs)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_lhs, grad_rhs, grad_out) = grads.muts_and_ref(&lhs, &rhs, &phantom_out);
+            lhs.device.backward(grad_lhs, grad_rhs, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor_ops::*;
+    use crate::tests::TestDevice;
+
+    #[test]
+    fn test_concat_axis_1_backward() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<2, 3>, f32, _> = dev.sample_normal();
+        let b: Tensor<Rank2<2, 4>, f32, _> = dev.sample_normal();
+        let (a_array, b_array) = (a.array(), b.array());
+        let c: Tensor<Rank2<2, 7>, f32, _, _> = a.trace().concat(b.trace());
+        let c_array = c.array();
+        for i in 0..2 {
+            assert_eq!(c_array[i][..3], a_array[i]);
+            assert_eq!(c_array[i][3..], b_array[i]);
+        }
+
+        let g = (c * dev.tensor([[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]; 2]))
+            .sum()
+            .backward();
+        assert_eq!(g.get(&a).array(), [[1.0, 2.0, 3.0]; 2]);
+        assert_eq!(g.get(&b).array(), [[4.0, 5.0, 6.0, 7.0]; 2]);
+    }
+
+    #[test]
+    fn test_concat_axis_0_backward() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<1, 3>, f32, _> = dev.sample_normal();
+        let b: Tensor<Rank2<2, 3>, f32, _> = dev.sample_normal();
+        let (a_array, b_array) = (a.array(), b.array());
+        let c: Tensor<Rank2<3, 3>, f32, _, _> = a.trace().concat(b.trace());
+        assert_eq!(c.array(), [a_array[0], b_array[0], b_array[1]]);
+
+        let g = (c * dev.tensor([[1.0; 3], [2.0; 3], [3.0; 3]]))
+            .sum()
+            .backward();
+        assert_eq!(g.get(&a).array(), [[1.0; 3]]);
+        assert_eq!(g.get(&b).array(), [[2.0; 3], [3.0; 3]]);
+    }
+
+    #[test]
+    fn test_concat_runtime_dims() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let mut b: Tensor<(Const<2>, usize), f32, _> = dev.zeros_like(&(Const, 1));
+        b.copy_from(&[7.0, 8.0]);
+        let c: Tensor<(Const<2>, usize), f32, _> = a.concat::<_, Axis<1>>(b);
+        assert_eq!(c.shape(), &(Const, 4));
+        assert_eq!(c.as_vec(), [1.0, 2.0, 3.0, 7.0, 4.0, 5.0, 6.0, 8.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_concat_mismatched_dims() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<(usize, usize), f32, _> = dev.zeros_like(&(2, 3));
+        let b: Tensor<(usize, usize), f32, _> = dev.zeros_like(&(3, 1));
+        let _: Tensor<(usize, usize), f32, _> = a.concat::<_, Axis<1>>(b);
+    }
+
+    #[test]
+    fn test_split_then_concat_is_identity() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 6, 3>, f32, _> = dev.sample_normal();
+        let [a, b, c] = t.trace().split::<Rank3<2, 2, 3>, _, 3>();
+        let r: Tensor<Rank3<2, 6, 3>, f32, _, _> = a.concat::<Rank3<2, 4, 3>, _>(b).concat(c);
+        assert_eq!(r.array(), t.array());
+        let g = r.exp().sum().backward();
+        assert_eq!(
+            g.get(&t).array(),
+            t.array().map(|a| a.map(|b| b.map(f32::exp)))
+        );
+    }
+}
@@ -472,4 +472,32 @@ mod tests {
             [[-0.19717735, -0.19717735, -0.19717735],[-0.19717735, 1.3412137, 2.9476144],[-0.19717735, 4.247249, -2.1779637]],
         ]);
     }
+
+    #[test]
+    fn test_conv2d_3x3_s1p1_matches_finite_differences() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<1, 3, 3>, f32, _> =
+            dev.tensor([[[0.1, -0.4, 0.7], [1.2, -0.3, 0.5], [-0.8, 0.2, 0.6]]]);
+        let w: Tensor<Rank4<2, 1, 3, 3>, f32, _> = dev.sample_normal();
+        let loss = |x: [[[f32; 3]; 3]; 1]| {
+            let y: Tensor<Rank3<2, 3, 3>, f32, _> = dev.tensor(x).conv2d::<1, 1>(w.clone());
+            y.square().sum::<Rank0, _>().array()
+        };
+
+        // padding 1 keeps the spatial dims of a 3x3 kernel
+        let y: Tensor<Rank3<2, 3, 3>, f32, _, _> = x.trace().conv2d::<1, 1>(w.clone());
+        let g = y.square().sum().backward();
+        let grad = g.get(&x).array();
+        let x = x.array();
+        for i in 0..3 {
+            for j in 0..3 {
+                let mut xp = x;
+                xp[0][i][j] += 1e-1;
+                let mut xm = x;
+                xm[0][i][j] -= 1e-1;
+                let fd = (loss(xp) - loss(xm)) / 2e-1;
+                assert_close_with_tolerance(&grad[0][i][j], &fd, 1e-3);
+            }
+        }
+    }
 }
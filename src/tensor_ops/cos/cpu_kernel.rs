@@ -1,12 +1,12 @@
 use crate::tensor_ops::cpu_kernels::UnaryDerivative;
 
-impl UnaryDerivative<f32> for super::CosKernelOp {
+impl<F: num_traits::Float> UnaryDerivative<F> for super::CosKernelOp {
     #[inline(always)]
-    fn f(&self, x: &f32) -> f32 {
+    fn f(&self, x: &F) -> F {
         x.cos()
     }
     #[inline(always)]
-    fn df(&self, x: &f32) -> f32 {
+    fn df(&self, x: &F) -> F {
         -x.sin()
     }
 }
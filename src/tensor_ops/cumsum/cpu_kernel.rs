@@ -0,0 +1,68 @@
+use crate::{
+    shapes::{Axes, Dtype, HasAxes, Shape},
+    tensor::cpu::{Cpu, LendingIterator, StridedArray},
+};
+
+/// Cumulative sum of a contiguous array along `ax`, in place.
+fn cumsum_contiguous<S: Shape, E: Dtype>(arr: &mut StridedArray<S, E>, ax: usize, reverse: bool) {
+    let size = arr.shape.concrete()[ax];
+    let stride = arr.strides[ax];
+    let line = stride * size;
+    let data = std::sync::Arc::make_mut(&mut arr.data);
+    if reverse {
+        for i in (0..data.len()).rev() {
+            if i % line < line - stride {
+                data[i] = data[i] + data[i + stride];
+            }
+        }
+    } else {
+        for i in 0..data.len() {
+            if i % line >= stride {
+                data[i] = data[i] + data[i - stride];
+            }
+        }
+    }
+}
+
+impl<E: Dtype> super::CumSumKernel<E> for Cpu {
+    fn forward<S, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        inp: &Self::Storage<S, E>,
+        reverse: bool,
+    ) -> Result<Self::Storage<S, E>, Self::Err>
+    where
+        S: Shape + HasAxes<Ax>,
+    {
+        let mut out = StridedArray::new(inp.shape)?;
+        let mut out_iter = out.iter_mut_with_index();
+        while let Some((o, i)) = out_iter.next() {
+            *o = inp[i];
+        }
+        cumsum_contiguous(&mut out, Ax::as_array()[0] as usize, reverse);
+        Ok(out)
+    }
+
+    fn backward<S, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+        reverse: bool,
+    ) -> Result<(), Self::Err>
+    where
+        S: Shape + HasAxes<Ax>,
+    {
+        // the gradient of a cumsum is a cumsum in the opposite direction
+        let mut tmp = StridedArray::new(grad_out.shape)?;
+        let mut tmp_iter = tmp.iter_mut_with_index();
+        while let Some((t, i)) = tmp_iter.next() {
+            *t = grad_out[i];
+        }
+        cumsum_contiguous(&mut tmp, Ax::as_array()[0] as usize, !reverse);
+
+        let mut tmp_iter = tmp.iter_with_index();
+        while let Some((t, i)) = tmp_iter.next() {
+            grad_inp[i] += *t;
+        }
+        Ok(())
+    }
+}
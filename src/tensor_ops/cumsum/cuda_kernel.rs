@@ -0,0 +1,92 @@
+use crate::{
+    shapes::{Axes, HasAxes, Shape},
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{CudaSlice, LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/cumsum.ptx"));
+const MODULE_NAME: &str = "cumsum";
+const FWD_FN_NAME: &str = "cumsum_forward";
+const BWD_FN_NAME: &str = "cumsum_backward";
+const ALL_FN_NAMES: [&str; 2] = [FWD_FN_NAME, BWD_FN_NAME];
+
+impl super::CumSumKernel<f32> for Cuda {
+    fn forward<S, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        inp: &Self::Storage<S, f32>,
+        reverse: bool,
+    ) -> Result<Self::Storage<S, f32>, Self::Err>
+    where
+        S: Shape + HasAxes<Ax>,
+    {
+        if !self.dev.has_func(MODULE_NAME, FWD_FN_NAME) {
+            self.dev
+                .load_ptx(PTX_SRC.into(), MODULE_NAME, &ALL_FN_NAMES)?;
+        }
+
+        let ax = Ax::as_array()[0] as usize;
+        let shape = inp.shape;
+        let strides = shape.strides();
+        let numel = shape.num_elements();
+        let mut storage = self.dev.alloc_zeros_async::<f32>(numel)?;
+
+        let dims: CudaSlice<usize> = self.dev.take_async(shape.concrete().into())?;
+        let inp_strides: CudaSlice<usize> = self.dev.take_async(inp.strides.into())?;
+        let out_strides: CudaSlice<usize> = self.dev.take_async(strides.into())?;
+
+        let num_lines = numel / shape.concrete()[ax];
+        let fwd_fn = self.dev.get_func(MODULE_NAME, FWD_FN_NAME).unwrap();
+        let cfg = LaunchConfig::for_num_elems(num_lines as u32);
+        let params = (
+            num_lines,         // const size_t num_lines,
+            S::NUM_DIMS,       // const size_t num_dims,
+            ax,                // const size_t ax,
+            reverse as usize,  // const size_t reverse,
+            &dims,             // const size_t *dims,
+            inp.data.as_ref(), // const float *inp,
+            &inp_strides,      // const size_t *inp_strides,
+            &mut storage,      // float *out,
+            &out_strides,      // const size_t *out_strides
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+        Ok(CudaArray {
+            data: Arc::new(storage.into()),
+            shape,
+            strides,
+        })
+    }
+
+    fn backward<S, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        grad_inp: &mut Self::Storage<S, f32>,
+        grad_out: &Self::Storage<S, f32>,
+        reverse: bool,
+    ) -> Result<(), Self::Err>
+    where
+        S: Shape + HasAxes<Ax>,
+    {
+        let ax = Ax::as_array()[0] as usize;
+        let shape = grad_out.shape;
+        let dims: CudaSlice<usize> = self.dev.take_async(shape.concrete().into())?;
+        let inp_strides: CudaSlice<usize> = self.dev.take_async(grad_inp.strides.into())?;
+        let out_strides: CudaSlice<usize> = self.dev.take_async(grad_out.strides.into())?;
+
+        let num_lines = shape.num_elements() / shape.concrete()[ax];
+        let bwd_fn = self.dev.get_func(MODULE_NAME, BWD_FN_NAME).unwrap();
+        let cfg = LaunchConfig::for_num_elems(num_lines as u32);
+        let params = (
+            num_lines,                         // const size_t num_lines,
+            S::NUM_DIMS,                       // const size_t num_dims,
+            ax,                                // const size_t ax,
+            reverse as usize,                  // const size_t reverse,
+            &dims,                             // const size_t *dims,
+            Arc::make_mut(&mut grad_inp.data), // float *grad_inp,
+            &inp_strides,                      // const size_t *inp_strides,
+            grad_out.data.as_ref(),            // const float *grad_out,
+            &out_strides,                      // const size_t *out_strides
+        );
+        unsafe { bwd_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+}
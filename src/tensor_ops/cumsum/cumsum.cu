@@ -0,0 +1,82 @@
+// Computes the offsets of the first element of line `i` along `ax`, where
+// a line is every element with the same index in all other dimensions.
+__device__ void line_offsets(
+    unsigned int i,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    unsigned int *inp_i,
+    unsigned int *out_i
+) {
+    *inp_i = 0;
+    *out_i = 0;
+    for (int d = num_dims - 1; d >= 0; d--) {
+        if (d == ax) {
+            continue;
+        }
+        unsigned int i_d = i % dims[d];
+        i /= dims[d];
+        *inp_i += i_d * inp_strides[d];
+        *out_i += i_d * out_strides[d];
+    }
+}
+
+// One thread per line along `ax`.
+extern "C" __global__ void cumsum_forward(
+    const size_t num_lines,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t reverse,
+    const size_t *dims,
+    const float *inp,
+    const size_t *inp_strides,
+    float *out,
+    const size_t *out_strides
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= num_lines) {
+        return;
+    }
+
+    unsigned int inp_i, out_i;
+    line_offsets(i, num_dims, ax, dims, inp_strides, out_strides, &inp_i, &out_i);
+
+    float acc = 0.0;
+    for (unsigned int m = 0; m < dims[ax]; m++) {
+        unsigned int j = reverse ? dims[ax] - 1 - m : m;
+        acc += inp[inp_i + j * inp_strides[ax]];
+        out[out_i + j * out_strides[ax]] = acc;
+    }
+}
+
+// One thread per line along `ax`. The gradient of a cumsum is a cumsum
+// in the opposite direction.
+extern "C" __global__ void cumsum_backward(
+    const size_t num_lines,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t reverse,
+    const size_t *dims,
+    float *grad_inp,
+    const size_t *inp_strides,
+    const float *grad_out,
+    const size_t *out_strides
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= num_lines) {
+        return;
+    }
+
+    unsigned int inp_i, out_i;
+    line_offsets(i, num_dims, ax, dims, inp_strides, out_strides, &inp_i, &out_i);
+
+    float acc = 0.0;
+    for (unsigned int m = 0; m < dims[ax]; m++) {
+        unsigned int j = reverse ? m : dims[ax] - 1 - m;
+        acc += grad_out[out_i + j * out_strides[ax]];
+        // inp may be broadcasted, so multiple lines can map to the same gradient
+        atomicAdd(grad_inp + inp_i + j * inp_strides[ax], acc);
+    }
+}
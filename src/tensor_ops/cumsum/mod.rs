@@ -0,0 +1,163 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+pub trait CumSumKernel<E: Dtype>: DeviceStorage {
+    fn forward<S, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        inp: &Self::Storage<S, E>,
+        reverse: bool,
+    ) -> Result<Self::Storage<S, E>, Self::Err>
+    where
+        S: Shape + HasAxes<Ax>;
+    fn backward<S, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+        reverse: bool,
+    ) -> Result<(), Self::Err>
+    where
+        S: Shape + HasAxes<Ax>;
+}
+
+/// Cumulative sum along a single axis.
+pub trait CumSum: HasErr + HasShape {
+    /// Cumulative sum along axis `Ax`, starting from index 0.
+    /// **Pytorch equivalent**: `t.cumsum(Ax)`
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// let r = t.clone().cumsum::<Axis<1>>();
+    /// assert_eq!(r.array(), [[1.0, 3.0, 6.0], [4.0, 9.0, 15.0]]);
+    ///
+    /// let r = t.cumsum::<Axis<0>>();
+    /// assert_eq!(r.array(), [[1.0, 2.0, 3.0], [5.0, 7.0, 9.0]]);
+    /// ```
+    fn cumsum<Ax: Axes<Array = [isize; 1]>>(self) -> Self
+    where
+        Self::Shape: HasAxes<Ax>,
+    {
+        self.try_cumsum::<Ax>().unwrap()
+    }
+    /// Fallible version of [CumSum::cumsum]
+    fn try_cumsum<Ax: Axes<Array = [isize; 1]>>(self) -> Result<Self, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax>;
+
+    /// Cumulative sum along axis `Ax`, starting from the last index.
+    /// **Pytorch equivalent**: `t.flip(Ax).cumsum(Ax).flip(Ax)`
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank1<4>, f32, _> = dev.tensor([1.0, 2.0, 3.0, 4.0]);
+    /// let r = t.cumsum_reverse::<Axis<0>>();
+    /// assert_eq!(r.array(), [10.0, 9.0, 7.0, 4.0]);
+    /// ```
+    fn cumsum_reverse<Ax: Axes<Array = [isize; 1]>>(self) -> Self
+    where
+        Self::Shape: HasAxes<Ax>,
+    {
+        self.try_cumsum_reverse::<Ax>().unwrap()
+    }
+    /// Fallible version of [CumSum::cumsum_reverse]
+    fn try_cumsum_reverse<Ax: Axes<Array = [isize; 1]>>(self) -> Result<Self, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: CumSumKernel<E>, T: Tape<D>> Tensor<S, E, D, T> {
+    fn try_cumsum_impl<Ax: Axes<Array = [isize; 1]>>(self, reverse: bool) -> Result<Self, D::Err>
+    where
+        S: HasAxes<Ax>,
+    {
+        let (inp, mut tape) = self.split_tape();
+        let storage = inp.device.forward::<S, Ax>(&inp.storage, reverse)?;
+        let out = inp.device.upgrade(storage);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward::<S, Ax>(grad_inp, grad_out, reverse)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+impl<S: Shape, E: Dtype, D: CumSumKernel<E>, T: Tape<D>> CumSum for Tensor<S, E, D, T> {
+    fn try_cumsum<Ax: Axes<Array = [isize; 1]>>(self) -> Result<Self, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax>,
+    {
+        self.try_cumsum_impl::<Ax>(false)
+    }
+
+    fn try_cumsum_reverse<Ax: Axes<Array = [isize; 1]>>(self) -> Result<Self, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax>,
+    {
+        self.try_cumsum_impl::<Ax>(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor_ops::*;
+    use crate::tests::TestDevice;
+
+    #[test]
+    fn test_cumsum_1d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<4>, f32, _> = dev.tensor([1.0, 2.0, 3.0, 4.0]);
+        let r = t.trace().cumsum::<Axis<0>>();
+        assert_eq!(r.array(), [1.0, 3.0, 6.0, 10.0]);
+
+        // d(sum(r))/dt_i = sum_j dr_j/dt_i = number of j >= i
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [4.0, 3.0, 2.0, 1.0]);
+    }
+
+    #[test]
+    fn test_cumsum_reverse_1d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<4>, f32, _> = dev.tensor([1.0, 2.0, 3.0, 4.0]);
+        let r = t.trace().cumsum_reverse::<Axis<0>>();
+        assert_eq!(r.array(), [10.0, 9.0, 7.0, 4.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [1.0, 2.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    fn test_cumsum_2d_weighted_backward() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let w: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 10.0, 100.0], [-1.0, 0.0, 1.0]]);
+
+        let r = t.trace().cumsum::<Axis<1>>();
+        assert_eq!(r.array(), [[1.0, 3.0, 6.0], [4.0, 9.0, 15.0]]);
+        let g = (r * w.clone()).sum().backward();
+        assert_eq!(g.get(&t).array(), [[111.0, 110.0, 100.0], [0.0, 1.0, 1.0]]);
+
+        let r = t.trace().cumsum::<Axis<0>>();
+        assert_eq!(r.array(), [[1.0, 2.0, 3.0], [5.0, 7.0, 9.0]]);
+        let g = (r * w).sum().backward();
+        assert_eq!(g.get(&t).array(), [[0.0, 10.0, 101.0], [-1.0, 0.0, 1.0]]);
+    }
+
+    #[test]
+    fn test_cumsum_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<3>, f32, _> = dev.tensor([1.0, 2.0, 3.0]);
+        let r = t.trace().broadcast::<Rank2<2, 3>, _>().cumsum::<Axis<1>>();
+        assert_eq!(r.array(), [[1.0, 3.0, 6.0]; 2]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [6.0, 4.0, 2.0]);
+    }
+}
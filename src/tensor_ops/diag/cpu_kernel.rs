@@ -0,0 +1,52 @@
+use crate::{
+    shapes::{Dim, Dtype},
+    tensor::cpu::{Cpu, StridedArray},
+};
+
+impl<E: Dtype> super::DiagKernel<E> for Cpu {
+    fn diagonal_fwd<N: Dim>(
+        &self,
+        inp: &Self::Storage<(N, N), E>,
+    ) -> Result<Self::Storage<(N,), E>, Self::Err> {
+        let n = inp.shape.0;
+        let mut out = StridedArray::new((n,))?;
+        for i in 0..n.size() {
+            out[[i]] = inp[[i, i]];
+        }
+        Ok(out)
+    }
+
+    fn diagonal_bwd<N: Dim>(
+        &self,
+        grad_inp: &mut Self::Storage<(N, N), E>,
+        grad_out: &Self::Storage<(N,), E>,
+    ) -> Result<(), Self::Err> {
+        for i in 0..grad_out.shape.0.size() {
+            grad_inp[[i, i]] += grad_out[[i]];
+        }
+        Ok(())
+    }
+
+    fn diag_fwd<N: Dim>(
+        &self,
+        inp: &Self::Storage<(N,), E>,
+    ) -> Result<Self::Storage<(N, N), E>, Self::Err> {
+        let n = inp.shape.0;
+        let mut out = StridedArray::new((n, n))?;
+        for i in 0..n.size() {
+            out[[i, i]] = inp[[i]];
+        }
+        Ok(out)
+    }
+
+    fn diag_bwd<N: Dim>(
+        &self,
+        grad_inp: &mut Self::Storage<(N,), E>,
+        grad_out: &Self::Storage<(N, N), E>,
+    ) -> Result<(), Self::Err> {
+        for i in 0..grad_inp.shape.0.size() {
+            grad_inp[[i]] += grad_out[[i, i]];
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,129 @@
+use crate::{
+    shapes::{Dim, Shape},
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/diag.ptx"));
+const MODULE_NAME: &str = "diag";
+const DIAGONAL_FWD_FN_NAME: &str = "diagonal_forward";
+const DIAGONAL_BWD_FN_NAME: &str = "diagonal_backward";
+const DIAG_FWD_FN_NAME: &str = "diag_forward";
+const DIAG_BWD_FN_NAME: &str = "diag_backward";
+const ALL_FN_NAMES: [&str; 4] = [
+    DIAGONAL_FWD_FN_NAME,
+    DIAGONAL_BWD_FN_NAME,
+    DIAG_FWD_FN_NAME,
+    DIAG_BWD_FN_NAME,
+];
+
+impl Cuda {
+    fn load_diag_module(&self) -> Result<(), <Self as crate::tensor::DeviceStorage>::Err> {
+        if !self.dev.has_func(MODULE_NAME, DIAGONAL_FWD_FN_NAME) {
+            self.dev
+                .load_ptx(PTX_SRC.into(), MODULE_NAME, &ALL_FN_NAMES)?;
+        }
+        Ok(())
+    }
+}
+
+impl super::DiagKernel<f32> for Cuda {
+    fn diagonal_fwd<N: Dim>(
+        &self,
+        inp: &Self::Storage<(N, N), f32>,
+    ) -> Result<Self::Storage<(N,), f32>, Self::Err> {
+        self.load_diag_module()?;
+        let shape = (inp.shape.0,);
+        let strides = shape.strides();
+        let n = shape.num_elements();
+        let mut storage = self.dev.alloc_zeros_async::<f32>(n)?;
+
+        let fwd_fn = self
+            .dev
+            .get_func(MODULE_NAME, DIAGONAL_FWD_FN_NAME)
+            .unwrap();
+        let cfg = LaunchConfig::for_num_elems(n as u32);
+        let params = (
+            n,                               // const size_t n,
+            inp.data.as_ref(),               // const float *inp,
+            inp.strides[0] + inp.strides[1], // const size_t inp_stride,
+            &mut storage,                    // float *out
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+        Ok(CudaArray {
+            data: Arc::new(storage.into()),
+            shape,
+            strides,
+        })
+    }
+
+    fn diagonal_bwd<N: Dim>(
+        &self,
+        grad_inp: &mut Self::Storage<(N, N), f32>,
+        grad_out: &Self::Storage<(N,), f32>,
+    ) -> Result<(), Self::Err> {
+        let n = grad_out.shape.num_elements();
+        let inp_stride = grad_inp.strides[0] + grad_inp.strides[1];
+        let bwd_fn = self
+            .dev
+            .get_func(MODULE_NAME, DIAGONAL_BWD_FN_NAME)
+            .unwrap();
+        let cfg = LaunchConfig::for_num_elems(n as u32);
+        let params = (
+            n,                                 // const size_t n,
+            Arc::make_mut(&mut grad_inp.data), // float *grad_inp,
+            inp_stride,                        // const size_t inp_stride,
+            grad_out.data.as_ref(),            // const float *grad_out,
+            grad_out.strides[0],               // const size_t out_stride
+        );
+        unsafe { bwd_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+
+    fn diag_fwd<N: Dim>(
+        &self,
+        inp: &Self::Storage<(N,), f32>,
+    ) -> Result<Self::Storage<(N, N), f32>, Self::Err> {
+        self.load_diag_module()?;
+        let shape = (inp.shape.0, inp.shape.0);
+        let strides = shape.strides();
+        let n = inp.shape.num_elements();
+        let mut storage = self.dev.alloc_zeros_async::<f32>(shape.num_elements())?;
+
+        let fwd_fn = self.dev.get_func(MODULE_NAME, DIAG_FWD_FN_NAME).unwrap();
+        let cfg = LaunchConfig::for_num_elems(n as u32);
+        let params = (
+            n,                 // const size_t n,
+            inp.data.as_ref(), // const float *inp,
+            inp.strides[0],    // const size_t inp_stride,
+            &mut storage,      // float *out
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+        Ok(CudaArray {
+            data: Arc::new(storage.into()),
+            shape,
+            strides,
+        })
+    }
+
+    fn diag_bwd<N: Dim>(
+        &self,
+        grad_inp: &mut Self::Storage<(N,), f32>,
+        grad_out: &Self::Storage<(N, N), f32>,
+    ) -> Result<(), Self::Err> {
+        let n = grad_inp.shape.num_elements();
+        let out_stride = grad_out.strides[0] + grad_out.strides[1];
+        let bwd_fn = self.dev.get_func(MODULE_NAME, DIAG_BWD_FN_NAME).unwrap();
+        let cfg = LaunchConfig::for_num_elems(n as u32);
+        let params = (
+            n,                                 // const size_t n,
+            Arc::make_mut(&mut grad_inp.data), // float *grad_inp,
+            grad_inp.strides[0],               // const size_t inp_stride,
+            grad_out.data.as_ref(),            // const float *grad_out,
+            out_stride,                        // const size_t out_stride
+        );
+        unsafe { bwd_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+#include "cuda_utils.cuh"
+
+// The diagonal of a matrix with strides `[s0, s1]` is a vector with stride `s0 + s1`,
+// so all of these kernels only need a single stride per tensor.
+
+extern "C" __global__ void diagonal_forward(
+    const size_t n,
+    const float *inp,
+    const size_t inp_stride,
+    float *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= n) {
+        return;
+    }
+
+    out[i] = inp[i * inp_stride];
+}
+
+extern "C" __global__ void diagonal_backward(
+    const size_t n,
+    float *grad_inp,
+    const size_t inp_stride,
+    const float *grad_out,
+    const size_t out_stride
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= n) {
+        return;
+    }
+
+    // inp may be broadcasted, so multiple elements can map to the same gradient
+    atomicAdd(grad_inp + i * inp_stride, grad_out[i * out_stride]);
+}
+
+extern "C" __global__ void diag_forward(
+    const size_t n,
+    const float *inp,
+    const size_t inp_stride,
+    float *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= n) {
+        return;
+    }
+
+    out[i * (n + 1)] = inp[i * inp_stride];
+}
+
+extern "C" __global__ void diag_backward(
+    const size_t n,
+    float *grad_inp,
+    const size_t inp_stride,
+    const float *grad_out,
+    const size_t out_stride
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= n) {
+        return;
+    }
+
+    atomicAdd(grad_inp + i * inp_stride, grad_out[i * out_stride]);
+}
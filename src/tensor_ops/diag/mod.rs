@@ -0,0 +1,155 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+pub trait DiagKernel<E: Dtype>: DeviceStorage {
+    /// Extracts the diagonal of a square matrix.
+    fn diagonal_fwd<N: Dim>(
+        &self,
+        inp: &Self::Storage<(N, N), E>,
+    ) -> Result<Self::Storage<(N,), E>, Self::Err>;
+    fn diagonal_bwd<N: Dim>(
+        &self,
+        grad_inp: &mut Self::Storage<(N, N), E>,
+        grad_out: &Self::Storage<(N,), E>,
+    ) -> Result<(), Self::Err>;
+
+    /// Builds a square matrix with `inp` on the diagonal, and zeros elsewhere.
+    fn diag_fwd<N: Dim>(
+        &self,
+        inp: &Self::Storage<(N,), E>,
+    ) -> Result<Self::Storage<(N, N), E>, Self::Err>;
+    fn diag_bwd<N: Dim>(
+        &self,
+        grad_inp: &mut Self::Storage<(N,), E>,
+        grad_out: &Self::Storage<(N, N), E>,
+    ) -> Result<(), Self::Err>;
+}
+
+impl<N: Dim, E: Dtype, D: DiagKernel<E>, T: Tape<D>> Tensor<(N, N), E, D, T> {
+    /// Extracts the diagonal of a square matrix. The gradient is placed on the
+    /// diagonal, and is zero elsewhere. This is the reverse of [Tensor::diag].
+    ///
+    /// **Pytorch equivalent**: `t.diagonal()`
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<2, 2>, f32, _> = dev.tensor([[1.0, 2.0], [3.0, 4.0]]);
+    /// let r = t.diagonal();
+    /// assert_eq!(r.array(), [1.0, 4.0]);
+    /// ```
+    ///
+    /// **Panics** if the matrix is not square.
+    pub fn diagonal(self) -> Tensor<(N,), E, D, T> {
+        self.try_diagonal().unwrap()
+    }
+
+    /// Fallible version of [Tensor::diagonal]
+    pub fn try_diagonal(self) -> Result<Tensor<(N,), E, D, T>, D::Err> {
+        let (rows, cols) = *self.shape();
+        assert_eq!(rows.size(), cols.size(), "diagonal needs a square matrix");
+        let (inp, mut tape) = self.split_tape();
+        let storage = inp.device.diagonal_fwd(&inp.storage)?;
+        let out = inp.device.upgrade(storage);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.diagonal_bwd(grad_inp, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+impl<N: Dim, E: Dtype, D: DiagKernel<E>, T: Tape<D>> Tensor<(N,), E, D, T> {
+    /// Builds a square matrix with this vector on the diagonal, and zeros elsewhere.
+    /// The gradient is the diagonal of the output's gradient. This is the reverse
+    /// of [Tensor::diagonal].
+    ///
+    /// **Pytorch equivalent**: `torch.diag(t)`
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank1<2>, f32, _> = dev.tensor([1.0, 2.0]);
+    /// let r = t.diag();
+    /// assert_eq!(r.array(), [[1.0, 0.0], [0.0, 2.0]]);
+    /// ```
+    pub fn diag(self) -> Tensor<(N, N), E, D, T> {
+        self.try_diag().unwrap()
+    }
+
+    /// Fallible version of [Tensor::diag]
+    #[allow(clippy::type_complexity)]
+    pub fn try_diag(self) -> Result<Tensor<(N, N), E, D, T>, D::Err> {
+        let (inp, mut tape) = self.split_tape();
+        let storage = inp.device.diag_fwd(&inp.storage)?;
+        let out = inp.device.upgrade(storage);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.diag_bwd(grad_inp, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::TestDevice};
+
+    #[test]
+    fn test_diag_diagonal_round_trip() {
+        let dev: TestDevice = Default::default();
+        let m: Tensor<Rank2<3, 3>, f32, _> = dev.sample_normal();
+        let d = m.clone().diagonal();
+        let r = d.clone().diag();
+        let m = m.array();
+        let d = d.array();
+        assert_eq!(d, [m[0][0], m[1][1], m[2][2]]);
+        assert_eq!(
+            r.array(),
+            [[d[0], 0.0, 0.0], [0.0, d[1], 0.0], [0.0, 0.0, d[2]]]
+        );
+        assert_eq!(r.diagonal().array(), d);
+    }
+
+    #[test]
+    fn test_diagonal_backward() {
+        let dev: TestDevice = Default::default();
+        let m: Tensor<Rank2<3, 3>, f32, _> = dev.sample_normal();
+        let r = m.trace().diagonal();
+        let g = (r * dev.tensor([1.0, 2.0, 3.0])).sum().backward();
+        assert_eq!(
+            g.get(&m).array(),
+            [[1.0, 0.0, 0.0], [0.0, 2.0, 0.0], [0.0, 0.0, 3.0]]
+        );
+    }
+
+    #[test]
+    fn test_diag_backward() {
+        let dev: TestDevice = Default::default();
+        let v: Tensor<Rank1<3>, f32, _> = dev.sample_normal();
+        let r = v.trace().diag();
+        let w = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        let g = (r * w).sum().backward();
+        assert_eq!(g.get(&v).array(), [1.0, 5.0, 9.0]);
+    }
+
+    #[test]
+    fn test_diagonal_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let v: Tensor<Rank1<2>, f32, _> = dev.tensor([1.0, 2.0]);
+        let r = v.trace().broadcast::<Rank2<2, 2>, Axis<0>>().diagonal();
+        assert_eq!(r.array(), [1.0, 2.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&v).array(), [1.0, 1.0]);
+    }
+}
@@ -80,6 +80,7 @@ where
 
 #[cfg(test)]
 mod tests {
+    use crate::shapes::*;
     use crate::tensor::*;
     use crate::tensor_ops::*;
     use crate::tests::TestDevice;
@@ -145,7 +146,7 @@ mod tests {
     #[test]
     fn test_scalar_div_0d() {
         let dev: TestDevice = Default::default();
-        let x = dev.tensor(1.0);
+        let x: Tensor<Rank0, f32, _> = dev.tensor(1.0);
         let r = x.trace() / 2.0;
         assert_eq!(r.array(), 0.5);
         let g = r.exp().backward();
@@ -155,7 +156,7 @@ mod tests {
     #[test]
     fn test_scalar_div_1d() {
         let dev: TestDevice = Default::default();
-        let x = dev.tensor([0.0, 1.0, 2.0]);
+        let x: Tensor<Rank1<3>, f32, _> = dev.tensor([0.0, 1.0, 2.0]);
         let r = x.trace() / 2.0;
         assert_eq!(r.array(), [0.0, 0.5, 1.0]);
         let g = r.exp().sum().backward();
@@ -165,7 +166,7 @@ mod tests {
     #[test]
     fn test_scalar_div_2d() {
         let dev: TestDevice = Default::default();
-        let x = dev.tensor([[1.0; 2]; 3]);
+        let x: Tensor<Rank2<3, 2>, f32, _> = dev.tensor([[1.0; 2]; 3]);
         let r = x.trace() / 2.0;
         assert_eq!(r.array(), [[0.5; 2]; 3]);
         let g = r.exp().sum().backward();
@@ -49,7 +49,7 @@ impl UnaryKernel<super::DropoutKernelOp, f32> for Cuda {
         );
         unsafe { fwd_fn.launch_async(cfg, params) }?;
         Ok(CudaArray {
-            data: Arc::new(storage),
+            data: Arc::new(storage.into()),
             shape: inp.shape,
             strides: inp.strides,
         })
@@ -0,0 +1,42 @@
+use crate::{
+    shapes::{Axes, Dtype, HasAxes, Shape},
+    tensor::cpu::{Cpu, LendingIterator, StridedArray},
+};
+
+impl<E: Dtype> super::FlipKernel<E> for Cpu {
+    fn forward<S, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err>
+    where
+        S: Shape + HasAxes<Ax>,
+    {
+        let ax = Ax::as_array()[0] as usize;
+        let size = inp.shape.concrete()[ax];
+        let mut out = StridedArray::new(inp.shape)?;
+        let mut out_iter = out.iter_mut_with_index();
+        while let Some((o, mut i)) = out_iter.next() {
+            i[ax] = size - 1 - i[ax];
+            *o = inp[i];
+        }
+        Ok(out)
+    }
+
+    fn backward<S, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err>
+    where
+        S: Shape + HasAxes<Ax>,
+    {
+        let ax = Ax::as_array()[0] as usize;
+        let size = grad_out.shape.concrete()[ax];
+        let mut out_iter = grad_out.iter_with_index();
+        while let Some((go, mut i)) = out_iter.next() {
+            i[ax] = size - 1 - i[ax];
+            grad_inp[i] += *go;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+// Computes the offsets of element `i` of a contiguous tensor into `inp` and `out`,
+// where the index along `ax` is reversed for `out`.
+__device__ void flipped_offsets(
+    unsigned int i,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    unsigned int *inp_i,
+    unsigned int *out_i
+) {
+    *inp_i = 0;
+    *out_i = 0;
+    for (int d = num_dims - 1; d >= 0; d--) {
+        unsigned int i_d = i % dims[d];
+        i /= dims[d];
+        *inp_i += i_d * inp_strides[d];
+        if (d == ax) {
+            i_d = dims[d] - 1 - i_d;
+        }
+        *out_i += i_d * out_strides[d];
+    }
+}
+
+extern "C" __global__ void flip_forward(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t *dims,
+    const float *inp,
+    const size_t *inp_strides,
+    float *out,
+    const size_t *out_strides
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int inp_i, out_i;
+    flipped_offsets(i, num_dims, ax, dims, inp_strides, out_strides, &inp_i, &out_i);
+    out[out_i] = inp[inp_i];
+}
+
+extern "C" __global__ void flip_backward(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t *dims,
+    float *grad_inp,
+    const size_t *inp_strides,
+    const float *grad_out,
+    const size_t *out_strides
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int inp_i, out_i;
+    flipped_offsets(i, num_dims, ax, dims, inp_strides, out_strides, &inp_i, &out_i);
+    // inp may be broadcasted, so multiple elements can map to the same gradient
+    atomicAdd(grad_inp + inp_i, grad_out[out_i]);
+}
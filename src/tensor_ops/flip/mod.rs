@@ -0,0 +1,106 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+pub trait FlipKernel<E: Dtype>: DeviceStorage {
+    fn forward<S, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        inp: &Self::Storage<S, E>,
+    ) -> Result<Self::Storage<S, E>, Self::Err>
+    where
+        S: Shape + HasAxes<Ax>;
+    fn backward<S, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err>
+    where
+        S: Shape + HasAxes<Ax>;
+}
+
+/// Reverses the order of elements along a single axis.
+pub trait Flip: HasErr + HasShape {
+    /// Reverses the order of elements along axis `Ax`. Flipping twice
+    /// results in the original tensor.
+    ///
+    /// **Pytorch equivalent**: `t.flip(Ax)`
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// let r = t.clone().flip::<Axis<1>>();
+    /// assert_eq!(r.array(), [[3.0, 2.0, 1.0], [6.0, 5.0, 4.0]]);
+    ///
+    /// let r = t.flip::<Axis<0>>();
+    /// assert_eq!(r.array(), [[4.0, 5.0, 6.0], [1.0, 2.0, 3.0]]);
+    /// ```
+    fn flip<Ax: Axes<Array = [isize; 1]>>(self) -> Self
+    where
+        Self::Shape: HasAxes<Ax>,
+    {
+        self.try_flip::<Ax>().unwrap()
+    }
+    /// Fallible version of [Flip::flip]
+    fn try_flip<Ax: Axes<Array = [isize; 1]>>(self) -> Result<Self, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: FlipKernel<E>, T: Tape<D>> Flip for Tensor<S, E, D, T> {
+    fn try_flip<Ax: Axes<Array = [isize; 1]>>(self) -> Result<Self, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax>,
+    {
+        let (inp, mut tape) = self.split_tape();
+        let storage = inp.device.forward::<S, Ax>(&inp.storage)?;
+        let out = inp.device.upgrade(storage);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward::<S, Ax>(grad_inp, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor_ops::*;
+    use crate::tests::TestDevice;
+
+    #[test]
+    fn test_flip_2d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let r = t.trace().flip::<Axis<1>>();
+        assert_eq!(r.array(), [[3.0, 2.0, 1.0], [6.0, 5.0, 4.0]]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [[1.0; 3]; 2]);
+    }
+
+    #[test]
+    fn test_flip_weighted_backward() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let w: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let r = t.trace().flip::<Axis<0>>();
+        assert_eq!(r.array(), [[4.0, 5.0, 6.0], [1.0, 2.0, 3.0]]);
+        let g = (r * w).sum().backward();
+        assert_eq!(g.get(&t).array(), [[4.0, 5.0, 6.0], [1.0, 2.0, 3.0]]);
+    }
+
+    #[test]
+    fn test_flip_is_own_inverse() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 3, 4>, f32, _> = dev.sample_normal();
+        let r = t.clone().flip::<Axis<2>>().flip::<Axis<2>>();
+        assert_eq!(r.array(), t.array());
+    }
+}
@@ -1,34 +1,35 @@
-use crate::tensor_ops::cpu_kernels::BinaryDerivative;
+use crate::{shapes::Dtype, tensor_ops::cpu_kernels::BinaryDerivative};
 
-impl BinaryDerivative<f32> for super::HuberErrorKernelOp<f32> {
+impl<F: num_traits::Float + Dtype> BinaryDerivative<F> for super::HuberErrorKernelOp<F> {
     #[inline(always)]
-    fn f(&self, x: &f32, y: &f32) -> f32 {
-        if (x - y).abs() < self.delta {
-            (x - y).powi(2) * 0.5
+    fn f(&self, x: &F, y: &F) -> F {
+        let half = F::from(0.5).unwrap();
+        if (*x - *y).abs() < self.delta {
+            (*x - *y).powi(2) * half
         } else {
-            (x - y).abs() * self.delta - 0.5 * self.delta * self.delta
+            (*x - *y).abs() * self.delta - half * self.delta * self.delta
         }
     }
 
     #[inline(always)]
-    fn dfdx(&self, x: &f32, y: &f32) -> f32 {
-        if (x - y) == 0.0 {
-            0.0
-        } else if (x - y).abs() < self.delta {
-            x - y
+    fn dfdx(&self, x: &F, y: &F) -> F {
+        if (*x - *y) == F::zero() {
+            F::zero()
+        } else if (*x - *y).abs() < self.delta {
+            *x - *y
         } else {
-            (x - y).signum() * self.delta
+            (*x - *y).signum() * self.delta
         }
     }
 
     #[inline(always)]
-    fn dfdy(&self, x: &f32, y: &f32) -> f32 {
-        if (x - y) == 0.0 {
-            0.0
-        } else if (x - y).abs() < self.delta {
-            y - x
+    fn dfdy(&self, x: &F, y: &F) -> F {
+        if (*x - *y) == F::zero() {
+            F::zero()
+        } else if (*x - *y).abs() < self.delta {
+            *y - *x
         } else {
-            (y - x).signum() * self.delta
+            (*y - *x).signum() * self.delta
         }
     }
 }
@@ -0,0 +1,107 @@
+use crate::{
+    gradients::Tape,
+    shapes::{Axes, HasShape, ReduceShape, Shape},
+    tensor::{HasErr, Tensor},
+};
+
+use super::{BroadcastTo, Device, SumTo, TryDiv};
+
+/// Projects `t` onto the unit sphere along `Ax`. `epsilon` is a lower bound on the norm
+/// to avoid division by zero. Computes `t / max(t.norm(Ax), epsilon)`.
+///
+/// Normalizing a single axis:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t: Tensor<Rank2<2, 2>, f32, _> = dev.tensor([[3.0, 4.0], [0.0, -2.0]]);
+/// let r = t.l2_normalize::<Axis<1>>(1e-12);
+/// assert_eq!(r.array(), [[0.6, 0.8], [0.0, -1.0]]);
+/// ```
+pub fn l2_normalize<Ax: Axes, S: Shape + ReduceShape<Ax>, D: Device<f32>, T: Tape<D>>(
+    t: Tensor<S, f32, D, T>,
+    epsilon: f32,
+) -> Tensor<S, f32, D, T> {
+    t.l2_normalize::<Ax>(epsilon)
+}
+
+impl<S: Shape, D: Device<f32>, T: Tape<D>> Tensor<S, f32, D, T> {
+    /// See [l2_normalize]
+    pub fn l2_normalize<Ax: Axes>(self, epsilon: f32) -> Self
+    where
+        S: ReduceShape<Ax>,
+    {
+        self.try_l2_normalize(epsilon).unwrap()
+    }
+
+    /// See [l2_normalize]
+    pub fn try_l2_normalize<Ax: Axes>(self, epsilon: f32) -> Result<Self, <Self as HasErr>::Err>
+    where
+        S: ReduceShape<Ax>,
+    {
+        // NOTE: clamping the squared norm keeps the gradient of sqrt finite for zero vectors
+        let norm = self
+            .retaped::<T>()
+            .try_square()?
+            .try_sum::<_, Ax>()?
+            .try_clamp(epsilon * epsilon, f32::INFINITY)?
+            .try_sqrt()?
+            .try_broadcast_like(self.shape())?;
+        self.try_div(norm)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{assert_close, AssertClose, TestDevice};
+    use crate::{shapes::*, tensor::*, tensor_ops::*};
+
+    #[test]
+    fn test_l2_normalize_1d() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor([3.0, 4.0]);
+        let r = a.trace().l2_normalize(1e-12);
+        assert_close(&r.array(), &[0.6, 0.8]);
+        let g = r.sum().backward();
+        // (I - y y^T) / ||x|| applied to [1, 1]
+        assert_close(&g.get(&a).array(), &[0.032, -0.024]);
+    }
+
+    #[test]
+    fn test_l2_normalize_finite_difference() {
+        let dev: TestDevice = Default::default();
+        let x = [[0.5, -1.0, 2.0], [1.5, 0.25, -0.75]];
+        let w = dev.tensor([[0.3, -0.7, 1.1], [-0.2, 0.9, 0.4]]);
+        let a = dev.tensor(x);
+        let r = a.trace().l2_normalize::<Axis<1>>(1e-12);
+        let g = (r * w.clone()).sum().backward();
+        let g = g.get(&a).array();
+
+        let f = |x: [[f32; 3]; 2]| -> f32 {
+            (dev.tensor(x).l2_normalize::<Axis<1>>(1e-12) * w.clone())
+                .sum::<Rank0, _>()
+                .array()
+        };
+        let h = 1e-2;
+        let mut fd = [[0.0; 3]; 2];
+        for i in 0..2 {
+            for j in 0..3 {
+                let mut xp = x;
+                let mut xm = x;
+                xp[i][j] += h;
+                xm[i][j] -= h;
+                fd[i][j] = (f(xp) - f(xm)) / (2.0 * h);
+            }
+        }
+        g.assert_close(&fd, 1e-3);
+    }
+
+    #[test]
+    fn test_l2_normalize_zero_vector() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<2, 3>, f32, _> = dev.zeros();
+        let r = a.trace().l2_normalize::<Axis<1>>(1e-6);
+        assert_eq!(r.array(), [[0.0; 3]; 2]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&a).array(), [[1e6; 3]; 2]);
+    }
+}
@@ -1,12 +1,12 @@
 use crate::tensor_ops::cpu_kernels::UnaryDerivative;
 
-impl UnaryDerivative<f32> for super::LnKernelOp {
+impl<F: num_traits::Float> UnaryDerivative<F> for super::LnKernelOp {
     #[inline(always)]
-    fn f(&self, x: &f32) -> f32 {
+    fn f(&self, x: &F) -> F {
         x.ln()
     }
     #[inline(always)]
-    fn df(&self, x: &f32) -> f32 {
-        1.0 / x
+    fn df(&self, x: &F) -> F {
+        F::one() / *x
     }
 }
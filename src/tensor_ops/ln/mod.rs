@@ -40,12 +40,12 @@ impl<S: Shape, E: Dtype, D: UnaryKernel<LnKernelOp, E>, T: Tape<D>> Tensor<S, E,
 
 #[cfg(test)]
 mod tests {
-    use crate::{tensor::*, tensor_ops::*, tests::TestDevice};
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::TestDevice};
 
     #[test]
     fn test_ln() {
         let dev: TestDevice = Default::default();
-        let x = dev.tensor([-2.0, -1.0, 0.0, 1.0, 2.0]);
+        let x: Tensor<Rank1<5>, f32, _> = dev.tensor([-2.0, -1.0, 0.0, 1.0, 2.0]);
         let r = x.trace().ln();
         let r_array = r.array();
         assert!(r_array[0].is_nan());
@@ -1,4 +1,4 @@
-use super::{BroadcastTo, Device, LogSumExpTo, TrySub};
+use super::{BroadcastTo, Device, LogSumExpTo, TryDiv, TrySub};
 use crate::{gradients::Tape, shapes::*, tensor::Tensor};
 
 /// `log(softmax(t))` in numerically stable way across `Ax`. Does `t - logsumexp(t)` under the hood.
@@ -46,6 +46,22 @@ impl<S: Shape, E: Dtype, D: Device<E>, T: Tape<D>> Tensor<S, E, D, T> {
         let logsumexp = logsumexp.try_broadcast_like(self.shape())?;
         self.try_sub(logsumexp)
     }
+
+    /// Computes `log_softmax(t / temperature)` across `Ax`. See [Tensor::softmax_with_temperature].
+    pub fn log_softmax_with_temperature<Ax: Axes>(self, temperature: E) -> Self
+    where
+        S: ReduceShape<Ax>,
+    {
+        self.try_log_softmax_with_temperature::<Ax>(temperature)
+            .unwrap()
+    }
+    /// See [Tensor::log_softmax_with_temperature]
+    pub fn try_log_softmax_with_temperature<Ax: Axes>(self, temperature: E) -> Result<Self, D::Err>
+    where
+        S: ReduceShape<Ax>,
+    {
+        self.try_div(temperature)?.try_log_softmax::<Ax>()
+    }
 }
 
 #[cfg(test)]
@@ -74,6 +90,19 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_log_softmax_with_temperature() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor([-2.0, -1.0, 0.0, 1.0, 2.0]);
+        let r = a.trace().log_softmax_with_temperature::<Axis<0>>(0.5);
+        let b = dev.tensor([-4.0, -2.0, 0.0, 2.0, 4.0]);
+        let r2 = b.trace().log_softmax::<Axis<0>>();
+        assert_close(&r.array(), &r2.array());
+        let g = r.mean().backward();
+        let g2 = r2.mean().backward();
+        assert_close(&g.get(&a).array(), &g2.get(&b).array().map(|x| x * 2.0));
+    }
+
     #[test]
     fn test_log_softmax_2d() {
         let dev: TestDevice = Default::default();
@@ -0,0 +1,121 @@
+use super::*;
+use crate::{gradients::Tape, shapes::*, tensor::*};
+use num_traits::Float;
+
+/// Reduction along multiple axes using the log of the mean of the exponentials.
+pub trait LogMeanExpTo: HasErr + HasShape {
+    /// Numerically stable `ln(mean(exp(t)))` reduction.
+    ///
+    /// **Pytorch equivalent**: `t.exp().mean(Axes).log()`
+    ///
+    /// This is [LogSumExpTo::logsumexp] minus the log of the number of reduced
+    /// elements, so it also subtracts the max before exponentiating and does not
+    /// overflow for large inputs.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank1<2>, f32, _> = dev.tensor([1000.0, 1000.0]);
+    /// let r = t.logmeanexp::<Rank0, _>(); // or `logmeanexp::<_, Axis<0>>()`
+    /// assert_eq!(r.array(), 1000.0);
+    /// ```
+    fn logmeanexp<Dst: Shape, Ax: Axes>(self) -> Self::WithShape<Dst>
+    where
+        Self::Shape: HasAxes<Ax> + ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_logmeanexp().unwrap()
+    }
+    /// Fallible version of [LogMeanExpTo::logmeanexp]
+    fn try_logmeanexp<Dst: Shape, Ax: Axes>(self) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax> + ReduceShapeTo<Dst, Ax>;
+
+    /// Numerically stable `mean(exp(t))` reduction, computed as
+    /// `exp(logmeanexp(t))`. Large inputs only overflow if the result itself
+    /// is too large to represent.
+    ///
+    /// **Pytorch equivalent**: `t.exp().mean(Axes)`
+    ///
+    /// Example:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<2, 2>, f32, _> = dev.tensor([[-100.0, -100.0], [0.0, 0.0]]);
+    /// let r = t.mean_exp::<_, Axis<1>>();
+    /// assert_eq!(r.array(), [(-100.0f32).exp(), 1.0]);
+    /// ```
+    fn mean_exp<Dst: Shape, Ax: Axes>(self) -> Self::WithShape<Dst>
+    where
+        Self::Shape: HasAxes<Ax> + ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_mean_exp().unwrap()
+    }
+    /// Fallible version of [LogMeanExpTo::mean_exp]
+    fn try_mean_exp<Dst: Shape, Ax: Axes>(self) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax> + ReduceShapeTo<Dst, Ax>;
+}
+
+impl<S: Shape, E: Dtype + Float, D: Device<E>, T: Tape<D>> LogMeanExpTo for Tensor<S, E, D, T> {
+    fn try_logmeanexp<Dst: Shape, Ax: Axes>(self) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax> + ReduceShapeTo<Dst, Ax>,
+    {
+        let num_elements_reduced = <S as HasAxes<Ax>>::size(self.shape()) as f64;
+        let ln_n = E::from(num_elements_reduced.ln()).unwrap();
+        self.try_logsumexp::<Dst, Ax>()?.try_sub(ln_n)
+    }
+
+    fn try_mean_exp<Dst: Shape, Ax: Axes>(self) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax> + ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_logmeanexp::<Dst, Ax>()?.try_exp()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::*;
+
+    #[test]
+    fn test_logmeanexp_no_overflow() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor([1000.0, 1001.0]);
+
+        let naive = (1000.0f64.exp() + 1001.0f64.exp()) / 2.0;
+        assert_eq!(naive.ln(), f64::INFINITY);
+        let expected = 1000.0 + ((1.0 + 1.0f64.exp()) / 2.0).ln();
+
+        let r = a.trace().logmeanexp();
+        assert!((r.array() as f64 - expected).abs() / expected < 1e-6);
+        let g = r.backward();
+        assert_close(&g.get(&a).array(), &[0.26894143, 0.7310586]);
+    }
+
+    #[test]
+    fn test_logmeanexp_matches_naive() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor([[0.5, -1.5, 2.0], [3.0, 0.0, -0.25]]);
+        let r = a.trace().logmeanexp::<Rank1<2>, _>();
+        let naive = a.trace().exp().mean::<Rank1<2>, _>().ln();
+        assert_close(&r.array(), &naive.array());
+
+        let g1 = r.sum().backward();
+        let g2 = naive.sum().backward();
+        assert_close(&g1.get(&a).array(), &g2.get(&a).array());
+    }
+
+    #[test]
+    fn test_mean_exp_backward() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor([[0.0, 1.0], [-2.0, 0.5]]);
+        let r = a.trace().mean_exp::<_, Axis<1>>();
+        let e = a.array().map(|r| r.map(f32::exp));
+        assert_close(&r.array(), &e.map(|r| (r[0] + r[1]) / 2.0));
+        let g = r.sum().backward();
+        assert_close(&g.get(&a).array(), &e.map(|r| r.map(|v| v / 2.0)));
+    }
+}
@@ -7,6 +7,10 @@ pub trait LogSumExpTo: HasErr + HasShape {
     ///
     /// **Pytorch equivalent**: `t.exp().sum(Axes).log()`
     ///
+    /// This subtracts the max along the reduced axes before exponentiating, so it
+    /// does not overflow for large inputs like the naive formula does. The gradient
+    /// is the softmax of the input along the reduced axes.
+    ///
     /// **Related functions**: [ln()], [exp()], [log_softmax()], [softmax()]
     ///
     /// Example:
@@ -81,4 +85,24 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_logsumexp_matches_naive() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor([[0.5, -1.5, 2.0], [3.0, 0.0, -0.25]]);
+        let r = a.clone().logsumexp::<Rank1<2>, _>();
+        let naive = a.exp().sum::<Rank1<2>, _>().ln();
+        assert_close(&r.array(), &naive.array());
+    }
+
+    #[test]
+    fn test_logsumexp_no_overflow() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor([1000.0, 1001.0]);
+        assert_eq!(a.clone().exp().sum().ln().array(), f32::INFINITY);
+        let r = a.trace().logsumexp();
+        assert_eq!(r.array(), 1001.31323);
+        let g = r.backward();
+        assert_close(&g.get(&a).array(), &[0.26894143, 0.7310586]);
+    }
 }
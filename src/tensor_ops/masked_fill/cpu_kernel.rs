@@ -0,0 +1,40 @@
+use super::MaskedFillKernel;
+use crate::{
+    shapes::{Dtype, Shape},
+    tensor::cpu::{Cpu, LendingIterator, StridedArray},
+};
+
+impl<E: Dtype> MaskedFillKernel<E> for Cpu {
+    fn forward<S: Shape>(
+        &self,
+        mask: &Self::Storage<S, bool>,
+        inp: &Self::Storage<S, E>,
+        value: E,
+    ) -> Result<Self::Storage<S, E>, Self::Err> {
+        let mut out: Self::Storage<S, E> = StridedArray::new(inp.shape)?;
+        let mut mask_iter = mask.iter();
+        let mut inp_iter = inp.iter();
+        let mut out_iter = out.iter_mut();
+        while let Some((o, (m, i))) = out_iter.next().zip(mask_iter.next().zip(inp_iter.next())) {
+            *o = if *m { value } else { *i };
+        }
+        Ok(out)
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        mask: &Self::Storage<S, bool>,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err> {
+        let mut mask_iter = mask.iter();
+        let mut inp_iter = grad_inp.iter_mut();
+        let mut out_iter = grad_out.iter();
+        while let Some((i, (m, o))) = inp_iter.next().zip(mask_iter.next().zip(out_iter.next())) {
+            if !*m {
+                *i += *o;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+use super::MaskedFillKernel;
+use crate::{
+    shapes::Shape,
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{CudaSlice, LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/masked_fill.ptx"));
+const MODULE_NAME: &str = "masked_fill";
+const FWD_FN_NAME: &str = "masked_fill_forward";
+const BWD_FN_NAME: &str = "masked_fill_backward";
+const ALL_FN_NAMES: [&str; 2] = [FWD_FN_NAME, BWD_FN_NAME];
+
+impl MaskedFillKernel<f32> for Cuda {
+    fn forward<S: Shape>(
+        &self,
+        mask: &Self::Storage<S, bool>,
+        inp: &Self::Storage<S, f32>,
+        value: f32,
+    ) -> Result<Self::Storage<S, f32>, Self::Err> {
+        if !self.dev.has_func(MODULE_NAME, FWD_FN_NAME) {
+            self.dev
+                .load_ptx(PTX_SRC.into(), MODULE_NAME, &ALL_FN_NAMES)?;
+        }
+
+        let shape = inp.shape;
+        let strides = inp.shape.strides();
+        let numel = shape.num_elements();
+
+        let mut storage = self.dev.alloc_zeros_async::<f32>(numel)?;
+
+        let dims: CudaSlice<usize> = self.dev.take_async(shape.concrete().into())?;
+        let mask_strides: CudaSlice<usize> = self.dev.take_async(mask.strides.into())?;
+        let inp_strides: CudaSlice<usize> = self.dev.take_async(inp.strides.into())?;
+
+        let fwd_fn = self.dev.get_func(MODULE_NAME, FWD_FN_NAME).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,              // const size_t numel,
+            S::NUM_DIMS,        // const size_t num_dims,
+            &dims,              // const size_t *dims,
+            mask.data.as_ref(), // const bool *mask,
+            &mask_strides,      // const size_t *mask_strides,
+            inp.data.as_ref(),  // const float *inp,
+            &inp_strides,       // const size_t *inp_strides,
+            value,              // const float value,
+            &mut storage,       // float *out,
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+        Ok(CudaArray {
+            data: Arc::new(storage.into()),
+            shape,
+            strides,
+        })
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        mask: &Self::Storage<S, bool>,
+        grad_inp: &mut Self::Storage<S, f32>,
+        grad_out: &Self::Storage<S, f32>,
+    ) -> Result<(), Self::Err> {
+        let bwd_fn = self.dev.get_func(MODULE_NAME, BWD_FN_NAME).unwrap();
+        let numel = mask.shape.num_elements();
+
+        let dims: CudaSlice<usize> = self.dev.take_async(mask.shape.concrete().into())?;
+        let mask_strides: CudaSlice<usize> = self.dev.take_async(mask.strides.into())?;
+        let inp_strides: CudaSlice<usize> = self.dev.take_async(grad_inp.strides.into())?;
+
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,                             // const size_t numel,
+            S::NUM_DIMS,                       // const size_t num_dims,
+            &dims,                             // const size_t *dims,
+            mask.data.as_ref(),                // const bool *mask,
+            &mask_strides,                     // const size_t *mask_strides,
+            Arc::make_mut(&mut grad_inp.data), // float *grad_inp,
+            &inp_strides,                      // const size_t *inp_strides,
+            grad_out.data.as_ref(),            // const float *grad_out,
+        );
+        unsafe { bwd_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+}
@@ -0,0 +1,201 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::{broadcast_to::BroadcastKernel, BroadcastTo};
+use crate::{
+    gradients::Tape,
+    shapes::{Axes, BroadcastShapeTo, Dtype, HasShape, Shape},
+    tensor::{DeviceStorage, HasErr, PutTape, SplitTape, Tensor},
+};
+
+pub trait MaskedFillKernel<E: Dtype>: DeviceStorage {
+    fn forward<S: Shape>(
+        &self,
+        mask: &Self::Storage<S, bool>,
+        inp: &Self::Storage<S, E>,
+        value: E,
+    ) -> Result<Self::Storage<S, E>, Self::Err>;
+
+    fn backward<S: Shape>(
+        &self,
+        mask: &Self::Storage<S, bool>,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Marker for shapes of masks that can be used with a tensor of shape `S`:
+/// either `S` itself, or any shape that broadcasts to `S` along axes `Ax`.
+pub trait BroadcastMaskTo<S: Shape, Ax>: Shape {
+    fn try_broadcast_mask<D: BroadcastKernel<bool>>(
+        mask: Tensor<Self, bool, D>,
+        dst: &S,
+    ) -> Result<Tensor<S, bool, D>, D::Err>;
+}
+
+impl<S: Shape> BroadcastMaskTo<S, ()> for S {
+    fn try_broadcast_mask<D: BroadcastKernel<bool>>(
+        mask: Tensor<S, bool, D>,
+        dst: &S,
+    ) -> Result<Tensor<S, bool, D>, D::Err> {
+        assert_eq!(mask.shape(), dst);
+        Ok(mask)
+    }
+}
+
+impl<M: Shape, S: Shape, Ax: Axes> BroadcastMaskTo<S, Ax> for M
+where
+    M: BroadcastShapeTo<S, Ax>,
+{
+    fn try_broadcast_mask<D: BroadcastKernel<bool>>(
+        mask: Tensor<M, bool, D>,
+        dst: &S,
+    ) -> Result<Tensor<S, bool, D>, D::Err> {
+        mask.try_broadcast_like(dst)
+    }
+}
+
+/// Replaces values with a scalar wherever a boolean mask is true.
+/// Equivalent to `torch.masked_fill` from pytorch.
+pub trait MaskedFill<E: Dtype, D: DeviceStorage>: HasErr + HasShape {
+    /// Sets every element where `mask` is `true` to `value`, and passes the
+    /// rest through unchanged. The mask must have the same shape as `self`, or
+    /// a shape that can be broadcast to it.
+    ///
+    /// Gradients only flow through the elements that were not filled.
+    ///
+    /// A common use is masking out attention scores before a softmax:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let scores = dev.tensor([1.0, 2.0, 3.0]);
+    /// let mask = dev.tensor([false, false, true]);
+    /// let r = scores.masked_fill(mask, f32::NEG_INFINITY);
+    /// assert_eq!(r.array(), [1.0, 2.0, f32::NEG_INFINITY]);
+    /// ```
+    ///
+    /// The same mask can be applied to every row of a batch:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let scores = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// let mask = dev.tensor([true, false, false]);
+    /// let r = scores.masked_fill(mask, 0.0);
+    /// assert_eq!(r.array(), [[0.0, 2.0, 3.0], [0.0, 5.0, 6.0]]);
+    /// ```
+    fn masked_fill<M: BroadcastMaskTo<Self::Shape, Ax>, Ax>(
+        self,
+        mask: Tensor<M, bool, D>,
+        value: E,
+    ) -> Self {
+        self.try_masked_fill(mask, value).unwrap()
+    }
+
+    /// Fallible version of [MaskedFill::masked_fill]
+    fn try_masked_fill<M: BroadcastMaskTo<Self::Shape, Ax>, Ax>(
+        self,
+        mask: Tensor<M, bool, D>,
+        value: E,
+    ) -> Result<Self, Self::Err>;
+}
+
+impl<S: Shape, E: Dtype, D: MaskedFillKernel<E> + BroadcastKernel<bool>, T: Tape<D>>
+    MaskedFill<E, D> for Tensor<S, E, D, T>
+{
+    fn try_masked_fill<M: BroadcastMaskTo<S, Ax>, Ax>(
+        self,
+        mask: Tensor<M, bool, D>,
+        value: E,
+    ) -> Result<Self, Self::Err> {
+        let mask = M::try_broadcast_mask(mask, self.shape())?;
+        let (inp, mut tape) = self.split_tape();
+        let storage = MaskedFillKernel::forward(&inp.device, &mask.storage, &inp.storage, value)?;
+        let out = inp.device.upgrade(storage);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            MaskedFillKernel::backward(&inp.device, &mask.storage, grad_inp, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shapes::*;
+    use crate::tensor::*;
+    use crate::tensor_ops::*;
+    use crate::tests::TestDevice;
+
+    #[test]
+    fn test_masked_fill_1d_backward() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<5>, f32, _> = dev.sample_normal();
+        let mask = dev.tensor([false, true, false, true, false]);
+        let r = t.trace().masked_fill(mask, 2.0);
+        let t_array = t.array();
+        assert_eq!(r.array(), [t_array[0], 2.0, t_array[2], 2.0, t_array[4]]);
+        let g = r.exp().sum().backward();
+        assert_eq!(
+            g.get(&t).array(),
+            [
+                t_array[0].exp(),
+                0.0,
+                t_array[2].exp(),
+                0.0,
+                t_array[4].exp()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_masked_fill_before_softmax() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 3>, f32, _> = dev.sample_normal();
+        let mask = dev.tensor([[false, false, true], [true, false, false]]);
+        let r = t
+            .trace()
+            .masked_fill(mask, f32::NEG_INFINITY)
+            .softmax::<Axis<1>>();
+        let r_array = r.array();
+        assert_eq!(r_array[0][2], 0.0);
+        assert_eq!(r_array[1][0], 0.0);
+        let g = (r * dev.sample_normal()).sum().backward();
+        let g = g.get(&t).array();
+        assert_eq!(g[0][2], 0.0);
+        assert_eq!(g[1][0], 0.0);
+        assert!(g[0][0] != 0.0 && g[1][1] != 0.0);
+    }
+
+    #[test]
+    fn test_masked_fill_broadcasted_mask() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 3, 4>, f32, _> = dev.sample_normal();
+        let mask: Tensor<Rank2<2, 4>, bool, _> =
+            dev.tensor([[true, false, false, true], [false, false, true, false]]);
+        let mask_array = mask.array();
+        let r = t.trace().masked_fill(mask, 0.0);
+        let t_array = t.array();
+        let r_array = r.array();
+        let g = r.sum().backward();
+        let g = g.get(&t).array();
+        for i in 0..2 {
+            for j in 0..3 {
+                for k in 0..4 {
+                    if mask_array[i][k] {
+                        assert_eq!(r_array[i][j][k], 0.0);
+                        assert_eq!(g[i][j][k], 0.0);
+                    } else {
+                        assert_eq!(r_array[i][j][k], t_array[i][j][k]);
+                        assert_eq!(g[i][j][k], 1.0);
+                    }
+                }
+            }
+        }
+    }
+}
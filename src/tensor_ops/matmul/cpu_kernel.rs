@@ -1,253 +1,318 @@
 use crate::shapes::*;
-use crate::tensor::cpu::{Cpu, StridedArray, View, ViewMut};
+use crate::tensor::cpu::{Cpu, LendingIterator, StridedArray, View, ViewMut};
 
-#[cfg(feature = "cblas")]
-use cblas_sys::{
-    cblas_sgemm as sgemm, CblasColMajor as ColMajor, CblasNoTrans as NoTr,
-    CblasRowMajor as RowMajor, CblasTrans as Tr,
-};
+pub(crate) trait MatMulImpl: Sized {
+    /// Computes `c += a * b`
+    fn matmul<M: Dim, K: Dim, N: Dim>(
+        a: View<(M, K), Self>,
+        b: View<(K, N), Self>,
+        c: &mut ViewMut<(M, N), Self>,
+    );
+}
 
-#[inline]
-pub(crate) fn matmul<M: Dim, K: Dim, N: Dim>(
-    a: View<(M, K), f32>,
-    b: View<(K, N), f32>,
-    c: &mut ViewMut<(M, N), f32>,
-) {
-    let [m, k] = a.shape.concrete();
-    let n = b.shape.1.size();
+macro_rules! impl_matmul {
+    ($Dtype:ty, $gemm:path, $cblas_gemm:path) => {
+        impl MatMulImpl for $Dtype {
+            #[inline]
+            fn matmul<M: Dim, K: Dim, N: Dim>(
+                a: View<(M, K), Self>,
+                b: View<(K, N), Self>,
+                c: &mut ViewMut<(M, N), Self>,
+            ) {
+                let [m, k] = a.shape.concrete();
+                let n = b.shape.1.size();
 
-    let ap = a.ptr();
-    let bp = b.ptr();
-    let cp = c.ptr_mut();
+                let ap = a.ptr();
+                let bp = b.ptr();
+                let cp = c.ptr_mut();
 
-    #[cfg(not(feature = "cblas"))]
-    unsafe {
-        let [ar, ac] = a.strides.map(|x| x as isize);
-        let [br, bc] = b.strides.map(|x| x as isize);
-        let [cr, cc] = c.strides.map(|x| x as isize);
-        matrixmultiply::sgemm(m, k, n, 1.0, ap, ar, ac, bp, br, bc, 1.0, cp, cr, cc);
-    }
+                #[cfg(not(feature = "cblas"))]
+                unsafe {
+                    let [ar, ac] = a.strides.map(|x| x as isize);
+                    let [br, bc] = b.strides.map(|x| x as isize);
+                    let [cr, cc] = c.strides.map(|x| x as isize);
+                    $gemm(m, k, n, 1.0, ap, ar, ac, bp, br, bc, 1.0, cp, cr, cc);
+                }
 
-    #[cfg(feature = "cblas")]
-    unsafe {
-        let (lda, a_tr) = super::matrix_strides((m, k), a.strides);
-        let (ldb, b_tr) = super::matrix_strides((k, n), b.strides);
-        let (ldc, c_tr) = super::matrix_strides((m, n), c.strides);
-        let (m, n, k) = (m as libc::c_int, n as libc::c_int, k as libc::c_int);
-        let layout = if c_tr { ColMajor } else { RowMajor };
-        let (a_tr, b_tr) = if c_tr {
-            (if a_tr { NoTr } else { Tr }, if b_tr { NoTr } else { Tr })
-        } else {
-            (if a_tr { Tr } else { NoTr }, if b_tr { Tr } else { NoTr })
-        };
-        sgemm(
-            layout, a_tr, b_tr, m, n, k, 1.0, ap, lda as i32, bp, ldb as i32, 1.0, cp, ldc as i32,
-        )
-    }
+                #[cfg(feature = "cblas")]
+                unsafe {
+                    use cblas_sys::{
+                        CblasColMajor as ColMajor, CblasNoTrans as NoTr, CblasRowMajor as RowMajor,
+                        CblasTrans as Tr,
+                    };
+                    let (lda, a_tr) = super::matrix_strides((m, k), a.strides);
+                    let (ldb, b_tr) = super::matrix_strides((k, n), b.strides);
+                    let (ldc, c_tr) = super::matrix_strides((m, n), c.strides);
+                    let (m, n, k) = (m as libc::c_int, n as libc::c_int, k as libc::c_int);
+                    let layout = if c_tr { ColMajor } else { RowMajor };
+                    let (a_tr, b_tr) = if c_tr {
+                        (if a_tr { NoTr } else { Tr }, if b_tr { NoTr } else { Tr })
+                    } else {
+                        (if a_tr { Tr } else { NoTr }, if b_tr { Tr } else { NoTr })
+                    };
+                    $cblas_gemm(
+                        layout, a_tr, b_tr, m, n, k, 1.0, ap, lda as i32, bp, ldb as i32, 1.0, cp,
+                        ldc as i32,
+                    )
+                }
+            }
+        }
+    };
 }
 
-impl super::VecVecKernel<f32> for Cpu {
-    fn forward<M: Dim, N: Dim>(
-        &self,
-        lhs: &Self::Storage<(M,), f32>,
-        rhs: &Self::Storage<(N,), f32>,
-    ) -> Result<Self::Storage<(M, N), f32>, Self::Err> {
-        let mut out = StridedArray::new((lhs.shape().0, rhs.shape().0))?;
-        matmul(lhs.view().br1(), rhs.view().br0(), &mut out.view_mut());
-        Ok(out)
-    }
-    fn backward<M: Dim, N: Dim>(
-        &self,
-        lhs: &Self::Storage<(M,), f32>,
-        grad_lhs: &mut Self::Storage<(M,), f32>,
-        rhs: &Self::Storage<(N,), f32>,
-        grad_rhs: &mut Self::Storage<(N,), f32>,
-        grad_out: &Self::Storage<(M, N), f32>,
-    ) -> Result<(), Self::Err> {
-        let grad_out = grad_out.view();
-        let lhs = lhs.view().br1().tr();
-        let rhs = rhs.view().br0().tr();
-        matmul(grad_out, rhs, &mut grad_lhs.view_mut().br1());
-        matmul(lhs, grad_out, &mut grad_rhs.view_mut().br0());
-        Ok(())
-    }
-}
+impl_matmul!(f32, matrixmultiply::sgemm, cblas_sys::cblas_sgemm);
+#[cfg(feature = "f64")]
+impl_matmul!(f64, matrixmultiply::dgemm, cblas_sys::cblas_dgemm);
 
-impl super::VecMatKernel<f32> for Cpu {
-    fn forward<const K: usize, N: Dim>(
-        &self,
-        lhs: &Self::Storage<(Const<K>,), f32>,
-        rhs: &Self::Storage<(Const<K>, N), f32>,
-    ) -> Result<Self::Storage<(N,), f32>, Self::Err> {
-        let mut out = StridedArray::new((rhs.shape.1,))?;
-        matmul(lhs.view().br0(), rhs.view(), &mut out.view_mut().br0());
-        Ok(out)
-    }
-    fn backward<const K: usize, N: Dim>(
-        &self,
-        lhs: &Self::Storage<(Const<K>,), f32>,
-        grad_lhs: &mut Self::Storage<(Const<K>,), f32>,
-        rhs: &Self::Storage<(Const<K>, N), f32>,
-        grad_rhs: &mut Self::Storage<(Const<K>, N), f32>,
-        grad_out: &Self::Storage<(N,), f32>,
-    ) -> Result<(), Self::Err> {
-        let grad_out = grad_out.view().br0();
-        matmul(grad_out, rhs.view().tr(), &mut grad_lhs.view_mut().br0());
-        matmul(lhs.view().br0().tr(), grad_out, &mut grad_rhs.view_mut());
-        Ok(())
-    }
+#[inline]
+pub(crate) fn matmul<M: Dim, K: Dim, N: Dim, E: MatMulImpl>(
+    a: View<(M, K), E>,
+    b: View<(K, N), E>,
+    c: &mut ViewMut<(M, N), E>,
+) {
+    E::matmul(a, b, c)
 }
 
-impl super::MatMatKernel<f32> for Cpu {
-    fn forward<M: Dim, const K: usize, N: Dim>(
-        &self,
-        lhs: &Self::Storage<(M, Const<K>), f32>,
-        rhs: &Self::Storage<(Const<K>, N), f32>,
-    ) -> Result<Self::Storage<(M, N), f32>, Self::Err> {
-        let mut out = StridedArray::new((lhs.shape.0, rhs.shape.1))?;
-        matmul(lhs.view(), rhs.view(), &mut out.view_mut());
-        Ok(out)
-    }
-    fn backward<M: Dim, const K: usize, N: Dim>(
-        &self,
-        lhs: &Self::Storage<(M, Const<K>), f32>,
-        grad_lhs: &mut Self::Storage<(M, Const<K>), f32>,
-        rhs: &Self::Storage<(Const<K>, N), f32>,
-        grad_rhs: &mut Self::Storage<(Const<K>, N), f32>,
-        grad_out: &Self::Storage<(M, N), f32>,
-    ) -> Result<(), Self::Err> {
-        let grad_out = grad_out.view();
-        matmul(grad_out, rhs.view().tr(), &mut grad_lhs.view_mut());
-        matmul(lhs.view().tr(), grad_out, &mut grad_rhs.view_mut());
-        Ok(())
-    }
-}
+/// The kernels are implemented per dtype (instead of generically over [MatMulImpl])
+/// so that the dtype of `Cpu` tensors can still be inferred.
+macro_rules! impl_matmul_kernels {
+    ($Dtype:ty) => {
+        impl super::VecVecKernel<$Dtype> for Cpu {
+            fn forward<M: Dim, N: Dim>(
+                &self,
+                lhs: &Self::Storage<(M,), $Dtype>,
+                rhs: &Self::Storage<(N,), $Dtype>,
+            ) -> Result<Self::Storage<(M, N), $Dtype>, Self::Err> {
+                let mut out = StridedArray::new((lhs.shape().0, rhs.shape().0))?;
+                matmul(lhs.view().br1(), rhs.view().br0(), &mut out.view_mut());
+                Ok(out)
+            }
+            fn backward<M: Dim, N: Dim>(
+                &self,
+                lhs: &Self::Storage<(M,), $Dtype>,
+                grad_lhs: &mut Self::Storage<(M,), $Dtype>,
+                rhs: &Self::Storage<(N,), $Dtype>,
+                grad_rhs: &mut Self::Storage<(N,), $Dtype>,
+                grad_out: &Self::Storage<(M, N), $Dtype>,
+            ) -> Result<(), Self::Err> {
+                let grad_out = grad_out.view();
+                let lhs = lhs.view().br1().tr();
+                let rhs = rhs.view().br0().tr();
+                matmul(grad_out, rhs, &mut grad_lhs.view_mut().br1());
+                matmul(lhs, grad_out, &mut grad_rhs.view_mut().br0());
+                Ok(())
+            }
+        }
 
-impl super::MatMatBrKernel<f32> for Cpu {
-    fn forward<B: Dim, M: Dim, const K: usize, N: Dim>(
-        &self,
-        lhs: &Self::Storage<(B, M, Const<K>), f32>,
-        rhs: &Self::Storage<(Const<K>, N), f32>,
-    ) -> Result<Self::Storage<(B, M, N), f32>, Self::Err> {
-        let (batch, seq, _) = *lhs.shape();
-        let (_, n) = *rhs.shape();
-        let mut out = StridedArray::new((batch, seq, n))?;
-        let a = lhs.view();
-        let b = rhs.view();
-        let mut c = out.view_mut();
-        for batch in 0..batch.size() {
-            matmul(a.idx(batch), b, &mut c.idx_mut(batch));
+        impl super::VecMatKernel<$Dtype> for Cpu {
+            fn forward<const K: usize, N: Dim>(
+                &self,
+                lhs: &Self::Storage<(Const<K>,), $Dtype>,
+                rhs: &Self::Storage<(Const<K>, N), $Dtype>,
+            ) -> Result<Self::Storage<(N,), $Dtype>, Self::Err> {
+                let mut out = StridedArray::new((rhs.shape.1,))?;
+                matmul(lhs.view().br0(), rhs.view(), &mut out.view_mut().br0());
+                Ok(out)
+            }
+            fn backward<const K: usize, N: Dim>(
+                &self,
+                lhs: &Self::Storage<(Const<K>,), $Dtype>,
+                grad_lhs: &mut Self::Storage<(Const<K>,), $Dtype>,
+                rhs: &Self::Storage<(Const<K>, N), $Dtype>,
+                grad_rhs: &mut Self::Storage<(Const<K>, N), $Dtype>,
+                grad_out: &Self::Storage<(N,), $Dtype>,
+            ) -> Result<(), Self::Err> {
+                let grad_out = grad_out.view().br0();
+                matmul(grad_out, rhs.view().tr(), &mut grad_lhs.view_mut().br0());
+                matmul(lhs.view().br0().tr(), grad_out, &mut grad_rhs.view_mut());
+                Ok(())
+            }
         }
-        Ok(out)
-    }
-    fn backward<B: Dim, M: Dim, const K: usize, N: Dim>(
-        &self,
-        lhs: &Self::Storage<(B, M, Const<K>), f32>,
-        grad_lhs: &mut Self::Storage<(B, M, Const<K>), f32>,
-        rhs: &Self::Storage<(Const<K>, N), f32>,
-        grad_rhs: &mut Self::Storage<(Const<K>, N), f32>,
-        grad_out: &Self::Storage<(B, M, N), f32>,
-    ) -> Result<(), Self::Err> {
-        let batch_size = lhs.shape().0.size();
-        let lhs = lhs.view();
-        let mut grad_lhs = grad_lhs.view_mut();
-        let rhs = rhs.view().tr();
-        let mut grad_rhs = grad_rhs.view_mut();
-        let grad_out = grad_out.view();
-        for b in 0..batch_size {
-            let go = grad_out.idx(b);
-            matmul(go, rhs, &mut grad_lhs.idx_mut(b));
-            matmul(lhs.idx(b).tr(), go, &mut grad_rhs);
+
+        impl super::MatMatKernel<$Dtype> for Cpu {
+            fn forward<M: Dim, const K: usize, N: Dim>(
+                &self,
+                lhs: &Self::Storage<(M, Const<K>), $Dtype>,
+                rhs: &Self::Storage<(Const<K>, N), $Dtype>,
+            ) -> Result<Self::Storage<(M, N), $Dtype>, Self::Err> {
+                let mut out = StridedArray::new((lhs.shape.0, rhs.shape.1))?;
+                matmul(lhs.view(), rhs.view(), &mut out.view_mut());
+                Ok(out)
+            }
+            fn backward<M: Dim, const K: usize, N: Dim>(
+                &self,
+                lhs: &Self::Storage<(M, Const<K>), $Dtype>,
+                grad_lhs: &mut Self::Storage<(M, Const<K>), $Dtype>,
+                rhs: &Self::Storage<(Const<K>, N), $Dtype>,
+                grad_rhs: &mut Self::Storage<(Const<K>, N), $Dtype>,
+                grad_out: &Self::Storage<(M, N), $Dtype>,
+            ) -> Result<(), Self::Err> {
+                let grad_out = grad_out.view();
+                matmul(grad_out, rhs.view().tr(), &mut grad_lhs.view_mut());
+                matmul(lhs.view().tr(), grad_out, &mut grad_rhs.view_mut());
+                Ok(())
+            }
         }
-        Ok(())
-    }
-}
 
-impl super::MatMatBatch3Kernel<f32> for Cpu {
-    fn forward<const B: usize, M: Dim, const K: usize, N: Dim>(
-        &self,
-        lhs: &Self::Storage<(Const<B>, M, Const<K>), f32>,
-        rhs: &Self::Storage<(Const<B>, Const<K>, N), f32>,
-    ) -> Result<Self::Storage<(Const<B>, M, N), f32>, Self::Err> {
-        let m: M = lhs.shape().1;
-        let n: N = rhs.shape().2;
-        let mut out = StridedArray::new((Const, m, n))?;
-        let a = lhs.view();
-        let b = rhs.view();
-        let mut c = out.view_mut();
-        for batch in 0..B {
-            matmul(a.idx(batch), b.idx(batch), &mut c.idx_mut(batch));
+        impl super::MatMatBiasKernel<$Dtype> for Cpu {
+            fn forward<M: Dim, const K: usize, N: Dim>(
+                &self,
+                lhs: &Self::Storage<(M, Const<K>), $Dtype>,
+                rhs: &Self::Storage<(Const<K>, N), $Dtype>,
+                bias: &Self::Storage<(N,), $Dtype>,
+            ) -> Result<Self::Storage<(M, N), $Dtype>, Self::Err> {
+                let mut out = StridedArray::new((lhs.shape.0, rhs.shape.1))?;
+                matmul(lhs.view(), rhs.view(), &mut out.view_mut());
+                let mut out_iter = out.iter_mut_with_index();
+                while let Some((o, [_, j])) = out_iter.next() {
+                    *o += bias[[j]];
+                }
+                Ok(out)
+            }
+            fn backward_bias<M: Dim, N: Dim>(
+                &self,
+                grad_bias: &mut Self::Storage<(N,), $Dtype>,
+                grad_out: &Self::Storage<(M, N), $Dtype>,
+            ) -> Result<(), Self::Err> {
+                let mut out_iter = grad_out.iter_with_index();
+                while let Some((go, [_, j])) = out_iter.next() {
+                    grad_bias[[j]] += *go;
+                }
+                Ok(())
+            }
         }
-        Ok(out)
-    }
-    fn backward<const B: usize, M: Dim, const K: usize, N: Dim>(
-        &self,
-        lhs: &Self::Storage<(Const<B>, M, Const<K>), f32>,
-        grad_lhs: &mut Self::Storage<(Const<B>, M, Const<K>), f32>,
-        rhs: &Self::Storage<(Const<B>, Const<K>, N), f32>,
-        grad_rhs: &mut Self::Storage<(Const<B>, Const<K>, N), f32>,
-        grad_out: &Self::Storage<(Const<B>, M, N), f32>,
-    ) -> Result<(), Self::Err> {
-        let lhs = lhs.view();
-        let mut grad_lhs = grad_lhs.view_mut();
-        let rhs = rhs.view();
-        let mut grad_rhs = grad_rhs.view_mut();
-        let grad_out = grad_out.view();
-        for b in 0..B {
-            let go = grad_out.idx(b);
-            matmul(go, rhs.idx(b).tr(), &mut grad_lhs.idx_mut(b));
-            matmul(lhs.idx(b).tr(), go, &mut grad_rhs.idx_mut(b));
+
+        impl super::MatMatBrKernel<$Dtype> for Cpu {
+            fn forward<B: Dim, M: Dim, const K: usize, N: Dim>(
+                &self,
+                lhs: &Self::Storage<(B, M, Const<K>), $Dtype>,
+                rhs: &Self::Storage<(Const<K>, N), $Dtype>,
+            ) -> Result<Self::Storage<(B, M, N), $Dtype>, Self::Err> {
+                let (batch, seq, _) = *lhs.shape();
+                let (_, n) = *rhs.shape();
+                let mut out = StridedArray::new((batch, seq, n))?;
+                let a = lhs.view();
+                let b = rhs.view();
+                let mut c = out.view_mut();
+                for batch in 0..batch.size() {
+                    matmul(a.idx(batch), b, &mut c.idx_mut(batch));
+                }
+                Ok(out)
+            }
+            fn backward<B: Dim, M: Dim, const K: usize, N: Dim>(
+                &self,
+                lhs: &Self::Storage<(B, M, Const<K>), $Dtype>,
+                grad_lhs: &mut Self::Storage<(B, M, Const<K>), $Dtype>,
+                rhs: &Self::Storage<(Const<K>, N), $Dtype>,
+                grad_rhs: &mut Self::Storage<(Const<K>, N), $Dtype>,
+                grad_out: &Self::Storage<(B, M, N), $Dtype>,
+            ) -> Result<(), Self::Err> {
+                let batch_size = lhs.shape().0.size();
+                let lhs = lhs.view();
+                let mut grad_lhs = grad_lhs.view_mut();
+                let rhs = rhs.view().tr();
+                let mut grad_rhs = grad_rhs.view_mut();
+                let grad_out = grad_out.view();
+                for b in 0..batch_size {
+                    let go = grad_out.idx(b);
+                    matmul(go, rhs, &mut grad_lhs.idx_mut(b));
+                    matmul(lhs.idx(b).tr(), go, &mut grad_rhs);
+                }
+                Ok(())
+            }
         }
-        Ok(())
-    }
-}
 
-impl super::MatMatBatch4Kernel<f32> for Cpu {
-    fn forward<const B: usize, const S: usize, M: Dim, const K: usize, N: Dim>(
-        &self,
-        lhs: &Self::Storage<(Const<B>, Const<S>, M, Const<K>), f32>,
-        rhs: &Self::Storage<(Const<B>, Const<S>, Const<K>, N), f32>,
-    ) -> Result<Self::Storage<(Const<B>, Const<S>, M, N), f32>, Self::Err> {
-        let m: M = lhs.shape.2;
-        let n: N = rhs.shape.3;
-        let mut out = StridedArray::new((Const, Const, m, n))?;
-        let lhs = lhs.view();
-        let rhs = rhs.view();
-        let mut out_view = out.view_mut();
-        for b in 0..B {
-            let l_b = lhs.idx(b);
-            let r_b = rhs.idx(b);
-            let mut o_b = out_view.idx_mut(b);
-            for s in 0..S {
-                matmul(l_b.idx(s), r_b.idx(s), &mut o_b.idx_mut(s));
+        impl super::MatMatBatch3Kernel<$Dtype> for Cpu {
+            fn forward<const B: usize, M: Dim, const K: usize, N: Dim>(
+                &self,
+                lhs: &Self::Storage<(Const<B>, M, Const<K>), $Dtype>,
+                rhs: &Self::Storage<(Const<B>, Const<K>, N), $Dtype>,
+            ) -> Result<Self::Storage<(Const<B>, M, N), $Dtype>, Self::Err> {
+                let m: M = lhs.shape().1;
+                let n: N = rhs.shape().2;
+                let mut out = StridedArray::new((Const, m, n))?;
+                let a = lhs.view();
+                let b = rhs.view();
+                let mut c = out.view_mut();
+                for batch in 0..B {
+                    matmul(a.idx(batch), b.idx(batch), &mut c.idx_mut(batch));
+                }
+                Ok(out)
+            }
+            fn backward<const B: usize, M: Dim, const K: usize, N: Dim>(
+                &self,
+                lhs: &Self::Storage<(Const<B>, M, Const<K>), $Dtype>,
+                grad_lhs: &mut Self::Storage<(Const<B>, M, Const<K>), $Dtype>,
+                rhs: &Self::Storage<(Const<B>, Const<K>, N), $Dtype>,
+                grad_rhs: &mut Self::Storage<(Const<B>, Const<K>, N), $Dtype>,
+                grad_out: &Self::Storage<(Const<B>, M, N), $Dtype>,
+            ) -> Result<(), Self::Err> {
+                let lhs = lhs.view();
+                let mut grad_lhs = grad_lhs.view_mut();
+                let rhs = rhs.view();
+                let mut grad_rhs = grad_rhs.view_mut();
+                let grad_out = grad_out.view();
+                for b in 0..B {
+                    let go = grad_out.idx(b);
+                    matmul(go, rhs.idx(b).tr(), &mut grad_lhs.idx_mut(b));
+                    matmul(lhs.idx(b).tr(), go, &mut grad_rhs.idx_mut(b));
+                }
+                Ok(())
             }
         }
-        Ok(out)
-    }
-    fn backward<const B: usize, const S: usize, M: Dim, const K: usize, N: Dim>(
-        &self,
-        lhs: &Self::Storage<(Const<B>, Const<S>, M, Const<K>), f32>,
-        grad_lhs: &mut Self::Storage<(Const<B>, Const<S>, M, Const<K>), f32>,
-        rhs: &Self::Storage<(Const<B>, Const<S>, Const<K>, N), f32>,
-        grad_rhs: &mut Self::Storage<(Const<B>, Const<S>, Const<K>, N), f32>,
-        grad_out: &Self::Storage<(Const<B>, Const<S>, M, N), f32>,
-    ) -> Result<(), Self::Err> {
-        let lhs = lhs.view();
-        let mut grad_lhs = grad_lhs.view_mut();
-        let rhs = rhs.view();
-        let mut grad_rhs = grad_rhs.view_mut();
-        let grad_out = grad_out.view();
-        for b in 0..B {
-            let l_b = lhs.idx(b);
-            let mut gl_b = grad_lhs.idx_mut(b);
-            let r_b = rhs.idx(b);
-            let mut gr_b = grad_rhs.idx_mut(b);
-            let go_b = grad_out.idx(b);
-            for s in 0..S {
-                matmul(go_b.idx(s), r_b.idx(s).tr(), &mut gl_b.idx_mut(s));
-                matmul(l_b.idx(s).tr(), go_b.idx(s), &mut gr_b.idx_mut(s));
+
+        impl super::MatMatBatch4Kernel<$Dtype> for Cpu {
+            fn forward<const B: usize, const S: usize, M: Dim, const K: usize, N: Dim>(
+                &self,
+                lhs: &Self::Storage<(Const<B>, Const<S>, M, Const<K>), $Dtype>,
+                rhs: &Self::Storage<(Const<B>, Const<S>, Const<K>, N), $Dtype>,
+            ) -> Result<Self::Storage<(Const<B>, Const<S>, M, N), $Dtype>, Self::Err> {
+                let m: M = lhs.shape.2;
+                let n: N = rhs.shape.3;
+                let mut out = StridedArray::new((Const, Const, m, n))?;
+                let lhs = lhs.view();
+                let rhs = rhs.view();
+                let mut out_view = out.view_mut();
+                for b in 0..B {
+                    let l_b = lhs.idx(b);
+                    let r_b = rhs.idx(b);
+                    let mut o_b = out_view.idx_mut(b);
+                    for s in 0..S {
+                        matmul(l_b.idx(s), r_b.idx(s), &mut o_b.idx_mut(s));
+                    }
+                }
+                Ok(out)
+            }
+            fn backward<const B: usize, const S: usize, M: Dim, const K: usize, N: Dim>(
+                &self,
+                lhs: &Self::Storage<(Const<B>, Const<S>, M, Const<K>), $Dtype>,
+                grad_lhs: &mut Self::Storage<(Const<B>, Const<S>, M, Const<K>), $Dtype>,
+                rhs: &Self::Storage<(Const<B>, Const<S>, Const<K>, N), $Dtype>,
+                grad_rhs: &mut Self::Storage<(Const<B>, Const<S>, Const<K>, N), $Dtype>,
+                grad_out: &Self::Storage<(Const<B>, Const<S>, M, N), $Dtype>,
+            ) -> Result<(), Self::Err> {
+                let lhs = lhs.view();
+                let mut grad_lhs = grad_lhs.view_mut();
+                let rhs = rhs.view();
+                let mut grad_rhs = grad_rhs.view_mut();
+                let grad_out = grad_out.view();
+                for b in 0..B {
+                    let l_b = lhs.idx(b);
+                    let mut gl_b = grad_lhs.idx_mut(b);
+                    let r_b = rhs.idx(b);
+                    let mut gr_b = grad_rhs.idx_mut(b);
+                    let go_b = grad_out.idx(b);
+                    for s in 0..S {
+                        matmul(go_b.idx(s), r_b.idx(s).tr(), &mut gl_b.idx_mut(s));
+                        matmul(l_b.idx(s).tr(), go_b.idx(s), &mut gr_b.idx_mut(s));
+                    }
+                }
+                Ok(())
             }
         }
-        Ok(())
-    }
+    };
 }
+
+impl_matmul_kernels!(f32);
+#[cfg(feature = "f64")]
+impl_matmul_kernels!(f64);
@@ -8,13 +8,19 @@ use cudarc::{
         result::CublasError, sys::cublasOperation_t, CudaBlas, Gemm, GemmConfig,
         StridedBatchedConfig,
     },
-    driver::{DevicePtr, DevicePtrMut},
+    driver::{DevicePtr, DevicePtrMut, LaunchAsync, LaunchConfig},
 };
 use std::sync::Arc;
 
 const TRANS: cublasOperation_t = cublasOperation_t::CUBLAS_OP_T;
 const NO_TRANS: cublasOperation_t = cublasOperation_t::CUBLAS_OP_N;
 
+const BIAS_MODULE_NAME: &str = "matmul_bias";
+const BIAS_FWD_FN_NAME: &str = "matmul_bias_forward";
+const BIAS_BWD_FN_NAME: &str = "matmul_bias_backward";
+const BIAS_ALL_FN_NAMES: [&str; 2] = [BIAS_FWD_FN_NAME, BIAS_BWD_FN_NAME];
+const BIAS_PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/matmul_bias.ptx"));
+
 fn sgemm_config<M: Dim, K: Dim, N: Dim>(
     (m, k, n): (M, K, N),
     lhs_strides: [usize; 2],
@@ -181,7 +187,7 @@ impl super::VecVecKernel<f32> for Cuda {
         }
 
         Ok(CudaArray {
-            data: Arc::new(storage),
+            data: Arc::new(storage.into()),
             shape,
             strides: shape.strides(),
         })
@@ -256,7 +262,7 @@ impl super::VecMatKernel<f32> for Cuda {
         }
 
         Ok(CudaArray {
-            data: Arc::new(storage),
+            data: Arc::new(storage.into()),
             shape,
             strides: shape.strides(),
         })
@@ -329,7 +335,7 @@ impl super::MatMatKernel<f32> for Cuda {
         }?;
 
         Ok(CudaArray {
-            data: Arc::new(storage),
+            data: Arc::new(storage.into()),
             shape,
             strides: shape.strides(),
         })
@@ -376,6 +382,84 @@ impl super::MatMatKernel<f32> for Cuda {
     }
 }
 
+impl super::MatMatBiasKernel<f32> for Cuda {
+    fn forward<M: Dim, const K: usize, N: Dim>(
+        &self,
+        lhs: &Self::Storage<(M, Const<K>), f32>,
+        rhs: &Self::Storage<(Const<K>, N), f32>,
+        bias: &Self::Storage<(N,), f32>,
+    ) -> Result<Self::Storage<(M, N), f32>, Self::Err> {
+        if !self.dev.has_func(BIAS_MODULE_NAME, BIAS_FWD_FN_NAME) {
+            self.dev
+                .load_ptx(BIAS_PTX_SRC.into(), BIAS_MODULE_NAME, &BIAS_ALL_FN_NAMES)?;
+        }
+
+        let (m, _) = lhs.shape;
+        let (k, n) = rhs.shape;
+        let shape = (m, n);
+        let strides = shape.strides();
+        let numel = shape.num_elements();
+        let mut storage = self.dev.alloc_zeros_async::<f32>(numel)?;
+
+        let fwd_fn = self
+            .dev
+            .get_func(BIAS_MODULE_NAME, BIAS_FWD_FN_NAME)
+            .unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,              // const size_t numel,
+            n.size(),           // const size_t n,
+            bias.strides[0],    // const size_t bias_stride,
+            bias.data.as_ref(), // const float *bias,
+            &mut storage,       // float *out
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+
+        // out = lhs * rhs + out, where out already holds the broadcasted bias
+        unsafe {
+            sgemm(
+                self.blas.as_ref(),
+                (m, k, n),
+                lhs.data.as_ref(),
+                lhs.strides,
+                rhs.data.as_ref(),
+                rhs.strides,
+                1.0,
+                &mut storage,
+                strides,
+            )
+        }?;
+
+        Ok(CudaArray {
+            data: Arc::new(storage.into()),
+            shape,
+            strides,
+        })
+    }
+
+    fn backward_bias<M: Dim, N: Dim>(
+        &self,
+        grad_bias: &mut Self::Storage<(N,), f32>,
+        grad_out: &Self::Storage<(M, N), f32>,
+    ) -> Result<(), Self::Err> {
+        let bwd_fn = self
+            .dev
+            .get_func(BIAS_MODULE_NAME, BIAS_BWD_FN_NAME)
+            .unwrap();
+        let numel = grad_out.shape.num_elements();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,                              // const size_t numel,
+            grad_out.shape.1.size(),            // const size_t n,
+            grad_bias.strides[0],               // const size_t bias_stride,
+            Arc::make_mut(&mut grad_bias.data), // float *grad_bias,
+            grad_out.data.as_ref(),             // const float *grad_out
+        );
+        unsafe { bwd_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+}
+
 impl super::MatMatBrKernel<f32> for Cuda {
     fn forward<B: Dim, M: Dim, const K: usize, N: Dim>(
         &self,
@@ -402,7 +486,7 @@ impl super::MatMatBrKernel<f32> for Cuda {
             )?;
         }
         Ok(CudaArray {
-            data: Arc::new(storage),
+            data: Arc::new(storage.into()),
             shape,
             strides,
         })
@@ -481,7 +565,7 @@ impl super::MatMatBatch3Kernel<f32> for Cuda {
             )?;
         }
         Ok(CudaArray {
-            data: Arc::new(storage),
+            data: Arc::new(storage.into()),
             shape,
             strides,
         })
@@ -558,7 +642,7 @@ impl super::MatMatBatch4Kernel<f32> for Cuda {
             }
         }
         Ok(CudaArray {
-            data: Arc::new(storage),
+            data: Arc::new(storage.into()),
             shape,
             strides,
         })
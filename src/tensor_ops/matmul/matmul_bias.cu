@@ -0,0 +1,30 @@
+// Writes `bias` into every row of `out`, which is a contiguous (M, N) matrix.
+// The gemm call then accumulates `lhs * rhs` on top of it.
+extern "C" __global__ void matmul_bias_forward(
+    const size_t numel,
+    const size_t n,
+    const size_t bias_stride,
+    const float *bias,
+    float *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+    out[i] = bias[(i % n) * bias_stride];
+}
+
+// grad_out is a contiguous (M, N) matrix, and every row is summed into grad_bias.
+extern "C" __global__ void matmul_bias_backward(
+    const size_t numel,
+    const size_t n,
+    const size_t bias_stride,
+    float *grad_bias,
+    const float *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+    atomicAdd(grad_bias + (i % n) * bias_stride, grad_out[i]);
+}
@@ -5,9 +5,10 @@ pub(super) mod cpu_kernel;
 #[cfg(feature = "cuda")]
 pub(super) mod cuda_kernel;
 
+use super::{BroadcastTo, Device, SumTo, TryAdd};
 use crate::{
     gradients::{Merge, Tape},
-    shapes::{Const, Dim, Dtype, Shape},
+    shapes::{Const, Dim, Dtype, HasShape, Rank0, Shape},
     tensor::{DeviceStorage, HasErr, PutTape, SplitTape, Tensor},
 };
 
@@ -41,7 +42,8 @@ use crate::{
 /// let _: Tensor<Rank2<2, 4>, f32, _> = x.matmul(y);
 /// ```
 ///
-/// 4. Batched matmul
+/// 4. Batched matmul. The batch dimension is shared, and each batch item is multiplied
+///    independently, like pytorch's `torch.bmm`.
 /// ```rust
 /// # use dfdx::prelude::*;
 /// # let dev: Cpu = Default::default();
@@ -65,6 +67,44 @@ where
     lhs.matmul(rhs)
 }
 
+/// Outer product of two vectors: `out[i, j] = lhs[i] * rhs[j]`. This is the same
+/// as the Vector x Vector case of [matmul].
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([1.0, 2.0]);
+/// let b = dev.tensor([3.0, 4.0, 5.0]);
+/// let r = outer(a, b);
+/// assert_eq!(r.array(), [[3.0, 4.0, 5.0], [6.0, 8.0, 10.0]]);
+/// ```
+pub fn outer<M: Dim, N: Dim, E: Dtype, D: VecVecKernel<E>, T: Tape<D> + Merge<R>, R: Tape<D>>(
+    lhs: Tensor<(M,), E, D, T>,
+    rhs: Tensor<(N,), E, D, R>,
+) -> Tensor<(M, N), E, D, T> {
+    lhs.matmul(rhs)
+}
+
+/// Dot product of two vectors: `out = sum_i lhs[i] * rhs[i]`.
+///
+/// Note that this is **not** the Vector x Vector case of [matmul], which computes
+/// the [outer] product.
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a = dev.tensor([1.0, 2.0, 3.0]);
+/// let b = dev.tensor([4.0, 5.0, 6.0]);
+/// let r = dot(a, b);
+/// assert_eq!(r.array(), 32.0);
+/// ```
+pub fn dot<N: Dim, E: Dtype, D: Device<E>, T: Tape<D> + Merge<R>, R: Tape<D>>(
+    lhs: Tensor<(N,), E, D, T>,
+    rhs: Tensor<(N,), E, D, R>,
+) -> Tensor<Rank0, E, D, T> {
+    (lhs * rhs).sum()
+}
+
 /// Fallible matrix multiplication. See [matmul] for examples.
 pub trait TryMatMul<Rhs>: HasErr {
     type Output;
@@ -74,6 +114,39 @@ pub trait TryMatMul<Rhs>: HasErr {
     fn try_matmul(self, rhs: Rhs) -> Result<Self::Output, Self::Err>;
 }
 
+/// Matrix multiplication followed by adding a bias vector to every row of the
+/// result: `lhs * rhs + bias`. This is what [crate::nn::Linear] uses.
+///
+/// For the batched matrix case, `(M, K) * (K, N) + (N,)`, this is a fused op that
+/// never materializes the intermediate product. On cuda this is a single gemm call that
+/// accumulates into the broadcasted bias. The vector and 3d cases are [matmul] followed by
+/// [add()](crate::tensor_ops::add).
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let x = dev.tensor([[1.0, 2.0], [3.0, 4.0]]);
+/// let w = dev.tensor([[1.0, 0.0, 1.0], [0.0, 1.0, 1.0]]);
+/// let b = dev.tensor([0.5, -0.5, 0.0]);
+/// let r = matmul_bias(x, w, b);
+/// assert_eq!(r.array(), [[1.5, 1.5, 3.0], [3.5, 3.5, 7.0]]);
+/// ```
+pub fn matmul_bias<Lhs, Rhs, Bias>(lhs: Lhs, rhs: Rhs, bias: Bias) -> Lhs::Output
+where
+    Lhs: TryMatMulBias<Rhs, Bias>,
+{
+    lhs.matmul_bias(rhs, bias)
+}
+
+/// Fallible matrix multiplication plus bias. See [matmul_bias] for examples.
+pub trait TryMatMulBias<Rhs, Bias>: HasErr {
+    type Output;
+    fn matmul_bias(self, rhs: Rhs, bias: Bias) -> Self::Output {
+        self.try_matmul_bias(rhs, bias).unwrap()
+    }
+    fn try_matmul_bias(self, rhs: Rhs, bias: Bias) -> Result<Self::Output, Self::Err>;
+}
+
 #[rustfmt::skip]
 fn try_binary_op<
     Lhs: Shape,
@@ -187,6 +260,100 @@ where
     }
 }
 
+pub trait MatMatBiasKernel<E: Dtype>: DeviceStorage {
+    /// Computes `lhs * rhs + bias` without materializing `lhs * rhs`.
+    fn forward<M: Dim, const K: usize, N: Dim>(
+        &self,
+        lhs: &Self::Storage<(M, Const<K>), E>,
+        rhs: &Self::Storage<(Const<K>, N), E>,
+        bias: &Self::Storage<(N,), E>,
+    ) -> Result<Self::Storage<(M, N), E>, Self::Err>;
+
+    /// Accumulates the gradient of `bias`. The gradients of `lhs` and `rhs`
+    /// are the same as [MatMatKernel::backward()].
+    fn backward_bias<M: Dim, N: Dim>(
+        &self,
+        grad_bias: &mut Self::Storage<(N,), E>,
+        grad_out: &Self::Storage<(M, N), E>,
+    ) -> Result<(), Self::Err>;
+}
+
+impl<M: Dim, const K: usize, N: Dim, E: Dtype, D, T, R>
+    TryMatMulBias<Tensor<(Const<K>, N), E, D, R>, Tensor<(N,), E, D, R>>
+    for Tensor<(M, Const<K>), E, D, T>
+where
+    D: MatMatKernel<E> + MatMatBiasKernel<E>,
+    T: Tape<D> + Merge<R>,
+    R: Tape<D>,
+{
+    type Output = Tensor<(M, N), E, D, T>;
+    fn try_matmul_bias(
+        self,
+        rhs: Tensor<(Const<K>, N), E, D, R>,
+        bias: Tensor<(N,), E, D, R>,
+    ) -> Result<Self::Output, Self::Err> {
+        let (lhs, ltape) = self.split_tape();
+        let (rhs, rtape) = rhs.split_tape();
+        let (bias, btape) = bias.split_tape();
+        let mut tape = ltape.merge(rtape).merge(btape);
+        let storage =
+            MatMatBiasKernel::forward(&lhs.device, &lhs.storage, &rhs.storage, &bias.storage)?;
+        let out = lhs.device.upgrade(storage);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&lhs)?;
+        tape.try_alloc_grad(&rhs)?;
+        tape.try_alloc_grad(&bias)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_bias, grad_out) = grads.mut_and_ref(&bias, &phantom_out);
+            bias.device.backward_bias(grad_bias, grad_out)?;
+            let (grad_lhs, grad_rhs, grad_out) = grads.muts_and_ref(&lhs, &rhs, &phantom_out);
+            MatMatKernel::backward(
+                &lhs.device,
+                &lhs.storage,
+                grad_lhs,
+                &rhs.storage,
+                grad_rhs,
+                grad_out,
+            )
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+impl<const K: usize, N: Dim, E: Dtype, D: Device<E>, T: Tape<D> + Merge<R>, R: Tape<D>>
+    TryMatMulBias<Tensor<(Const<K>, N), E, D, R>, Tensor<(N,), E, D, R>>
+    for Tensor<(Const<K>,), E, D, T>
+{
+    type Output = Tensor<(N,), E, D, T>;
+    fn try_matmul_bias(
+        self,
+        rhs: Tensor<(Const<K>, N), E, D, R>,
+        bias: Tensor<(N,), E, D, R>,
+    ) -> Result<Self::Output, Self::Err> {
+        self.try_matmul(rhs)?.try_add(bias)
+    }
+}
+
+impl<B: Dim, M: Dim, const K: usize, N: Dim, E: Dtype, D: Device<E>, T, R>
+    TryMatMulBias<Tensor<(Const<K>, N), E, D, R>, Tensor<(N,), E, D, R>>
+    for Tensor<(B, M, Const<K>), E, D, T>
+where
+    T: Tape<D> + Merge<R>,
+    R: Tape<D>,
+{
+    type Output = Tensor<(B, M, N), E, D, T>;
+    fn try_matmul_bias(
+        self,
+        rhs: Tensor<(Const<K>, N), E, D, R>,
+        bias: Tensor<(N,), E, D, R>,
+    ) -> Result<Self::Output, Self::Err> {
+        let out = self.try_matmul(rhs)?;
+        let bias = bias.try_broadcast_like(out.shape())?;
+        out.try_add(bias)
+    }
+}
+
 pub trait MatMatBrKernel<E: Dtype>: DeviceStorage {
     fn forward<B: Dim, M: Dim, const K: usize, N: Dim>(
         &self,
@@ -429,8 +596,8 @@ mod tests {
     fn test_matmul_broadcast_actual() {
         const N: usize = 5;
         let dev: TestDevice = Default::default();
-        let a = dev.sample_normal::<Rank3<N, 4, 3>>();
-        let b = dev.sample_normal::<Rank2<3, 2>>();
+        let a: Tensor<Rank3<N, 4, 3>, f32, _> = dev.sample_normal();
+        let b: Tensor<Rank2<3, 2>, f32, _> = dev.sample_normal();
         let b_up = dev.tensor([b.array(); N]);
         let r1 = a.trace().matmul(b_up.clone());
         let r2 = a.trace().matmul(b.clone());
@@ -472,6 +639,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_matmul_batched_3d_against_2d() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank3<2, 2, 2>, f32, _> =
+            dev.tensor([[[1.0, 2.0], [3.0, 4.0]], [[0.0, 1.0], [1.0, 0.0]]]);
+        let b: Tensor<Rank3<2, 2, 2>, f32, _> =
+            dev.tensor([[[1.0, 0.0], [0.0, 1.0]], [[2.0, 3.0], [4.0, 5.0]]]);
+        let c = a.trace().matmul(b.clone());
+        assert_eq!(
+            c.array(),
+            [[[1.0, 2.0], [3.0, 4.0]], [[4.0, 5.0], [2.0, 3.0]]]
+        );
+        let g = c.sum().backward();
+        assert_eq!(
+            g.get(&a).array(),
+            [[[1.0, 1.0], [1.0, 1.0]], [[5.0, 9.0], [5.0, 9.0]]]
+        );
+        assert_eq!(
+            g.get(&b).array(),
+            [[[4.0, 4.0], [6.0, 6.0]], [[1.0, 1.0], [1.0, 1.0]]]
+        );
+
+        for i in 0..2 {
+            let sub_a = dev.tensor(a.array()[i]);
+            let sub_b = dev.tensor(b.array()[i]);
+            let sub_c = sub_a.trace().matmul(sub_b.clone());
+            let sub_g = sub_c.sum().backward();
+            assert_eq!(sub_g.get(&sub_a).array(), g.get(&a).array()[i]);
+            assert_eq!(sub_g.get(&sub_b).array(), g.get(&b).array()[i]);
+        }
+    }
+
     #[test]
     fn test_matmul_batched_4d() {
         let dev: TestDevice = Default::default();
@@ -571,6 +770,33 @@ mod tests {
         assert_close(&g.get(&b).array(), &[-0.13630435, -1.6781758, -0.75171506]);
     }
 
+    #[test]
+    fn test_outer() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank1<2>, f32, _> = dev.tensor([1.0, 2.0]);
+        let b: Tensor<Rank1<3>, f32, _> = dev.tensor([3.0, 4.0, 5.0]);
+        let r = outer(a.trace(), b.trace());
+        assert_eq!(r.array(), [[3.0, 4.0, 5.0], [6.0, 8.0, 10.0]]);
+        let w = dev.tensor([[1.0, 0.0, -1.0], [0.0, 2.0, 0.0]]);
+        let g = (r * w).sum().backward();
+        // d/da_i = sum_j w_ij * b_j
+        assert_eq!(g.get(&a).array(), [-2.0, 8.0]);
+        // d/db_j = sum_i w_ij * a_i
+        assert_eq!(g.get(&b).array(), [1.0, 4.0, -1.0]);
+    }
+
+    #[test]
+    fn test_dot() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank1<3>, f32, _> = dev.tensor([1.0, 2.0, 3.0]);
+        let b: Tensor<Rank1<3>, f32, _> = dev.tensor([4.0, 5.0, 6.0]);
+        let r = dot(a.trace(), b.trace());
+        assert_eq!(r.array(), 32.0);
+        let g = (r * 2.0).backward();
+        assert_eq!(g.get(&a).array(), [8.0, 10.0, 12.0]);
+        assert_eq!(g.get(&b).array(), [2.0, 4.0, 6.0]);
+    }
+
     #[test]
     fn test_small_matmul_vv() {
         let dev: TestDevice = Default::default();
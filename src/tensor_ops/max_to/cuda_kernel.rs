@@ -58,7 +58,7 @@ impl super::MaxReduceKernel<f32> for Cuda {
         );
         unsafe { fwd_fn.launch_async(cfg, params) }?;
         Ok(CudaArray {
-            data: Arc::new(storage),
+            data: Arc::new(storage.into()),
             shape: dst,
             strides: dst.strides(),
         })
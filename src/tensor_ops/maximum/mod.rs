@@ -14,6 +14,10 @@ pub struct MaximumKernelOp;
 ///
 /// **Pytorch equivalent**: `torch.maximum(a, b)`
 ///
+/// The gradient flows to the larger of the two inputs at each position, and is split
+/// evenly between them when they are equal. Both inputs must have the same shape,
+/// use [crate::tensor_ops::BroadcastTo] to maximum against a lower rank tensor.
+///
 /// Example:
 /// ```rust
 /// # use dfdx::prelude::*;
@@ -22,6 +26,7 @@ pub struct MaximumKernelOp;
 /// let b = dev.tensor([[1.0, 0.5, 1.0], [-2.0, 2.0, -3.5]]);
 /// let r = a.maximum(b);
 /// assert_eq!(r.array(), [[1.0, 2.0, 3.0], [-1.0, 2.0, -3.0]]);
+/// ```
 pub fn maximum<S: Shape, E: Dtype, D: Device<E>, LTape: Tape<D> + Merge<RTape>, RTape: Tape<D>>(
     lhs: Tensor<S, E, D, LTape>,
     rhs: Tensor<S, E, D, RTape>,
@@ -64,4 +69,23 @@ mod tests {
         assert_eq!(g.get(&a).array(), [[0.0, 0.5, 1.0], [0.5, 1.0, 0.0]]);
         assert_eq!(g.get(&b).array(), [[1.0, 0.5, 0.0], [0.5, 0.0, 1.0]]);
     }
+
+    #[test]
+    fn test_maximum_1d_and_broadcast() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor([1.0, 4.0, 3.0]);
+        let b = dev.tensor([2.0, 2.0, 5.0]);
+        let r = a.trace().maximum(b.trace());
+        assert_eq!(r.array(), [2.0, 4.0, 5.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&a).array(), [0.0, 1.0, 0.0]);
+        assert_eq!(g.get(&b).array(), [1.0, 0.0, 1.0]);
+
+        let a = dev.tensor([[1.0, 4.0, 3.0], [3.0, 0.0, -1.0]]);
+        let r = a.trace().maximum(b.trace().broadcast());
+        assert_eq!(r.array(), [[2.0, 4.0, 5.0], [3.0, 2.0, 5.0]]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&a).array(), [[0.0, 1.0, 0.0], [1.0, 0.0, 0.0]]);
+        assert_eq!(g.get(&b).array(), [1.0, 1.0, 2.0]);
+    }
 }
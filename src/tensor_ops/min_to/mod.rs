@@ -89,16 +89,16 @@ mod tests {
     #[test]
     fn test_min_valid_axes() {
         let dev: TestDevice = Default::default();
-        let _ = dev.zeros::<Rank1<5>>().min::<Rank0, _>();
-        let _ = dev.zeros::<Rank2<5, 3>>().min::<Rank1<3>, _>();
-        let _ = dev.zeros::<Rank2<5, 3>>().min::<Rank1<5>, _>();
-        let _ = dev.zeros::<Rank3<7, 5, 3>>().min::<Rank2<5, 3>, _>();
-        let _ = dev.zeros::<Rank3<7, 5, 3>>().min::<Rank2<7, 3>, _>();
-        let _ = dev.zeros::<Rank3<7, 5, 3>>().min::<Rank2<7, 5>, _>();
-        let _ = dev.zeros::<Rank4<9, 7, 5, 3>>().min::<Rank3<7, 5, 3>, _>();
-        let _ = dev.zeros::<Rank4<9, 7, 5, 3>>().min::<Rank3<9, 5, 3>, _>();
-        let _ = dev.zeros::<Rank4<9, 7, 5, 3>>().min::<Rank3<9, 7, 3>, _>();
-        let _ = dev.zeros::<Rank4<9, 7, 5, 3>>().min::<Rank3<9, 7, 5>, _>();
+        let _: Tensor<Rank0, f32, _> = dev.zeros::<Rank1<5>>().min();
+        let _: Tensor<Rank1<3>, f32, _> = dev.zeros::<Rank2<5, 3>>().min();
+        let _: Tensor<Rank1<5>, f32, _> = dev.zeros::<Rank2<5, 3>>().min();
+        let _: Tensor<Rank2<5, 3>, f32, _> = dev.zeros::<Rank3<7, 5, 3>>().min();
+        let _: Tensor<Rank2<7, 3>, f32, _> = dev.zeros::<Rank3<7, 5, 3>>().min();
+        let _: Tensor<Rank2<7, 5>, f32, _> = dev.zeros::<Rank3<7, 5, 3>>().min();
+        let _: Tensor<Rank3<7, 5, 3>, f32, _> = dev.zeros::<Rank4<9, 7, 5, 3>>().min();
+        let _: Tensor<Rank3<9, 5, 3>, f32, _> = dev.zeros::<Rank4<9, 7, 5, 3>>().min();
+        let _: Tensor<Rank3<9, 7, 3>, f32, _> = dev.zeros::<Rank4<9, 7, 5, 3>>().min();
+        let _: Tensor<Rank3<9, 7, 5>, f32, _> = dev.zeros::<Rank4<9, 7, 5, 3>>().min();
     }
 
     #[test]
@@ -14,6 +14,10 @@ pub struct MinimumKernelOp;
 ///
 /// **Pytorch equivalent**: `torch.minimum(a, b)`
 ///
+/// The gradient flows to the smaller of the two inputs at each position, and is split
+/// evenly between them when they are equal. Both inputs must have the same shape,
+/// use [crate::tensor_ops::BroadcastTo] to minimum against a lower rank tensor.
+///
 /// Example:
 /// ```rust
 /// # use dfdx::prelude::*;
@@ -22,6 +26,7 @@ pub struct MinimumKernelOp;
 /// let b = dev.tensor([[1.0, 0.5, 1.0], [-2.0, 2.0, -3.5]]);
 /// let r = a.minimum(b);
 /// assert_eq!(r.array(), [[1.0, 0.5, 1.0], [-2.0, -2.0, -3.5]]);
+/// ```
 pub fn minimum<S: Shape, E: Dtype, D: Device<E>, LTape: Tape<D> + Merge<RTape>, RTape: Tape<D>>(
     lhs: Tensor<S, E, D, LTape>,
     rhs: Tensor<S, E, D, RTape>,
@@ -52,6 +52,7 @@
 //! - [MaxTo]
 //! - [MeanTo]
 //! - [MinTo]
+//! - [ProdTo]
 //! - [SumTo]
 //! - [VarTo]
 //! - [StddevTo]
@@ -98,6 +99,27 @@
 //! let _ = t.clone().permute::<_, Axes3<1, 2, 0>>();
 //! ```
 //!
+//! # Splitting
+//!
+//! [Split] splits an axis into a number of equally sized chunks. It takes the shape of
+//! each chunk, the axis, and the number of chunks:
+//! ```rust
+//! # use dfdx::prelude::*;
+//! # let dev: Cpu = Default::default();
+//! let t: Tensor<Rank2<4, 6>, f32, _> = dev.zeros();
+//! let [a, b, c] = t.split::<Rank2<4, 2>, Axis<1>, 3>();
+//! ```
+//!
+//! [TryConcat] is the inverse, joining two tensors along an axis:
+//! ```rust
+//! # use dfdx::prelude::*;
+//! # let dev: Cpu = Default::default();
+//! # let t: Tensor<Rank2<4, 6>, f32, _> = dev.zeros();
+//! # let [a, b, c] = t.split::<Rank2<4, 2>, Axis<1>, 3>();
+//! let ab: Tensor<Rank2<4, 4>, f32, _> = a.concat(b);
+//! let abc: Tensor<Rank2<4, 6>, f32, _> = ab.concat(c);
+//! ```
+//!
 //! # Indexing using select and gather
 //!
 //! Two traits provide indexing capability [SelectTo] and [GatherTo]. The difference is:
@@ -138,26 +160,38 @@
 //! let r = t.select::<Rank1<2>, _>(dev.tensor(1).broadcast());
 //! assert_eq!(r.array(), [2.0, 5.0]);
 //! ```
+//!
+//! The inverse of gather is [ScatterTo::scatter_add], which adds values back into
+//! the positions they would have been gathered from.
 
 mod utilities;
 pub use utilities::*;
 
 mod abs;
 mod add;
+mod argreduce;
 mod bce;
+mod bool_reduce;
 mod boolean;
 mod broadcast_to;
 mod choose;
 mod clamp;
+mod concat;
 mod cos;
+mod cumsum;
+mod diag;
 mod div;
 mod dropout;
 mod exp;
+mod flip;
 mod gelu;
 mod huber_error;
+mod l2_normalize;
 mod ln;
 mod log_softmax;
+mod logmeanexp_to;
 mod logsumexp_to;
+mod masked_fill;
 mod matmul;
 mod max_to;
 mod maximum;
@@ -165,65 +199,109 @@ mod mean_to;
 mod min_to;
 mod minimum;
 mod mul;
+mod nan_to_num;
 mod nans_to;
+mod narrow;
 mod negate;
 mod normalize;
+mod one_hot;
+mod pad;
 mod permute_to;
 mod pow;
+mod prod;
+mod recip;
 mod relu;
+mod repeat;
 mod reshape_to;
+mod roll;
+mod scatter;
 mod select_and_gather;
 mod sigmoid;
+mod sign;
 mod sin;
 mod softmax;
+mod sort;
+mod split;
 mod sqrt;
 mod square;
+mod stack;
 mod stddev_to;
 mod sub;
 mod sum_to;
+mod take_along_axis;
 mod tanh;
+mod to_dtype;
+mod top_k;
+mod transpose;
+mod tri;
+mod upsample2d;
 mod var_to;
 
 pub use abs::abs;
 pub use add::{add, TryAdd};
+pub use argreduce::ArgReduceTo;
 pub use bce::bce_with_logits;
+pub use bool_reduce::BoolReduceTo;
 pub use boolean::{bool_and, bool_not, bool_or, bool_xor};
 pub use broadcast_to::BroadcastTo;
 pub use choose::ChooseFrom;
 pub use clamp::clamp;
+pub use concat::TryConcat;
 pub use cos::cos;
+pub use cumsum::CumSum;
 pub use div::{div, TryDiv};
 pub use dropout::dropout;
 pub use exp::exp;
+pub use flip::Flip;
 pub use gelu::gelu;
 pub use huber_error::huber_error;
+pub use l2_normalize::l2_normalize;
 pub use ln::ln;
 pub use log_softmax::log_softmax;
+pub use logmeanexp_to::LogMeanExpTo;
 pub use logsumexp_to::LogSumExpTo;
-pub use matmul::{matmul, TryMatMul};
+pub use masked_fill::{BroadcastMaskTo, MaskedFill};
+pub use matmul::{dot, matmul, matmul_bias, outer, TryMatMul, TryMatMulBias};
 pub use max_to::MaxTo;
 pub use maximum::maximum;
 pub use mean_to::MeanTo;
 pub use min_to::MinTo;
 pub use minimum::minimum;
 pub use mul::{mul, TryMul};
+pub use nan_to_num::nan_to_num;
 pub use nans_to::nans_to;
+pub use narrow::TryNarrow;
 pub use negate::negate;
 pub use normalize::normalize;
+pub use one_hot::OneHot;
+pub use pad::TryPad;
 pub use permute_to::PermuteTo;
 pub use pow::{powf, powi};
+pub use prod::ProdTo;
+pub use recip::recip;
 pub use relu::relu;
+pub use repeat::TryRepeat;
 pub use reshape_to::ReshapeTo;
+pub use roll::Roll;
+pub use scatter::ScatterTo;
 pub use select_and_gather::{GatherTo, SelectTo};
 pub use sigmoid::sigmoid;
+pub use sign::sign;
 pub use sin::sin;
 pub use softmax::softmax;
+pub use sort::Sort;
+pub use split::Split;
 pub use sqrt::sqrt;
 pub use square::square;
+pub use stack::{stack, try_stack};
 pub use stddev_to::StddevTo;
 pub use sub::{sub, TrySub};
 pub use sum_to::SumTo;
+pub use take_along_axis::TakeAlongAxis;
 pub use tanh::tanh;
+pub use top_k::TopK;
+pub use transpose::TryTranspose;
+pub use upsample2d::{Bilinear, GenericUpsample2D, NearestNeighbor, TryUpsample2D, UpsampleMethod};
 pub use var_to::VarTo;
 
 #[cfg(feature = "nightly")]
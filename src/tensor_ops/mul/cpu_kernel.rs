@@ -1,25 +1,25 @@
 use crate::tensor_ops::cpu_kernels::{BinaryDerivative, UnaryDerivative};
 
-impl UnaryDerivative<f32> for super::ScalarMulKernelOp<f32> {
-    fn f(&self, x: &f32) -> f32 {
-        x * self.scalar
+impl<F: num_traits::Float> UnaryDerivative<F> for super::ScalarMulKernelOp<F> {
+    fn f(&self, x: &F) -> F {
+        *x * self.scalar
     }
-    fn df(&self, _: &f32) -> f32 {
+    fn df(&self, _: &F) -> F {
         self.scalar
     }
 }
 
-impl BinaryDerivative<f32> for super::BinaryMulKernelOp {
+impl<F: num_traits::Float> BinaryDerivative<F> for super::BinaryMulKernelOp {
     #[inline(always)]
-    fn f(&self, x: &f32, y: &f32) -> f32 {
-        x * y
+    fn f(&self, x: &F, y: &F) -> F {
+        *x * *y
     }
     #[inline(always)]
-    fn dfdx(&self, _x: &f32, y: &f32) -> f32 {
+    fn dfdx(&self, _x: &F, y: &F) -> F {
         *y
     }
     #[inline(always)]
-    fn dfdy(&self, x: &f32, _y: &f32) -> f32 {
+    fn dfdy(&self, x: &F, _y: &F) -> F {
         *x
     }
 }
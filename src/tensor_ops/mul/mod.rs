@@ -73,7 +73,7 @@ where
 }
 #[cfg(test)]
 mod tests {
-    use crate::{tensor::*, tensor_ops::*, tests::*};
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::*};
 
     #[test]
     fn test_mul_0d() {
@@ -135,7 +135,7 @@ mod tests {
     #[test]
     fn test_scalar_mul_0d() {
         let dev: TestDevice = Default::default();
-        let x = dev.tensor(1.0);
+        let x: Tensor<Rank0, f32, _> = dev.tensor(1.0);
         let r = x.trace() * 0.5;
         assert_eq!(r.array(), 0.5);
         let g = r.exp().backward();
@@ -145,7 +145,7 @@ mod tests {
     #[test]
     fn test_scalar_mul_1d() {
         let dev: TestDevice = Default::default();
-        let x = dev.tensor([0.0, 1.0, 2.0]);
+        let x: Tensor<Rank1<3>, f32, _> = dev.tensor([0.0, 1.0, 2.0]);
         let r = x.trace() * 0.5;
         assert_eq!(r.array(), [0.0, 0.5, 1.0]);
         let g = r.exp().sum().backward();
@@ -155,7 +155,7 @@ mod tests {
     #[test]
     fn test_scalar_mul_2d() {
         let dev: TestDevice = Default::default();
-        let x = dev.tensor([[1.0; 2]; 3]);
+        let x: Tensor<Rank2<3, 2>, f32, _> = dev.tensor([[1.0; 2]; 3]);
         let r = x.trace() * 0.5;
         assert_eq!(r.array(), [[0.5; 2]; 3]);
         let g = r.exp().sum().backward();
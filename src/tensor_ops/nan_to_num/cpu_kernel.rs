@@ -0,0 +1,38 @@
+use crate::{
+    shapes::{Dtype, Shape},
+    tensor::cpu::{Cpu, StridedArray},
+    tensor_ops::cpu_kernels::UnaryDerivative,
+};
+
+impl<F: num_traits::Float> UnaryDerivative<F> for super::NanToNumKernelOp<F> {
+    #[inline(always)]
+    fn f(&self, x: &F) -> F {
+        if x.is_nan() {
+            self.nan
+        } else if *x == F::infinity() {
+            self.posinf
+        } else if *x == F::neg_infinity() {
+            self.neginf
+        } else {
+            *x
+        }
+    }
+    #[inline(always)]
+    fn df(&self, x: &F) -> F {
+        if x.is_finite() {
+            F::one()
+        } else {
+            F::zero()
+        }
+    }
+}
+
+impl<E: Dtype + num_traits::Float> super::FiniteCheckKernel<E> for Cpu {
+    fn has_nan<S: Shape>(&self, inp: &StridedArray<S, E>) -> Result<bool, Self::Err> {
+        Ok(inp.data.iter().any(|x| x.is_nan()))
+    }
+
+    fn all_finite<S: Shape>(&self, inp: &StridedArray<S, E>) -> Result<bool, Self::Err> {
+        Ok(inp.data.iter().all(|x| x.is_finite()))
+    }
+}
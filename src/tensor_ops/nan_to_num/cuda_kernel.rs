@@ -0,0 +1,57 @@
+use crate::{shapes::Shape, tensor::cuda::Cuda, tensor_ops::cuda_kernels::UnaryOpCudaKernel};
+use cudarc::driver::{LaunchAsync, LaunchConfig};
+
+unsafe impl cudarc::driver::AsKernelParam for super::NanToNumKernelOp<f32> {}
+
+impl UnaryOpCudaKernel for super::NanToNumKernelOp<f32> {
+    const PTX_SRC: &'static str = include_str!(concat!(env!("OUT_DIR"), "/nan_to_num.ptx"));
+    const MODULE_NAME: &'static str = "nan_to_num";
+    const FWD_FN_NAME: &'static str = "nan_to_num_forward";
+    const BWD_FN_NAME: &'static str = "nan_to_num_backward";
+}
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/finite_check.ptx"));
+const MODULE_NAME: &str = "finite_check";
+const HAS_NAN_FN_NAME: &str = "has_nan";
+const HAS_NON_FINITE_FN_NAME: &str = "has_non_finite";
+const ALL_FN_NAMES: [&str; 2] = [HAS_NAN_FN_NAME, HAS_NON_FINITE_FN_NAME];
+
+impl Cuda {
+    /// Launches `fn_name` over the raw data of `inp`, returning whether any thread raised the flag.
+    fn finite_check<S: Shape>(
+        &self,
+        fn_name: &str,
+        inp: &<Self as crate::tensor::DeviceStorage>::Storage<S, f32>,
+    ) -> Result<bool, <Self as crate::tensor::HasErr>::Err> {
+        if !self.dev.has_func(MODULE_NAME, fn_name) {
+            self.dev
+                .load_ptx(PTX_SRC.into(), MODULE_NAME, &ALL_FN_NAMES)?;
+        }
+
+        let numel = inp.data.len();
+        let mut flag = self.dev.alloc_zeros_async::<u32>(1)?;
+        let func = self.dev.get_func(MODULE_NAME, fn_name).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,             // const size_t numel,
+            inp.data.as_ref(), // const float *inp,
+            &mut flag,         // unsigned int *flag
+        );
+        unsafe { func.launch_async(cfg, params) }?;
+
+        // only the flag is copied back to the host
+        let mut buf = [0u32; 1];
+        self.dev.sync_copy_from(&flag, &mut buf)?;
+        Ok(buf[0] != 0)
+    }
+}
+
+impl super::FiniteCheckKernel<f32> for Cuda {
+    fn has_nan<S: Shape>(&self, inp: &Self::Storage<S, f32>) -> Result<bool, Self::Err> {
+        self.finite_check(HAS_NAN_FN_NAME, inp)
+    }
+
+    fn all_finite<S: Shape>(&self, inp: &Self::Storage<S, f32>) -> Result<bool, Self::Err> {
+        Ok(!self.finite_check(HAS_NON_FINITE_FN_NAME, inp)?)
+    }
+}
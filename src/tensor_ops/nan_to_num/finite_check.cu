@@ -0,0 +1,31 @@
+// Sets `flag` if any value in `inp` is nan.
+extern "C" __global__ void has_nan(
+    const size_t numel,
+    const float *inp,
+    unsigned int *flag
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    if (isnan(inp[i])) {
+        *flag = 1;
+    }
+}
+
+// Sets `flag` if any value in `inp` is nan or infinite.
+extern "C" __global__ void has_non_finite(
+    const size_t numel,
+    const float *inp,
+    unsigned int *flag
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    if (!isfinite(inp[i])) {
+        *flag = 1;
+    }
+}
@@ -0,0 +1,132 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::ops::{try_unary_op, UnaryKernel};
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct NanToNumKernelOp<E> {
+    nan: E,
+    posinf: E,
+    neginf: E,
+}
+
+pub trait FiniteCheckKernel<E: Dtype>: DeviceStorage {
+    fn has_nan<S: Shape>(&self, inp: &Self::Storage<S, E>) -> Result<bool, Self::Err>;
+    fn all_finite<S: Shape>(&self, inp: &Self::Storage<S, E>) -> Result<bool, Self::Err>;
+}
+
+/// Replaces [f32::NAN] with `nan`, [f32::INFINITY] with `posinf`, and
+/// [f32::NEG_INFINITY] with `neginf`. Gradients only flow through finite values.
+///
+/// **Pytorch equivalent**: `t.nan_to_num(nan, posinf, neginf)`
+///
+/// Example:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([1.0, f32::NAN, f32::INFINITY, f32::NEG_INFINITY]);
+/// let r = t.nan_to_num(0.0, 1e6, -1e6);
+/// assert_eq!(r.array(), [1.0, 0.0, 1e6, -1e6]);
+/// ```
+pub fn nan_to_num<S: Shape, E: Dtype, D: UnaryKernel<NanToNumKernelOp<E>, E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+    nan: E,
+    posinf: E,
+    neginf: E,
+) -> Tensor<S, E, D, T> {
+    t.nan_to_num(nan, posinf, neginf)
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<NanToNumKernelOp<E>, E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [nan_to_num]
+    pub fn nan_to_num(self, nan: E, posinf: E, neginf: E) -> Self {
+        self.try_nan_to_num(nan, posinf, neginf).unwrap()
+    }
+    /// See [nan_to_num]
+    pub fn try_nan_to_num(self, nan: E, posinf: E, neginf: E) -> Result<Self, D::Err> {
+        try_unary_op(
+            NanToNumKernelOp {
+                nan,
+                posinf,
+                neginf,
+            },
+            self,
+        )
+    }
+}
+
+impl<S: Shape, E: Dtype, D: FiniteCheckKernel<E>, T> Tensor<S, E, D, T> {
+    /// Whether any value is [f32::NAN]. Only a single flag is copied back from the device.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// assert!(dev.tensor([1.0, f32::NAN]).has_nan());
+    /// assert!(!dev.tensor([1.0, f32::INFINITY]).has_nan());
+    /// ```
+    pub fn has_nan(&self) -> bool {
+        self.try_has_nan().unwrap()
+    }
+    /// See [Tensor::has_nan]
+    pub fn try_has_nan(&self) -> Result<bool, D::Err> {
+        self.device.has_nan(&self.storage)
+    }
+
+    /// Whether all values are finite, meaning none are nan or infinite.
+    /// Only a single flag is copied back from the device.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// assert!(dev.tensor([1.0, -2.0]).all_finite());
+    /// assert!(!dev.tensor([1.0, f32::INFINITY]).all_finite());
+    /// ```
+    pub fn all_finite(&self) -> bool {
+        self.try_all_finite().unwrap()
+    }
+    /// See [Tensor::all_finite]
+    pub fn try_all_finite(&self) -> Result<bool, D::Err> {
+        self.device.all_finite(&self.storage)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::TestDevice};
+
+    #[test]
+    fn test_nan_to_num_1d() {
+        let dev: TestDevice = Default::default();
+        let t = dev.tensor([1.0, f32::NAN, f32::INFINITY, f32::NEG_INFINITY, -2.0]);
+        assert!(t.has_nan());
+        assert!(!t.all_finite());
+
+        let r = t.trace().nan_to_num(0.5, 100.0, -100.0);
+        assert_eq!(r.array(), [1.0, 0.5, 100.0, -100.0, -2.0]);
+        assert!(!r.has_nan());
+        assert!(r.all_finite());
+
+        let g = (r * dev.tensor([1.0, 2.0, 3.0, 4.0, 5.0])).sum().backward();
+        assert_eq!(g.get(&t).array(), [1.0, 0.0, 0.0, 0.0, 5.0]);
+    }
+
+    #[test]
+    fn test_finite_checks() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<_, f32, _> = dev.tensor([[1.0, -2.0], [3.0, 1e30]]);
+        assert!(!t.has_nan());
+        assert!(t.all_finite());
+
+        let t = dev.tensor([0.0, f32::NEG_INFINITY]);
+        assert!(!t.has_nan());
+        assert!(!t.all_finite());
+
+        let t = dev.tensor([f32::NAN]).broadcast::<Rank2<2, 1>, _>();
+        assert!(t.has_nan());
+        assert!(!t.all_finite());
+    }
+}
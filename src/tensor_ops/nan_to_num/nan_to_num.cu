@@ -0,0 +1,11 @@
+#include "unary_op_macros.cuh"
+
+struct NanToNumKernelOp {
+    float nan;
+    float posinf;
+    float neginf;
+};
+
+UNARY_OP(nan_to_num_forward, nan_to_num_backward, NanToNumKernelOp,
+        isnan(x) ? op.nan : (isinf(x) ? (x > 0 ? op.posinf : op.neginf) : x),
+        isfinite(x) ? 1.0 : 0.0)
@@ -0,0 +1,86 @@
+use crate::{
+    shapes::{Axes, ResizeDimTo, Shape},
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{CudaSlice, LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/narrow.ptx"));
+const MODULE_NAME: &str = "narrow";
+const FWD_FN_NAME: &str = "narrow_forward";
+const BWD_FN_NAME: &str = "narrow_backward";
+const ALL_FN_NAMES: [&str; 2] = [FWD_FN_NAME, BWD_FN_NAME];
+
+impl super::NarrowKernel<f32> for Cuda {
+    fn forward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, f32>,
+        start: usize,
+    ) -> Result<Self::Storage<Dst, f32>, Self::Err>
+    where
+        Src: ResizeDimTo<Dst, Ax>,
+    {
+        if !self.dev.has_func(MODULE_NAME, FWD_FN_NAME) {
+            self.dev
+                .load_ptx(PTX_SRC.into(), MODULE_NAME, &ALL_FN_NAMES)?;
+        }
+
+        let ax = Ax::as_array()[0] as usize;
+        let strides = dst.strides();
+        let numel = dst.num_elements();
+        let mut storage = self.dev.alloc_zeros_async::<f32>(numel)?;
+
+        let dims: CudaSlice<usize> = self.dev.take_async(dst.concrete().into())?;
+        let inp_strides: CudaSlice<usize> = self.dev.take_async(inp.strides.into())?;
+
+        let fwd_fn = self.dev.get_func(MODULE_NAME, FWD_FN_NAME).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,             // const size_t numel,
+            Src::NUM_DIMS,     // const size_t num_dims,
+            ax,                // const size_t ax,
+            start,             // const size_t start,
+            &dims,             // const size_t *dims,
+            inp.data.as_ref(), // const float *inp,
+            &inp_strides,      // const size_t *inp_strides,
+            &mut storage,      // float *out
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+        Ok(CudaArray {
+            data: Arc::new(storage.into()),
+            shape: dst,
+            strides,
+        })
+    }
+
+    fn backward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        grad_inp: &mut Self::Storage<Src, f32>,
+        grad_out: &Self::Storage<Dst, f32>,
+        start: usize,
+    ) -> Result<(), Self::Err>
+    where
+        Src: ResizeDimTo<Dst, Ax>,
+    {
+        let ax = Ax::as_array()[0] as usize;
+        let numel = grad_out.shape.num_elements();
+        let dims: CudaSlice<usize> = self.dev.take_async(grad_out.shape.concrete().into())?;
+        let inp_strides: CudaSlice<usize> = self.dev.take_async(grad_inp.strides.into())?;
+
+        let bwd_fn = self.dev.get_func(MODULE_NAME, BWD_FN_NAME).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,                             // const size_t numel,
+            Src::NUM_DIMS,                     // const size_t num_dims,
+            ax,                                // const size_t ax,
+            start,                             // const size_t start,
+            &dims,                             // const size_t *dims,
+            Arc::make_mut(&mut grad_inp.data), // float *grad_inp,
+            &inp_strides,                      // const size_t *inp_strides,
+            grad_out.data.as_ref(),            // const float *grad_out
+        );
+        unsafe { bwd_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+}
@@ -0,0 +1,140 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+pub trait NarrowKernel<E: Dtype>: DeviceStorage {
+    fn forward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, E>,
+        start: usize,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>
+    where
+        Src: ResizeDimTo<Dst, Ax>;
+    fn backward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        grad_inp: &mut Self::Storage<Src, E>,
+        grad_out: &Self::Storage<Dst, E>,
+        start: usize,
+    ) -> Result<(), Self::Err>
+    where
+        Src: ResizeDimTo<Dst, Ax>;
+}
+
+/// Takes a contiguous slice along an axis, keeping the axis. Equivalent to `torch.narrow`.
+///
+/// Unlike [super::SelectTo::select], the axis is not removed, and the bounds can be
+/// known only at runtime.
+pub trait TryNarrow: HasErr + HasShape {
+    /// Keeps the `len` elements starting at `start` along axis `Ax`, i.e. the
+    /// range `start..start + len`. Gradients are zero outside of that range.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// let r: Tensor<(Const<2>, usize), f32, _> = t.clone().narrow::<_, Axis<1>>(1, 2);
+    /// assert_eq!(r.as_vec(), [2.0, 3.0, 5.0, 6.0]);
+    ///
+    /// let r = t.narrow::<Rank2<1, 3>, Axis<0>>(1, 1);
+    /// assert_eq!(r.array(), [[4.0, 5.0, 6.0]]);
+    /// ```
+    ///
+    /// **Panics** if `start + len` is larger than the size of the axis, or if the size
+    /// of `Ax` in `Dst` is a [Const] that is not `len`.
+    fn narrow<Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        self,
+        start: usize,
+        len: usize,
+    ) -> Self::WithShape<Dst>
+    where
+        Self::Shape: ResizeDimTo<Dst, Ax>,
+    {
+        self.try_narrow::<Dst, Ax>(start, len).unwrap()
+    }
+
+    /// Fallible version of [TryNarrow::narrow]
+    fn try_narrow<Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        self,
+        start: usize,
+        len: usize,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ResizeDimTo<Dst, Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: NarrowKernel<E>, T: Tape<D>> TryNarrow for Tensor<S, E, D, T> {
+    fn try_narrow<Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        self,
+        start: usize,
+        len: usize,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ResizeDimTo<Dst, Ax>,
+    {
+        let size = self.shape().concrete()[Ax::as_array()[0] as usize];
+        assert!(
+            start + len <= size,
+            "narrow range {}..{} is out of bounds for axis of size {size}",
+            start,
+            start + len
+        );
+        let dst: Dst = self.shape().resize(len);
+
+        let (inp, mut tape) = self.split_tape();
+        let storage = inp.device.forward(dst, &inp.storage, start)?;
+        let out = inp.device.upgrade(storage);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(grad_inp, grad_out, start)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor_ops::*;
+    use crate::tests::TestDevice;
+
+    #[test]
+    fn test_narrow_axis_1() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 5>, f32, _> =
+            dev.tensor([[1.0, 2.0, 3.0, 4.0, 5.0], [6.0, 7.0, 8.0, 9.0, 10.0]]);
+        let r = t.trace().narrow::<Rank2<2, 3>, Axis<1>>(1, 3);
+        assert_eq!(r.array(), [[2.0, 3.0, 4.0], [7.0, 8.0, 9.0]]);
+        let w = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let g = (r * w).sum().backward();
+        assert_eq!(
+            g.get(&t).array(),
+            [[0.0, 1.0, 2.0, 3.0, 0.0], [0.0, 4.0, 5.0, 6.0, 0.0]]
+        );
+    }
+
+    #[test]
+    fn test_narrow_runtime_len() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<3, 2>, f32, _> = dev.tensor([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        let r: Tensor<(usize, Const<2>), f32, _, _> = t.trace().narrow::<_, Axis<0>>(2, 1);
+        assert_eq!(r.shape(), &(1, Const));
+        assert_eq!(r.as_vec(), [5.0, 6.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [[0.0, 0.0], [0.0, 0.0], [1.0, 1.0]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_narrow_out_of_bounds() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<3>, f32, _> = dev.zeros();
+        let _: Tensor<(usize,), f32, _> = t.narrow::<_, Axis<0>>(2, 2);
+    }
+}
@@ -0,0 +1,27 @@
+use crate::{
+    shapes::{AppendDim, Const, Dtype, Shape},
+    tensor::cpu::{Cpu, LendingIterator, StridedArray},
+};
+
+impl<E: Dtype> super::OneHotKernel<E> for Cpu {
+    fn forward<S, const N: usize>(
+        &self,
+        idx: &Self::Storage<S, usize>,
+    ) -> Result<Self::Storage<S::Appended, E>, Self::Err>
+    where
+        S: AppendDim<Const<N>>,
+    {
+        crate::tensor_ops::select_and_gather::check_indices(&idx.data, S::NUM_DIMS, N)?;
+        let mut out = StridedArray::new(idx.shape.append(Const))?;
+        let mut idx_iter = idx.iter_with_index();
+        while let Some((i, i_idx)) = idx_iter.next() {
+            let mut i_out: <S::Appended as Shape>::Concrete = Default::default();
+            for j in 0..S::NUM_DIMS {
+                i_out[j] = i_idx[j];
+            }
+            i_out[S::NUM_DIMS] = *i;
+            out[i_out] = E::ONE;
+        }
+        Ok(out)
+    }
+}
@@ -0,0 +1,54 @@
+use crate::{
+    shapes::{AppendDim, Const, Shape},
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{CudaSlice, LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/one_hot.ptx"));
+const MODULE_NAME: &str = "one_hot";
+const FWD_FN_NAME: &str = "one_hot_forward";
+const ALL_FN_NAMES: [&str; 1] = [FWD_FN_NAME];
+
+impl super::OneHotKernel<f32> for Cuda {
+    fn forward<S, const N: usize>(
+        &self,
+        idx: &Self::Storage<S, usize>,
+    ) -> Result<Self::Storage<S::Appended, f32>, Self::Err>
+    where
+        S: AppendDim<Const<N>>,
+    {
+        self.check_indices(idx, S::NUM_DIMS, N)?;
+
+        if !self.dev.has_func(MODULE_NAME, FWD_FN_NAME) {
+            self.dev
+                .load_ptx(PTX_SRC.into(), MODULE_NAME, &ALL_FN_NAMES)?;
+        }
+
+        let shape = idx.shape.append(Const);
+        let strides = shape.strides();
+        let mut storage = self.dev.alloc_zeros_async::<f32>(shape.num_elements())?;
+
+        let numel = idx.shape.num_elements();
+        let idx_dims: CudaSlice<usize> = self.dev.take_async(idx.shape.concrete().into())?;
+        let idx_strides: CudaSlice<usize> = self.dev.take_async(idx.strides.into())?;
+
+        let fwd_fn = self.dev.get_func(MODULE_NAME, FWD_FN_NAME).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,             // const size_t numel,
+            S::NUM_DIMS,       // const size_t num_dims,
+            &idx_dims,         // const size_t *dims,
+            idx.data.as_ref(), // const size_t *idx,
+            &idx_strides,      // const size_t *idx_strides,
+            N,                 // const size_t n,
+            &mut storage,      // float *out
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+        Ok(CudaArray {
+            data: Arc::new(storage.into()),
+            shape,
+            strides,
+        })
+    }
+}
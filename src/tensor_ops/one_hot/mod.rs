@@ -0,0 +1,108 @@
+#![allow(clippy::type_complexity)]
+
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{shapes::*, tensor::*};
+
+pub trait OneHotKernel<E: Dtype>: DeviceStorage {
+    fn forward<S, const N: usize>(
+        &self,
+        idx: &Self::Storage<S, usize>,
+    ) -> Result<Self::Storage<S::Appended, E>, Self::Err>
+    where
+        S: AppendDim<Const<N>>;
+}
+
+/// One hot encodes a tensor of indices, adding a new last dimension of size `N`.
+/// Equivalent to `torch.nn.functional.one_hot` from pytorch.
+///
+/// This is not differentiable, so the output has no tape.
+pub trait OneHot<E: Dtype, D: DeviceStorage>: HasErr + HasShape {
+    /// The output has a `1` at each index, and `0` everywhere else:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let idx: Tensor<Rank1<3>, usize, _> = dev.tensor([0, 2, 1]);
+    /// let r: Tensor<Rank2<3, 3>, f32, _> = idx.one_hot::<3>();
+    /// assert_eq!(r.array(), [[1.0, 0.0, 0.0], [0.0, 0.0, 1.0], [0.0, 1.0, 0.0]]);
+    /// ```
+    ///
+    /// Indices that are greater than or equal to `N` are an error, see [OneHot::try_one_hot].
+    fn one_hot<const N: usize>(self) -> Tensor<<Self::Shape as AppendDim<Const<N>>>::Appended, E, D>
+    where
+        Self::Shape: AppendDim<Const<N>>,
+    {
+        self.try_one_hot().unwrap()
+    }
+
+    /// Fallible version of [OneHot::one_hot]. Returns an error if any index is out of bounds.
+    fn try_one_hot<const N: usize>(
+        self,
+    ) -> Result<Tensor<<Self::Shape as AppendDim<Const<N>>>::Appended, E, D>, Self::Err>
+    where
+        Self::Shape: AppendDim<Const<N>>;
+}
+
+impl<S: Shape, E: Dtype, D: OneHotKernel<E>> OneHot<E, D> for Tensor<S, usize, D> {
+    fn try_one_hot<const N: usize>(
+        self,
+    ) -> Result<Tensor<<S as AppendDim<Const<N>>>::Appended, E, D>, Self::Err>
+    where
+        S: AppendDim<Const<N>>,
+    {
+        let storage = self.device.forward::<S, N>(&self.storage)?;
+        Ok(self.device.upgrade(storage))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::TestDevice;
+
+    #[test]
+    fn test_one_hot_1d() {
+        let dev: TestDevice = Default::default();
+        let idx = dev.tensor([0, 2, 1]);
+        let r: Tensor<Rank2<3, 3>, f32, _> = idx.one_hot::<3>();
+        assert_eq!(
+            r.array(),
+            [[1.0, 0.0, 0.0], [0.0, 0.0, 1.0], [0.0, 1.0, 0.0]]
+        );
+    }
+
+    #[test]
+    fn test_one_hot_2d() {
+        let dev: TestDevice = Default::default();
+        let idx = dev.tensor([[3, 0], [1, 1]]);
+        let r: Tensor<Rank3<2, 2, 4>, f32, _> = idx.one_hot::<4>();
+        assert_eq!(
+            r.array(),
+            [
+                [[0.0, 0.0, 0.0, 1.0], [1.0, 0.0, 0.0, 0.0]],
+                [[0.0, 1.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0]],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_one_hot_runtime_dim() {
+        let dev: TestDevice = Default::default();
+        let mut idx: Tensor<(usize,), usize, _> = dev.zeros_like(&(2,));
+        idx.copy_from(&[1, 0]);
+        let r: Tensor<(usize, Const<2>), f32, _> = idx.one_hot::<2>();
+        assert_eq!(r.shape(), &(2, Const));
+        assert_eq!(r.as_vec(), [0.0, 1.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_one_hot_out_of_bounds() {
+        let dev: TestDevice = Default::default();
+        let idx: Tensor<Rank1<3>, usize, _> = dev.tensor([0, 3, 1]);
+        let r: Result<Tensor<Rank2<3, 3>, f32, _>, _> = idx.try_one_hot::<3>();
+        assert!(r.is_err());
+    }
+}
@@ -0,0 +1,21 @@
+#include "cuda_utils.cuh"
+
+extern "C" __global__ void one_hot_forward(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t *idx,
+    const size_t *idx_strides,
+    const size_t n,
+    float *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int idx_i = get_strided_index(i, num_dims, dims, idx_strides);
+
+    // out is contiguous, so each index owns the `n` elements starting at `i * n`
+    out[i * n + idx[idx_i]] = 1.0;
+}
@@ -0,0 +1,58 @@
+use crate::shapes::{Axes, Dtype, ResizeDimTo, Shape};
+use crate::tensor::cpu::{Cpu, LendingIterator, StridedArray};
+
+impl<E: Dtype> super::PadKernel<E> for Cpu {
+    fn forward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        dst: Dst,
+        before: usize,
+        value: E,
+        inp: &Self::Storage<Src, E>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>
+    where
+        Src: ResizeDimTo<Dst, Ax>,
+    {
+        let ax = Ax::as_array()[0] as usize;
+        let size = inp.shape.concrete()[ax];
+        let mut out = StridedArray::new(dst)?;
+        let mut out_iter = out.iter_mut_with_index();
+        while let Some((o, i_out)) = out_iter.next() {
+            if (before..before + size).contains(&i_out[ax]) {
+                let mut i_inp: Src::Concrete = Default::default();
+                for j in 0..Src::NUM_DIMS {
+                    i_inp[j] = i_out[j];
+                }
+                i_inp[ax] -= before;
+                *o = inp[i_inp];
+            } else {
+                *o = value;
+            }
+        }
+        Ok(out)
+    }
+
+    fn backward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        before: usize,
+        grad_inp: &mut Self::Storage<Src, E>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>
+    where
+        Src: ResizeDimTo<Dst, Ax>,
+    {
+        let ax = Ax::as_array()[0] as usize;
+        let size = grad_inp.shape.concrete()[ax];
+        let mut out_iter = grad_out.iter_with_index();
+        while let Some((go, i_out)) = out_iter.next() {
+            if (before..before + size).contains(&i_out[ax]) {
+                let mut i_inp: Src::Concrete = Default::default();
+                for j in 0..Src::NUM_DIMS {
+                    i_inp[j] = i_out[j];
+                }
+                i_inp[ax] -= before;
+                grad_inp[i_inp] += *go;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,157 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+pub trait PadKernel<E: Dtype>: DeviceStorage {
+    fn forward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        dst: Dst,
+        before: usize,
+        value: E,
+        inp: &Self::Storage<Src, E>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>
+    where
+        Src: ResizeDimTo<Dst, Ax>;
+    fn backward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        before: usize,
+        grad_inp: &mut Self::Storage<Src, E>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>
+    where
+        Src: ResizeDimTo<Dst, Ax>;
+}
+
+/// Pads a single axis with a constant value. Equivalent to `torch.nn.functional.pad`
+/// with `mode="constant"` along one dimension.
+pub trait TryPad<E: Dtype>: HasErr + HasShape {
+    /// Inserts `before` copies of `value` at the start of axis `Ax`, and `after` copies
+    /// at the end. The gradient of the padded positions is dropped.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<2, 2>, f32, _> = dev.tensor([[1.0, 2.0], [3.0, 4.0]]);
+    /// let r = t.clone().pad::<Rank2<2, 5>, Axis<1>>(1, 2, 0.0);
+    /// assert_eq!(r.array(), [[0.0, 1.0, 2.0, 0.0, 0.0], [0.0, 3.0, 4.0, 0.0, 0.0]]);
+    ///
+    /// // pad multiple axes by padding them one at a time
+    /// let r = t
+    ///     .pad::<Rank2<3, 2>, Axis<0>>(0, 1, -1.0)
+    ///     .pad::<Rank2<3, 3>, Axis<1>>(1, 0, -1.0);
+    /// assert_eq!(r.array(), [[-1.0, 1.0, 2.0], [-1.0, 3.0, 4.0], [-1.0; 3]]);
+    /// ```
+    ///
+    /// **Panics** if the size of `Ax` in `Dst` is a [Const] that is not the size
+    /// of the input axis plus `before + after`.
+    fn pad<Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        self,
+        before: usize,
+        after: usize,
+        value: E,
+    ) -> Self::WithShape<Dst>
+    where
+        Self::Shape: ResizeDimTo<Dst, Ax>,
+    {
+        self.try_pad(before, after, value).unwrap()
+    }
+
+    /// Fallible version of [TryPad::pad]
+    fn try_pad<Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        self,
+        before: usize,
+        after: usize,
+        value: E,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ResizeDimTo<Dst, Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: PadKernel<E>, T: Tape<D>> TryPad<E> for Tensor<S, E, D, T> {
+    fn try_pad<Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        self,
+        before: usize,
+        after: usize,
+        value: E,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ResizeDimTo<Dst, Ax>,
+    {
+        let size = self.shape().concrete()[Ax::as_array()[0] as usize];
+        let dst: Dst = self.shape().resize(before + size + after);
+
+        let (inp, mut tape) = self.split_tape();
+        let storage = inp.device.forward(dst, before, value, &inp.storage)?;
+        let out = inp.device.upgrade(storage);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(before, grad_inp, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor_ops::*;
+    use crate::tests::TestDevice;
+
+    #[test]
+    fn test_pad_1d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<3>, f32, _> = dev.tensor([1.0, 2.0, 3.0]);
+        let r = t.trace().pad::<Rank1<5>, _>(1, 1, 0.0);
+        assert_eq!(r.array(), [0.0, 1.0, 2.0, 3.0, 0.0]);
+        let g = (r * dev.tensor([10.0, 1.0, 2.0, 3.0, 20.0]))
+            .sum()
+            .backward();
+        assert_eq!(g.get(&t).array(), [1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_pad_asymmetric_axis_0() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<1, 2>, f32, _> = dev.tensor([[1.0, 2.0]]);
+        let r = t.trace().pad::<Rank2<4, 2>, Axis<0>>(2, 1, 5.0);
+        assert_eq!(r.array(), [[5.0; 2], [5.0; 2], [1.0, 2.0], [5.0; 2]]);
+        let g = r.exp().sum().backward();
+        assert_eq!(g.get(&t).array(), [[1.0f32.exp(), 2.0f32.exp()]]);
+    }
+
+    #[test]
+    fn test_pad_runtime_dim() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 1>, f32, _> = dev.tensor([[1.0], [2.0]]);
+        let r: Tensor<(Const<2>, usize), f32, _> = t.pad::<_, Axis<1>>(0, 2, -1.0);
+        assert_eq!(r.shape(), &(Const, 3));
+        assert_eq!(r.as_vec(), [1.0, -1.0, -1.0, 2.0, -1.0, -1.0]);
+    }
+
+    #[test]
+    fn test_pad_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<2>, f32, _> = dev.tensor([1.0, 2.0]);
+        let r = t
+            .trace()
+            .broadcast::<Rank2<3, 2>, _>()
+            .pad::<Rank2<3, 3>, Axis<1>>(1, 0, 0.0);
+        assert_eq!(r.array(), [[0.0, 1.0, 2.0]; 3]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [3.0; 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_pad_wrong_dst_size() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<2>, f32, _> = dev.zeros();
+        let _ = t.pad::<Rank1<5>, _>(1, 1, 0.0);
+    }
+}
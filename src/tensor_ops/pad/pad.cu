@@ -0,0 +1,82 @@
+// One thread per element of `out`. `dims` and `out_strides` describe `out`,
+// and `inp_strides` describe `inp`, whose axis `ax` is `before + after` shorter.
+__device__ bool padded_offsets(
+    unsigned int i,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t before,
+    const size_t inp_size,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    unsigned int *inp_i,
+    unsigned int *out_i
+) {
+    bool in_bounds = true;
+    *inp_i = 0;
+    *out_i = 0;
+    for (int d = num_dims - 1; d >= 0; d--) {
+        unsigned int i_d = i % dims[d];
+        i /= dims[d];
+        *out_i += i_d * out_strides[d];
+        if (d == ax) {
+            if (i_d < before || i_d >= before + inp_size) {
+                in_bounds = false;
+                continue;
+            }
+            i_d -= before;
+        }
+        *inp_i += i_d * inp_strides[d];
+    }
+    return in_bounds;
+}
+
+extern "C" __global__ void pad_forward(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t before,
+    const size_t inp_size,
+    const float value,
+    const size_t *dims,
+    const float *inp,
+    const size_t *inp_strides,
+    float *out,
+    const size_t *out_strides
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int inp_i, out_i;
+    if (padded_offsets(i, num_dims, ax, before, inp_size, dims, inp_strides, out_strides, &inp_i, &out_i)) {
+        out[out_i] = inp[inp_i];
+    } else {
+        out[out_i] = value;
+    }
+}
+
+extern "C" __global__ void pad_backward(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t before,
+    const size_t inp_size,
+    const size_t *dims,
+    float *grad_inp,
+    const size_t *inp_strides,
+    const float *grad_out,
+    const size_t *out_strides
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int inp_i, out_i;
+    if (padded_offsets(i, num_dims, ax, before, inp_size, dims, inp_strides, out_strides, &inp_i, &out_i)) {
+        // inp may be broadcasted, so multiple elements can map to the same gradient
+        atomicAdd(grad_inp + inp_i, grad_out[out_i]);
+    }
+}
@@ -1,23 +1,23 @@
-use crate::tensor_ops::cpu_kernels::UnaryDerivative;
+use crate::{shapes::Dtype, tensor_ops::cpu_kernels::UnaryDerivative};
 
-impl UnaryDerivative<f32> for super::PowKernelOp<i32> {
+impl<F: num_traits::Float> UnaryDerivative<F> for super::PowKernelOp<i32> {
     #[inline(always)]
-    fn f(&self, x: &f32) -> f32 {
+    fn f(&self, x: &F) -> F {
         x.powi(self.0)
     }
     #[inline(always)]
-    fn df(&self, x: &f32) -> f32 {
-        self.0 as f32 * x.powi(self.0 - 1)
+    fn df(&self, x: &F) -> F {
+        F::from(self.0).unwrap() * x.powi(self.0 - 1)
     }
 }
 
-impl UnaryDerivative<f32> for super::PowKernelOp<f32> {
+impl<F: num_traits::Float + Dtype> UnaryDerivative<F> for super::PowKernelOp<F> {
     #[inline(always)]
-    fn f(&self, x: &f32) -> f32 {
+    fn f(&self, x: &F) -> F {
         x.powf(self.0)
     }
     #[inline(always)]
-    fn df(&self, x: &f32) -> f32 {
-        self.0 * x.powf(self.0 - 1.0)
+    fn df(&self, x: &F) -> F {
+        self.0 * x.powf(self.0 - F::one())
     }
 }
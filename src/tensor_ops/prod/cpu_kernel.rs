@@ -0,0 +1,72 @@
+use crate::{
+    shapes::{Axes, Dtype, ReduceShapeTo, Shape},
+    tensor::cpu::{Cpu, LendingIterator, StridedArray},
+};
+
+impl<E: Dtype> super::ProdKernel<E> for Cpu {
+    fn forward<Src, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, E>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>,
+    {
+        let mut out: StridedArray<Dst, E> = StridedArray::try_new_with(dst, E::ONE)?;
+        let mut out_iter = out.iter_mut_as(&inp.shape);
+        let mut inp_iter = inp.iter();
+        while let Some((o, i)) = out_iter.next().zip(inp_iter.next()) {
+            o.mul_assign(*i);
+        }
+        Ok(out)
+    }
+
+    fn backward<Src, Dst: Shape, Ax: Axes>(
+        &self,
+        inp: &Self::Storage<Src, E>,
+        grad_inp: &mut Self::Storage<Src, E>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>,
+    {
+        let zero = E::default();
+
+        // product of the non-zero elements & number of zeros in each reduction
+        let mut nz_prod: StridedArray<Dst, E> = StridedArray::try_new_with(grad_out.shape, E::ONE)?;
+        let mut num_zeros: StridedArray<Dst, usize> = StridedArray::new(grad_out.shape)?;
+        {
+            let mut nz_iter = nz_prod.iter_mut_as(&inp.shape);
+            let mut inp_iter = inp.iter();
+            while let Some((p, i)) = nz_iter.next().zip(inp_iter.next()) {
+                if *i != zero {
+                    p.mul_assign(*i);
+                }
+            }
+            let mut zeros_iter = num_zeros.iter_mut_as(&inp.shape);
+            let mut inp_iter = inp.iter();
+            while let Some((n, i)) = zeros_iter.next().zip(inp_iter.next()) {
+                if *i == zero {
+                    *n += 1;
+                }
+            }
+        }
+
+        let mut inp_iter = inp.iter();
+        let mut grad_inp_iter = grad_inp.iter_mut();
+        let mut nz_iter = nz_prod.iter_as(&inp.shape);
+        let mut zeros_iter = num_zeros.iter_as(&inp.shape);
+        let mut grad_out_iter = grad_out.iter_as(&inp.shape);
+        for _ in 0..inp.shape.num_elements() {
+            let x = *inp_iter.next().unwrap();
+            let p = *nz_iter.next().unwrap();
+            let d = match *zeros_iter.next().unwrap() {
+                0 => p / x,
+                1 if x == zero => p,
+                _ => zero,
+            };
+            *grad_inp_iter.next().unwrap() += *grad_out_iter.next().unwrap() * d;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+use crate::tensor_ops::internal_reshapes::permute_for_reductions;
+use crate::{
+    shapes::{Axes, BroadcastStridesTo, ReduceShapeTo, Shape},
+    tensor::cuda::{Cuda, CudaArray, CudaError},
+};
+
+use cudarc::driver::{CudaSlice, LaunchAsync, LaunchConfig};
+
+use std::sync::Arc;
+
+const MODULE_NAME: &str = "prod";
+const FWD_FN_NAME: &str = "prod_forward";
+const NZ_FN_NAME: &str = "prod_nonzero";
+const BWD_FN_NAME: &str = "prod_backward";
+const ALL_FN_NAMES: [&str; 4] = [FWD_FN_NAME, NZ_FN_NAME, BWD_FN_NAME, "fill_with"];
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/prod.ptx"));
+
+fn ones(dev: &Cuda, numel: usize) -> Result<CudaSlice<f32>, CudaError> {
+    let mut storage = dev.dev.alloc_zeros_async::<f32>(numel)?;
+    let fill_fn = dev.dev.get_func(MODULE_NAME, "fill_with").unwrap();
+    unsafe {
+        fill_fn.launch_async(
+            LaunchConfig::for_num_elems(numel as u32),
+            (&mut storage, 1.0f32, numel),
+        )
+    }?;
+    Ok(storage)
+}
+
+impl super::ProdKernel<f32> for Cuda {
+    fn forward<Src, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, f32>,
+    ) -> Result<Self::Storage<Dst, f32>, Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>,
+    {
+        if !self.dev.has_func(MODULE_NAME, FWD_FN_NAME) {
+            self.dev
+                .load_ptx(PTX_SRC.into(), MODULE_NAME, &ALL_FN_NAMES)?;
+        }
+
+        let mut storage = ones(self, dst.num_elements())?;
+
+        let fwd_fn = self.dev.get_func(MODULE_NAME, FWD_FN_NAME).unwrap();
+
+        let (dims, strides) = permute_for_reductions::<_, Ax>(inp.shape.concrete(), inp.strides);
+        let num_dims = dims.len();
+        let dims: CudaSlice<usize> = self.dev.take_async(dims)?;
+        let strides: CudaSlice<usize> = self.dev.take_async(strides)?;
+
+        let physical_numel = inp.data.len();
+        let virtual_numel = inp.shape.num_elements();
+        let elems_per_thread = (virtual_numel / physical_numel) as f32;
+        let chunk_len = physical_numel / dst.num_elements();
+
+        let cfg = LaunchConfig::for_num_elems(physical_numel as u32);
+        let params = (
+            physical_numel,    // const size_t numel,
+            num_dims,          // const size_t num_dims,
+            elems_per_thread,  // const float elems_per_thread,
+            chunk_len,         // const size_t chunk_len,
+            inp.data.as_ref(), // const float *inp,
+            &dims,             // const size_t *dims,
+            &strides,          // const size_t *strides,
+            &mut storage,      // float *out
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+        Ok(CudaArray {
+            data: Arc::new(storage.into()),
+            shape: dst,
+            strides: dst.strides(),
+        })
+    }
+
+    fn backward<Src, Dst: Shape, Ax: Axes>(
+        &self,
+        inp: &Self::Storage<Src, f32>,
+        grad_inp: &mut Self::Storage<Src, f32>,
+        grad_out: &Self::Storage<Dst, f32>,
+    ) -> Result<(), Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>,
+    {
+        let dst = grad_out.shape;
+        let nz_fn = self.dev.get_func(MODULE_NAME, NZ_FN_NAME).unwrap();
+        let bwd_fn = self.dev.get_func(MODULE_NAME, BWD_FN_NAME).unwrap();
+
+        let physical_numel = inp.data.len();
+        let virtual_numel = inp.shape.num_elements();
+        let elems_per_thread = (virtual_numel / physical_numel) as f32;
+
+        // product of the non-zero elements & number of zeros in each reduction
+        let mut nz_prod = ones(self, dst.num_elements())?;
+        let mut num_zeros = self.dev.alloc_zeros_async::<f32>(dst.num_elements())?;
+        {
+            let (dims, strides) =
+                permute_for_reductions::<_, Ax>(inp.shape.concrete(), inp.strides);
+            let num_dims = dims.len();
+            let dims: CudaSlice<usize> = self.dev.take_async(dims)?;
+            let strides: CudaSlice<usize> = self.dev.take_async(strides)?;
+            let chunk_len = physical_numel / dst.num_elements();
+
+            let cfg = LaunchConfig::for_num_elems(physical_numel as u32);
+            let params = (
+                physical_numel,    // const size_t numel,
+                num_dims,          // const size_t num_dims,
+                elems_per_thread,  // const float elems_per_thread,
+                chunk_len,         // const size_t chunk_len,
+                inp.data.as_ref(), // const float *inp,
+                &dims,             // const size_t *dims,
+                &strides,          // const size_t *strides,
+                &mut nz_prod,      // float *nz_prod,
+                &mut num_zeros,    // float *num_zeros
+            );
+            unsafe { nz_fn.launch_async(cfg, params) }?;
+        }
+
+        let dims: CudaSlice<usize> = self.dev.take_async(grad_inp.shape.concrete().into())?;
+        let inp_strides: CudaSlice<usize> = self.dev.take_async(grad_inp.strides.into())?;
+        let out_strides: Src::Concrete =
+            BroadcastStridesTo::<Src, Ax>::broadcast_strides(&grad_out.shape, grad_out.strides);
+        let out_strides: CudaSlice<usize> = self.dev.take_async(out_strides.into())?;
+        let nz_strides: Src::Concrete =
+            BroadcastStridesTo::<Src, Ax>::broadcast_strides(&dst, dst.strides());
+        let nz_strides: CudaSlice<usize> = self.dev.take_async(nz_strides.into())?;
+
+        let physical_numel = grad_inp.data.len();
+        let cfg = LaunchConfig::for_num_elems(physical_numel as u32);
+        let params = (
+            physical_numel,                    // const size_t numel,
+            Src::NUM_DIMS,                     // const size_t num_dims,
+            elems_per_thread,                  // const float elems_per_thread,
+            &dims,                             // const size_t *dims,
+            inp.data.as_ref(),                 // const float *inp,
+            Arc::make_mut(&mut grad_inp.data), // float *grad_inp,
+            &inp_strides,                      // const size_t *inp_strides,
+            grad_out.data.as_ref(),            // const float *grad_out,
+            &out_strides,                      // const size_t *out_strides,
+            &nz_prod,                          // const float *nz_prod,
+            &num_zeros,                        // const float *num_zeros,
+            &nz_strides,                       // const size_t *nz_strides
+        );
+        unsafe { bwd_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+}
@@ -0,0 +1,136 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+pub trait ProdKernel<E: Dtype>: DeviceStorage {
+    fn forward<Src, Dst: Shape, Ax: Axes>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, E>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>;
+    fn backward<Src, Dst: Shape, Ax: Axes>(
+        &self,
+        inp: &Self::Storage<Src, E>,
+        grad_inp: &mut Self::Storage<Src, E>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>
+    where
+        Src: Shape + ReduceShapeTo<Dst, Ax>;
+}
+
+/// Reduction along multiple axes using multiplication.
+pub trait ProdTo: HasErr + HasShape {
+    /// Product reduction. **Pytorch equivalent**: `t.prod(Ax)`
+    ///
+    /// The gradient of each element is the product of all the other elements
+    /// that were reduced with it. This is computed without dividing by the element
+    /// itself, so zeros are handled correctly:
+    /// - If there are no zeros, the gradient is `prod / x_i`
+    /// - If there is exactly one zero, that element's gradient is the product of the rest,
+    ///   and every other element's gradient is `0`
+    /// - If there are multiple zeros, all gradients are `0`
+    ///
+    /// Example reducing a single axis:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 2.0, 3.0], [-1.0, -2.0, -3.0]]);
+    /// let r = t.prod::<Rank1<2>, _>(); // or `prod::<_, Axis<1>>()`
+    /// assert_eq!(r.array(), [6.0, -6.0]);
+    /// ```
+    ///
+    /// Reducing multiple axes:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// # let t = dev.tensor([[1.0, 2.0, 3.0], [-1.0, -2.0, -3.0]]);
+    /// let r = t.prod::<Rank0, _>();
+    /// assert_eq!(r.array(), -36.0);
+    /// ```
+    fn prod<Dst: Shape, Ax: Axes>(self) -> Self::WithShape<Dst>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_prod().unwrap()
+    }
+    /// Fallible version of [ProdTo::prod]
+    fn try_prod<Dst: Shape, Ax: Axes>(self) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: ProdKernel<E>, T: Tape<D>> ProdTo for Tensor<S, E, D, T> {
+    fn try_prod<Dst: Shape, Ax: Axes>(self) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ReduceShapeTo<Dst, Ax>,
+    {
+        let dst: Dst = self.shape().reduced();
+        let (inp, mut tape) = self.split_tape();
+        let out = inp.device.upgrade(inp.device.forward(dst, &inp.storage)?);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(&inp.storage, grad_inp, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor_ops::*;
+    use crate::tests::{assert_close, TestDevice};
+
+    #[test]
+    fn test_prod_1d() {
+        let dev: TestDevice = Default::default();
+        let t = dev.tensor([1.0, 2.0, 3.0, 4.0]);
+        let r = t.trace().prod::<Rank0, _>();
+        assert_eq!(r.array(), 24.0);
+        let g = r.backward();
+        assert_eq!(g.get(&t).array(), [24.0, 12.0, 8.0, 6.0]);
+    }
+
+    #[test]
+    fn test_prod_axis_1_2d() {
+        let dev: TestDevice = Default::default();
+        let t = dev.tensor([[1.0, 2.0, 3.0], [-1.0, -2.0, 0.5]]);
+        let r = t.trace().prod::<_, Axis<1>>();
+        assert_eq!(r.array(), [6.0, 1.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [[6.0, 3.0, 2.0], [-1.0, -0.5, 2.0]]);
+    }
+
+    #[test]
+    fn test_prod_with_zeros() {
+        let dev: TestDevice = Default::default();
+        let t = dev.tensor([[2.0, 0.0, 3.0, 4.0], [0.0, 2.0, 0.0, 5.0]]);
+        let r = t.trace().prod::<_, Axis<1>>();
+        assert_eq!(r.array(), [0.0, 0.0]);
+        let g = r.sum().backward();
+        assert_eq!(
+            g.get(&t).array(),
+            [[0.0, 24.0, 0.0, 0.0], [0.0, 0.0, 0.0, 0.0]]
+        );
+    }
+
+    #[test]
+    fn test_prod_axes_3d_to_1d() {
+        let dev: TestDevice = Default::default();
+        let t = dev.sample_normal::<Rank3<2, 3, 4>>();
+        let r = t.trace().prod::<Rank1<4>, _>();
+        let r2 = t.trace().prod::<_, Axis<0>>().prod::<_, Axis<0>>();
+        assert_close(&r.array(), &r2.array());
+        let g = r.mean().backward();
+        let g2 = r2.mean().backward();
+        assert_close(&g.get(&t).array(), &g2.get(&t).array());
+    }
+}
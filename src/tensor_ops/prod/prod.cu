@@ -0,0 +1,102 @@
+#include "cuda_utils.cuh"
+
+// There is no atomicMul for floats, so this is implemented with atomicCAS
+__device__ __forceinline__ float atomicMulf(float * addr, float value) {
+    unsigned int *addr_as_uint = (unsigned int *)addr;
+    unsigned int old = *addr_as_uint;
+    unsigned int assumed;
+    do {
+        assumed = old;
+        old = atomicCAS(addr_as_uint, assumed, __float_as_uint(value * __uint_as_float(assumed)));
+    } while (assumed != old);
+    return __uint_as_float(old);
+}
+
+// strides and dims specify how to index inp to put all multiplied elements next to
+// each other, and chunk_len is len(inp) / len(out)
+extern "C" __global__ void prod_forward(
+    const size_t numel,
+    const size_t num_dims,
+    const float elems_per_thread,
+    const size_t chunk_len,
+    const float *inp,
+    const size_t *dims,
+    const size_t *strides,
+    float *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int inp_i = get_strided_index(i, num_dims, dims, strides);
+    atomicMulf(out + i / chunk_len, powf(inp[inp_i], elems_per_thread));
+}
+
+// Computes the product of the non-zero elements & the number of zero elements
+// of each chunk. Indexing is the same as prod_forward.
+extern "C" __global__ void prod_nonzero(
+    const size_t numel,
+    const size_t num_dims,
+    const float elems_per_thread,
+    const size_t chunk_len,
+    const float *inp,
+    const size_t *dims,
+    const size_t *strides,
+    float *nz_prod,
+    float *num_zeros
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int inp_i = get_strided_index(i, num_dims, dims, strides);
+    float x = inp[inp_i];
+    if (x == 0.0) {
+        atomicAdd(num_zeros + i / chunk_len, elems_per_thread);
+    } else {
+        atomicMulf(nz_prod + i / chunk_len, powf(x, elems_per_thread));
+    }
+}
+
+// Accepts pre-broadcasted strides for input, output, and the non-zero statistics.
+// So all are expected to be broadcasted to the same size.
+extern "C" __global__ void prod_backward(
+    const size_t numel,
+    const size_t num_dims,
+    const float elems_per_thread,
+    const size_t *dims,
+    const float *inp,
+    float *grad_inp,
+    const size_t *inp_strides,
+    const float *grad_out,
+    const size_t *out_strides,
+    const float *nz_prod,
+    const float *num_zeros,
+    const size_t *nz_strides
+) {
+    unsigned int inp_i = blockIdx.x * blockDim.x + threadIdx.x;
+
+    if (inp_i >= numel) {
+        return;
+    }
+
+    unsigned int i = get_unstrided_index(inp_i, num_dims, dims, inp_strides);
+    unsigned int out_i = get_strided_index(i, num_dims, dims, out_strides);
+    unsigned int nz_i = get_strided_index(i, num_dims, dims, nz_strides);
+
+    float x = inp[inp_i];
+    float zeros = num_zeros[nz_i];
+    float d;
+    if (zeros == 0.0) {
+        d = nz_prod[nz_i] / x;
+    } else if (zeros == 1.0 && x == 0.0) {
+        d = nz_prod[nz_i];
+    } else {
+        d = 0.0;
+    }
+    grad_inp[inp_i] += grad_out[out_i] * d * elems_per_thread;
+}
@@ -0,0 +1,10 @@
+use crate::tensor_ops::cuda_kernels::UnaryOpCudaKernel;
+
+unsafe impl cudarc::driver::AsKernelParam for super::RecipKernelOp {}
+
+impl UnaryOpCudaKernel for super::RecipKernelOp {
+    const PTX_SRC: &'static str = include_str!(concat!(env!("OUT_DIR"), "/recip.ptx"));
+    const MODULE_NAME: &'static str = "recip";
+    const FWD_FN_NAME: &'static str = "recip_forward";
+    const BWD_FN_NAME: &'static str = "recip_backward";
+}
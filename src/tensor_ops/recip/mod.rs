@@ -0,0 +1,56 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::ops::{try_unary_op, UnaryKernel};
+use crate::{gradients::Tape, shapes::*, tensor::Tensor};
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct RecipKernelOp;
+
+/// [Reciprocal](https://en.wikipedia.org/wiki/Multiplicative_inverse). `1 / t`
+///
+/// The derivative is `-1 / t^2`. Like regular division, `t == 0` results
+/// in `inf` for both the value and the gradient.
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-2.0, 0.5, 1.0, 4.0]);
+/// let r = t.recip();
+/// assert_eq!(r.array(), [-0.5, 2.0, 1.0, 0.25]);
+/// ```
+pub fn recip<S: Shape, E: Dtype, D: UnaryKernel<RecipKernelOp, E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.recip()
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<RecipKernelOp, E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [recip]
+    pub fn recip(self) -> Self {
+        self.try_recip().unwrap()
+    }
+    /// See [recip]
+    pub fn try_recip(self) -> Result<Self, D::Err> {
+        try_unary_op(RecipKernelOp, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tensor::*, tensor_ops::*, tests::TestDevice};
+
+    #[test]
+    fn test_recip() {
+        let dev: TestDevice = Default::default();
+        let x = dev.tensor([-2.0, -0.5, 0.5, 1.0, 4.0]);
+        let r = x.trace().recip();
+        assert_eq!(r.array(), [-0.5, -2.0, 2.0, 1.0, 0.25]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&x).array(), [-0.25, -4.0, -4.0, -1.0, -0.0625]);
+    }
+}
@@ -0,0 +1,7 @@
+#include "unary_op_macros.cuh"
+
+struct RecipKernelOp {};
+
+UNARY_OP(recip_forward, recip_backward, RecipKernelOp,
+        1.0 / x,
+        -1.0 / (x * x));
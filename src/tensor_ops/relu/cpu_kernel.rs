@@ -1,16 +1,16 @@
 use crate::tensor_ops::cpu_kernels::UnaryDerivative;
 
-impl UnaryDerivative<f32> for super::ReLUKernelOp {
+impl<F: num_traits::Float> UnaryDerivative<F> for super::ReLUKernelOp {
     #[inline(always)]
-    fn f(&self, x: &f32) -> f32 {
-        x.max(0.0)
+    fn f(&self, x: &F) -> F {
+        x.max(F::zero())
     }
     #[inline(always)]
-    fn df(&self, x: &f32) -> f32 {
-        if x > &0.0 {
-            1.0
+    fn df(&self, x: &F) -> F {
+        if x > &F::zero() {
+            F::one()
         } else {
-            0.0
+            F::zero()
         }
     }
 }
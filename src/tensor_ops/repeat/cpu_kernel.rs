@@ -0,0 +1,49 @@
+use crate::{
+    shapes::{Axes, Dtype, ResizeDimTo, Shape},
+    tensor::cpu::{Cpu, LendingIterator, StridedArray},
+};
+
+impl<E: Dtype> super::RepeatKernel<E> for Cpu {
+    fn forward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, E>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>
+    where
+        Src: ResizeDimTo<Dst, Ax>,
+    {
+        let ax = Ax::as_array()[0] as usize;
+        let size = inp.shape.concrete()[ax];
+        let mut out = StridedArray::new(dst)?;
+        let mut out_iter = out.iter_mut_with_index();
+        while let Some((o, i_dst)) = out_iter.next() {
+            let mut i_inp: Src::Concrete = Default::default();
+            for d in 0..Src::NUM_DIMS {
+                i_inp[d] = if d == ax { i_dst[d] % size } else { i_dst[d] };
+            }
+            *o = inp[i_inp];
+        }
+        Ok(out)
+    }
+
+    fn backward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        grad_inp: &mut Self::Storage<Src, E>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>
+    where
+        Src: ResizeDimTo<Dst, Ax>,
+    {
+        let ax = Ax::as_array()[0] as usize;
+        let size = grad_inp.shape.concrete()[ax];
+        let mut out_iter = grad_out.iter_with_index();
+        while let Some((g, i_dst)) = out_iter.next() {
+            let mut i_inp: Src::Concrete = Default::default();
+            for d in 0..Src::NUM_DIMS {
+                i_inp[d] = if d == ax { i_dst[d] % size } else { i_dst[d] };
+            }
+            grad_inp[i_inp] += *g;
+        }
+        Ok(())
+    }
+}
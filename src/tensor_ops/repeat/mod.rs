@@ -0,0 +1,142 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+pub trait RepeatKernel<E: Dtype>: DeviceStorage {
+    fn forward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        dst: Dst,
+        inp: &Self::Storage<Src, E>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>
+    where
+        Src: ResizeDimTo<Dst, Ax>;
+    fn backward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        grad_inp: &mut Self::Storage<Src, E>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>
+    where
+        Src: ResizeDimTo<Dst, Ax>;
+}
+
+/// Repeats a tensor `N` times along an axis. Equivalent to `torch.Tensor.repeat`
+/// along a single dimension.
+///
+/// Unlike broadcasting, this copies the data, and works for axes of any size.
+pub trait TryRepeat: HasErr + HasShape {
+    /// Tiles the tensor `N` times along axis `Ax`, so that axis becomes `N` times larger.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<2, 2>, f32, _> = dev.tensor([[1.0, 2.0], [3.0, 4.0]]);
+    /// let r = t.clone().repeat::<Rank2<2, 6>, Axis<1>, 3>();
+    /// assert_eq!(r.array(), [[1.0, 2.0, 1.0, 2.0, 1.0, 2.0], [3.0, 4.0, 3.0, 4.0, 3.0, 4.0]]);
+    ///
+    /// let r = t.repeat::<Rank2<4, 2>, Axis<0>, 2>();
+    /// assert_eq!(r.array(), [[1.0, 2.0], [3.0, 4.0], [1.0, 2.0], [3.0, 4.0]]);
+    /// ```
+    ///
+    /// **Panics** if the size of `Ax` in `Dst` is a [Const] that is not `N` times the
+    /// size of the input axis.
+    fn repeat<Dst: Shape, Ax: Axes<Array = [isize; 1]>, const N: usize>(
+        self,
+    ) -> Self::WithShape<Dst>
+    where
+        Self::Shape: ResizeDimTo<Dst, Ax>,
+    {
+        self.try_repeat::<Dst, Ax, N>().unwrap()
+    }
+
+    /// Fallible version of [TryRepeat::repeat]
+    fn try_repeat<Dst: Shape, Ax: Axes<Array = [isize; 1]>, const N: usize>(
+        self,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ResizeDimTo<Dst, Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: RepeatKernel<E>, T: Tape<D>> TryRepeat for Tensor<S, E, D, T> {
+    fn try_repeat<Dst: Shape, Ax: Axes<Array = [isize; 1]>, const N: usize>(
+        self,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ResizeDimTo<Dst, Ax>,
+    {
+        let size = self.shape().concrete()[Ax::as_array()[0] as usize];
+        let dst: Dst = self.shape().resize(size * N);
+
+        let (inp, mut tape) = self.split_tape();
+        let storage = inp.device.forward(dst, &inp.storage)?;
+        let out = inp.device.upgrade(storage);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(grad_inp, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor_ops::*;
+    use crate::tests::TestDevice;
+
+    #[test]
+    fn test_repeat_1d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<2>, f32, _> = dev.tensor([1.0, 2.0]);
+        let r = t.trace().repeat::<Rank1<6>, _, 3>();
+        assert_eq!(r.array(), [1.0, 2.0, 1.0, 2.0, 1.0, 2.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [3.0, 3.0]);
+    }
+
+    #[test]
+    fn test_repeat_axis_0_weighted_backward() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<1, 3>, f32, _> = dev.tensor([[1.0, 2.0, 3.0]]);
+        let r = t.trace().repeat::<Rank2<2, 3>, Axis<0>, 2>();
+        assert_eq!(r.array(), [[1.0, 2.0, 3.0]; 2]);
+        let w = dev.tensor([[1.0, 2.0, 3.0], [10.0, 20.0, 30.0]]);
+        let g = (r * w).sum().backward();
+        assert_eq!(g.get(&t).array(), [[11.0, 22.0, 33.0]]);
+    }
+
+    #[test]
+    fn test_repeat_runtime_dim() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 1>, f32, _> = dev.tensor([[1.0], [2.0]]);
+        let r: Tensor<(Const<2>, usize), f32, _> = t.repeat::<_, Axis<1>, 3>();
+        assert_eq!(r.shape(), &(Const, 3));
+        assert_eq!(r.as_vec(), [1.0, 1.0, 1.0, 2.0, 2.0, 2.0]);
+    }
+
+    #[test]
+    fn test_repeat_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<2>, f32, _> = dev.tensor([1.0, 2.0]);
+        let r = t
+            .trace()
+            .broadcast::<Rank2<3, 2>, _>()
+            .repeat::<Rank2<3, 4>, Axis<1>, 2>();
+        assert_eq!(r.array(), [[1.0, 2.0, 1.0, 2.0]; 3]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [6.0, 6.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_repeat_wrong_dst_size() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<2>, f32, _> = dev.zeros();
+        let _ = t.repeat::<Rank1<5>, _, 3>();
+    }
+}
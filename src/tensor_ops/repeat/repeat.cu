@@ -0,0 +1,61 @@
+// Maps an index into the contiguous output to the input, where the input
+// is repeated along `ax` with `size` elements in each repetition.
+__device__ unsigned int repeated_index(
+    unsigned int i,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t size,
+    const size_t *dims,
+    const size_t *inp_strides
+) {
+    unsigned int inp_i = 0;
+    for (int d = num_dims - 1; d >= 0; d--) {
+        unsigned int i_d = i % dims[d];
+        i /= dims[d];
+        if (d == ax) {
+            i_d %= size;
+        }
+        inp_i += i_d * inp_strides[d];
+    }
+    return inp_i;
+}
+
+// One thread per output element. `dims` are the dims of the output.
+extern "C" __global__ void repeat_forward(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t size,
+    const size_t *dims,
+    const float *inp,
+    const size_t *inp_strides,
+    float *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    out[i] = inp[repeated_index(i, num_dims, ax, size, dims, inp_strides)];
+}
+
+// One thread per output element. `dims` are the dims of the output.
+extern "C" __global__ void repeat_backward(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t size,
+    const size_t *dims,
+    float *grad_inp,
+    const size_t *inp_strides,
+    const float *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    // every repetition maps to the same gradient
+    unsigned int inp_i = repeated_index(i, num_dims, ax, size, dims, inp_strides);
+    atomicAdd(grad_inp + inp_i, grad_out[i]);
+}
@@ -1,5 +1,5 @@
 use crate::{
-    shapes::{HasSameNumelAs, Shape},
+    shapes::Shape,
     tensor::cuda::{Cuda, CudaArray},
 };
 use cudarc::driver::{CudaSlice, LaunchAsync, LaunchConfig};
@@ -16,10 +16,8 @@ impl super::ReshapeKernel<f32> for Cuda {
         &self,
         dst: Dst,
         inp: &Self::Storage<Src, f32>,
-    ) -> Result<Self::Storage<Dst, f32>, Self::Err>
-    where
-        Src: HasSameNumelAs<Dst>,
-    {
+    ) -> Result<Self::Storage<Dst, f32>, Self::Err> {
+        super::check_numel(&inp.shape, &dst)?;
         if !self.dev.has_func(MODULE_NAME, FWD_FN_NAME) {
             self.dev
                 .load_ptx(PTX_SRC.into(), MODULE_NAME, &ALL_FN_NAMES)?;
@@ -49,7 +47,7 @@ impl super::ReshapeKernel<f32> for Cuda {
         unsafe { fwd_fn.launch_async(cfg, params) }?;
 
         Ok(CudaArray {
-            data: Arc::new(storage),
+            data: Arc::new(storage.into()),
             shape: dst,
             strides: dst.strides(),
         })
@@ -59,10 +57,7 @@ impl super::ReshapeKernel<f32> for Cuda {
         &self,
         grad_inp: &mut Self::Storage<Src, f32>,
         grad_out: &Self::Storage<Dst, f32>,
-    ) -> Result<(), Self::Err>
-    where
-        Src: HasSameNumelAs<Dst>,
-    {
+    ) -> Result<(), Self::Err> {
         let bwd_fn = self.dev.get_func(MODULE_NAME, BWD_FN_NAME).unwrap();
         let numel = grad_inp.data.len();
 
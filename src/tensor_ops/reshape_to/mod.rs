@@ -10,19 +10,37 @@ pub trait ReshapeKernel<E: Dtype>: DeviceStorage {
         &self,
         dst: Dst,
         inp: &Self::Storage<Src, E>,
-    ) -> Result<Self::Storage<Dst, E>, Self::Err>
-    where
-        Src: HasSameNumelAs<Dst>;
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>;
     fn backward<Src: Shape, Dst: Shape>(
         &self,
         grad_inp: &mut Self::Storage<Src, E>,
         grad_out: &Self::Storage<Dst, E>,
-    ) -> Result<(), Self::Err>
-    where
-        Src: HasSameNumelAs<Dst>;
+    ) -> Result<(), Self::Err>;
+}
+
+/// Checks that `src` and `dst` have the same number of elements.
+pub(crate) fn check_numel<Src: Shape, Dst: Shape>(src: &Src, dst: &Dst) -> Result<(), CpuError> {
+    let (src, dst) = (src.num_elements(), dst.num_elements());
+    if src != dst {
+        return Err(CpuError::ShapeMismatch { src, dst });
+    }
+    Ok(())
 }
 
-/// **Requires Nightly** Change the shape of a tensor moving data around.
+/// Change the shape of a tensor moving data around.
+///
+/// [ReshapeTo::reshape()] checks at compile time that the number of elements
+/// stays the same, which **requires nightly**. [ReshapeTo::reshape_like()] also
+/// works with runtime shapes, and [ReshapeTo::try_reshape_like()] returns
+/// [CpuError::ShapeMismatch] instead of panicking if the number of elements differ.
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t: Tensor<Rank2<2, 3>, f32, _> = dev.zeros();
+/// let _: Tensor<(usize,), f32, _> = t.clone().reshape_like(&(6,));
+/// assert!(t.try_reshape_like(&(Const::<5>,)).is_err());
+/// ```
 pub trait ReshapeTo: HasErr + HasShape {
     fn reshape<Dst: Shape + Default>(self) -> Self::WithShape<Dst>
     where
@@ -32,17 +50,20 @@ pub trait ReshapeTo: HasErr + HasShape {
     }
     fn try_reshape<Dst: Shape + Default>(self) -> Result<Self::WithShape<Dst>, Self::Err>
     where
-        Self::Shape: HasSameNumelAs<Dst>;
+        Self::Shape: HasSameNumelAs<Dst>,
+    {
+        self.try_reshape_like(&Default::default())
+    }
+    fn reshape_like<Dst: Shape>(self, dst: &Dst) -> Self::WithShape<Dst> {
+        self.try_reshape_like(dst).unwrap()
+    }
+    fn try_reshape_like<Dst: Shape>(self, dst: &Dst) -> Result<Self::WithShape<Dst>, Self::Err>;
 }
 
 impl<S: Shape, E: Dtype, D: ReshapeKernel<E>, T: Tape<D>> ReshapeTo for Tensor<S, E, D, T> {
-    fn try_reshape<Dst: Shape + Default>(self) -> Result<Self::WithShape<Dst>, Self::Err>
-    where
-        Self::Shape: HasSameNumelAs<Dst>,
-    {
-        let dst: Dst = Default::default();
+    fn try_reshape_like<Dst: Shape>(self, dst: &Dst) -> Result<Self::WithShape<Dst>, Self::Err> {
         let (inp, mut tape) = self.split_tape();
-        let out = inp.device.upgrade(inp.device.forward(dst, &inp.storage)?);
+        let out = inp.device.upgrade(inp.device.forward(*dst, &inp.storage)?);
         let phantom_out = out.clone();
         tape.try_alloc_grad(&inp)?;
         tape.try_alloc_grad(&out)?;
@@ -54,7 +75,6 @@ impl<S: Shape, E: Dtype, D: ReshapeKernel<E>, T: Tape<D>> ReshapeTo for Tensor<S
     }
 }
 
-#[cfg(feature = "nightly")]
 #[cfg(test)]
 mod tests {
     use crate::tensor::*;
@@ -63,6 +83,7 @@ mod tests {
 
     use super::*;
 
+    #[cfg(feature = "nightly")]
     #[test]
     fn test_valid_reshapes() {
         let dev: TestDevice = Default::default();
@@ -98,6 +119,7 @@ mod tests {
         let _: Tensor<Rank4<4, 1, 2, 2>, f32, _> = t.clone().reshape();
     }
 
+    #[cfg(feature = "nightly")]
     #[test]
     fn test_1d_reshape() {
         let dev: TestDevice = Default::default();
@@ -110,4 +132,32 @@ mod tests {
             [0.18419516, 0.20356713, 0.22497648, 0.24863747, 0.2747869, 0.3036865]
         )
     }
+
+    #[test]
+    fn test_reshape_like() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[0.1, 0.2, 0.3], [0.4, 0.5, 0.6]]);
+        let b = a.trace().reshape_like(&(Const::<6>,));
+        assert_eq!(b.array(), [0.1, 0.2, 0.3, 0.4, 0.5, 0.6]);
+        let g = (b * dev.tensor([1.0, 2.0, 3.0, 4.0, 5.0, 6.0]))
+            .sum()
+            .backward();
+        assert_eq!(g.get(&a).array(), [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    }
+
+    #[test]
+    fn test_reshape_like_runtime() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<(usize, Const<3>), f32, _> = dev.zeros_like(&(4, Const));
+        let b = a.reshape_like(&(2, 6));
+        assert_eq!(b.shape(), &(2, 6));
+    }
+
+    #[test]
+    fn test_reshape_like_wrong_numel() {
+        let dev: Cpu = Default::default();
+        let a: Tensor<Rank2<2, 3>, f32, _> = dev.zeros();
+        let r = a.trace().try_reshape_like(&(Const::<5>,));
+        assert!(matches!(r, Err(CpuError::ShapeMismatch { src: 6, dst: 5 })));
+    }
 }
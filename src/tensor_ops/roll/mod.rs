@@ -0,0 +1,131 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+pub trait RollKernel<E: Dtype>: DeviceStorage {
+    /// `shift` is always in `0..size` of the axis.
+    fn forward<S, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        inp: &Self::Storage<S, E>,
+        shift: usize,
+    ) -> Result<Self::Storage<S, E>, Self::Err>
+    where
+        S: Shape + HasAxes<Ax>;
+    fn backward<S, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+        shift: usize,
+    ) -> Result<(), Self::Err>
+    where
+        S: Shape + HasAxes<Ax>;
+}
+
+/// Circularly shifts elements along a single axis.
+pub trait Roll: HasErr + HasShape {
+    /// Shifts elements `shift` positions along axis `Ax`, wrapping elements
+    /// that go past the end around to the start. Negative shifts roll towards
+    /// the start, and shifts larger than the axis wrap around.
+    ///
+    /// **Pytorch equivalent**: `t.roll(shift, Ax)`
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+    /// let r = t.clone().roll::<Axis<1>>(1);
+    /// assert_eq!(r.array(), [[3.0, 1.0, 2.0], [6.0, 4.0, 5.0]]);
+    ///
+    /// let r = t.roll::<Axis<1>>(-1);
+    /// assert_eq!(r.array(), [[2.0, 3.0, 1.0], [5.0, 6.0, 4.0]]);
+    /// ```
+    fn roll<Ax: Axes<Array = [isize; 1]>>(self, shift: isize) -> Self
+    where
+        Self::Shape: HasAxes<Ax>,
+    {
+        self.try_roll::<Ax>(shift).unwrap()
+    }
+    /// Fallible version of [Roll::roll]
+    fn try_roll<Ax: Axes<Array = [isize; 1]>>(self, shift: isize) -> Result<Self, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: RollKernel<E>, T: Tape<D>> Roll for Tensor<S, E, D, T> {
+    fn try_roll<Ax: Axes<Array = [isize; 1]>>(self, shift: isize) -> Result<Self, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax>,
+    {
+        let size = self.shape().concrete()[Ax::as_array()[0] as usize];
+        let shift = if size == 0 {
+            0
+        } else {
+            shift.rem_euclid(size as isize) as usize
+        };
+
+        let (inp, mut tape) = self.split_tape();
+        let storage = inp.device.forward::<S, Ax>(&inp.storage, shift)?;
+        let out = inp.device.upgrade(storage);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward::<S, Ax>(grad_inp, grad_out, shift)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor_ops::*;
+    use crate::tests::TestDevice;
+
+    #[test]
+    fn test_roll_1d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<4>, f32, _> = dev.tensor([1.0, 2.0, 3.0, 4.0]);
+        let r = t.trace().roll::<Axis<0>>(1);
+        assert_eq!(r.array(), [4.0, 1.0, 2.0, 3.0]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [1.0; 4]);
+    }
+
+    #[test]
+    fn test_roll_negative_and_wrapping_shifts() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<4>, f32, _> = dev.tensor([1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(t.clone().roll::<Axis<0>>(-1).array(), [2.0, 3.0, 4.0, 1.0]);
+        assert_eq!(t.clone().roll::<Axis<0>>(5).array(), [4.0, 1.0, 2.0, 3.0]);
+        assert_eq!(t.clone().roll::<Axis<0>>(-6).array(), [3.0, 4.0, 1.0, 2.0]);
+        assert_eq!(t.clone().roll::<Axis<0>>(4).array(), t.array());
+    }
+
+    #[test]
+    fn test_roll_2d_weighted_backward() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<3, 2>, f32, _> = dev.tensor([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        let r = t.trace().roll::<Axis<0>>(1);
+        assert_eq!(r.array(), [[5.0, 6.0], [1.0, 2.0], [3.0, 4.0]]);
+        let w = dev.tensor([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        let g = (r * w).sum().backward();
+        // the gradient is the weights rolled back
+        assert_eq!(g.get(&t).array(), [[3.0, 4.0], [5.0, 6.0], [1.0, 2.0]]);
+    }
+
+    #[test]
+    fn test_roll_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<3>, f32, _> = dev.tensor([1.0, 2.0, 3.0]);
+        let r = t.trace().broadcast::<Rank2<2, 3>, _>().roll::<Axis<1>>(2);
+        assert_eq!(r.array(), [[2.0, 3.0, 1.0]; 2]);
+        let w = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let g = (r * w).sum().backward();
+        assert_eq!(g.get(&t).array(), [9.0, 5.0, 7.0]);
+    }
+}
@@ -0,0 +1,92 @@
+#![allow(clippy::needless_range_loop)]
+
+use crate::shapes::{Axes, Dtype, ReplaceDimTo, Shape};
+use crate::tensor::cpu::{Cpu, LendingIterator, StridedArray};
+use crate::tensor_ops::select_and_gather::check_indices;
+
+impl<E: Dtype> super::ScatterKernel<E> for Cpu {
+    fn forward<Src, Dst: Shape, Idx: Shape>(
+        &self,
+        inp: &Self::Storage<Src, E>,
+        idx: &Self::Storage<Idx, usize>,
+        values: &Self::Storage<Dst, E>,
+    ) -> Result<Self::Storage<Src, E>, Self::Err>
+    where
+        Src: ReplaceDimTo<Dst, Idx>,
+    {
+        let ax = Src::Ax::as_array()[0] as usize;
+        assert!(<Idx as Shape>::NUM_DIMS >= ax);
+        super::check_scatter_shapes(&inp.shape, &idx.shape, &values.shape)?;
+        check_indices(idx.data.as_ref(), ax, inp.shape.concrete()[ax])?;
+
+        // NOTE: this is the same exact indexing logic as gather
+        let offset = <Idx as Shape>::NUM_DIMS - ax;
+
+        let mut out = StridedArray::new(inp.shape)?;
+        {
+            let mut out_iter = out.iter_mut();
+            let mut inp_iter = inp.iter();
+            while let Some((o, i)) = out_iter.next().zip(inp_iter.next()) {
+                *o = *i;
+            }
+        }
+
+        let mut values_iter = values.iter_with_index();
+        while let Some((x, i_replaced)) = values_iter.next() {
+            let mut i_idx: <Idx as Shape>::Concrete = Default::default();
+            let mut i_out: Src::Concrete = Default::default();
+            for j in 0..<Idx as Shape>::NUM_DIMS {
+                i_idx[j] = i_replaced[j];
+            }
+            for j in 0..Src::NUM_DIMS {
+                i_out[j] = match j.cmp(&ax) {
+                    std::cmp::Ordering::Less => i_replaced[j],
+                    std::cmp::Ordering::Equal => idx[i_idx],
+                    std::cmp::Ordering::Greater => i_replaced[j - 1 + offset],
+                };
+            }
+            out[i_out] += *x;
+        }
+        Ok(out)
+    }
+
+    fn backward<Src, Dst: Shape, Idx: Shape>(
+        &self,
+        grad_inp: &mut Self::Storage<Src, E>,
+        idx: &Self::Storage<Idx, usize>,
+        grad_values: &mut Self::Storage<Dst, E>,
+        grad_out: &Self::Storage<Src, E>,
+    ) -> Result<(), Self::Err>
+    where
+        Src: ReplaceDimTo<Dst, Idx>,
+    {
+        let ax = Src::Ax::as_array()[0] as usize;
+        let offset = <Idx as Shape>::NUM_DIMS - ax;
+
+        {
+            let mut grad_inp_iter = grad_inp.iter_mut();
+            let mut grad_out_iter = grad_out.iter();
+            while let Some((gi, go)) = grad_inp_iter.next().zip(grad_out_iter.next()) {
+                *gi += *go;
+            }
+        }
+
+        let mut values_iter = grad_values.iter_mut_with_index();
+        while let Some((x, i_replaced)) = values_iter.next() {
+            let mut i_idx: <Idx as Shape>::Concrete = Default::default();
+            let mut i_out: Src::Concrete = Default::default();
+            for j in 0..<Idx as Shape>::NUM_DIMS {
+                i_idx[j] = i_replaced[j];
+            }
+            for j in 0..Src::NUM_DIMS {
+                i_out[j] = match j.cmp(&ax) {
+                    std::cmp::Ordering::Less => i_replaced[j],
+                    std::cmp::Ordering::Equal => idx[i_idx],
+                    std::cmp::Ordering::Greater => i_replaced[j - 1 + offset],
+                };
+            }
+            *x += grad_out[i_out];
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{
+    shapes::{Axes, ReplaceDimTo, Shape},
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{CudaSlice, LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/scatter.ptx"));
+const MODULE_NAME: &str = "scatter";
+const IDENTITY_FN_NAME: &str = "scatter_identity";
+const FWD_FN_NAME: &str = "scatter_forward";
+const BWD_FN_NAME: &str = "scatter_backward";
+const ALL_FN_NAMES: [&str; 3] = [IDENTITY_FN_NAME, FWD_FN_NAME, BWD_FN_NAME];
+
+impl super::ScatterKernel<f32> for Cuda {
+    fn forward<Src, Dst: Shape, Idx: Shape>(
+        &self,
+        inp: &Self::Storage<Src, f32>,
+        idx: &Self::Storage<Idx, usize>,
+        values: &Self::Storage<Dst, f32>,
+    ) -> Result<Self::Storage<Src, f32>, Self::Err>
+    where
+        Src: ReplaceDimTo<Dst, Idx>,
+    {
+        let ax = Src::Ax::as_array()[0] as usize;
+        super::check_scatter_shapes(&inp.shape, &idx.shape, &values.shape)?;
+        self.check_indices(idx, ax, inp.shape.concrete()[ax])?;
+
+        if !self.dev.has_func(MODULE_NAME, FWD_FN_NAME) {
+            self.dev
+                .load_ptx(PTX_SRC.into(), MODULE_NAME, &ALL_FN_NAMES)?;
+        }
+
+        let shape = inp.shape;
+        let strides = shape.strides();
+        let numel = shape.num_elements();
+        let mut storage = self.dev.alloc_zeros_async::<f32>(numel)?;
+
+        let out_dims: CudaSlice<usize> = self.dev.take_async(shape.concrete().into())?;
+        let inp_strides: CudaSlice<usize> = self.dev.take_async(inp.strides.into())?;
+        let out_strides: CudaSlice<usize> = self.dev.take_async(strides.into())?;
+
+        let identity_fn = self.dev.get_func(MODULE_NAME, IDENTITY_FN_NAME).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,             // const size_t numel,
+            Src::NUM_DIMS,     // const size_t num_dims,
+            &out_dims,         // const size_t *dims,
+            inp.data.as_ref(), // const float *inp,
+            &inp_strides,      // const size_t *inp_strides,
+            &mut storage,      // float *out,
+            &out_strides,      // const size_t *out_strides
+        );
+        unsafe { identity_fn.launch_async(cfg, params) }?;
+
+        let numel = values.shape.num_elements();
+        let idx_dims: CudaSlice<usize> = self.dev.take_async(idx.shape.concrete().into())?;
+        let idx_strides: CudaSlice<usize> = self.dev.take_async(idx.strides.into())?;
+        let values_dims: CudaSlice<usize> = self.dev.take_async(values.shape.concrete().into())?;
+        let values_strides: CudaSlice<usize> = self.dev.take_async(values.strides.into())?;
+
+        let fwd_fn = self.dev.get_func(MODULE_NAME, FWD_FN_NAME).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,                // const size_t numel,
+            &mut storage,         // float *out,
+            Src::NUM_DIMS,        // const size_t out_num_dims,
+            &out_dims,            // const size_t *out_dims,
+            &out_strides,         // const size_t *out_strides,
+            idx.data.as_ref(),    // const size_t *idx,
+            Idx::NUM_DIMS,        // const size_t idx_num_dims,
+            &idx_dims,            // const size_t *idx_dims,
+            &idx_strides,         // const size_t *idx_strides,
+            values.data.as_ref(), // const float *values,
+            Dst::NUM_DIMS,        // const size_t values_num_dims,
+            &values_dims,         // const size_t *values_dims,
+            &values_strides,      // const size_t *values_strides
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+
+        Ok(CudaArray {
+            data: Arc::new(storage.into()),
+            shape,
+            strides,
+        })
+    }
+
+    fn backward<Src, Dst: Shape, Idx: Shape>(
+        &self,
+        grad_inp: &mut Self::Storage<Src, f32>,
+        idx: &Self::Storage<Idx, usize>,
+        grad_values: &mut Self::Storage<Dst, f32>,
+        grad_out: &Self::Storage<Src, f32>,
+    ) -> Result<(), Self::Err>
+    where
+        Src: ReplaceDimTo<Dst, Idx>,
+    {
+        let numel = grad_out.shape.num_elements();
+        let out_dims: CudaSlice<usize> = self.dev.take_async(grad_out.shape.concrete().into())?;
+        let inp_strides: CudaSlice<usize> = self.dev.take_async(grad_inp.strides.into())?;
+        let out_strides: CudaSlice<usize> = self.dev.take_async(grad_out.strides.into())?;
+
+        let identity_fn = self.dev.get_func(MODULE_NAME, IDENTITY_FN_NAME).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,                             // const size_t numel,
+            Src::NUM_DIMS,                     // const size_t num_dims,
+            &out_dims,                         // const size_t *dims,
+            grad_out.data.as_ref(),            // const float *inp,
+            &out_strides,                      // const size_t *inp_strides,
+            Arc::make_mut(&mut grad_inp.data), // float *out,
+            &inp_strides,                      // const size_t *out_strides
+        );
+        unsafe { identity_fn.launch_async(cfg, params) }?;
+
+        let numel = grad_values.shape.num_elements();
+        let idx_dims: CudaSlice<usize> = self.dev.take_async(idx.shape.concrete().into())?;
+        let idx_strides: CudaSlice<usize> = self.dev.take_async(idx.strides.into())?;
+        let values_dims: CudaSlice<usize> =
+            self.dev.take_async(grad_values.shape.concrete().into())?;
+        let values_strides: CudaSlice<usize> = self.dev.take_async(grad_values.strides.into())?;
+
+        let bwd_fn = self.dev.get_func(MODULE_NAME, BWD_FN_NAME).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,                                // const size_t numel,
+            grad_out.data.as_ref(),               // const float *grad_out,
+            Src::NUM_DIMS,                        // const size_t out_num_dims,
+            &out_dims,                            // const size_t *out_dims,
+            &out_strides,                         // const size_t *out_strides,
+            idx.data.as_ref(),                    // const size_t *idx,
+            Idx::NUM_DIMS,                        // const size_t idx_num_dims,
+            &idx_dims,                            // const size_t *idx_dims,
+            &idx_strides,                         // const size_t *idx_strides,
+            Arc::make_mut(&mut grad_values.data), // float *grad_values,
+            Dst::NUM_DIMS,                        // const size_t values_num_dims,
+            &values_dims,                         // const size_t *values_dims,
+            &values_strides,                      // const size_t *values_strides
+        );
+        unsafe { bwd_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+}
@@ -0,0 +1,262 @@
+#![allow(clippy::type_complexity)]
+
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{
+    gradients::{Merge, Tape},
+    shapes::*,
+    tensor::{cpu::CpuError, *},
+};
+
+pub trait ScatterKernel<E: Dtype>: DeviceStorage {
+    fn forward<Src, Dst: Shape, Idx: Shape>(
+        &self,
+        inp: &Self::Storage<Src, E>,
+        idx: &Self::Storage<Idx, usize>,
+        values: &Self::Storage<Dst, E>,
+    ) -> Result<Self::Storage<Src, E>, Self::Err>
+    where
+        Src: ReplaceDimTo<Dst, Idx>;
+    fn backward<Src, Dst: Shape, Idx: Shape>(
+        &self,
+        grad_inp: &mut Self::Storage<Src, E>,
+        idx: &Self::Storage<Idx, usize>,
+        grad_values: &mut Self::Storage<Dst, E>,
+        grad_out: &Self::Storage<Src, E>,
+    ) -> Result<(), Self::Err>
+    where
+        Src: ReplaceDimTo<Dst, Idx>;
+}
+
+/// Checks that `idx` matches `inp` on the axes before the scattered one, and that `values`
+/// has the shape that gathering from `inp` with `idx` would produce.
+pub(crate) fn check_scatter_shapes<Src, Dst: Shape, Idx: Shape>(
+    inp: &Src,
+    idx: &Idx,
+    values: &Dst,
+) -> Result<(), CpuError>
+where
+    Src: ReplaceDimTo<Dst, Idx>,
+{
+    let ax = Src::Ax::as_array()[0] as usize;
+    let (inp_dims, idx_dims, values_dims) = (inp.concrete(), idx.concrete(), values.concrete());
+    let batched = Src::NUM_DIMS != Dst::NUM_DIMS;
+    if !batched {
+        for axis in 0..Idx::NUM_DIMS - 1 {
+            if idx_dims[axis] != inp_dims[axis] {
+                return Err(CpuError::DimMismatch {
+                    axis,
+                    expected: inp_dims[axis],
+                    found: idx_dims[axis],
+                });
+            }
+        }
+    }
+    for axis in 0..Dst::NUM_DIMS {
+        let expected = match (batched, axis.cmp(&ax)) {
+            (true, _) if axis < Idx::NUM_DIMS => idx_dims[axis],
+            (true, _) => inp_dims[axis + 1 - Idx::NUM_DIMS],
+            (false, std::cmp::Ordering::Equal) => idx_dims[Idx::NUM_DIMS - 1],
+            (false, _) => inp_dims[axis],
+        };
+        if values_dims[axis] != expected {
+            return Err(CpuError::DimMismatch {
+                axis,
+                expected,
+                found: values_dims[axis],
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Add values into a single axis at the given indices. This is the inverse of [super::GatherTo],
+/// and is equivalent to `torch.scatter_add` from pytorch.
+pub trait ScatterTo<Values: HasShape, D: DeviceStorage>: HasErr + HasShape {
+    /// Accumulates `values` into `self` at the positions named by `idx`.
+    ///
+    /// The index and values shapes follow the same conventions as [super::GatherTo::gather]:
+    /// `values` has the shape that gathering from `self` with `idx` would produce, and
+    /// each element of `values` is added to the element of `self` it would have been gathered from.
+    /// Repeated indices accumulate.
+    ///
+    /// For example, given a tensor of shape (M, N, O), here are the required
+    /// index & values shapes to scatter into each axis:
+    /// - Axis 0: index shape (Z, ), values shape (Z, N, O)
+    /// - Axis 1: index shape (M, Z), values shape (M, Z, O)
+    /// - Axis 2: index shape (M, N, Z), values shape (M, N, Z)
+    ///
+    /// Here is an example scattering into a 2d tensor:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank2<3, 2>, f32, _> = dev.zeros();
+    ///
+    /// // scatter into the 0th axis
+    /// let idx: Tensor<Rank1<4>, usize, _> = dev.tensor([0, 0, 1, 2]);
+    /// let values = dev.tensor([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0]]);
+    /// let r = a.clone().scatter_add(idx, values);
+    /// assert_eq!(r.array(), [[4.0, 6.0], [5.0, 6.0], [7.0, 8.0]]);
+    ///
+    /// // scatter into the 1st axis
+    /// let idx: Tensor<Rank2<3, 1>, usize, _> = dev.tensor([[1], [0], [1]]);
+    /// let values = dev.tensor([[1.0], [2.0], [3.0]]);
+    /// let r = a.scatter_add(idx, values);
+    /// assert_eq!(r.array(), [[0.0, 1.0], [2.0, 0.0], [0.0, 3.0]]);
+    ///```
+    fn scatter_add<Idx: Shape>(self, idx: Tensor<Idx, usize, D>, values: Values) -> Self
+    where
+        Self::Shape: ReplaceDimTo<Values::Shape, Idx>,
+    {
+        self.try_scatter_add(idx, values).unwrap()
+    }
+
+    /// Fallible version of [ScatterTo::scatter_add]. Returns [CpuError::DimMismatch] if the
+    /// shapes of `idx` and `values` don't agree with `self`.
+    fn try_scatter_add<Idx: Shape>(
+        self,
+        idx: Tensor<Idx, usize, D>,
+        values: Values,
+    ) -> Result<Self, Self::Err>
+    where
+        Self::Shape: ReplaceDimTo<Values::Shape, Idx>;
+}
+
+impl<Src: Shape, Dst: Shape, E: Dtype, D: ScatterKernel<E>, T: Tape<D> + Merge<R>, R: Tape<D>>
+    ScatterTo<Tensor<Dst, E, D, R>, D> for Tensor<Src, E, D, T>
+{
+    fn try_scatter_add<Idx: Shape>(
+        self,
+        idx: Tensor<Idx, usize, D>,
+        values: Tensor<Dst, E, D, R>,
+    ) -> Result<Self, Self::Err>
+    where
+        Src: ReplaceDimTo<Dst, Idx>,
+    {
+        let (inp, tape) = self.split_tape();
+        let (values, values_tape) = values.split_tape();
+        let mut tape = tape.merge(values_tape);
+        let storage = inp
+            .device
+            .forward(&inp.storage, &idx.storage, &values.storage)?;
+        let out = inp.device.upgrade(storage);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&values)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_values, grad_out) = grads.muts_and_ref(&inp, &values, &phantom_out);
+            inp.device
+                .backward(grad_inp, &idx.storage, grad_values, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor_ops::*;
+    use crate::tests::{assert_close, TestDevice};
+
+    #[test]
+    fn test_scatter_1d_backward() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<5>, f32, _> = dev.sample_normal();
+        let v: Tensor<Rank1<4>, f32, _> = dev.sample_normal();
+        let t_array = t.array();
+        let v_array = v.array();
+        let r = t.trace().scatter_add(dev.tensor([0, 1, 1, 3]), v.trace());
+        let r_array = r.array();
+        assert_close(
+            &r_array,
+            &[
+                t_array[0] + v_array[0],
+                t_array[1] + v_array[1] + v_array[2],
+                t_array[2],
+                t_array[3] + v_array[3],
+                t_array[4],
+            ],
+        );
+        let g = r.exp().sum().backward();
+        assert_close(&g.get(&t).array(), &r_array.map(f32::exp));
+        assert_close(
+            &g.get(&v).array(),
+            &[
+                r_array[0].exp(),
+                r_array[1].exp(),
+                r_array[1].exp(),
+                r_array[3].exp(),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_scatter_is_gather_adjoint() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<4, 3>, f32, _> = dev.zeros();
+        let v: Tensor<Rank2<5, 3>, f32, _> = dev.sample_normal();
+        let idx = dev.tensor([2, 0, 2, 3, 0]);
+
+        // scattering is the same as the backward of gather
+        let g = (t.trace().gather(idx.clone()) * v.clone()).sum().backward();
+        let r = t.clone().scatter_add(idx, v);
+        assert_close(&r.array(), &g.get(&t).array());
+    }
+
+    #[test]
+    fn test_scatter_3d_axis_2_backward() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 2, 3>, f32, _> = dev.zeros();
+        let v = dev.tensor([[[1.0, 2.0], [3.0, 4.0]], [[5.0, 6.0], [7.0, 8.0]]]);
+        let idx = dev.tensor([[[0, 0], [1, 2]], [[2, 1], [1, 1]]]);
+        let r = t.trace().scatter_add(idx, v.trace());
+        assert_eq!(
+            r.array(),
+            [
+                [[3.0, 0.0, 0.0], [0.0, 3.0, 4.0]],
+                [[0.0, 6.0, 5.0], [0.0, 15.0, 0.0]]
+            ]
+        );
+        let g = (r * dev.tensor([[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]; 2]))
+            .sum()
+            .backward();
+        assert_eq!(g.get(&t).array(), [[[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]; 2]);
+        assert_eq!(
+            g.get(&v).array(),
+            [[[1.0, 1.0], [5.0, 6.0]], [[3.0, 2.0], [5.0, 5.0]]]
+        );
+    }
+
+    #[test]
+    fn test_try_scatter_add_shape_mismatch() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<(usize, Const<3>), f32, _> = dev.zeros_like(&(4, Const));
+        let idx = dev.tensor_from_vec(std::vec![2, 0, 2, 3, 0], (5,));
+        let v: Tensor<(usize, Const<3>), f32, _> = dev.zeros_like(&(4, Const));
+        let r = t.clone().try_scatter_add(idx, v);
+        assert!(matches!(
+            r,
+            Err(CpuError::DimMismatch {
+                axis: 0,
+                expected: 5,
+                found: 4
+            })
+        ));
+
+        let idx: Tensor<(usize, Const<1>), usize, _> = dev.zeros_like(&(2, Const));
+        let v: Tensor<(usize, Const<1>), f32, _> = dev.zeros_like(&(2, Const));
+        let r = t.try_scatter_add(idx, v);
+        assert!(matches!(
+            r,
+            Err(CpuError::DimMismatch {
+                axis: 0,
+                expected: 4,
+                found: 2
+            })
+        ));
+    }
+}
@@ -0,0 +1,117 @@
+#include "cuda_utils.cuh"
+
+// NOTE: this is the same exact indexing logic as gather, where `out` here is `inp` in gather,
+// and `values` here is `out` in gather.
+__device__ unsigned int get_scattered_index(
+    const unsigned int index,
+    const size_t out_num_dims,
+    const size_t *out_dims,
+    const size_t *out_strides,
+    const size_t *idx,
+    const size_t idx_num_dims,
+    const size_t *idx_dims,
+    const size_t *idx_strides,
+    const size_t values_num_dims
+) {
+    unsigned int ax;
+
+    if (values_num_dims > out_num_dims) {
+        ax = 0;
+    } else {
+        ax = idx_num_dims - 1;
+    }
+
+    unsigned int elem_size = 1; // the size of each indexed element
+    unsigned int row_len = out_dims[ax]; // the size of the indexed dimension
+
+    for (unsigned int d = 0; d < out_num_dims - ax - 1; d++) {
+        unsigned int dim_idx = out_num_dims - 1 - d;
+        elem_size *= out_dims[dim_idx];
+    }
+
+    // location to find the index for the replaced dimension in "idx"
+    unsigned int idx_idx = get_strided_index(index / elem_size, idx_num_dims, idx_dims, idx_strides);
+
+    // indices for dimensions before, at, and after the indexed dimension
+    unsigned int idx_before = index / (elem_size * row_len);
+    unsigned int idx_mid = idx[idx_idx];
+    unsigned int idx_after = index % elem_size;
+
+    // recombine
+    unsigned int new_idx = (idx_before * row_len + idx_mid) * elem_size + idx_after;
+    return get_strided_index(new_idx, out_num_dims, out_dims, out_strides);
+}
+
+extern "C" __global__ void scatter_identity(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const float *inp,
+    const size_t *inp_strides,
+    float *out,
+    const size_t *out_strides
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int inp_i = get_strided_index(i, num_dims, dims, inp_strides);
+    unsigned int out_i = get_strided_index(i, num_dims, dims, out_strides);
+
+    atomicAdd(out + out_i, inp[inp_i]);
+}
+
+extern "C" __global__ void scatter_forward(
+    const size_t numel,
+    float *out,
+    const size_t out_num_dims,
+    const size_t *out_dims,
+    const size_t *out_strides,
+    const size_t *idx,
+    const size_t idx_num_dims,
+    const size_t *idx_dims,
+    const size_t *idx_strides,
+    const float *values,
+    const size_t values_num_dims,
+    const size_t *values_dims,
+    const size_t *values_strides
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int values_i = get_strided_index(i, values_num_dims, values_dims, values_strides);
+    unsigned int out_i =
+        get_scattered_index(i, out_num_dims, out_dims, out_strides, idx, idx_num_dims, idx_dims, idx_strides, values_num_dims);
+
+    atomicAdd(out + out_i, values[values_i]);
+}
+
+extern "C" __global__ void scatter_backward(
+    const size_t numel,
+    const float *grad_out,
+    const size_t out_num_dims,
+    const size_t *out_dims,
+    const size_t *out_strides,
+    const size_t *idx,
+    const size_t idx_num_dims,
+    const size_t *idx_dims,
+    const size_t *idx_strides,
+    float *grad_values,
+    const size_t values_num_dims,
+    const size_t *values_dims,
+    const size_t *values_strides
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int values_i = get_strided_index(i, values_num_dims, values_dims, values_strides);
+    unsigned int out_i =
+        get_scattered_index(i, out_num_dims, out_dims, out_strides, idx, idx_num_dims, idx_dims, idx_strides, values_num_dims);
+
+    atomicAdd(grad_values + values_i, grad_out[out_i]);
+}
@@ -1,7 +1,8 @@
 #![allow(clippy::needless_range_loop)]
 
-use crate::shapes::{Axes, Dtype, RemoveDimTo, ReplaceDimTo, Shape};
+use crate::shapes::{Axes, Dim, Dtype, RemoveDimTo, ReplaceDimTo, Shape};
 use crate::tensor::cpu::{Cpu, LendingIterator, StridedArray};
+use alloc::{sync::Arc, vec, vec::Vec};
 
 impl<E: Dtype> super::ReplaceDimKernel<E> for Cpu {
     fn forward<Src: Shape, Dst: Shape, Idx: Shape>(
@@ -14,6 +15,7 @@ impl<E: Dtype> super::ReplaceDimKernel<E> for Cpu {
     {
         let ax = Src::Ax::as_array()[0] as usize;
         assert!(<Idx as Shape>::NUM_DIMS >= ax);
+        super::check_indices(idx.data.as_ref(), ax, inp.shape.concrete()[ax])?;
 
         let offset = <Idx as Shape>::NUM_DIMS - ax;
 
@@ -54,24 +56,46 @@ impl<E: Dtype> super::ReplaceDimKernel<E> for Cpu {
     {
         let ax = Src::Ax::as_array()[0] as usize;
 
-        // NOTE: this is the same exact indexing logic as forward
-        let offset = <Idx as Shape>::NUM_DIMS - ax;
+        // NOTE: this is the same exact indexing logic as forward, except that
+        // the dimensions after the indexed axis are handled all at once for
+        // each index. `out` is laid out as `[idx dims..., inp dims after ax...]`,
+        // so the offsets of those trailing dimensions can be computed once up front.
+        let inp_dims = grad_inp.shape.concrete();
+        let mut inp_tail: Vec<usize> = vec![0];
+        let mut out_tail: Vec<usize> = vec![0];
+        for (j, dim) in inp_dims.into_iter().enumerate().skip(ax + 1) {
+            let j_out = j - 1 - ax + <Idx as Shape>::NUM_DIMS;
+            let mut next_inp = Vec::with_capacity(inp_tail.len() * dim);
+            let mut next_out = Vec::with_capacity(out_tail.len() * dim);
+            for (&i_inp, &i_out) in inp_tail.iter().zip(out_tail.iter()) {
+                for k in 0..dim {
+                    next_inp.push(i_inp + k * grad_inp.strides[j]);
+                    next_out.push(i_out + k * grad_out.strides[j_out]);
+                }
+            }
+            inp_tail = next_inp;
+            out_tail = next_out;
+        }
 
-        let mut out_iter = grad_out.iter_with_index();
-        while let Some((x, i_replaced)) = out_iter.next() {
-            let mut i_idx: <Idx as Shape>::Concrete = Default::default();
-            let mut i_inp: Src::Concrete = Default::default();
+        // NOTE: repeated indices are not grouped together. `grad_out` is read once and in
+        // order here, and reading it is what bounds this loop. Visiting it grouped by index
+        // reads it out of order, which was measured to be slower even when most indices repeat.
+        let grad_inp_data = Arc::make_mut(&mut grad_inp.data);
+        let mut idx_iter = idx.iter_with_index();
+        while let Some((&i_ax, i_idx)) = idx_iter.next() {
+            // the indices into `idx` are the "head" of the indices into `out`,
+            // and the first `ax` of them are also the head of the indices into `inp`.
+            let mut inp_head = i_ax * grad_inp.strides[ax];
+            let mut out_head = 0;
             for j in 0..<Idx as Shape>::NUM_DIMS {
-                i_idx[j] = i_replaced[j];
+                if j < ax {
+                    inp_head += i_idx[j] * grad_inp.strides[j];
+                }
+                out_head += i_idx[j] * grad_out.strides[j];
             }
-            for j in 0..Src::NUM_DIMS {
-                i_inp[j] = match j.cmp(&ax) {
-                    std::cmp::Ordering::Less => i_replaced[j],
-                    std::cmp::Ordering::Equal => idx[i_idx],
-                    std::cmp::Ordering::Greater => i_replaced[j - 1 + offset],
-                };
+            for (&i_inp, &i_out) in inp_tail.iter().zip(out_tail.iter()) {
+                grad_inp_data[inp_head + i_inp] += grad_out.data[out_head + i_out];
             }
-            grad_inp[i_inp] += *x;
         }
         Ok(())
     }
@@ -87,6 +111,7 @@ impl<E: Dtype> super::RemoveDimKernel<E> for Cpu {
         Src: RemoveDimTo<Dst, Idx>,
     {
         let ax = Src::Ax::as_array()[0] as usize;
+        super::check_indices(idx.data.as_ref(), ax, inp.shape.concrete()[ax])?;
 
         let mut out = StridedArray::new(inp.shape.remove(idx.shape))?;
         let mut out_iter = out.iter_mut_with_index();
@@ -144,3 +169,46 @@ impl<E: Dtype> super::RemoveDimKernel<E> for Cpu {
         Ok(())
     }
 }
+
+impl<E: Dtype> super::GatherRowsKernel<E> for Cpu {
+    fn forward<M: Dim, N: Dim, Z: Dim>(
+        &self,
+        inp: &Self::Storage<(M, N), E>,
+        idx: &Self::Storage<(Z,), usize>,
+    ) -> Result<Self::Storage<(Z, N), E>, Self::Err> {
+        let (m, n) = inp.shape;
+        let z = idx.shape.0;
+        super::check_indices(idx.data.as_ref(), 0, m.size())?;
+
+        let mut out: StridedArray<(Z, N), E> = StridedArray::new((z, n))?;
+        let out_strides = out.strides;
+        let out_data = Arc::make_mut(&mut out.data);
+        for i in 0..z.size() {
+            let row = idx[[i]];
+            for j in 0..n.size() {
+                out_data[i * out_strides[0] + j * out_strides[1]] =
+                    inp.data[row * inp.strides[0] + j * inp.strides[1]];
+            }
+        }
+        Ok(out)
+    }
+
+    fn backward<M: Dim, N: Dim, Z: Dim>(
+        &self,
+        grad_inp: &mut Self::Storage<(M, N), E>,
+        idx: &Self::Storage<(Z,), usize>,
+        grad_out: &Self::Storage<(Z, N), E>,
+    ) -> Result<(), Self::Err> {
+        let (z, n) = grad_out.shape;
+        let inp_strides = grad_inp.strides;
+        let grad_inp_data = Arc::make_mut(&mut grad_inp.data);
+        for i in 0..z.size() {
+            let row = idx[[i]];
+            for j in 0..n.size() {
+                grad_inp_data[row * inp_strides[0] + j * inp_strides[1]] +=
+                    grad_out.data[i * grad_out.strides[0] + j * grad_out.strides[1]];
+            }
+        }
+        Ok(())
+    }
+}
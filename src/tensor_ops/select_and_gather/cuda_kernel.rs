@@ -1,12 +1,27 @@
 #![allow(clippy::needless_range_loop)]
 
 use crate::{
-    shapes::{RemoveDimTo, ReplaceDimTo, Shape},
-    tensor::cuda::{Cuda, CudaArray},
+    shapes::{Axes, Dim, RemoveDimTo, ReplaceDimTo, Shape},
+    tensor::cuda::{Cuda, CudaArray, CudaError},
 };
 use cudarc::driver::{CudaSlice, LaunchAsync, LaunchConfig};
 use std::sync::Arc;
 
+impl Cuda {
+    /// Copies `idx` back to the host and checks it with [super::check_indices].
+    pub(crate) fn check_indices<S: Shape>(
+        &self,
+        idx: &CudaArray<S, usize>,
+        axis: usize,
+        size: usize,
+    ) -> Result<(), CudaError> {
+        let mut buf = std::vec![0; idx.data.len()];
+        self.dev.sync_copy_from(idx.data.as_ref(), &mut buf)?;
+        super::check_indices(&buf, axis, size)?;
+        Ok(())
+    }
+}
+
 const GATHER_PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/gather.ptx"));
 const GATHER_MODULE_NAME: &str = "gather";
 const GATHER_FWD_FN_NAME: &str = "gather_forward";
@@ -22,6 +37,9 @@ impl super::ReplaceDimKernel<f32> for Cuda {
     where
         Src: ReplaceDimTo<Dst, Idx>,
     {
+        let ax = Src::Ax::as_array()[0] as usize;
+        self.check_indices(idx, ax, inp.shape.concrete()[ax])?;
+
         if !self.dev.has_func(GATHER_MODULE_NAME, GATHER_FWD_FN_NAME) {
             self.dev.load_ptx(
                 GATHER_PTX_SRC.into(),
@@ -60,7 +78,7 @@ impl super::ReplaceDimKernel<f32> for Cuda {
         unsafe { fwd_fn.launch_async(cfg, params) }?;
 
         Ok(CudaArray {
-            data: Arc::new(storage),
+            data: Arc::new(storage.into()),
             shape: dst,
             strides: dst.strides(),
         })
@@ -120,6 +138,9 @@ impl super::RemoveDimKernel<f32> for Cuda {
     where
         Src: RemoveDimTo<Dst, Idx>,
     {
+        let ax = Src::Ax::as_array()[0] as usize;
+        self.check_indices(idx, ax, inp.shape.concrete()[ax])?;
+
         if !self.dev.has_func(SELECT_MODULE_NAME, SELECT_FWD_FN_NAME) {
             self.dev.load_ptx(
                 SELECT_PTX_SRC.into(),
@@ -161,7 +182,7 @@ impl super::RemoveDimKernel<f32> for Cuda {
         unsafe { fwd_fn.launch_async(cfg, params) }?;
 
         Ok(CudaArray {
-            data: Arc::new(storage),
+            data: Arc::new(storage.into()),
             shape: dst,
             strides: dst.strides(),
         })
@@ -208,3 +229,87 @@ impl super::RemoveDimKernel<f32> for Cuda {
         Ok(())
     }
 }
+
+const GATHER_ROWS_PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/gather_rows.ptx"));
+const GATHER_ROWS_MODULE_NAME: &str = "gather_rows";
+const GATHER_ROWS_FWD_FN_NAME: &str = "gather_rows_forward";
+const GATHER_ROWS_BWD_FN_NAME: &str = "gather_rows_backward";
+const GATHER_ROWS_ALL_FN_NAMES: [&str; 2] = [GATHER_ROWS_FWD_FN_NAME, GATHER_ROWS_BWD_FN_NAME];
+
+impl super::GatherRowsKernel<f32> for Cuda {
+    fn forward<M: Dim, N: Dim, Z: Dim>(
+        &self,
+        inp: &Self::Storage<(M, N), f32>,
+        idx: &Self::Storage<(Z,), usize>,
+    ) -> Result<Self::Storage<(Z, N), f32>, Self::Err> {
+        self.check_indices(idx, 0, inp.shape.0.size())?;
+
+        if !self
+            .dev
+            .has_func(GATHER_ROWS_MODULE_NAME, GATHER_ROWS_FWD_FN_NAME)
+        {
+            self.dev.load_ptx(
+                GATHER_ROWS_PTX_SRC.into(),
+                GATHER_ROWS_MODULE_NAME,
+                &GATHER_ROWS_ALL_FN_NAMES,
+            )?;
+        }
+
+        let dst = (idx.shape.0, inp.shape.1);
+        let numel = dst.num_elements();
+        let mut storage = self.dev.alloc_zeros_async::<f32>(numel)?;
+
+        let fwd_fn = self
+            .dev
+            .get_func(GATHER_ROWS_MODULE_NAME, GATHER_ROWS_FWD_FN_NAME)
+            .unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,             // const size_t numel,
+            dst.1.size(),      // const size_t num_cols,
+            inp.data.as_ref(), // const float *inp,
+            inp.strides[0],    // const size_t inp_row_stride,
+            inp.strides[1],    // const size_t inp_col_stride,
+            idx.data.as_ref(), // const size_t *idx,
+            idx.strides[0],    // const size_t idx_stride,
+            &mut storage,      // float *out
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+
+        Ok(CudaArray {
+            data: Arc::new(storage.into()),
+            shape: dst,
+            strides: dst.strides(),
+        })
+    }
+
+    fn backward<M: Dim, N: Dim, Z: Dim>(
+        &self,
+        grad_inp: &mut Self::Storage<(M, N), f32>,
+        idx: &Self::Storage<(Z,), usize>,
+        grad_out: &Self::Storage<(Z, N), f32>,
+    ) -> Result<(), Self::Err> {
+        let bwd_fn = self
+            .dev
+            .get_func(GATHER_ROWS_MODULE_NAME, GATHER_ROWS_BWD_FN_NAME)
+            .unwrap();
+        let numel = grad_out.shape.num_elements();
+        let inp_strides = grad_inp.strides;
+
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,                             // const size_t numel,
+            grad_out.shape.1.size(),           // const size_t num_cols,
+            Arc::make_mut(&mut grad_inp.data), // float *grad_inp,
+            inp_strides[0],                    // const size_t inp_row_stride,
+            inp_strides[1],                    // const size_t inp_col_stride,
+            idx.data.as_ref(),                 // const size_t *idx,
+            idx.strides[0],                    // const size_t idx_stride,
+            grad_out.data.as_ref(),            // const float *grad_out,
+            grad_out.strides[0],               // const size_t out_row_stride,
+            grad_out.strides[1],               // const size_t out_col_stride
+        );
+        unsafe { bwd_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+}
@@ -0,0 +1,46 @@
+extern "C" __global__ void gather_rows_forward(
+    const size_t numel,
+    const size_t num_cols,
+    const float *inp,
+    const size_t inp_row_stride,
+    const size_t inp_col_stride,
+    const size_t *idx,
+    const size_t idx_stride,
+    float *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int z = i / num_cols;
+    unsigned int col = i % num_cols;
+    unsigned int row = idx[z * idx_stride];
+    out[i] = inp[row * inp_row_stride + col * inp_col_stride];
+}
+
+extern "C" __global__ void gather_rows_backward(
+    const size_t numel,
+    const size_t num_cols,
+    float *grad_inp,
+    const size_t inp_row_stride,
+    const size_t inp_col_stride,
+    const size_t *idx,
+    const size_t idx_stride,
+    const float *grad_out,
+    const size_t out_row_stride,
+    const size_t out_col_stride
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int z = i / num_cols;
+    unsigned int col = i % num_cols;
+    unsigned int row = idx[z * idx_stride];
+    atomicAdd(
+        grad_inp + row * inp_row_stride + col * inp_col_stride,
+        grad_out[z * out_row_stride + col * out_col_stride]
+    );
+}
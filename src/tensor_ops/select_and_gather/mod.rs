@@ -43,6 +43,28 @@ pub trait RemoveDimKernel<E: Dtype>: DeviceStorage {
         Src: RemoveDimTo<Dst, Idx>;
 }
 
+pub trait GatherRowsKernel<E: Dtype>: DeviceStorage {
+    fn forward<M: Dim, N: Dim, Z: Dim>(
+        &self,
+        inp: &Self::Storage<(M, N), E>,
+        idx: &Self::Storage<(Z,), usize>,
+    ) -> Result<Self::Storage<(Z, N), E>, Self::Err>;
+    fn backward<M: Dim, N: Dim, Z: Dim>(
+        &self,
+        grad_inp: &mut Self::Storage<(M, N), E>,
+        idx: &Self::Storage<(Z,), usize>,
+        grad_out: &Self::Storage<(Z, N), E>,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Checks that every value in `idx` can index into an axis of length `size`.
+pub(crate) fn check_indices(idx: &[usize], axis: usize, size: usize) -> Result<(), CpuError> {
+    match idx.iter().find(|&&i| i >= size) {
+        Some(&index) => Err(CpuError::IndexOutOfBounds { axis, index, size }),
+        None => Ok(()),
+    }
+}
+
 /// Select a single value from a single dimension, removing that dimension
 /// from the shape. Equivalent to `torch.select` from pytorch.
 pub trait SelectTo<D: DeviceStorage>: HasErr + HasShape {
@@ -85,6 +107,115 @@ pub trait SelectTo<D: DeviceStorage>: HasErr + HasShape {
     ) -> Result<Self::WithShape<Dst>, Self::Err>
     where
         Self::Shape: RemoveDimTo<Dst, Idx>;
+
+    /// Same as [SelectTo::select], but borrows the index tensor so it can be reused.
+    /// The index storage is shared with the backward op, not copied.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let keys: Tensor<Rank2<3, 5>, f32, _> = dev.zeros();
+    /// let values: Tensor<Rank2<3, 5>, f32, _> = dev.zeros();
+    /// let idx: Tensor<Rank1<3>, usize, _> = dev.tensor([0, 2, 4]);
+    /// let _: Tensor<Rank1<3>, f32, _> = keys.select_ref(&idx);
+    /// let _: Tensor<Rank1<3>, f32, _> = values.select_ref(&idx);
+    ///```
+    fn select_ref<Dst: Shape, Idx: Shape>(self, idx: &Tensor<Idx, usize, D>) -> Self::WithShape<Dst>
+    where
+        Self::Shape: RemoveDimTo<Dst, Idx>,
+    {
+        self.try_select_ref(idx).unwrap()
+    }
+
+    /// Fallible version of [SelectTo::select_ref]
+    fn try_select_ref<Dst: Shape, Idx: Shape>(
+        self,
+        idx: &Tensor<Idx, usize, D>,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: RemoveDimTo<Dst, Idx>,
+    {
+        self.try_select(idx.clone())
+    }
+
+    /// Same as [SelectTo::select], but the axis to select from is given explicitly
+    /// instead of being inferred from the shape of the index.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank2<3, 5>, f32, _> = dev.zeros();
+    /// let idx: Tensor<Rank1<3>, usize, _> = dev.tensor([0, 2, 4]);
+    /// let _: Tensor<Rank1<3>, f32, _> = a.select_axis::<Axis<1>, _, _>(idx);
+    ///```
+    ///
+    /// This fails to compile if `idx` does not have the index shape for `Ax`.
+    fn select_axis<Ax: Axes<Array = [isize; 1]>, Dst: Shape, Idx: Shape>(
+        self,
+        idx: Tensor<Idx, usize, D>,
+    ) -> Self::WithShape<Dst>
+    where
+        Self::Shape: RemoveDimTo<Dst, Idx, Ax = Ax>,
+    {
+        self.try_select(idx).unwrap()
+    }
+
+    /// Fallible version of [SelectTo::select_axis]
+    fn try_select_axis<Ax: Axes<Array = [isize; 1]>, Dst: Shape, Idx: Shape>(
+        self,
+        idx: Tensor<Idx, usize, D>,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: RemoveDimTo<Dst, Idx, Ax = Ax>,
+    {
+        self.try_select(idx)
+    }
+
+    /// Select from the 0th axis using a python style index, where negative
+    /// values count backwards from the end of the axis (`-1` is the last element).
+    ///
+    /// The index is resolved against the runtime size of the axis, so this
+    /// works for both [Const] and [usize] dimensions. Indices outside of
+    /// `-size..size` are out of bounds.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank2<3, 5>, f32, _> = dev.sample_normal();
+    /// let r: Tensor<Rank1<5>, f32, _> = a.clone().select_signed(-1);
+    /// assert_eq!(r.array(), a.select(dev.tensor(2)).array());
+    ///```
+    fn select_signed<Dst: Shape>(self, idx: isize) -> Self::WithShape<Dst>
+    where
+        Self::Shape: RemoveDimTo<Dst, Rank0>,
+        D: TensorFromArray<usize, Rank0, usize>,
+    {
+        self.try_select_signed(idx).unwrap()
+    }
+
+    /// Fallible version of [SelectTo::select_signed]
+    fn try_select_signed<Dst: Shape>(self, idx: isize) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: RemoveDimTo<Dst, Rank0>,
+        D: TensorFromArray<usize, Rank0, usize>;
+
+    /// Select the last element of the 0th axis. Same as `select_signed(-1)`.
+    fn select_last<Dst: Shape>(self) -> Self::WithShape<Dst>
+    where
+        Self::Shape: RemoveDimTo<Dst, Rank0>,
+        D: TensorFromArray<usize, Rank0, usize>,
+    {
+        self.select_signed(-1)
+    }
+
+    /// Fallible version of [SelectTo::select_last]
+    fn try_select_last<Dst: Shape>(self) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: RemoveDimTo<Dst, Rank0>,
+        D: TensorFromArray<usize, Rank0, usize>,
+    {
+        self.try_select_signed(-1)
+    }
 }
 
 impl<Src: Shape, E: Dtype, D: RemoveDimKernel<E>, T: Tape<D>> SelectTo<D> for Tensor<Src, E, D, T> {
@@ -107,6 +238,22 @@ impl<Src: Shape, E: Dtype, D: RemoveDimKernel<E>, T: Tape<D>> SelectTo<D> for Te
         });
         Ok(out.put_tape(tape))
     }
+
+    fn try_select_signed<Dst: Shape>(self, idx: isize) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: RemoveDimTo<Dst, Rank0>,
+        D: TensorFromArray<usize, Rank0, usize>,
+    {
+        let size = self.shape().concrete()[0];
+        let idx = if idx < 0 {
+            // out of bounds negative indices resolve to `size`, so the kernel reports them
+            size.checked_sub(idx.unsigned_abs()).unwrap_or(size)
+        } else {
+            idx as usize
+        };
+        let idx = self.device.try_tensor(idx)?;
+        self.try_select(idx)
+    }
 }
 
 /// Select multiple values from a single axis, replacing that dimension
@@ -139,6 +286,36 @@ pub trait GatherTo<D: DeviceStorage>: HasErr + HasShape {
     /// let idx: Tensor<Rank2<3, 2>, usize, _> = dev.tensor([[0, 1], [2, 3], [4, 4]]);
     /// let _: Tensor<Rank2<3, 2>, f32, _> = a.gather(idx);
     ///```
+    ///
+    /// The new dimension can also be a runtime [usize], for example when the number
+    /// of indices is only known at runtime:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank2<3, 5>, f32, _> = dev.zeros();
+    /// let ids = std::vec![2, 0, 1, 1, 0, 2];
+    /// let mut idx: Tensor<(usize,), usize, _> = dev.zeros_like(&(ids.len(),));
+    /// idx.copy_from(&ids);
+    /// let r: Tensor<(usize, Const<5>), f32, _> = a.gather(idx);
+    /// assert_eq!(r.shape(), &(6, Const));
+    ///```
+    ///
+    /// The index never has a tape, since [usize] tensors are not differentiable.
+    /// Indices computed from a traced tensor, for example with [crate::tensor_ops::ArgReduceTo::argmax],
+    /// come out without a tape, so they can be passed straight in. Use
+    /// [crate::tensor::SplitTape::with_empty_tape] to compute the index without
+    /// dropping the tape of the tensor it is computed from:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let emb: Tensor<Rank2<4, 3>, f32, _> = dev.sample_normal();
+    /// let logits: Tensor<Rank2<2, 4>, f32, _, _> = dev.sample_normal().trace();
+    /// let ids = logits.with_empty_tape().argmax::<_, Axis<1>>();
+    /// let _: Tensor<Rank2<2, 3>, f32, _, _> = emb.trace().gather(ids);
+    ///```
+    ///
+    /// An index tensor that was explicitly given a tape can have it removed with
+    /// [Tensor::detached].
     fn gather<Dst: Shape, Idx: Shape>(self, idx: Tensor<Idx, usize, D>) -> Self::WithShape<Dst>
     where
         Self::Shape: ReplaceDimTo<Dst, Idx>,
@@ -152,6 +329,69 @@ pub trait GatherTo<D: DeviceStorage>: HasErr + HasShape {
     ) -> Result<Self::WithShape<Dst>, Self::Err>
     where
         Self::Shape: ReplaceDimTo<Dst, Idx>;
+
+    /// Same as [GatherTo::gather], but borrows the index tensor so it can be reused.
+    /// The index storage is shared with the backward op, not copied.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let keys: Tensor<Rank2<3, 5>, f32, _> = dev.zeros();
+    /// let values: Tensor<Rank2<3, 5>, f32, _> = dev.zeros();
+    /// let idx: Tensor<Rank1<4>, usize, _> = dev.tensor([0, 0, 1, 2]);
+    /// let _: Tensor<Rank2<4, 5>, f32, _> = keys.gather_ref(&idx);
+    /// let _: Tensor<Rank2<4, 5>, f32, _> = values.gather_ref(&idx);
+    ///```
+    fn gather_ref<Dst: Shape, Idx: Shape>(self, idx: &Tensor<Idx, usize, D>) -> Self::WithShape<Dst>
+    where
+        Self::Shape: ReplaceDimTo<Dst, Idx>,
+    {
+        self.try_gather_ref(idx).unwrap()
+    }
+
+    /// Fallible version of [GatherTo::gather_ref]
+    fn try_gather_ref<Dst: Shape, Idx: Shape>(
+        self,
+        idx: &Tensor<Idx, usize, D>,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ReplaceDimTo<Dst, Idx>,
+    {
+        self.try_gather(idx.clone())
+    }
+
+    /// Same as [GatherTo::gather], but the axis to gather from is given explicitly
+    /// instead of being inferred from the shape of the index.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank2<3, 5>, f32, _> = dev.zeros();
+    /// let idx: Tensor<Rank2<3, 2>, usize, _> = dev.tensor([[0, 1], [2, 3], [4, 4]]);
+    /// let _: Tensor<Rank2<3, 2>, f32, _> = a.gather_axis::<Axis<1>, _, _>(idx);
+    ///```
+    ///
+    /// This fails to compile if `idx` does not have the index shape for `Ax`.
+    fn gather_axis<Ax: Axes<Array = [isize; 1]>, Dst: Shape, Idx: Shape>(
+        self,
+        idx: Tensor<Idx, usize, D>,
+    ) -> Self::WithShape<Dst>
+    where
+        Self::Shape: ReplaceDimTo<Dst, Idx, Ax = Ax>,
+    {
+        self.try_gather(idx).unwrap()
+    }
+
+    /// Fallible version of [GatherTo::gather_axis]
+    fn try_gather_axis<Ax: Axes<Array = [isize; 1]>, Dst: Shape, Idx: Shape>(
+        self,
+        idx: Tensor<Idx, usize, D>,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ReplaceDimTo<Dst, Idx, Ax = Ax>,
+    {
+        self.try_gather(idx)
+    }
 }
 
 impl<Src: Shape, E: Dtype, D: ReplaceDimKernel<E>, T: Tape<D>> GatherTo<D>
@@ -178,16 +418,88 @@ impl<Src: Shape, E: Dtype, D: ReplaceDimKernel<E>, T: Tape<D>> GatherTo<D>
     }
 }
 
+impl<M: Dim, N: Dim, E: Dtype, D: GatherRowsKernel<E>, T: Tape<D>> Tensor<(M, N), E, D, T> {
+    /// Gathers rows of a 2d tensor, which is the same as [GatherTo::gather] along
+    /// the 0th axis with a 1d index. This is the common case for embedding lookups,
+    /// and uses a dedicated kernel that copies whole rows at once.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank2<3, 2>, f32, _> = dev.tensor([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+    /// let idx: Tensor<Rank1<4>, usize, _> = dev.tensor([2, 0, 2, 1]);
+    /// let r: Tensor<Rank2<4, 2>, f32, _> = a.gather_rows(idx);
+    /// assert_eq!(r.array(), [[5.0, 6.0], [1.0, 2.0], [5.0, 6.0], [3.0, 4.0]]);
+    ///```
+    pub fn gather_rows<Z: Dim>(self, idx: Tensor<(Z,), usize, D>) -> Tensor<(Z, N), E, D, T> {
+        self.try_gather_rows(idx).unwrap()
+    }
+
+    /// Fallible version of [Tensor::gather_rows]
+    pub fn try_gather_rows<Z: Dim>(
+        self,
+        idx: Tensor<(Z,), usize, D>,
+    ) -> Result<Tensor<(Z, N), E, D, T>, D::Err> {
+        let (inp, mut tape) = self.split_tape();
+        let storage = inp.device.forward(&inp.storage, &idx.storage)?;
+        let out = inp.device.upgrade(storage);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(grad_inp, &idx.storage, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::tensor_ops::*;
     use crate::tests::{assert_close, TestDevice};
 
+    #[test]
+    fn test_gather_rows_matches_gather() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<64, 16>, f32, _> = dev.sample_normal();
+        let ids: std::vec::Vec<usize> = (0..200).map(|i| (i * 37 + 11) % 64).collect();
+        let mut idx: Tensor<(usize,), usize, _> = dev.zeros_like(&(ids.len(),));
+        idx.copy_from(&ids);
+        let w: Tensor<(usize, Const<16>), f32, _> =
+            dev.sample_like(&(ids.len(), Const), rand_distr::StandardNormal);
+
+        let r1 = t.trace().gather_rows(idx.clone());
+        let r2: Tensor<(usize, Const<16>), f32, _, _> = t.trace().gather(idx);
+        assert_eq!(r1.as_vec(), r2.as_vec());
+
+        let g1 = (r1 * w.clone()).sum().backward();
+        let g2 = (r2 * w).sum().backward();
+        assert_eq!(g1.get(&t).array(), g2.get(&t).array());
+    }
+
+    #[test]
+    fn test_gather_rows_backward() {
+        let dev: TestDevice = Default::default();
+        let t = dev.tensor([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        let r = t.trace().gather_rows(dev.tensor([2, 0, 2]));
+        assert_eq!(r.array(), [[5.0, 6.0], [1.0, 2.0], [5.0, 6.0]]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [[1.0, 1.0], [0.0, 0.0], [2.0, 2.0]]);
+    }
+
+    #[test]
+    fn test_gather_rows_out_of_bounds() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<3, 2>, f32, _> = dev.zeros();
+        assert!(t.try_gather_rows(dev.tensor([0, 3])).is_err());
+    }
+
     #[test]
     fn test_remove_1d_backward() {
         let dev: TestDevice = Default::default();
-        let t = dev.sample_normal::<Rank1<5>>();
+        let t: Tensor<Rank1<5>, f32, _> = dev.sample_normal();
         let r = t.trace().select(dev.tensor(0));
         let t_array = t.array();
         assert_eq!(r.array(), t_array[0]);
@@ -198,7 +510,7 @@ mod tests {
     #[test]
     fn test_replace_1d_backward() {
         let dev: TestDevice = Default::default();
-        let t = dev.sample_normal::<Rank1<5>>();
+        let t: Tensor<Rank1<5>, f32, _> = dev.sample_normal();
         let r = t.trace().gather(dev.tensor([0, 1, 1, 3]));
         let t_array = t.array();
         assert_eq!(r.array(), [t_array[0], t_array[1], t_array[1], t_array[3]]);
@@ -328,4 +640,226 @@ mod tests {
         let g = r.sum().backward();
         assert_eq!(g.get(&t).array(), [[3.; 5], [0.; 5], [1.; 5], [2.; 5]]);
     }
+
+    #[test]
+    fn test_gather_many_duplicates_backward() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<64, 8>, f32, _> = dev.sample_normal();
+        let idx: [usize; 2048] = std::array::from_fn(|i| (i * 37 + i / 3) % 64);
+        let w: Tensor<Rank2<2048, 8>, f32, _> = dev.sample_normal();
+        let w_array = w.array();
+        let g = (t.trace().gather(dev.tensor(idx)) * w).sum().backward();
+
+        let mut expected = [[0.0; 8]; 64];
+        for (i, &j) in idx.iter().enumerate() {
+            for k in 0..8 {
+                expected[j][k] += w_array[i][k];
+            }
+        }
+        assert_close(&g.get(&t).array(), &expected);
+    }
+
+    #[test]
+    fn test_gather_long_duplicated_index_backward() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<1024, 4>, f32, _> = dev.sample_normal();
+        let ids: std::vec::Vec<usize> = (0..50_000).map(|i| (i * 7919 + i / 13) % 1024).collect();
+        let idx = dev.tensor_from_vec(ids.clone(), (ids.len(),));
+        let w: Tensor<(usize, Const<4>), f32, _> =
+            dev.sample_like(&(ids.len(), Const), rand_distr::StandardNormal);
+        let w_vec = w.as_vec();
+        let g = (t.trace().gather(idx) * w).sum().backward();
+
+        // every gradient is accumulated in the order of the index, so this is exact
+        let mut expected = [[0.0; 4]; 1024];
+        for (i, &j) in ids.iter().enumerate() {
+            for k in 0..4 {
+                expected[j][k] += w_vec[i * 4 + k];
+            }
+        }
+        assert_eq!(g.get(&t).array(), expected);
+    }
+
+    #[test]
+    fn test_gather_axis_1_duplicates_backward() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 3, 4>, f32, _> = dev.sample_normal();
+        let idx = dev.tensor([[0, 2, 2, 2, 0], [1, 1, 0, 1, 1]]);
+        let w: Tensor<Rank3<2, 5, 4>, f32, _> = dev.sample_normal();
+        let w_array = w.array();
+        let g = (t.trace().gather(idx.clone()) * w).sum().backward();
+
+        let idx = idx.array();
+        let mut expected = [[[0.0; 4]; 3]; 2];
+        for b in 0..2 {
+            for (i, &j) in idx[b].iter().enumerate() {
+                for k in 0..4 {
+                    expected[b][j][k] += w_array[b][i][k];
+                }
+            }
+        }
+        assert_close(&g.get(&t).array(), &expected);
+    }
+
+    #[test]
+    fn test_gather_argmax_of_traced() {
+        let dev: TestDevice = Default::default();
+        let emb: Tensor<Rank2<4, 3>, f32, _> = dev.sample_normal();
+        let emb_array = emb.array();
+        let x: Tensor<Rank2<2, 4>, f32, _> =
+            dev.tensor([[0.1, 0.5, -1.0, 0.2], [2.0, -3.0, 1.0, 4.0]]);
+        let logits = x.trace();
+
+        let ids = logits.with_empty_tape().argmax::<_, Axis<1>>();
+        assert_eq!(ids.array(), [1, 3]);
+        let r: Tensor<Rank2<2, 3>, f32, _, _> = emb.trace().gather(ids);
+        assert_eq!(r.array(), [emb_array[1], emb_array[3]]);
+
+        let g = r.sum().backward();
+        assert_eq!(
+            g.get(&emb).array(),
+            [[0.0; 3], [1.0; 3], [0.0; 3], [1.0; 3]]
+        );
+
+        // the tape of `logits` is untouched and still usable
+        let g = logits.exp().sum().backward();
+        assert_close(&g.get(&x).array(), &x.array().map(|r| r.map(f32::exp)));
+    }
+
+    #[test]
+    fn test_gather_runtime_index_len() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<3, 5>, f32, _> = dev.sample_normal();
+        let t_array = t.array();
+        let ids = std::vec![2, 0, 2, 1];
+        let mut idx: Tensor<(usize,), usize, _> = dev.zeros_like(&(ids.len(),));
+        idx.copy_from(&ids);
+
+        let r: Tensor<(usize, Const<5>), f32, _, _> = t.trace().gather(idx);
+        assert_eq!(r.shape(), &(4, Const));
+        assert_eq!(
+            r.as_vec(),
+            [t_array[2], t_array[0], t_array[2], t_array[1]].concat()
+        );
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [[1.0; 5], [1.0; 5], [2.0; 5]]);
+    }
+
+    #[test]
+    fn test_gather_runtime_index_len_axis_1() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<3, 5>, f32, _> = dev.sample_normal();
+        let t_array = t.array();
+        let mut idx: Tensor<(Const<3>, usize), usize, _> = dev.zeros_like(&(Const, 2));
+        idx.copy_from(&[4, 0, 1, 1, 3, 2]);
+
+        let r: Tensor<(Const<3>, usize), f32, _> = t.gather(idx);
+        assert_eq!(r.shape(), &(Const, 2));
+        assert_eq!(
+            r.as_vec(),
+            [
+                t_array[0][4],
+                t_array[0][0],
+                t_array[1][1],
+                t_array[1][1],
+                t_array[2][3],
+                t_array[2][2]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_gather_index_out_of_bounds() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<5>, f32, _> = dev.sample_normal();
+        let r = t.trace().try_gather(dev.tensor([0, 7, 2]));
+        assert!(r.is_err());
+    }
+
+    #[test]
+    fn test_select_signed() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<5>, f32, _> = dev.sample_normal();
+        let r = t.clone().select_signed(-1);
+        assert_eq!(r.array(), t.clone().select(dev.tensor(4)).array());
+        assert_eq!(t.clone().select_last().array(), r.array());
+        assert_eq!(t.clone().select_signed(-5).array(), t.array()[0]);
+        assert_eq!(t.clone().select_signed(2).array(), t.array()[2]);
+        assert!(t.clone().try_select_signed::<Rank0>(-6).is_err());
+        assert!(t.try_select_signed::<Rank0>(5).is_err());
+    }
+
+    #[test]
+    fn test_select_signed_runtime_dim() {
+        let dev: TestDevice = Default::default();
+        let mut t: Tensor<(usize, Const<3>), f32, _> = dev.zeros_like(&(4, Const));
+        t.copy_from(&[
+            1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0,
+        ]);
+        let r: Tensor<Rank1<3>, f32, _> = t.clone().select_signed(-1);
+        assert_eq!(r.array(), [10.0, 11.0, 12.0]);
+        assert_eq!(r.array(), t.clone().select(dev.tensor(3)).array());
+        assert_eq!(t.select_signed(-4).array(), [1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_select_index_out_of_bounds() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 3>, f32, _> = dev.sample_normal();
+        let r = t.try_select::<Rank1<2>, _>(dev.tensor([1, 3]));
+        assert!(r.is_err());
+    }
+
+    #[test]
+    fn test_gather_ref_shared_index() {
+        let dev: TestDevice = Default::default();
+        let keys: Tensor<Rank2<3, 2>, f32, _> = dev.tensor([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        let values: Tensor<Rank2<3, 2>, f32, _> =
+            dev.tensor([[-1.0, -2.0], [-3.0, -4.0], [-5.0, -6.0]]);
+        let idx = dev.tensor([2, 0, 2]);
+
+        let k: Tensor<Rank2<3, 2>, f32, _, _> = keys.trace().gather_ref(&idx);
+        let v: Tensor<Rank2<3, 2>, f32, _, _> = values.trace().gather_ref(&idx);
+        assert_eq!(k.array(), [[5.0, 6.0], [1.0, 2.0], [5.0, 6.0]]);
+        assert_eq!(v.array(), [[-5.0, -6.0], [-1.0, -2.0], [-5.0, -6.0]]);
+
+        // both backward ops share the index storage instead of copying it
+        assert_eq!(std::sync::Arc::strong_count(&idx.storage.data), 3);
+
+        let g = k.sum().backward();
+        assert_eq!(g.get(&keys).array(), [[1.0, 1.0], [0.0, 0.0], [2.0, 2.0]]);
+        let g = v.sum().backward();
+        assert_eq!(g.get(&values).array(), [[1.0, 1.0], [0.0, 0.0], [2.0, 2.0]]);
+        assert_eq!(std::sync::Arc::strong_count(&idx.storage.data), 1);
+    }
+
+    #[test]
+    fn test_select_and_gather_explicit_axis() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<4, 4>, f32, _> = dev.tensor([
+            [0.0, 1.0, 2.0, 3.0],
+            [4.0, 5.0, 6.0, 7.0],
+            [8.0, 9.0, 10.0, 11.0],
+            [12.0, 13.0, 14.0, 15.0],
+        ]);
+
+        let r0: Tensor<Rank1<4>, f32, _, _> = t.trace().select_axis::<Axis<0>, _, _>(dev.tensor(1));
+        assert_eq!(r0.array(), [4.0, 5.0, 6.0, 7.0]);
+        let g = r0.sum().backward();
+        assert_eq!(g.get(&t).array()[1], [1.0; 4]);
+
+        let r1: Tensor<Rank1<4>, f32, _, _> = t
+            .trace()
+            .select_axis::<Axis<1>, _, _>(dev.tensor([1, 1, 1, 1]));
+        assert_eq!(r1.array(), [1.0, 5.0, 9.0, 13.0]);
+        let g = r1.sum().backward();
+        assert_eq!(g.get(&t).array().map(|row| row[1]), [1.0; 4]);
+
+        let r: Tensor<Rank2<4, 1>, f32, _> =
+            t.clone()
+                .gather_axis::<Axis<1>, _, _>(dev.tensor([[3], [2], [1], [0]]));
+        assert_eq!(r.array(), [[3.0], [6.0], [9.0], [12.0]]);
+        let r: Tensor<Rank2<1, 4>, f32, _> = t.gather_axis::<Axis<0>, _, _>(dev.tensor([3]));
+        assert_eq!(r.array(), [[12.0, 13.0, 14.0, 15.0]]);
+    }
 }
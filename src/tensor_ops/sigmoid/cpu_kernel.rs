@@ -1,13 +1,13 @@
 use crate::tensor_ops::cpu_kernels::UnaryDerivative;
 
-impl UnaryDerivative<f32> for super::SigmoidKernelOp {
+impl<F: num_traits::Float> UnaryDerivative<F> for super::SigmoidKernelOp {
     #[inline(always)]
-    fn f(&self, x: &f32) -> f32 {
-        1.0 / (1.0 + (-x).exp())
+    fn f(&self, x: &F) -> F {
+        F::one() / (F::one() + (-*x).exp())
     }
     #[inline(always)]
-    fn df(&self, x: &f32) -> f32 {
-        let fx = 1.0 / (1.0 + (-x).exp());
-        fx * (1.0 - fx)
+    fn df(&self, x: &F) -> F {
+        let fx = F::one() / (F::one() + (-*x).exp());
+        fx * (F::one() - fx)
     }
 }
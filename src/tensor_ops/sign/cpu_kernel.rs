@@ -0,0 +1,16 @@
+use crate::tensor_ops::cpu_kernels::UnaryDerivative;
+
+impl<F: num_traits::Float> UnaryDerivative<F> for super::SignKernelOp {
+    #[inline(always)]
+    fn f(&self, x: &F) -> F {
+        if x == &F::zero() {
+            F::zero()
+        } else {
+            x.signum()
+        }
+    }
+    #[inline(always)]
+    fn df(&self, _: &F) -> F {
+        F::zero()
+    }
+}
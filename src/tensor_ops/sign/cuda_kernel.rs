@@ -0,0 +1,10 @@
+use crate::tensor_ops::cuda_kernels::UnaryOpCudaKernel;
+
+unsafe impl cudarc::driver::AsKernelParam for super::SignKernelOp {}
+
+impl UnaryOpCudaKernel for super::SignKernelOp {
+    const PTX_SRC: &'static str = include_str!(concat!(env!("OUT_DIR"), "/sign.ptx"));
+    const MODULE_NAME: &'static str = "sign";
+    const FWD_FN_NAME: &'static str = "sign_forward";
+    const BWD_FN_NAME: &'static str = "sign_backward";
+}
@@ -0,0 +1,56 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use super::ops::{try_unary_op, UnaryKernel};
+use crate::{gradients::Tape, shapes::*, tensor::Tensor};
+
+#[repr(C)]
+#[derive(Debug, Default, Copy, Clone)]
+pub struct SignKernelOp;
+
+/// [Sign function](https://en.wikipedia.org/wiki/Sign_function). -1.0 for t < 0,
+/// 0.0 for t == 0, and 1.0 for t > 0.
+///
+/// The derivative is 0.0 everywhere (including at 0).
+///
+/// Examples:
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t = dev.tensor([-2.0, 0.0, 0.5, 3.0]);
+/// let r = t.sign();
+/// assert_eq!(r.array(), [-1.0, 0.0, 1.0, 1.0]);
+/// ```
+pub fn sign<S: Shape, E: Dtype, D: UnaryKernel<SignKernelOp, E>, T: Tape<D>>(
+    t: Tensor<S, E, D, T>,
+) -> Tensor<S, E, D, T> {
+    t.sign()
+}
+
+impl<S: Shape, E: Dtype, D: UnaryKernel<SignKernelOp, E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// See [sign]
+    pub fn sign(self) -> Self {
+        self.try_sign().unwrap()
+    }
+    /// See [sign]
+    pub fn try_sign(self) -> Result<Self, D::Err> {
+        try_unary_op(SignKernelOp, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{tensor::*, tensor_ops::*, tests::TestDevice};
+
+    #[test]
+    fn test_sign() {
+        let dev: TestDevice = Default::default();
+        let x = dev.tensor([-2.0, -1.0, 0.0, 1.0, 2.0]);
+        let r = x.trace().sign();
+        assert_eq!(r.array(), [-1.0, -1.0, 0.0, 1.0, 1.0]);
+        let g = r.mean().backward();
+        assert_eq!(g.get(&x).array(), [0.0; 5]);
+    }
+}
@@ -0,0 +1,7 @@
+#include "unary_op_macros.cuh"
+
+struct SignKernelOp {};
+
+UNARY_OP(sign_forward, sign_backward, SignKernelOp,
+        x == 0.0 ? 0.0 : copysignf(1.0, x),
+        0.0);
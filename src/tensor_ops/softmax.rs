@@ -39,6 +39,33 @@ impl<S: Shape, E: Dtype, D: Device<E>, T: Tape<D>> Tensor<S, E, D, T> {
     {
         self.try_log_softmax::<Ax>()?.try_exp()
     }
+
+    /// Computes `softmax(t / temperature)` across `Ax`. Higher temperatures
+    /// produce flatter distributions, and lower temperatures sharper ones.
+    ///
+    /// The gradient flows through the division, so it is scaled by `1 / temperature`.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let logits: Tensor<Rank1<3>, f32, _> = dev.tensor([1.0, 2.0, 3.0]);
+    /// let probs = logits.softmax_with_temperature::<Axis<0>>(0.7);
+    /// ```
+    pub fn softmax_with_temperature<Ax: Axes>(self, temperature: E) -> Self
+    where
+        S: ReduceShape<Ax>,
+    {
+        self.try_softmax_with_temperature::<Ax>(temperature)
+            .unwrap()
+    }
+    /// See [Tensor::softmax_with_temperature]
+    pub fn try_softmax_with_temperature<Ax: Axes>(self, temperature: E) -> Result<Self, D::Err>
+    where
+        S: ReduceShape<Ax>,
+    {
+        self.try_log_softmax_with_temperature::<Ax>(temperature)?
+            .try_exp()
+    }
 }
 
 #[cfg(test)]
@@ -123,6 +150,29 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_softmax_with_temperature() {
+        let dev: TestDevice = Default::default();
+        let a = dev.tensor([-2.0, -1.0, 0.0, 1.0, 2.0]);
+        let cold = a.clone().softmax::<Axis<0>>().array();
+        let hot = a.clone().softmax_with_temperature::<Axis<0>>(2.0).array();
+        assert!(hot[4] < cold[4]);
+        assert!(hot[0] > cold[0]);
+        assert_close(&hot.iter().sum::<f32>(), &1.0);
+
+        let w = dev.tensor([0.0, 0.0, 1.0, 0.0, 2.0]);
+        let r = a.trace().softmax_with_temperature::<Axis<0>>(2.0);
+        let g = (r * w.clone()).mean().backward();
+
+        // plain softmax of pre-scaled logits gives the same values,
+        // but its gradient is missing the 1/temp factor
+        let b = a.clone() / 2.0;
+        let r2 = b.trace().softmax::<Axis<0>>();
+        assert_close(&r2.array(), &hot);
+        let g2 = (r2 * w).mean().backward();
+        assert_close(&g.get(&a).array(), &g2.get(&b).array().map(|x| x / 2.0));
+    }
+
     #[test]
     fn test_softmax_3d_to_1d_12() {
         let dev: TestDevice = Default::default();
@@ -0,0 +1,73 @@
+use crate::{
+    shapes::{Axes, Dtype, HasAxes, Shape},
+    tensor::cpu::{Cpu, LendingIterator, StridedArray},
+};
+use std::vec::Vec;
+
+impl<E: Dtype> super::SortKernel<E> for Cpu {
+    fn forward<S, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        inp: &Self::Storage<S, E>,
+        descending: bool,
+    ) -> Result<(Self::Storage<S, E>, Self::Storage<S, usize>), Self::Err>
+    where
+        S: Shape + HasAxes<Ax>,
+    {
+        let ax = Ax::as_array()[0] as usize;
+        let size = inp.shape.concrete()[ax];
+        let mut values = StridedArray::new(inp.shape)?;
+        let mut idx = StridedArray::new(inp.shape)?;
+
+        // every line along the axis starts where its index is 0
+        let mut starts = Vec::new();
+        let mut idx_iter = idx.iter_with_index();
+        while let Some((_, i)) = idx_iter.next() {
+            if i[ax] == 0 {
+                starts.push(i);
+            }
+        }
+
+        let mut line = Vec::with_capacity(size);
+        for mut i in starts {
+            line.clear();
+            for j in 0..size {
+                i[ax] = j;
+                line.push((inp[i], j));
+            }
+            // stable sort, so equal values keep their original order
+            line.sort_by(|a, b| {
+                let ord = a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal);
+                if descending {
+                    ord.reverse()
+                } else {
+                    ord
+                }
+            });
+            for (r, &(v, j)) in line.iter().enumerate() {
+                i[ax] = r;
+                values[i] = v;
+                idx[i] = j;
+            }
+        }
+        Ok((values, idx))
+    }
+
+    fn backward<S, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        idx: &Self::Storage<S, usize>,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err>
+    where
+        S: Shape + HasAxes<Ax>,
+    {
+        let ax = Ax::as_array()[0] as usize;
+        let mut out_iter = grad_out.iter_with_index();
+        while let Some((g, mut i)) = out_iter.next() {
+            let r = i;
+            i[ax] = idx[r];
+            grad_inp[i] += *g;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{
+    shapes::{Axes, HasAxes, Shape},
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{CudaSlice, LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/sort.ptx"));
+const MODULE_NAME: &str = "sort";
+const FWD_FN_NAME: &str = "sort_forward";
+const BWD_FN_NAME: &str = "sort_backward";
+const ALL_FN_NAMES: [&str; 2] = [FWD_FN_NAME, BWD_FN_NAME];
+
+impl super::SortKernel<f32> for Cuda {
+    fn forward<S, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        inp: &Self::Storage<S, f32>,
+        descending: bool,
+    ) -> Result<(Self::Storage<S, f32>, Self::Storage<S, usize>), Self::Err>
+    where
+        S: Shape + HasAxes<Ax>,
+    {
+        if !self.dev.has_func(MODULE_NAME, FWD_FN_NAME) {
+            self.dev
+                .load_ptx(PTX_SRC.into(), MODULE_NAME, &ALL_FN_NAMES)?;
+        }
+
+        let shape = inp.shape;
+        let strides = shape.strides();
+        let numel = shape.num_elements();
+        let mut values = self.dev.alloc_zeros_async::<f32>(numel)?;
+        let mut idx = self.dev.alloc_zeros_async::<usize>(numel)?;
+
+        let dims: CudaSlice<usize> = self.dev.take_async(shape.concrete().into())?;
+        let inp_strides: CudaSlice<usize> = self.dev.take_async(inp.strides.into())?;
+        let out_strides: CudaSlice<usize> = self.dev.take_async(strides.into())?;
+
+        let fwd_fn = self.dev.get_func(MODULE_NAME, FWD_FN_NAME).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,                      // const size_t numel,
+            S::NUM_DIMS,                // const size_t num_dims,
+            Ax::as_array()[0] as usize, // const size_t ax,
+            descending as usize,        // const size_t descending,
+            &dims,                      // const size_t *dims,
+            inp.data.as_ref(),          // const float *inp,
+            &inp_strides,               // const size_t *inp_strides,
+            &mut values,                // float *values,
+            &mut idx,                   // size_t *idx,
+            &out_strides,               // const size_t *out_strides
+        );
+        unsafe { fwd_fn.launch_async(cfg, params) }?;
+        Ok((
+            CudaArray {
+                data: Arc::new(values.into()),
+                shape,
+                strides,
+            },
+            CudaArray {
+                data: Arc::new(idx.into()),
+                shape,
+                strides,
+            },
+        ))
+    }
+
+    fn backward<S, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        idx: &Self::Storage<S, usize>,
+        grad_inp: &mut Self::Storage<S, f32>,
+        grad_out: &Self::Storage<S, f32>,
+    ) -> Result<(), Self::Err>
+    where
+        S: Shape + HasAxes<Ax>,
+    {
+        let numel = grad_out.shape.num_elements();
+        let dims: CudaSlice<usize> = self.dev.take_async(grad_out.shape.concrete().into())?;
+        let inp_strides: CudaSlice<usize> = self.dev.take_async(grad_inp.strides.into())?;
+        let out_strides: CudaSlice<usize> = self.dev.take_async(grad_out.strides.into())?;
+
+        let bwd_fn = self.dev.get_func(MODULE_NAME, BWD_FN_NAME).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,                             // const size_t numel,
+            S::NUM_DIMS,                       // const size_t num_dims,
+            Ax::as_array()[0] as usize,        // const size_t ax,
+            &dims,                             // const size_t *dims,
+            idx.data.as_ref(),                 // const size_t *idx,
+            Arc::make_mut(&mut grad_inp.data), // float *grad_inp,
+            &inp_strides,                      // const size_t *inp_strides,
+            grad_out.data.as_ref(),            // const float *grad_out,
+            &out_strides,                      // const size_t *out_strides
+        );
+        unsafe { bwd_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+}
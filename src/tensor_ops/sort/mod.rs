@@ -0,0 +1,137 @@
+#![allow(clippy::type_complexity)]
+
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+pub trait SortKernel<E: Dtype>: DeviceStorage {
+    fn forward<S, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        inp: &Self::Storage<S, E>,
+        descending: bool,
+    ) -> Result<(Self::Storage<S, E>, Self::Storage<S, usize>), Self::Err>
+    where
+        S: Shape + HasAxes<Ax>;
+    fn backward<S, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        idx: &Self::Storage<S, usize>,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+    ) -> Result<(), Self::Err>
+    where
+        S: Shape + HasAxes<Ax>;
+}
+
+/// Sorts values along an axis, along with the permutation that sorts them.
+/// Equivalent to `torch.sort(stable=True)` from pytorch.
+pub trait Sort<D: DeviceStorage>: HasErr + HasShape {
+    /// Sorts along axis `Ax`, in ascending order unless `descending` is true. Returns
+    /// `(values, indices)`, where `indices` are the positions of `values` along `Ax`
+    /// in the original tensor.
+    ///
+    /// `values` keeps the tape, and its gradient is scattered back through the permutation.
+    /// The sort is stable, so equal values keep their original order.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[3.0, 1.0, 2.0], [0.5, 1.5, -1.0]]);
+    /// let (values, indices) = t.clone().sort::<Axis<1>>(false);
+    /// assert_eq!(values.array(), [[1.0, 2.0, 3.0], [-1.0, 0.5, 1.5]]);
+    /// assert_eq!(indices.array(), [[1, 2, 0], [2, 0, 1]]);
+    ///
+    /// let (values, indices) = t.sort::<Axis<1>>(true);
+    /// assert_eq!(values.array(), [[3.0, 2.0, 1.0], [1.5, 0.5, -1.0]]);
+    /// assert_eq!(indices.array(), [[0, 2, 1], [1, 0, 2]]);
+    /// ```
+    fn sort<Ax: Axes<Array = [isize; 1]>>(
+        self,
+        descending: bool,
+    ) -> (Self, Tensor<Self::Shape, usize, D>)
+    where
+        Self: Sized,
+        Self::Shape: HasAxes<Ax>,
+    {
+        self.try_sort::<Ax>(descending).unwrap()
+    }
+
+    /// Fallible version of [Sort::sort]
+    fn try_sort<Ax: Axes<Array = [isize; 1]>>(
+        self,
+        descending: bool,
+    ) -> Result<(Self, Tensor<Self::Shape, usize, D>), Self::Err>
+    where
+        Self: Sized,
+        Self::Shape: HasAxes<Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: SortKernel<E>, T: Tape<D>> Sort<D> for Tensor<S, E, D, T> {
+    fn try_sort<Ax: Axes<Array = [isize; 1]>>(
+        self,
+        descending: bool,
+    ) -> Result<(Self, Tensor<S, usize, D>), Self::Err>
+    where
+        S: HasAxes<Ax>,
+    {
+        let (inp, mut tape) = self.split_tape();
+        let (values, idx) = inp.device.forward::<S, Ax>(&inp.storage, descending)?;
+        let out = inp.device.upgrade(values);
+        let idx = inp.device.upgrade(idx);
+        let phantom_out = out.clone();
+        let phantom_idx = idx.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device
+                .backward::<S, Ax>(&phantom_idx.storage, grad_inp, grad_out)
+        });
+        Ok((out.put_tape(tape), idx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor_ops::*;
+    use crate::tests::TestDevice;
+
+    #[test]
+    fn test_sort_1d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<3>, f32, _> = dev.tensor([3.0, 1.0, 2.0]);
+        let (values, indices) = t.trace().sort::<Axis<0>>(false);
+        assert_eq!(values.array(), [1.0, 2.0, 3.0]);
+        assert_eq!(indices.array(), [1, 2, 0]);
+        let g = (values * dev.tensor([10.0, 20.0, 30.0])).sum().backward();
+        assert_eq!(g.get(&t).array(), [30.0, 10.0, 20.0]);
+    }
+
+    #[test]
+    fn test_sort_descending_ties() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<4>, f32, _> = dev.tensor([1.0, 2.0, 1.0, 2.0]);
+        let (values, indices) = t.clone().sort::<Axis<0>>(true);
+        assert_eq!(values.array(), [2.0, 2.0, 1.0, 1.0]);
+        assert_eq!(indices.array(), [1, 3, 0, 2]);
+        let (values, indices) = t.sort::<Axis<0>>(false);
+        assert_eq!(values.array(), [1.0, 1.0, 2.0, 2.0]);
+        assert_eq!(indices.array(), [0, 2, 1, 3]);
+    }
+
+    #[test]
+    fn test_sort_axis_0() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<3, 2>, f32, _> = dev.tensor([[1.0, 5.0], [3.0, 4.0], [2.0, 6.0]]);
+        let (values, indices) = t.trace().sort::<Axis<0>>(false);
+        assert_eq!(values.array(), [[1.0, 4.0], [2.0, 5.0], [3.0, 6.0]]);
+        assert_eq!(indices.array(), [[0, 1], [2, 0], [1, 2]]);
+        let g = (values * dev.tensor([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]))
+            .sum()
+            .backward();
+        assert_eq!(g.get(&t).array(), [[1.0, 4.0], [5.0, 2.0], [3.0, 6.0]]);
+    }
+}
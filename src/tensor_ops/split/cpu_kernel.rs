@@ -0,0 +1,52 @@
+use crate::shapes::{Axes, Dtype, ResizeDimTo, Shape};
+use crate::tensor::cpu::{Cpu, LendingIterator, StridedArray};
+
+impl<E: Dtype> super::SplitKernel<E> for Cpu {
+    fn forward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        chunk: usize,
+        num_chunks: usize,
+        inp: &Self::Storage<Src, E>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>
+    where
+        Src: ResizeDimTo<Dst, Ax>,
+    {
+        let ax = Ax::as_array()[0] as usize;
+        let dst: Dst = super::try_split_shape(&inp.shape, num_chunks)?;
+        let offset = chunk * dst.concrete()[ax];
+        let mut out = StridedArray::new(dst)?;
+        let mut out_iter = out.iter_mut_with_index();
+        while let Some((o, i_out)) = out_iter.next() {
+            let mut i_inp: Src::Concrete = Default::default();
+            for j in 0..Src::NUM_DIMS {
+                i_inp[j] = i_out[j];
+            }
+            i_inp[ax] += offset;
+            *o = inp[i_inp];
+        }
+        Ok(out)
+    }
+
+    fn backward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        chunk: usize,
+        grad_inp: &mut Self::Storage<Src, E>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>
+    where
+        Src: ResizeDimTo<Dst, Ax>,
+    {
+        let ax = Ax::as_array()[0] as usize;
+        let offset = chunk * grad_out.shape.concrete()[ax];
+        let mut out_iter = grad_out.iter_with_index();
+        while let Some((o, i_out)) = out_iter.next() {
+            let mut i_inp: Src::Concrete = Default::default();
+            for j in 0..Src::NUM_DIMS {
+                i_inp[j] = i_out[j];
+            }
+            i_inp[ax] += offset;
+            grad_inp[i_inp] += *o;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,223 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+pub trait SplitKernel<E: Dtype>: DeviceStorage {
+    /// Copies chunk number `chunk` out of `inp`, where `inp` is split into `num_chunks` chunks.
+    fn forward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        chunk: usize,
+        num_chunks: usize,
+        inp: &Self::Storage<Src, E>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>
+    where
+        Src: ResizeDimTo<Dst, Ax>;
+    fn backward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        chunk: usize,
+        grad_inp: &mut Self::Storage<Src, E>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>
+    where
+        Src: ResizeDimTo<Dst, Ax>;
+}
+
+/// Computes the shape of each chunk when axis `Ax` of `src` is split into `num_chunks` chunks.
+pub(crate) fn try_split_shape<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+    src: &Src,
+    num_chunks: usize,
+) -> Result<Dst, CpuError>
+where
+    Src: ResizeDimTo<Dst, Ax>,
+{
+    let ax = Ax::as_array()[0] as usize;
+    let src_dims = src.concrete();
+    let size = src_dims[ax];
+    let err = CpuError::UnevenSplit {
+        size,
+        chunks: num_chunks,
+    };
+    if size.checked_rem(num_chunks) != Some(0) {
+        return Err(err);
+    }
+    let mut dst_dims: Dst::Concrete = Default::default();
+    for i in 0..Dst::NUM_DIMS {
+        dst_dims[i] = if i == ax {
+            size / num_chunks
+        } else {
+            src_dims[i]
+        };
+    }
+    Dst::from_concrete(&dst_dims).ok_or(err)
+}
+
+/// Split a tensor into `N` equally sized chunks along a single axis.
+/// Equivalent to `torch.chunk` from pytorch.
+pub trait Split: HasErr + HasShape {
+    /// Splits axis `Ax` into `N` chunks of shape `Dst`. The size of the axis must
+    /// be divisible by `N`.
+    ///
+    /// The tape of `self` is put into the first chunk, and every other chunk gets a new tape
+    /// holding its own backward operation. Gradients flow back through every chunk whose tape
+    /// is merged (e.g. by binary operations) into the tape that `backward` is called on.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let a: Tensor<Rank2<4, 6>, f32, _> = dev.zeros();
+    /// let [q, k, v] = a.clone().split::<Rank2<4, 2>, Axis<1>, 3>();
+    /// let [top, bottom]: [Tensor<Rank2<2, 6>, f32, _>; 2] = a.split::<_, Axis<0>, 2>();
+    /// ```
+    fn split<Dst: Shape, Ax: Axes<Array = [isize; 1]>, const N: usize>(
+        self,
+    ) -> [Self::WithShape<Dst>; N]
+    where
+        Self::Shape: ResizeDimTo<Dst, Ax>,
+    {
+        self.try_split().unwrap()
+    }
+
+    /// Fallible version of [Split::split]. Returns [CpuError::UnevenSplit] if the axis
+    /// can't be split into `N` chunks of shape `Dst`.
+    fn try_split<Dst: Shape, Ax: Axes<Array = [isize; 1]>, const N: usize>(
+        self,
+    ) -> Result<[Self::WithShape<Dst>; N], Self::Err>
+    where
+        Self::Shape: ResizeDimTo<Dst, Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: SplitKernel<E>, T: Tape<D>> Split for Tensor<S, E, D, T> {
+    fn try_split<Dst: Shape, Ax: Axes<Array = [isize; 1]>, const N: usize>(
+        self,
+    ) -> Result<[Self::WithShape<Dst>; N], Self::Err>
+    where
+        Self::Shape: ResizeDimTo<Dst, Ax>,
+    {
+        let (inp, tape) = self.split_tape();
+        let mut storages = std::vec::Vec::with_capacity(N);
+        for chunk in 0..N {
+            storages.push(inp.device.forward(chunk, N, &inp.storage)?);
+        }
+
+        let mut tapes = std::vec::Vec::with_capacity(N);
+        tapes.push(tape);
+        tapes.resize_with(N, T::default);
+
+        let mut chunks = std::vec::Vec::with_capacity(N);
+        for (chunk, (storage, mut tape)) in storages.into_iter().zip(tapes).enumerate() {
+            let out = inp.device.upgrade(storage);
+            let phantom_out = out.clone();
+            tape.try_alloc_grad(&inp)?;
+            tape.try_alloc_grad(&out)?;
+            let inp = inp.clone();
+            tape.add_backward_op(move |grads| {
+                let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+                inp.device.backward(chunk, grad_inp, grad_out)
+            });
+            chunks.push(out.put_tape(tape));
+        }
+
+        let mut chunks = chunks.into_iter();
+        Ok(std::array::from_fn(|_| chunks.next().unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor_ops::*;
+    use crate::tests::{assert_close, TestDevice};
+
+    #[test]
+    fn test_split_axis_1_backward() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<4, 6>, f32, _> = dev.sample_normal();
+        let t_array = t.array();
+        let [a, b, c] = t.trace().split::<Rank2<4, 2>, Axis<1>, 3>();
+        for (i, chunk) in [a.array(), b.array(), c.array()].iter().enumerate() {
+            for r in 0..4 {
+                assert_eq!(chunk[r], [t_array[r][2 * i], t_array[r][2 * i + 1]]);
+            }
+        }
+
+        // each chunk's gradient ends up in its own columns
+        let g = (a * 1.0 + b * 2.0 + c * 3.0).sum().backward();
+        assert_eq!(g.get(&t).array(), [[1.0, 1.0, 2.0, 2.0, 3.0, 3.0]; 4]);
+    }
+
+    #[test]
+    fn test_split_axis_0_backward() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<4, 2, 3>, f32, _> = dev.sample_normal();
+        let t_array = t.array();
+        let [a, b] = t.trace().split::<Rank3<2, 2, 3>, Axis<0>, 2>();
+        assert_eq!(a.array(), [t_array[0], t_array[1]]);
+        assert_eq!(b.array(), [t_array[2], t_array[3]]);
+        let g = (a.exp() * b).sum().backward();
+        let g = g.get(&t).array();
+        for i in 0..2 {
+            for j in 0..2 {
+                for k in 0..3 {
+                    let (x, y) = (t_array[i][j][k], t_array[i + 2][j][k]);
+                    assert_close(&g[i][j][k], &(x.exp() * y));
+                    assert_close(&g[i + 2][j][k], &x.exp());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_split_runtime_dim() {
+        let dev: TestDevice = Default::default();
+        let mut t: Tensor<(Const<2>, usize), f32, _> = dev.zeros_like(&(Const, 4));
+        t.copy_from(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]);
+        let [a, b]: [Tensor<(Const<2>, usize), f32, _>; 2] = t.split::<_, Axis<1>, 2>();
+        assert_eq!(a.shape(), &(Const, 2));
+        assert_eq!(a.as_vec(), [1.0, 2.0, 5.0, 6.0]);
+        assert_eq!(b.as_vec(), [3.0, 4.0, 7.0, 8.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_split_uneven() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<5>, f32, _> = dev.zeros();
+        let _: [Tensor<(usize,), f32, _>; 2] = t.split::<_, Axis<0>, 2>();
+    }
+
+    #[test]
+    fn test_try_split_errors() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<5>, f32, _> = dev.zeros();
+        let r: Result<[Tensor<(usize,), f32, _>; 2], _> = t.try_split::<_, Axis<0>, 2>();
+        assert!(matches!(
+            r,
+            Err(CpuError::UnevenSplit { size: 5, chunks: 2 })
+        ));
+
+        // evenly divisible, but the chunks don't have the requested shape
+        let t: Tensor<Rank1<6>, f32, _> = dev.zeros();
+        let r = t.try_split::<Rank1<2>, Axis<0>, 2>();
+        assert!(matches!(
+            r,
+            Err(CpuError::UnevenSplit { size: 6, chunks: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_split_backward_through_later_chunk() {
+        let dev: TestDevice = Default::default();
+        let t = dev.tensor([1.0, 2.0, 3.0, 4.0]);
+        let [a, b] = t.trace().split::<Rank1<2>, Axis<0>, 2>();
+        let g = (b * 2.0 + a).sum().backward();
+        assert_eq!(g.get(&t).array(), [1.0, 1.0, 2.0, 2.0]);
+
+        // the input has upstream operations, which are in the tape of the first chunk
+        let [a, b] = (t.trace() * 3.0).split::<Rank1<2>, Axis<0>, 2>();
+        let g = (b.square() + a).sum().backward();
+        assert_eq!(g.get(&t).array(), [3.0, 3.0, 54.0, 72.0]);
+    }
+}
@@ -1,12 +1,12 @@
 use crate::tensor_ops::cpu_kernels::UnaryDerivative;
 
-impl UnaryDerivative<f32> for super::SqrtKernelOp {
+impl<F: num_traits::Float> UnaryDerivative<F> for super::SqrtKernelOp {
     #[inline(always)]
-    fn f(&self, x: &f32) -> f32 {
+    fn f(&self, x: &F) -> F {
         x.sqrt()
     }
     #[inline(always)]
-    fn df(&self, x: &f32) -> f32 {
-        0.5 / x.sqrt()
+    fn df(&self, x: &F) -> F {
+        F::from(0.5).unwrap() / x.sqrt()
     }
 }
@@ -40,12 +40,12 @@ impl<S: Shape, E: Dtype, D: UnaryKernel<SqrtKernelOp, E>, T: Tape<D>> Tensor<S,
 
 #[cfg(test)]
 mod tests {
-    use crate::{tensor::*, tensor_ops::*, tests::TestDevice};
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::TestDevice};
 
     #[test]
     fn test_sqrt() {
         let dev: TestDevice = Default::default();
-        let x = dev.tensor([-1.0, 0.0, 1.0, 4.0]);
+        let x: Tensor<Rank1<4>, f32, _> = dev.tensor([-1.0, 0.0, 1.0, 4.0]);
         let r = x.trace().sqrt();
         assert!(r.array()[0].is_nan());
         assert_eq!(r.array()[1..], [0.0, 1.0, 2.0]);
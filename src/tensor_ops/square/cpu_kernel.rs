@@ -1,12 +1,12 @@
 use crate::tensor_ops::cpu_kernels::UnaryDerivative;
 
-impl UnaryDerivative<f32> for super::SquareKernelOp {
+impl<F: num_traits::Float> UnaryDerivative<F> for super::SquareKernelOp {
     #[inline(always)]
-    fn f(&self, x: &f32) -> f32 {
+    fn f(&self, x: &F) -> F {
         x.powi(2)
     }
     #[inline(always)]
-    fn df(&self, x: &f32) -> f32 {
-        2.0 * x
+    fn df(&self, x: &F) -> F {
+        F::from(2.0).unwrap() * *x
     }
 }
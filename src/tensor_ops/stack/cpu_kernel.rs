@@ -0,0 +1,49 @@
+use crate::{
+    shapes::{Dim, Dtype, PrependDim, Shape},
+    tensor::cpu::{Cpu, LendingIterator, StridedArray},
+};
+
+/// Drops the leading index of `i_out`, which is the index of the stacked tensor.
+fn inner_index<S: Shape>(i_out: &impl std::ops::Index<usize, Output = usize>) -> S::Concrete {
+    let mut i_inp: S::Concrete = Default::default();
+    for j in 0..S::NUM_DIMS {
+        i_inp[j] = i_out[j + 1];
+    }
+    i_inp
+}
+
+impl<E: Dtype> super::StackKernel<E> for Cpu {
+    fn forward<S, Num: Dim>(
+        &self,
+        num: Num,
+        inps: &[Self::Storage<S, E>],
+    ) -> Result<Self::Storage<S::Prepended, E>, Self::Err>
+    where
+        S: PrependDim<Num>,
+    {
+        let mut out = StridedArray::new(inps[0].shape.prepend(num))?;
+        let mut out_iter = out.iter_mut_with_index();
+        while let Some((o, i_out)) = out_iter.next() {
+            *o = inps[i_out[0]][inner_index::<S>(&i_out)];
+        }
+        Ok(out)
+    }
+
+    fn backward<S, Num: Dim>(
+        &self,
+        i: usize,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S::Prepended, E>,
+    ) -> Result<(), Self::Err>
+    where
+        S: PrependDim<Num>,
+    {
+        let mut out_iter = grad_out.iter_with_index();
+        while let Some((go, i_out)) = out_iter.next() {
+            if i_out[0] == i {
+                grad_inp[inner_index::<S>(&i_out)] += *go;
+            }
+        }
+        Ok(())
+    }
+}
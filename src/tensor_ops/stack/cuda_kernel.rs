@@ -0,0 +1,83 @@
+use crate::{
+    shapes::{Dim, PrependDim, Shape},
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{CudaSlice, LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/stack.ptx"));
+const MODULE_NAME: &str = "stack";
+const FWD_FN_NAME: &str = "stack_forward";
+const BWD_FN_NAME: &str = "stack_backward";
+const ALL_FN_NAMES: [&str; 2] = [FWD_FN_NAME, BWD_FN_NAME];
+
+impl super::StackKernel<f32> for Cuda {
+    fn forward<S, Num: Dim>(
+        &self,
+        num: Num,
+        inps: &[Self::Storage<S, f32>],
+    ) -> Result<Self::Storage<S::Prepended, f32>, Self::Err>
+    where
+        S: PrependDim<Num>,
+    {
+        if !self.dev.has_func(MODULE_NAME, FWD_FN_NAME) {
+            self.dev
+                .load_ptx(PTX_SRC.into(), MODULE_NAME, &ALL_FN_NAMES)?;
+        }
+
+        let shape = inps[0].shape.prepend(num);
+        let strides = shape.strides();
+        let numel = inps[0].shape.num_elements();
+        let mut storage = self.dev.alloc_zeros_async::<f32>(shape.num_elements())?;
+        let dims: CudaSlice<usize> = self.dev.take_async(inps[0].shape.concrete().into())?;
+
+        for (i, inp) in inps.iter().enumerate() {
+            let inp_strides: CudaSlice<usize> = self.dev.take_async(inp.strides.into())?;
+            let fwd_fn = self.dev.get_func(MODULE_NAME, FWD_FN_NAME).unwrap();
+            let cfg = LaunchConfig::for_num_elems(numel as u32);
+            let params = (
+                numel,             // const size_t numel,
+                S::NUM_DIMS,       // const size_t num_dims,
+                &dims,             // const size_t *dims,
+                inp.data.as_ref(), // const float *inp,
+                &inp_strides,      // const size_t *inp_strides,
+                &mut storage,      // float *out,
+                i * numel,         // const size_t offset
+            );
+            unsafe { fwd_fn.launch_async(cfg, params) }?;
+        }
+        Ok(CudaArray {
+            data: Arc::new(storage.into()),
+            shape,
+            strides,
+        })
+    }
+
+    fn backward<S, Num: Dim>(
+        &self,
+        i: usize,
+        grad_inp: &mut Self::Storage<S, f32>,
+        grad_out: &Self::Storage<S::Prepended, f32>,
+    ) -> Result<(), Self::Err>
+    where
+        S: PrependDim<Num>,
+    {
+        let numel = grad_inp.shape.num_elements();
+        let dims: CudaSlice<usize> = self.dev.take_async(grad_inp.shape.concrete().into())?;
+        let inp_strides: CudaSlice<usize> = self.dev.take_async(grad_inp.strides.into())?;
+
+        let bwd_fn = self.dev.get_func(MODULE_NAME, BWD_FN_NAME).unwrap();
+        let cfg = LaunchConfig::for_num_elems(numel as u32);
+        let params = (
+            numel,                             // const size_t numel,
+            S::NUM_DIMS,                       // const size_t num_dims,
+            &dims,                             // const size_t *dims,
+            Arc::make_mut(&mut grad_inp.data), // float *grad_inp,
+            &inp_strides,                      // const size_t *inp_strides,
+            grad_out.data.as_ref(),            // const float *grad_out,
+            i * numel,                         // const size_t offset
+        );
+        unsafe { bwd_fn.launch_async(cfg, params) }?;
+        Ok(())
+    }
+}
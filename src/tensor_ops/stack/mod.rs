@@ -0,0 +1,133 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{
+    gradients::{Merge, NoneTape, Tape},
+    shapes::*,
+    tensor::*,
+};
+
+pub trait StackKernel<E: Dtype>: DeviceStorage {
+    fn forward<S, Num: Dim>(
+        &self,
+        num: Num,
+        inps: &[Self::Storage<S, E>],
+    ) -> Result<Self::Storage<S::Prepended, E>, Self::Err>
+    where
+        S: PrependDim<Num>;
+    /// Adds the gradient of the `i`th stacked tensor to `grad_inp`.
+    fn backward<S, Num: Dim>(
+        &self,
+        i: usize,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S::Prepended, E>,
+    ) -> Result<(), Self::Err>
+    where
+        S: PrependDim<Num>;
+}
+
+/// Stacks `N` tensors of the same shape along a new leading axis of size `N`.
+/// Equivalent to `torch.stack` from pytorch.
+///
+/// The tapes of all the tensors are merged into the result.
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let a: Tensor<Rank2<3, 4>, f32, _> = dev.zeros();
+/// let b: Tensor<Rank2<3, 4>, f32, _> = dev.zeros();
+/// let _: Tensor<Rank3<2, 3, 4>, f32, _> = stack([a, b]);
+/// ```
+///
+/// **Panics** if the tensors have different shapes.
+pub fn stack<const N: usize, S, E: Dtype, D: StackKernel<E>, T: Tape<D> + Merge<T>>(
+    tensors: [Tensor<S, E, D, T>; N],
+) -> Tensor<S::Prepended, E, D, T>
+where
+    S: PrependDim<Const<N>>,
+{
+    try_stack(tensors).unwrap()
+}
+
+/// Fallible version of [stack]
+pub fn try_stack<const N: usize, S, E: Dtype, D: StackKernel<E>, T: Tape<D> + Merge<T>>(
+    tensors: [Tensor<S, E, D, T>; N],
+) -> Result<Tensor<S::Prepended, E, D, T>, D::Err>
+where
+    S: PrependDim<Const<N>>,
+{
+    assert!(N > 0, "can't stack 0 tensors");
+    let shape = *tensors[0].shape();
+    let mut tape: Option<T> = None;
+    let inps: [Tensor<S, E, D, NoneTape>; N] = tensors.map(|t| {
+        assert_eq!(t.shape(), &shape);
+        let (t, t_tape) = t.split_tape();
+        tape = Some(match tape.take() {
+            None => t_tape,
+            Some(tape) => tape.merge(t_tape),
+        });
+        t
+    });
+    let mut tape = tape.unwrap();
+
+    let device = inps[0].device.clone();
+    let storages: std::vec::Vec<_> = inps.iter().map(|t| t.storage.clone()).collect();
+    let storage = device.forward(Const::<N>, &storages)?;
+    let out = device.upgrade(storage);
+    tape.try_alloc_grad(&out)?;
+    for (i, inp) in inps.into_iter().enumerate() {
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward::<S, Const<N>>(i, grad_inp, grad_out)
+        });
+    }
+    Ok(out.put_tape(tape))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor_ops::*;
+    use crate::tests::TestDevice;
+
+    #[test]
+    fn test_stack_1d_backward() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank1<3>, f32, _> = dev.tensor([1.0, 2.0, 3.0]);
+        let b: Tensor<Rank1<3>, f32, _> = dev.tensor([4.0, 5.0, 6.0]);
+        let r = stack([a.trace(), b.trace()]);
+        assert_eq!(r.array(), [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let g = (r * dev.tensor([[1.0, 2.0, 3.0], [-1.0, -2.0, -3.0]]))
+            .sum()
+            .backward();
+        assert_eq!(g.get(&a).array(), [1.0, 2.0, 3.0]);
+        assert_eq!(g.get(&b).array(), [-1.0, -2.0, -3.0]);
+    }
+
+    #[test]
+    fn test_stack_same_tensor_twice() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank2<2, 2>, f32, _> = dev.tensor([[1.0, 2.0], [3.0, 4.0]]);
+        let r: Tensor<Rank3<3, 2, 2>, f32, _, _> = stack([a.trace(), a.trace(), a.trace()]);
+        assert_eq!(r.array(), [[[1.0, 2.0], [3.0, 4.0]]; 3]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&a).array(), [[3.0; 2]; 2]);
+    }
+
+    #[test]
+    fn test_stack_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let a: Tensor<Rank1<2>, f32, _> = dev.tensor([1.0, 2.0]);
+        let b: Tensor<Rank2<3, 2>, f32, _> = dev.sample_normal();
+        let r = stack([a.trace().broadcast::<Rank2<3, 2>, _>(), b.trace()]);
+        assert_eq!(r.array()[0], [[1.0, 2.0]; 3]);
+        assert_eq!(r.array()[1], b.array());
+        let g = r.sum().backward();
+        assert_eq!(g.get(&a).array(), [3.0; 2]);
+        assert_eq!(g.get(&b).array(), [[1.0; 2]; 3]);
+    }
+}
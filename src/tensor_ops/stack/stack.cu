@@ -0,0 +1,39 @@
+#include "cuda_utils.cuh"
+
+// Copies one of the stacked tensors into `out`, starting at `offset`.
+extern "C" __global__ void stack_forward(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const float *inp,
+    const size_t *inp_strides,
+    float *out,
+    const size_t offset
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int inp_i = get_strided_index(i, num_dims, dims, inp_strides);
+    out[offset + i] = inp[inp_i];
+}
+
+extern "C" __global__ void stack_backward(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    float *grad_inp,
+    const size_t *inp_strides,
+    const float *grad_out,
+    const size_t offset
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int inp_i = get_strided_index(i, num_dims, dims, inp_strides);
+    // inp may be broadcasted, so multiple elements can map to the same gradient
+    atomicAdd(grad_inp + inp_i, grad_out[offset + i]);
+}
@@ -28,6 +28,37 @@ pub trait StddevTo: HasErr + HasShape {
     ) -> Result<Self::WithShape<Dst>, Self::Err>
     where
         Self::Shape: HasAxes<Ax> + ReduceShapeTo<Dst, Ax>;
+
+    /// Standard deviation reduction with `ddof` delta degrees of freedom.
+    /// See [super::VarTo::var_with_ddof], including for what happens if `ddof >= N`.
+    ///
+    /// **Pytorch equivalent**: `t.std(Axes, correction=ddof)`
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[2.0, 3.0, 4.0], [3.0, 6.0, 9.0]]);
+    /// let r = t.stddev_with_ddof::<Rank1<2>, _>(1, 0.0);
+    /// assert_eq!(r.array(), [1.0, 3.0]);
+    /// ```
+    fn stddev_with_ddof<Dst: Shape, Ax: Axes>(
+        self,
+        ddof: usize,
+        epsilon: f32,
+    ) -> Self::WithShape<Dst>
+    where
+        Self::Shape: HasAxes<Ax> + ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_stddev_with_ddof(ddof, epsilon).unwrap()
+    }
+    /// Fallible version of [StddevTo::stddev_with_ddof]
+    fn try_stddev_with_ddof<Dst: Shape, Ax: Axes>(
+        self,
+        ddof: usize,
+        epsilon: f32,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax> + ReduceShapeTo<Dst, Ax>;
 }
 
 impl<S: Shape, D: Device<f32>, T: Tape<D>> StddevTo for Tensor<S, f32, D, T> {
@@ -38,7 +69,18 @@ impl<S: Shape, D: Device<f32>, T: Tape<D>> StddevTo for Tensor<S, f32, D, T> {
     where
         Self::Shape: HasAxes<Ax> + ReduceShapeTo<Dst, Ax>,
     {
-        self.try_var()?.try_add(epsilon)?.try_sqrt()
+        self.try_stddev_with_ddof(0, epsilon)
+    }
+
+    fn try_stddev_with_ddof<Dst: Shape, Ax: Axes>(
+        self,
+        ddof: usize,
+        epsilon: f32,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax> + ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_var_with_ddof(ddof)?.try_add(epsilon)?.try_sqrt()
     }
 }
 
@@ -75,4 +117,15 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_std_with_ddof() {
+        let dev: TestDevice = Default::default();
+        let t = dev.tensor([[2.0, 3.0, 4.0], [3.0, 6.0, 9.0]]);
+        let r = t.trace().stddev_with_ddof::<Rank1<2>, _>(1, 0.0);
+        assert_eq!(r.array(), [1.0, 3.0]);
+        // (x_i - mean) / ((n - ddof) * std)
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [[-0.5, 0.0, 0.5], [-0.5, 0.0, 0.5]]);
+    }
 }
@@ -1,25 +1,25 @@
 use crate::tensor_ops::cpu_kernels::{BinaryDerivative, UnaryDerivative};
 
-impl UnaryDerivative<f32> for super::ScalarSubKernelOp<f32> {
-    fn f(&self, x: &f32) -> f32 {
-        x - self.scalar
+impl<F: num_traits::Float> UnaryDerivative<F> for super::ScalarSubKernelOp<F> {
+    fn f(&self, x: &F) -> F {
+        *x - self.scalar
     }
-    fn df(&self, _: &f32) -> f32 {
-        1.0
+    fn df(&self, _: &F) -> F {
+        F::one()
     }
 }
 
-impl BinaryDerivative<f32> for super::BinarySubKernelOp {
+impl<F: num_traits::Float> BinaryDerivative<F> for super::BinarySubKernelOp {
     #[inline(always)]
-    fn f(&self, x: &f32, y: &f32) -> f32 {
-        x - y
+    fn f(&self, x: &F, y: &F) -> F {
+        *x - *y
     }
     #[inline(always)]
-    fn dfdx(&self, _: &f32, _: &f32) -> f32 {
-        1.0
+    fn dfdx(&self, _: &F, _: &F) -> F {
+        F::one()
     }
     #[inline(always)]
-    fn dfdy(&self, _: &f32, _: &f32) -> f32 {
-        -1.0
+    fn dfdy(&self, _: &F, _: &F) -> F {
+        -F::one()
     }
 }
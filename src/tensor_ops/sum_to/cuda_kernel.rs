@@ -56,7 +56,7 @@ impl super::SumKernel<f32> for Cuda {
         );
         unsafe { fwd_fn.launch_async(cfg, params) }?;
         Ok(CudaArray {
-            data: Arc::new(storage),
+            data: Arc::new(storage.into()),
             shape: dst,
             strides: dst.strides(),
         })
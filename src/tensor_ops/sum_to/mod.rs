@@ -83,7 +83,7 @@ mod tests {
     #[test]
     fn test_sum_1d() {
         let dev: TestDevice = Default::default();
-        let t = dev.tensor([1.0, 2.0, 3.0]);
+        let t: Tensor<Rank1<3>, f32, _> = dev.tensor([1.0, 2.0, 3.0]);
         let r = t.trace().sum::<Rank0, _>();
         assert_eq!(r.array(), 6.0);
         // NOTE: .exp() to make sure its using result grad properly
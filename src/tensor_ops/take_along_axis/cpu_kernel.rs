@@ -0,0 +1,52 @@
+use crate::{
+    shapes::{Axes, Dtype, ResizeDimTo, Shape},
+    tensor::cpu::{Cpu, LendingIterator, StridedArray},
+    tensor_ops::select_and_gather::check_indices,
+};
+
+impl<E: Dtype> super::TakeAlongAxisKernel<E> for Cpu {
+    fn forward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        inp: &Self::Storage<Src, E>,
+        idx: &Self::Storage<Dst, usize>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>
+    where
+        Src: ResizeDimTo<Dst, Ax>,
+    {
+        let ax = Ax::as_array()[0] as usize;
+        super::check_take_along_shapes(&inp.shape, &idx.shape)?;
+        check_indices(idx.data.as_ref(), ax, inp.shape.concrete()[ax])?;
+
+        let mut out = StridedArray::new(idx.shape)?;
+        let mut out_iter = out.iter_mut_with_index();
+        while let Some((o, i)) = out_iter.next() {
+            let mut i_inp: Src::Concrete = Default::default();
+            for j in 0..Src::NUM_DIMS {
+                i_inp[j] = if j == ax { idx[i] } else { i[j] };
+            }
+            *o = inp[i_inp];
+        }
+        Ok(out)
+    }
+
+    fn backward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        grad_inp: &mut Self::Storage<Src, E>,
+        idx: &Self::Storage<Dst, usize>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>
+    where
+        Src: ResizeDimTo<Dst, Ax>,
+    {
+        let ax = Ax::as_array()[0] as usize;
+        let mut out_iter = grad_out.iter_with_index();
+        while let Some((go, i)) = out_iter.next() {
+            let mut i_inp: Src::Concrete = Default::default();
+            for j in 0..Src::NUM_DIMS {
+                i_inp[j] = if j == ax { idx[i] } else { i[j] };
+            }
+            grad_inp[i_inp] += *go;
+        }
+        Ok(())
+    }
+}
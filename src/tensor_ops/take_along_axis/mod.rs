@@ -0,0 +1,171 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+pub trait TakeAlongAxisKernel<E: Dtype>: DeviceStorage {
+    fn forward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        inp: &Self::Storage<Src, E>,
+        idx: &Self::Storage<Dst, usize>,
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>
+    where
+        Src: ResizeDimTo<Dst, Ax>;
+    fn backward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        grad_inp: &mut Self::Storage<Src, E>,
+        idx: &Self::Storage<Dst, usize>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>
+    where
+        Src: ResizeDimTo<Dst, Ax>;
+}
+
+/// Checks that `idx` has the same size as `inp` on every axis except `Ax`.
+pub(crate) fn check_take_along_shapes<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+    inp: &Src,
+    idx: &Dst,
+) -> Result<(), CpuError>
+where
+    Src: ResizeDimTo<Dst, Ax>,
+{
+    let ax = Ax::as_array()[0] as usize;
+    let (inp_dims, idx_dims) = (inp.concrete(), idx.concrete());
+    for axis in 0..Src::NUM_DIMS {
+        if axis != ax && inp_dims[axis] != idx_dims[axis] {
+            return Err(CpuError::DimMismatch {
+                axis,
+                expected: inp_dims[axis],
+                found: idx_dims[axis],
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Selects values along a single axis using an index with the same number of
+/// dimensions as the tensor. Equivalent to `torch.take_along_dim` from pytorch.
+pub trait TakeAlongAxis<D: DeviceStorage>: HasErr + HasShape {
+    /// Selects values along axis `Ax`, where `out[..., i, ...] = self[..., idx[..., i, ...], ...]`.
+    ///
+    /// `idx` must have the same size as `self` on every axis except `Ax`,
+    /// and the result has the same shape as `idx`. Unlike [super::GatherTo::gather],
+    /// the index always has the same rank as `self`, no matter which axis is indexed.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[10.0, 30.0, 20.0], [60.0, 40.0, 50.0]]);
+    ///
+    /// let idx: Tensor<Rank2<2, 2>, usize, _> = dev.tensor([[0, 2], [1, 0]]);
+    /// let r = t.clone().take_along_axis::<Axis<1>, _>(idx);
+    /// assert_eq!(r.array(), [[10.0, 20.0], [40.0, 60.0]]);
+    ///
+    /// let idx: Tensor<Rank2<1, 3>, usize, _> = dev.tensor([[1, 0, 1]]);
+    /// let r = t.take_along_axis::<Axis<0>, _>(idx);
+    /// assert_eq!(r.array(), [[60.0, 30.0, 50.0]]);
+    /// ```
+    fn take_along_axis<Ax: Axes<Array = [isize; 1]>, Dst: Shape>(
+        self,
+        idx: Tensor<Dst, usize, D>,
+    ) -> Self::WithShape<Dst>
+    where
+        Self::Shape: ResizeDimTo<Dst, Ax>,
+    {
+        self.try_take_along_axis(idx).unwrap()
+    }
+
+    /// Fallible version of [TakeAlongAxis::take_along_axis]
+    fn try_take_along_axis<Ax: Axes<Array = [isize; 1]>, Dst: Shape>(
+        self,
+        idx: Tensor<Dst, usize, D>,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ResizeDimTo<Dst, Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: TakeAlongAxisKernel<E>, T: Tape<D>> TakeAlongAxis<D>
+    for Tensor<S, E, D, T>
+{
+    fn try_take_along_axis<Ax: Axes<Array = [isize; 1]>, Dst: Shape>(
+        self,
+        idx: Tensor<Dst, usize, D>,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: ResizeDimTo<Dst, Ax>,
+    {
+        let (inp, mut tape) = self.split_tape();
+        let storage = inp.device.forward(&inp.storage, &idx.storage)?;
+        let out = inp.device.upgrade(storage);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(grad_inp, &idx.storage, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor_ops::*;
+    use crate::tests::{assert_close, TestDevice};
+
+    #[test]
+    fn test_take_along_axis_1() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[10.0, 30.0, 20.0], [60.0, 40.0, 50.0]]);
+        let idx: Tensor<Rank2<2, 2>, usize, _> = dev.tensor([[0, 2], [1, 0]]);
+        let r = t.trace().take_along_axis::<Axis<1>, _>(idx);
+        assert_eq!(r.array(), [[10.0, 20.0], [40.0, 60.0]]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [[1.0, 0.0, 1.0], [1.0, 1.0, 0.0]]);
+    }
+
+    #[test]
+    fn test_take_along_axis_0_repeated_indices() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[10.0, 30.0, 20.0], [60.0, 40.0, 50.0]]);
+        let idx: Tensor<Rank2<3, 3>, usize, _> = dev.tensor([[1, 0, 1], [1, 1, 0], [0, 0, 1]]);
+        let r = t.trace().take_along_axis::<Axis<0>, _>(idx);
+        assert_eq!(
+            r.array(),
+            [[60.0, 30.0, 50.0], [60.0, 40.0, 20.0], [10.0, 30.0, 50.0]]
+        );
+        let g = r.mean().backward();
+        let c = 1.0 / 9.0;
+        assert_close(
+            &g.get(&t).array(),
+            &[[c, 2.0 * c, c], [2.0 * c, c, 2.0 * c]],
+        );
+    }
+
+    #[test]
+    fn test_take_along_axis_out_of_bounds() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 3>, f32, _> = dev.zeros();
+        let idx: Tensor<Rank2<2, 1>, usize, _> = dev.tensor([[0], [3]]);
+        assert!(t.try_take_along_axis::<Axis<1>, _>(idx).is_err());
+    }
+
+    #[test]
+    fn test_take_along_axis_mismatched_dims() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<(usize, Const<3>), f32, _> = dev.zeros_like(&(2, Const));
+        let idx = dev.tensor_from_vec(std::vec![0, 1, 2], (3, Const::<1>));
+        let r = t.try_take_along_axis::<Axis<1>, _>(idx);
+        assert!(matches!(
+            r,
+            Err(CpuError::DimMismatch {
+                axis: 0,
+                expected: 2,
+                found: 3
+            })
+        ));
+    }
+}
@@ -0,0 +1,68 @@
+#include "cuda_utils.cuh"
+
+// Computes the offset into `inp` for element `i` of a contiguous tensor with
+// shape `dims`, where the index along `ax` is replaced by the value in `idx`.
+__device__ unsigned int take_along_axis_offset(
+    const unsigned int i,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t *dims,
+    const size_t *inp_strides,
+    const size_t *idx,
+    const size_t *idx_strides
+) {
+    unsigned int idx_i = get_strided_index(i, num_dims, dims, idx_strides);
+    unsigned int inp_i = 0;
+    unsigned int rem = i;
+    for (int d = num_dims - 1; d >= 0; d--) {
+        unsigned int i_d = rem % dims[d];
+        rem /= dims[d];
+        if (d == ax) {
+            i_d = idx[idx_i];
+        }
+        inp_i += i_d * inp_strides[d];
+    }
+    return inp_i;
+}
+
+extern "C" __global__ void take_along_axis_forward(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t *dims,
+    const float *inp,
+    const size_t *inp_strides,
+    const size_t *idx,
+    const size_t *idx_strides,
+    float *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    out[i] = inp[take_along_axis_offset(i, num_dims, ax, dims, inp_strides, idx, idx_strides)];
+}
+
+extern "C" __global__ void take_along_axis_backward(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t *dims,
+    float *grad_inp,
+    const size_t *inp_strides,
+    const size_t *idx,
+    const size_t *idx_strides,
+    const float *grad_out,
+    const size_t *out_strides
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int inp_i = take_along_axis_offset(i, num_dims, ax, dims, inp_strides, idx, idx_strides);
+    unsigned int out_i = get_strided_index(i, num_dims, dims, out_strides);
+    // multiple elements can take from the same input
+    atomicAdd(grad_inp + inp_i, grad_out[out_i]);
+}
@@ -0,0 +1,31 @@
+use crate::{
+    shapes::{Dtype, Shape},
+    tensor::cpu::{Cpu, LendingIterator, StridedArray},
+};
+use num_traits::NumCast;
+
+impl<E1: Dtype + NumCast, E2: Dtype + NumCast> super::ToDtypeKernel<E1, E2> for Cpu {
+    fn forward<S: Shape>(
+        &self,
+        inp: &Self::Storage<S, E1>,
+    ) -> Result<Self::Storage<S, E2>, Self::Err> {
+        let mut out: StridedArray<S, E2> = StridedArray::new(inp.shape)?;
+        let mut out_iter = out.iter_mut_with_index();
+        while let Some((o, i)) = out_iter.next() {
+            *o = E2::from(inp[i]).unwrap();
+        }
+        Ok(out)
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        grad_inp: &mut Self::Storage<S, E1>,
+        grad_out: &Self::Storage<S, E2>,
+    ) -> Result<(), Self::Err> {
+        let mut out_iter = grad_out.iter_with_index();
+        while let Some((go, i)) = out_iter.next() {
+            grad_inp[i] += E1::from(*go).unwrap();
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{
+    shapes::Shape,
+    tensor::cuda::{Cuda, CudaArray},
+};
+use cudarc::driver::{CudaSlice, LaunchAsync, LaunchConfig};
+use std::sync::Arc;
+
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/to_dtype.ptx"));
+const MODULE_NAME: &str = "to_dtype";
+const F32_F64_FWD: &str = "to_dtype_f32_f64_forward";
+const F32_F64_BWD: &str = "to_dtype_f32_f64_backward";
+const F64_F32_FWD: &str = "to_dtype_f64_f32_forward";
+const F64_F32_BWD: &str = "to_dtype_f64_f32_backward";
+const ALL_FN_NAMES: [&str; 4] = [F32_F64_FWD, F32_F64_BWD, F64_F32_FWD, F64_F32_BWD];
+
+macro_rules! to_dtype_impl {
+    ($Src:ty, $Dst:ty, Fwd=$FwdFn:ident, Bwd=$BwdFn:ident) => {
+        impl super::ToDtypeKernel<$Src, $Dst> for Cuda {
+            fn forward<S: Shape>(
+                &self,
+                inp: &Self::Storage<S, $Src>,
+            ) -> Result<Self::Storage<S, $Dst>, Self::Err> {
+                if !self.dev.has_func(MODULE_NAME, $FwdFn) {
+                    self.dev
+                        .load_ptx(PTX_SRC.into(), MODULE_NAME, &ALL_FN_NAMES)?;
+                }
+
+                let shape = inp.shape;
+                let strides = shape.strides();
+                let numel = shape.num_elements();
+                let mut storage = self.dev.alloc_zeros_async::<$Dst>(numel)?;
+
+                let dims: CudaSlice<usize> = self.dev.take_async(shape.concrete().into())?;
+                let inp_strides: CudaSlice<usize> = self.dev.take_async(inp.strides.into())?;
+
+                let fwd_fn = self.dev.get_func(MODULE_NAME, $FwdFn).unwrap();
+                let cfg = LaunchConfig::for_num_elems(numel as u32);
+                let params = (
+                    numel,             // const size_t numel,
+                    S::NUM_DIMS,       // const size_t num_dims,
+                    &dims,             // const size_t *dims,
+                    inp.data.as_ref(), // const SRC *inp,
+                    &inp_strides,      // const size_t *inp_strides,
+                    &mut storage,      // DST *out
+                );
+                unsafe { fwd_fn.launch_async(cfg, params) }?;
+                Ok(CudaArray {
+                    data: Arc::new(storage.into()),
+                    shape,
+                    strides,
+                })
+            }
+
+            fn backward<S: Shape>(
+                &self,
+                grad_inp: &mut Self::Storage<S, $Src>,
+                grad_out: &Self::Storage<S, $Dst>,
+            ) -> Result<(), Self::Err> {
+                let shape = grad_out.shape;
+                let numel = shape.num_elements();
+                let dims: CudaSlice<usize> = self.dev.take_async(shape.concrete().into())?;
+                let inp_strides: CudaSlice<usize> = self.dev.take_async(grad_inp.strides.into())?;
+
+                let bwd_fn = self.dev.get_func(MODULE_NAME, $BwdFn).unwrap();
+                let cfg = LaunchConfig::for_num_elems(numel as u32);
+                let params = (
+                    numel,                             // const size_t numel,
+                    S::NUM_DIMS,                       // const size_t num_dims,
+                    &dims,                             // const size_t *dims,
+                    Arc::make_mut(&mut grad_inp.data), // SRC *grad_inp,
+                    &inp_strides,                      // const size_t *inp_strides,
+                    grad_out.data.as_ref(),            // const DST *grad_out
+                );
+                unsafe { bwd_fn.launch_async(cfg, params) }?;
+                Ok(())
+            }
+        }
+    };
+}
+
+to_dtype_impl!(f32, f64, Fwd = F32_F64_FWD, Bwd = F32_F64_BWD);
+to_dtype_impl!(f64, f32, Fwd = F64_F32_FWD, Bwd = F64_F32_BWD);
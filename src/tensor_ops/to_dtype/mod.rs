@@ -0,0 +1,134 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+use num_traits::Float;
+
+pub trait ToDtypeKernel<E1: Dtype, E2: Dtype>: DeviceStorage {
+    fn forward<S: Shape>(
+        &self,
+        inp: &Self::Storage<S, E1>,
+    ) -> Result<Self::Storage<S, E2>, Self::Err>;
+    fn backward<S: Shape>(
+        &self,
+        grad_inp: &mut Self::Storage<S, E1>,
+        grad_out: &Self::Storage<S, E2>,
+    ) -> Result<(), Self::Err>;
+}
+
+impl<S: Shape, E1: Dtype + Float, D: DeviceStorage, T: Tape<D>> Tensor<S, E1, D, T> {
+    /// Converts the elements of a float tensor to another float dtype. The gradient
+    /// is converted back to the original dtype.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank1<3>, f32, _> = dev.tensor([1.0, 2.5, -3.0]);
+    /// let r: Tensor<Rank1<3>, f64, _> = t.to_dtype::<f64>();
+    /// assert_eq!(r.array(), [1.0, 2.5, -3.0]);
+    /// ```
+    ///
+    /// See [Tensor::cast] for converting to/from integer dtypes.
+    pub fn to_dtype<E2: Dtype + Float>(self) -> Tensor<S, E2, D, T>
+    where
+        D: ToDtypeKernel<E1, E2>,
+    {
+        self.try_to_dtype().unwrap()
+    }
+
+    /// Fallible version of [Tensor::to_dtype]
+    pub fn try_to_dtype<E2: Dtype + Float>(self) -> Result<Tensor<S, E2, D, T>, D::Err>
+    where
+        D: ToDtypeKernel<E1, E2>,
+    {
+        let (inp, mut tape) = self.split_tape();
+        let storage = inp.device.forward(&inp.storage)?;
+        let out = inp.device.upgrade(storage);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(grad_inp, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+impl<S: Shape, E1: Dtype, D: DeviceStorage> Tensor<S, E1, D> {
+    /// Converts the elements to another dtype, including integer dtypes like [usize].
+    /// Unlike [Tensor::to_dtype], this does not record a backward op, so it is only
+    /// available on tensors without a tape.
+    ///
+    /// Float to integer conversions round towards zero.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank1<3>, f32, _> = dev.tensor([1.0, 2.5, 3.9]);
+    /// let r: Tensor<Rank1<3>, usize, _> = t.cast();
+    /// assert_eq!(r.array(), [1, 2, 3]);
+    /// ```
+    ///
+    /// **Panics** if a value can't be represented in `E2`, like a negative value as [usize].
+    pub fn cast<E2: Dtype>(&self) -> Tensor<S, E2, D>
+    where
+        D: ToDtypeKernel<E1, E2>,
+    {
+        self.try_cast().unwrap()
+    }
+
+    /// Fallible version of [Tensor::cast]
+    pub fn try_cast<E2: Dtype>(&self) -> Result<Tensor<S, E2, D>, D::Err>
+    where
+        D: ToDtypeKernel<E1, E2>,
+    {
+        let storage = self.device.forward(&self.storage)?;
+        Ok(self.device.upgrade(storage))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_to_dtype_round_trip() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 3>, f32, _> = dev.sample_normal();
+        let r = t.trace().to_dtype::<f64>();
+        assert_eq!(r.array(), t.array().map(|a| a.map(|x| x as f64)));
+
+        let r = r.to_dtype::<f32>();
+        assert_close(&r.array(), &t.array());
+
+        let g = (r * dev.tensor([[1.0, 2.0, 3.0], [-1.0, -2.0, -3.0]]))
+            .sum()
+            .backward();
+        assert_eq!(g.get(&t).array(), [[1.0, 2.0, 3.0], [-1.0, -2.0, -3.0]]);
+    }
+
+    #[test]
+    fn test_to_dtype_broadcasted_backward() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<3>, f32, _> = dev.tensor([1.0, 2.0, 3.0]);
+        let r = t.trace().broadcast::<Rank2<2, 3>, _>().to_dtype::<f64>();
+        assert_eq!(r.array(), [[1.0, 2.0, 3.0]; 2]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [2.0; 3]);
+    }
+
+    #[test]
+    fn test_cast_integers() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<4>, f32, _> = dev.tensor([0.0, 1.5, 2.0, 3.99]);
+        let ids: Tensor<Rank1<4>, usize, _> = t.cast();
+        assert_eq!(ids.array(), [0, 1, 2, 3]);
+        let back: Tensor<Rank1<4>, f32, _> = ids.cast();
+        assert_eq!(back.array(), [0.0, 1.0, 2.0, 3.0]);
+    }
+}
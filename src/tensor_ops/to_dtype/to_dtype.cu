@@ -0,0 +1,37 @@
+#include "cuda_utils.cuh"
+
+#define TO_DTYPE_OP(SRC, DST, FORWARD, BACKWARD) \
+extern "C" __global__ void FORWARD( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    const SRC *inp, \
+    const size_t *inp_strides, \
+    DST *out \
+) { \
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x; \
+    if (i >= numel) { \
+        return; \
+    } \
+    unsigned int inp_i = get_strided_index(i, num_dims, dims, inp_strides); \
+    out[i] = (DST) inp[inp_i]; \
+} \
+\
+extern "C" __global__ void BACKWARD( \
+    const size_t numel, \
+    const size_t num_dims, \
+    const size_t *dims, \
+    SRC *grad_inp, \
+    const size_t *inp_strides, \
+    const DST *grad_out \
+) { \
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x; \
+    if (i >= numel) { \
+        return; \
+    } \
+    unsigned int inp_i = get_strided_index(i, num_dims, dims, inp_strides); \
+    atomicAdd(grad_inp + inp_i, (SRC) grad_out[i]); \
+}
+
+TO_DTYPE_OP(float, double, to_dtype_f32_f64_forward, to_dtype_f32_f64_backward);
+TO_DTYPE_OP(double, float, to_dtype_f64_f32_forward, to_dtype_f64_f32_backward);
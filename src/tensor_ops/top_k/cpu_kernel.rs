@@ -0,0 +1,73 @@
+use crate::{
+    shapes::{Axes, Dtype, ResizeDimTo, Shape},
+    tensor::cpu::{Cpu, LendingIterator, StridedArray},
+};
+use std::vec::Vec;
+
+impl<E: Dtype> super::TopKKernel<E> for Cpu {
+    fn forward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        k: usize,
+        inp: &Self::Storage<Src, E>,
+    ) -> Result<(Self::Storage<Dst, E>, Self::Storage<Dst, usize>), Self::Err>
+    where
+        Src: ResizeDimTo<Dst, Ax>,
+    {
+        let ax = Ax::as_array()[0] as usize;
+        let dst: Dst = super::try_top_k_shape(&inp.shape, k)?;
+        let mut values = StridedArray::new(dst)?;
+        let mut idx = StridedArray::new(dst)?;
+
+        // every line along the axis starts where its index is 0
+        let mut starts = Vec::new();
+        let mut idx_iter = idx.iter_with_index();
+        while let Some((_, i_dst)) = idx_iter.next() {
+            if i_dst[ax] == 0 {
+                starts.push(i_dst);
+            }
+        }
+
+        let mut line = Vec::with_capacity(inp.shape.concrete()[ax]);
+        for mut i_dst in starts {
+            let mut i_inp: Src::Concrete = Default::default();
+            for j in 0..Src::NUM_DIMS {
+                i_inp[j] = i_dst[j];
+            }
+            line.clear();
+            for j in 0..inp.shape.concrete()[ax] {
+                i_inp[ax] = j;
+                line.push((inp[i_inp], j));
+            }
+            // stable sort, so equal values keep the lower index first
+            line.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+            for (r, &(v, j)) in line.iter().take(k).enumerate() {
+                i_dst[ax] = r;
+                values[i_dst] = v;
+                idx[i_dst] = j;
+            }
+        }
+        Ok((values, idx))
+    }
+
+    fn backward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        idx: &Self::Storage<Dst, usize>,
+        grad_inp: &mut Self::Storage<Src, E>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>
+    where
+        Src: ResizeDimTo<Dst, Ax>,
+    {
+        let ax = Ax::as_array()[0] as usize;
+        let mut out_iter = grad_out.iter_with_index();
+        while let Some((g, i_dst)) = out_iter.next() {
+            let mut i_inp: Src::Concrete = Default::default();
+            for j in 0..Src::NUM_DIMS {
+                i_inp[j] = i_dst[j];
+            }
+            i_inp[ax] = idx[i_dst];
+            grad_inp[i_inp] += *g;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+#![allow(clippy::type_complexity)]
+
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+pub trait TopKKernel<E: Dtype>: DeviceStorage {
+    fn forward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        k: usize,
+        inp: &Self::Storage<Src, E>,
+    ) -> Result<(Self::Storage<Dst, E>, Self::Storage<Dst, usize>), Self::Err>
+    where
+        Src: ResizeDimTo<Dst, Ax>;
+    fn backward<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+        &self,
+        idx: &Self::Storage<Dst, usize>,
+        grad_inp: &mut Self::Storage<Src, E>,
+        grad_out: &Self::Storage<Dst, E>,
+    ) -> Result<(), Self::Err>
+    where
+        Src: ResizeDimTo<Dst, Ax>;
+}
+
+/// Computes the shape of the top `k` values when they are taken along axis `Ax` of `src`.
+pub(crate) fn try_top_k_shape<Src, Dst: Shape, Ax: Axes<Array = [isize; 1]>>(
+    src: &Src,
+    k: usize,
+) -> Result<Dst, CpuError>
+where
+    Src: ResizeDimTo<Dst, Ax>,
+{
+    let ax = Ax::as_array()[0] as usize;
+    let src_dims = src.concrete();
+    let size = src_dims[ax];
+    let err = CpuError::InvalidTopK { k, size };
+    if k > size {
+        return Err(err);
+    }
+    let mut dst_dims: Dst::Concrete = Default::default();
+    for i in 0..Dst::NUM_DIMS {
+        dst_dims[i] = if i == ax { k } else { src_dims[i] };
+    }
+    Dst::from_concrete(&dst_dims).ok_or(err)
+}
+
+/// The `K` largest values along an axis, along with their indices.
+/// Equivalent to `torch.topk` from pytorch.
+pub trait TopK<D: DeviceStorage>: HasErr + HasShape {
+    /// Takes the `K` largest values along axis `Ax`, in descending order. Returns
+    /// `(values, indices)`, where `indices` are the positions of `values` along `Ax`.
+    ///
+    /// `values` keeps the tape, and its gradient only flows back to the selected positions.
+    /// If there are equal values, the one with the lower index comes first.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<2, 4>, f32, _> = dev.tensor([[0.1, 0.9, 0.4, 0.7], [3.0, 2.0, 1.0, 0.0]]);
+    /// let (values, indices) = t.top_k::<Rank2<2, 2>, Axis<1>, 2>();
+    /// assert_eq!(values.array(), [[0.9, 0.7], [3.0, 2.0]]);
+    /// assert_eq!(indices.array(), [[1, 3], [0, 1]]);
+    /// ```
+    ///
+    /// **Panics** if `K` is larger than the size of the axis, or if `Dst` doesn't have size `K`
+    /// on axis `Ax`. See [TopK::try_top_k] for a version that returns an error instead.
+    fn top_k<Dst: Shape, Ax: Axes<Array = [isize; 1]>, const K: usize>(
+        self,
+    ) -> (Self::WithShape<Dst>, Tensor<Dst, usize, D>)
+    where
+        Self::Shape: ResizeDimTo<Dst, Ax>,
+    {
+        self.try_top_k::<Dst, Ax, K>().unwrap()
+    }
+
+    /// Fallible version of [TopK::top_k]. Returns [CpuError::InvalidTopK] if `K` is larger
+    /// than the size of the axis, or if `Dst` doesn't have size `K` on axis `Ax`.
+    fn try_top_k<Dst: Shape, Ax: Axes<Array = [isize; 1]>, const K: usize>(
+        self,
+    ) -> Result<(Self::WithShape<Dst>, Tensor<Dst, usize, D>), Self::Err>
+    where
+        Self::Shape: ResizeDimTo<Dst, Ax>;
+}
+
+impl<S: Shape, E: Dtype, D: TopKKernel<E>, T: Tape<D>> TopK<D> for Tensor<S, E, D, T> {
+    fn try_top_k<Dst: Shape, Ax: Axes<Array = [isize; 1]>, const K: usize>(
+        self,
+    ) -> Result<(Self::WithShape<Dst>, Tensor<Dst, usize, D>), Self::Err>
+    where
+        Self::Shape: ResizeDimTo<Dst, Ax>,
+    {
+        let (inp, mut tape) = self.split_tape();
+        let (values, idx) = inp.device.forward::<_, Dst, Ax>(K, &inp.storage)?;
+        let out = inp.device.upgrade(values);
+        let idx = inp.device.upgrade(idx);
+        let phantom_out = out.clone();
+        let phantom_idx = idx.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device
+                .backward(&phantom_idx.storage, grad_inp, grad_out)
+        });
+        Ok((out.put_tape(tape), idx))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor_ops::*;
+    use crate::tests::TestDevice;
+
+    #[test]
+    fn test_top_k_1d() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<4>, f32, _> = dev.tensor([0.1, 0.9, 0.4, 0.7]);
+        let (values, indices) = t.trace().top_k::<Rank1<2>, _, 2>();
+        assert_eq!(values.array(), [0.9, 0.7]);
+        assert_eq!(indices.array(), [1, 3]);
+        let g = (values * dev.tensor([1.0, 2.0])).sum().backward();
+        assert_eq!(g.get(&t).array(), [0.0, 1.0, 0.0, 2.0]);
+    }
+
+    #[test]
+    fn test_top_k_axis_0() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<3, 2>, f32, _> = dev.tensor([[1.0, 5.0], [3.0, 5.0], [2.0, 4.0]]);
+        let (values, indices) = t.trace().top_k::<Rank2<2, 2>, Axis<0>, 2>();
+        assert_eq!(values.array(), [[3.0, 5.0], [2.0, 5.0]]);
+        assert_eq!(indices.array(), [[1, 0], [2, 1]]);
+        let g = values.exp().sum().backward();
+        assert_eq!(
+            g.get(&t).array(),
+            [
+                [0.0, 5.0f32.exp()],
+                [3.0f32.exp(), 5.0f32.exp()],
+                [2.0f32.exp(), 0.0]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_top_k_broadcasted() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<3>, f32, _> = dev.tensor([1.0, 3.0, 2.0]);
+        let (values, indices) = t
+            .trace()
+            .broadcast::<Rank2<2, 3>, _>()
+            .top_k::<Rank2<2, 1>, Axis<1>, 1>();
+        assert_eq!(values.array(), [[3.0]; 2]);
+        assert_eq!(indices.array(), [[1]; 2]);
+        let g = values.sum().backward();
+        assert_eq!(g.get(&t).array(), [0.0, 2.0, 0.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_top_k_too_large() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<2>, f32, _> = dev.zeros();
+        let _ = t.top_k::<Rank1<3>, _, 3>();
+    }
+
+    #[test]
+    fn test_try_top_k_errors() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 3>, f32, _> = dev.zeros();
+        let r = t.clone().try_top_k::<Rank2<2, 4>, Axis<1>, 4>();
+        assert!(matches!(r, Err(CpuError::InvalidTopK { k: 4, size: 3 })));
+
+        // `K` is in range, but doesn't match the size of `Dst`
+        let r = t.clone().try_top_k::<Rank2<2, 1>, Axis<1>, 2>();
+        assert!(matches!(r, Err(CpuError::InvalidTopK { k: 2, size: 3 })));
+
+        let (values, _) = t.try_top_k::<(Const<2>, usize), Axis<1>, 2>().unwrap();
+        assert_eq!(values.shape().concrete(), [2, 2]);
+    }
+}
@@ -0,0 +1,86 @@
+#include "cuda_utils.cuh"
+
+// One thread per input element. Each thread computes the rank of its element
+// within its line along `ax`, and writes it to the output if the rank is < k.
+// Equal values are ranked by their index, so the lower index comes first.
+extern "C" __global__ void top_k_forward(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t k,
+    const size_t *dims,
+    const float *inp,
+    const size_t *inp_strides,
+    float *values,
+    size_t *idx,
+    const size_t *out_strides
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int inp_i = 0;
+    unsigned int out_i = 0;
+    unsigned int j = 0;
+    unsigned int tmp = i;
+    for (int d = num_dims - 1; d >= 0; d--) {
+        unsigned int i_d = tmp % dims[d];
+        tmp /= dims[d];
+        inp_i += i_d * inp_strides[d];
+        if (d == ax) {
+            j = i_d;
+        } else {
+            out_i += i_d * out_strides[d];
+        }
+    }
+
+    unsigned int line_start = inp_i - j * inp_strides[ax];
+    float v = inp[inp_i];
+    size_t rank = 0;
+    for (unsigned int m = 0; m < dims[ax]; m++) {
+        float other = inp[line_start + m * inp_strides[ax]];
+        if (other > v || (other == v && m < j)) {
+            rank++;
+        }
+    }
+
+    if (rank < k) {
+        out_i += rank * out_strides[ax];
+        values[out_i] = v;
+        idx[out_i] = j;
+    }
+}
+
+// One thread per output element. `dims` are the dims of the output.
+extern "C" __global__ void top_k_backward(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t ax,
+    const size_t *dims,
+    const size_t *idx,
+    float *grad_inp,
+    const size_t *inp_strides,
+    const float *grad_out,
+    const size_t *out_strides
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    unsigned int out_i = get_strided_index(i, num_dims, dims, out_strides);
+    unsigned int inp_i = 0;
+    unsigned int tmp = i;
+    for (int d = num_dims - 1; d >= 0; d--) {
+        unsigned int i_d = tmp % dims[d];
+        tmp /= dims[d];
+        if (d == ax) {
+            i_d = idx[out_i];
+        }
+        inp_i += i_d * inp_strides[d];
+    }
+
+    // inp may be broadcasted, so multiple outputs can map to the same gradient
+    atomicAdd(grad_inp + inp_i, grad_out[out_i]);
+}
@@ -0,0 +1,35 @@
+use crate::{
+    shapes::{Dtype, Shape},
+    tensor::cpu::{Cpu, LendingIterator, StridedArray},
+};
+
+impl<E: Dtype> super::TransposeKernel<E> for Cpu {
+    fn forward<Src: Shape, Dst: Shape<Concrete = Src::Concrete>>(
+        &self,
+        inp: &Self::Storage<Src, E>,
+        axes: [usize; 2],
+    ) -> Result<Self::Storage<Dst, E>, Self::Err> {
+        let dst: Dst = super::try_transpose_shape(&inp.shape, axes)?;
+        let mut out = StridedArray::new(dst)?;
+        let mut out_iter = out.iter_mut_with_index();
+        while let Some((o, mut i)) = out_iter.next() {
+            (i[axes[0]], i[axes[1]]) = (i[axes[1]], i[axes[0]]);
+            *o = inp[i];
+        }
+        Ok(out)
+    }
+
+    fn backward<Src: Shape, Dst: Shape<Concrete = Src::Concrete>>(
+        &self,
+        grad_inp: &mut Self::Storage<Src, E>,
+        grad_out: &Self::Storage<Dst, E>,
+        axes: [usize; 2],
+    ) -> Result<(), Self::Err> {
+        let mut out_iter = grad_out.iter_with_index();
+        while let Some((g, mut i)) = out_iter.next() {
+            (i[axes[0]], i[axes[1]]) = (i[axes[1]], i[axes[0]]);
+            grad_inp[i] += *g;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,200 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+pub trait TransposeKernel<E: Dtype>: DeviceStorage {
+    /// Swaps `axes` of `inp`. Implementations validate them with `try_transpose_shape`.
+    fn forward<Src: Shape, Dst: Shape<Concrete = Src::Concrete>>(
+        &self,
+        inp: &Self::Storage<Src, E>,
+        axes: [usize; 2],
+    ) -> Result<Self::Storage<Dst, E>, Self::Err>;
+    fn backward<Src: Shape, Dst: Shape<Concrete = Src::Concrete>>(
+        &self,
+        grad_inp: &mut Self::Storage<Src, E>,
+        grad_out: &Self::Storage<Dst, E>,
+        axes: [usize; 2],
+    ) -> Result<(), Self::Err>;
+}
+
+/// Computes the shape of `src` with `axes` swapped.
+pub(crate) fn try_transpose_shape<Src: Shape, Dst: Shape<Concrete = Src::Concrete>>(
+    src: &Src,
+    axes: [usize; 2],
+) -> Result<Dst, CpuError> {
+    let err = CpuError::InvalidTranspose {
+        axes,
+        num_dims: Src::NUM_DIMS,
+    };
+    let [a, b] = axes;
+    if a >= Src::NUM_DIMS || b >= Src::NUM_DIMS {
+        return Err(err);
+    }
+    let mut dims = src.concrete();
+    dims[a] = src.concrete()[b];
+    dims[b] = src.concrete()[a];
+    Dst::from_concrete(&dims).ok_or(err)
+}
+
+/// Swaps two axes that are chosen at runtime. **Pytorch equivalent**: `t.transpose(a, b)`
+///
+/// Unlike [super::PermuteTo::permute], the axes do not need to be known at compile time,
+/// which is useful for code that is generic over the rank of the tensor.
+pub trait TryTranspose: HasErr + HasShape {
+    /// Swaps axes `a` and `b`. `Dst` has to be able to hold the transposed shape,
+    /// so either the swapped [Const] dims, or [usize] dims.
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank3<1, 2, 3>, f32, _> = dev.zeros();
+    /// let _: Tensor<Rank3<3, 2, 1>, f32, _> = t.clone().transpose(0, 2);
+    /// let r: Tensor<(usize, usize, usize), f32, _> = t.transpose(1, 2);
+    /// assert_eq!(r.shape(), &(1, 3, 2));
+    /// ```
+    ///
+    /// **Panics** if `a` or `b` is not an axis of the tensor, or if `Dst` can not
+    /// represent the transposed shape. See [TryTranspose::try_transpose] for a version
+    /// that returns an error instead.
+    fn transpose<Dst: Shape<Concrete = <Self::Shape as Shape>::Concrete>>(
+        self,
+        a: usize,
+        b: usize,
+    ) -> Self::WithShape<Dst> {
+        self.try_transpose(a, b).unwrap()
+    }
+
+    /// Fallible version of [TryTranspose::transpose]. Returns [CpuError::InvalidTranspose]
+    /// if `a` or `b` is not an axis of the tensor, or if `Dst` can not represent the
+    /// transposed shape.
+    fn try_transpose<Dst: Shape<Concrete = <Self::Shape as Shape>::Concrete>>(
+        self,
+        a: usize,
+        b: usize,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>;
+}
+
+impl<S: Shape, E: Dtype, D: TransposeKernel<E>, T: Tape<D>> TryTranspose for Tensor<S, E, D, T> {
+    fn try_transpose<Dst: Shape<Concrete = S::Concrete>>(
+        self,
+        a: usize,
+        b: usize,
+    ) -> Result<Self::WithShape<Dst>, Self::Err> {
+        let axes = [a, b];
+        let (inp, mut tape) = self.split_tape();
+        let storage = inp.device.forward::<S, Dst>(&inp.storage, axes)?;
+        let out = inp.device.upgrade(storage);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(grad_inp, grad_out, axes)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tensor_ops::*;
+    use crate::tests::TestDevice;
+
+    #[test]
+    fn test_transpose_3d_axes_0_2() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 3, 4>, f32, _> = dev.sample_normal();
+        let r: Tensor<(usize, usize, usize), f32, _> = t.clone().transpose(0, 2);
+        assert_eq!(r.shape(), &(4, 3, 2));
+
+        let t_array = t.array();
+        let r_vec = r.as_vec();
+        for i in 0..2 {
+            for j in 0..3 {
+                for k in 0..4 {
+                    assert_eq!(r_vec[k * 6 + j * 2 + i], t_array[i][j][k]);
+                }
+            }
+        }
+
+        let w: Tensor<Rank3<4, 3, 2>, f32, _> = dev.sample_normal();
+        let w_array = w.array();
+        let r: Tensor<Rank3<4, 3, 2>, f32, _, _> = t.trace().transpose(0, 2);
+        let g = (r * w).sum().backward();
+        let g_array = g.get(&t).array();
+        for i in 0..2 {
+            for j in 0..3 {
+                for k in 0..4 {
+                    assert_eq!(g_array[i][j][k], w_array[k][j][i]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_transpose_matches_permute() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank3<2, 3, 4>, f32, _> = dev.sample_normal();
+        let r1: Tensor<Rank3<2, 4, 3>, f32, _> = t.clone().transpose(1, 2);
+        let r2 = t.clone().permute::<_, Axes3<0, 2, 1>>();
+        assert_eq!(r1.array(), r2.array());
+
+        let r: Tensor<Rank3<2, 3, 4>, f32, _> = t.clone().transpose(1, 1);
+        assert_eq!(r.array(), t.array());
+    }
+
+    #[test]
+    fn test_transpose_broadcasted_backward() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank1<3>, f32, _> = dev.tensor([1.0, 2.0, 3.0]);
+        let r: Tensor<Rank2<3, 2>, f32, _, _> =
+            t.trace().broadcast::<Rank2<2, 3>, _>().transpose(0, 1);
+        assert_eq!(r.array(), [[1.0, 1.0], [2.0, 2.0], [3.0, 3.0]]);
+        let w = dev.tensor([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        let g = (r * w).sum().backward();
+        assert_eq!(g.get(&t).array(), [3.0, 7.0, 11.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_transpose_axis_out_of_bounds() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 3>, f32, _> = dev.zeros();
+        let _: Tensor<(usize, usize), f32, _> = t.transpose(0, 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_transpose_wrong_dst() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 3>, f32, _> = dev.zeros();
+        let _: Tensor<Rank2<2, 3>, f32, _> = t.transpose(0, 1);
+    }
+
+    #[test]
+    fn test_try_transpose_errors() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 3>, f32, _> = dev.zeros();
+        let r = t.clone().try_transpose::<(usize, usize)>(0, 2);
+        assert!(matches!(
+            r,
+            Err(CpuError::InvalidTranspose {
+                axes: [0, 2],
+                num_dims: 2
+            })
+        ));
+
+        let r = t.try_transpose::<Rank2<2, 3>>(1, 0);
+        assert!(matches!(
+            r,
+            Err(CpuError::InvalidTranspose {
+                axes: [1, 0],
+                num_dims: 2
+            })
+        ));
+    }
+}
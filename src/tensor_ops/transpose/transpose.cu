@@ -0,0 +1,59 @@
+// Maps an index into the contiguous output to the input, where the output
+// is the input with axes `a` and `b` swapped.
+__device__ unsigned int transposed_index(
+    unsigned int i,
+    const size_t num_dims,
+    const size_t a,
+    const size_t b,
+    const size_t *dims,
+    const size_t *inp_strides
+) {
+    unsigned int inp_i = 0;
+    for (int d = num_dims - 1; d >= 0; d--) {
+        unsigned int i_d = i % dims[d];
+        i /= dims[d];
+        size_t inp_d = d == a ? b : (d == b ? a : d);
+        inp_i += i_d * inp_strides[inp_d];
+    }
+    return inp_i;
+}
+
+// One thread per output element. `dims` are the dims of the output.
+extern "C" __global__ void transpose_forward(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t a,
+    const size_t b,
+    const size_t *dims,
+    const float *inp,
+    const size_t *inp_strides,
+    float *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    out[i] = inp[transposed_index(i, num_dims, a, b, dims, inp_strides)];
+}
+
+// One thread per output element. `dims` are the dims of the output.
+extern "C" __global__ void transpose_backward(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t a,
+    const size_t b,
+    const size_t *dims,
+    float *grad_inp,
+    const size_t *inp_strides,
+    const float *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    // inp may be broadcasted, so multiple elements can map to the same gradient
+    unsigned int inp_i = transposed_index(i, num_dims, a, b, dims, inp_strides);
+    atomicAdd(grad_inp + inp_i, grad_out[i]);
+}
@@ -0,0 +1,38 @@
+use crate::{
+    shapes::{Dtype, Shape},
+    tensor::cpu::{Cpu, LendingIterator, StridedArray},
+};
+
+impl<E: Dtype> super::TriKernel<E> for Cpu {
+    fn forward<S: Shape>(
+        &self,
+        inp: &Self::Storage<S, E>,
+        upper: bool,
+        diagonal: isize,
+    ) -> Result<Self::Storage<S, E>, Self::Err> {
+        let mut out = StridedArray::new(inp.shape)?;
+        let mut out_iter = out.iter_mut_with_index();
+        while let Some((o, i)) = out_iter.next() {
+            if super::is_kept(i[S::NUM_DIMS - 2], i[S::NUM_DIMS - 1], upper, diagonal) {
+                *o = inp[i];
+            }
+        }
+        Ok(out)
+    }
+
+    fn backward<S: Shape>(
+        &self,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+        upper: bool,
+        diagonal: isize,
+    ) -> Result<(), Self::Err> {
+        let mut out_iter = grad_out.iter_with_index();
+        while let Some((go, i)) = out_iter.next() {
+            if super::is_kept(i[S::NUM_DIMS - 2], i[S::NUM_DIMS - 1], upper, diagonal) {
+                grad_inp[i] += *go;
+            }
+        }
+        Ok(())
+    }
+}
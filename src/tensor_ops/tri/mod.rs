@@ -0,0 +1,157 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{gradients::Tape, shapes::*, tensor::*};
+
+pub trait TriKernel<E: Dtype>: DeviceStorage {
+    /// Keeps elements on & above `diagonal` if `upper` is true, and elements on & below
+    /// `diagonal` otherwise. All other elements are zeroed.
+    fn forward<S: Shape>(
+        &self,
+        inp: &Self::Storage<S, E>,
+        upper: bool,
+        diagonal: isize,
+    ) -> Result<Self::Storage<S, E>, Self::Err>;
+    fn backward<S: Shape>(
+        &self,
+        grad_inp: &mut Self::Storage<S, E>,
+        grad_out: &Self::Storage<S, E>,
+        upper: bool,
+        diagonal: isize,
+    ) -> Result<(), Self::Err>;
+}
+
+/// Whether the element at `row` and `col` of a matrix is kept by [TriKernel].
+#[inline(always)]
+pub(super) fn is_kept(row: usize, col: usize, upper: bool, diagonal: isize) -> bool {
+    let offset = col as isize - row as isize;
+    if upper {
+        offset >= diagonal
+    } else {
+        offset <= diagonal
+    }
+}
+
+impl<S: Shape, E: Dtype, D: TriKernel<E>, T: Tape<D>> Tensor<S, E, D, T> {
+    /// Zeroes out all elements below `diagonal` of the last two dimensions, so
+    /// only the upper triangular part remains. `diagonal` of `0` is the main diagonal,
+    /// positive values are above it, and negative values below it. Gradients only flow
+    /// through the kept elements.
+    ///
+    /// **Pytorch equivalent**: `t.triu(diagonal)`
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<3, 3>, f32, _> = dev.ones();
+    /// let r = t.triu(1);
+    /// assert_eq!(r.array(), [[0.0, 1.0, 1.0], [0.0, 0.0, 1.0], [0.0, 0.0, 0.0]]);
+    /// ```
+    ///
+    /// **Panics** if the tensor has less than 2 dimensions.
+    pub fn triu(self, diagonal: isize) -> Self {
+        self.try_triu(diagonal).unwrap()
+    }
+
+    /// Fallible version of [Tensor::triu]
+    pub fn try_triu(self, diagonal: isize) -> Result<Self, D::Err> {
+        self.try_tri(true, diagonal)
+    }
+
+    /// Zeroes out all elements above `diagonal` of the last two dimensions, so
+    /// only the lower triangular part remains. `diagonal` of `0` is the main diagonal,
+    /// positive values are above it, and negative values below it. Gradients only flow
+    /// through the kept elements.
+    ///
+    /// **Pytorch equivalent**: `t.tril(diagonal)`
+    ///
+    /// For example, a causal mask:
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t: Tensor<Rank2<3, 3>, f32, _> = dev.ones();
+    /// let r = t.tril(0);
+    /// assert_eq!(r.array(), [[1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [1.0, 1.0, 1.0]]);
+    /// ```
+    ///
+    /// **Panics** if the tensor has less than 2 dimensions.
+    pub fn tril(self, diagonal: isize) -> Self {
+        self.try_tril(diagonal).unwrap()
+    }
+
+    /// Fallible version of [Tensor::tril]
+    pub fn try_tril(self, diagonal: isize) -> Result<Self, D::Err> {
+        self.try_tri(false, diagonal)
+    }
+
+    fn try_tri(self, upper: bool, diagonal: isize) -> Result<Self, D::Err> {
+        assert!(S::NUM_DIMS >= 2, "triu/tril need at least 2 dimensions");
+        let (inp, mut tape) = self.split_tape();
+        let storage = inp.device.forward(&inp.storage, upper, diagonal)?;
+        let out = inp.device.upgrade(storage);
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(grad_inp, grad_out, upper, diagonal)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{shapes::*, tensor::*, tensor_ops::*, tests::TestDevice};
+
+    #[test]
+    fn test_tril_causal_mask() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<3, 3>, f32, _> = dev.ones();
+        let r = t.trace().tril(0);
+        assert_eq!(
+            r.array(),
+            [[1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [1.0, 1.0, 1.0]]
+        );
+        let g = r.sum().backward();
+        assert_eq!(
+            g.get(&t).array(),
+            [[1.0, 0.0, 0.0], [1.0, 1.0, 0.0], [1.0, 1.0, 1.0]]
+        );
+    }
+
+    #[test]
+    fn test_triu_diagonals() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 3>, f32, _> = dev.tensor([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert_eq!(
+            t.clone().triu(0).array(),
+            [[1.0, 2.0, 3.0], [0.0, 5.0, 6.0]]
+        );
+        assert_eq!(
+            t.clone().triu(1).array(),
+            [[0.0, 2.0, 3.0], [0.0, 0.0, 6.0]]
+        );
+        assert_eq!(
+            t.clone().triu(-1).array(),
+            [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]
+        );
+        assert_eq!(
+            t.clone().tril(-1).array(),
+            [[0.0, 0.0, 0.0], [4.0, 0.0, 0.0]]
+        );
+        assert_eq!(t.tril(1).array(), [[1.0, 2.0, 0.0], [4.0, 5.0, 6.0]]);
+    }
+
+    #[test]
+    fn test_tril_batched_broadcast() {
+        let dev: TestDevice = Default::default();
+        let t: Tensor<Rank2<2, 2>, f32, _> = dev.tensor([[1.0, 2.0], [3.0, 4.0]]);
+        let r = t.trace().broadcast::<Rank3<2, 2, 2>, Axis<0>>().triu(0);
+        assert_eq!(r.array(), [[[1.0, 2.0], [0.0, 4.0]]; 2]);
+        let g = r.sum().backward();
+        assert_eq!(g.get(&t).array(), [[2.0, 2.0], [0.0, 2.0]]);
+    }
+}
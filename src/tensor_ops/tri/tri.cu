@@ -0,0 +1,60 @@
+#include "cuda_utils.cuh"
+
+// Whether element `i` of a contiguous tensor is kept, based on its position
+// in the last two dimensions.
+__device__ bool is_kept(
+    const unsigned int i,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t upper,
+    const long diagonal
+) {
+    long col = i % dims[num_dims - 1];
+    long row = (i / dims[num_dims - 1]) % dims[num_dims - 2];
+    long offset = col - row;
+    return upper ? offset >= diagonal : offset <= diagonal;
+}
+
+extern "C" __global__ void tri_forward(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t upper,
+    const long diagonal,
+    const float *inp,
+    const size_t *inp_strides,
+    float *out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    if (is_kept(i, num_dims, dims, upper, diagonal)) {
+        out[i] = inp[get_strided_index(i, num_dims, dims, inp_strides)];
+    }
+}
+
+extern "C" __global__ void tri_backward(
+    const size_t numel,
+    const size_t num_dims,
+    const size_t *dims,
+    const size_t upper,
+    const long diagonal,
+    float *grad_inp,
+    const size_t *inp_strides,
+    const float *grad_out,
+    const size_t *out_strides
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    if (i >= numel) {
+        return;
+    }
+
+    if (is_kept(i, num_dims, dims, upper, diagonal)) {
+        unsigned int inp_i = get_strided_index(i, num_dims, dims, inp_strides);
+        unsigned int out_i = get_strided_index(i, num_dims, dims, out_strides);
+        // inp may be broadcasted, so multiple elements can map to the same gradient
+        atomicAdd(grad_inp + inp_i, grad_out[out_i]);
+    }
+}
@@ -0,0 +1,146 @@
+use crate::shapes::*;
+use crate::tensor::cpu::Cpu;
+
+use std::sync::Arc;
+
+use super::{Bilinear, NearestNeighbor, Upsample2DOp};
+
+fn make_4d<S: Shape>(strides: S::Concrete) -> [usize; 4] {
+    match S::NUM_DIMS {
+        3 => [0, strides[0], strides[1], strides[2]],
+        4 => [strides[0], strides[1], strides[2], strides[3]],
+        _ => panic!("Only implemented for 3d & 4d arrays"),
+    }
+}
+
+/// The input index that output index `o` copies from.
+fn nearest(o: usize, size_in: usize, size_out: usize) -> usize {
+    (o * size_in / size_out).min(size_in - 1)
+}
+
+/// The two input indices that output index `o` interpolates between, and the
+/// weight of the second one.
+fn bilinear(o: usize, size_in: usize, size_out: usize) -> (usize, usize, f32) {
+    let src = (o as f32 + 0.5) * size_in as f32 / size_out as f32 - 0.5;
+    let src = src.max(0.0);
+    let i0 = (src as usize).min(size_in - 1);
+    let i1 = (i0 + 1).min(size_in - 1);
+    (i0, i1, src - i0 as f32)
+}
+
+impl super::Upsample2DKernel<f32, NearestNeighbor> for Cpu {
+    fn forward<I: Shape, O: Shape>(
+        &self,
+        op: Upsample2DOp,
+        inp: &Self::Storage<I, f32>,
+        out: &mut Self::Storage<O, f32>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_4d::<I>(inp.strides);
+        let ostr = make_4d::<O>(out.strides);
+
+        let buf = inp.data.as_ref();
+        let out_buf = Arc::make_mut(&mut out.data);
+        for b in 0..op.batch {
+            for c in 0..op.chan {
+                for oh in 0..op.h_out {
+                    let y = nearest(oh, op.h_in, op.h_out);
+                    for ow in 0..op.w_out {
+                        let x = nearest(ow, op.w_in, op.w_out);
+                        out_buf[b * ostr[0] + c * ostr[1] + oh * ostr[2] + ow * ostr[3]] =
+                            buf[b * istr[0] + c * istr[1] + y * istr[2] + x * istr[3]];
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn backward<I: Shape, O: Shape>(
+        &self,
+        op: Upsample2DOp,
+        grad_inp: &mut Self::Storage<I, f32>,
+        grad_out: &Self::Storage<O, f32>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_4d::<I>(grad_inp.strides);
+        let ostr = make_4d::<O>(grad_out.strides);
+
+        let ginp_buf = Arc::make_mut(&mut grad_inp.data);
+        let buf = grad_out.data.as_ref();
+        for b in 0..op.batch {
+            for c in 0..op.chan {
+                for oh in 0..op.h_out {
+                    let y = nearest(oh, op.h_in, op.h_out);
+                    for ow in 0..op.w_out {
+                        let x = nearest(ow, op.w_in, op.w_out);
+                        ginp_buf[b * istr[0] + c * istr[1] + y * istr[2] + x * istr[3]] +=
+                            buf[b * ostr[0] + c * ostr[1] + oh * ostr[2] + ow * ostr[3]];
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl super::Upsample2DKernel<f32, Bilinear> for Cpu {
+    fn forward<I: Shape, O: Shape>(
+        &self,
+        op: Upsample2DOp,
+        inp: &Self::Storage<I, f32>,
+        out: &mut Self::Storage<O, f32>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_4d::<I>(inp.strides);
+        let ostr = make_4d::<O>(out.strides);
+
+        let buf = inp.data.as_ref();
+        let out_buf = Arc::make_mut(&mut out.data);
+        for b in 0..op.batch {
+            for c in 0..op.chan {
+                let i_bc = b * istr[0] + c * istr[1];
+                for oh in 0..op.h_out {
+                    let (y0, y1, ly) = bilinear(oh, op.h_in, op.h_out);
+                    for ow in 0..op.w_out {
+                        let (x0, x1, lx) = bilinear(ow, op.w_in, op.w_out);
+                        let top = buf[i_bc + y0 * istr[2] + x0 * istr[3]] * (1.0 - lx)
+                            + buf[i_bc + y0 * istr[2] + x1 * istr[3]] * lx;
+                        let bottom = buf[i_bc + y1 * istr[2] + x0 * istr[3]] * (1.0 - lx)
+                            + buf[i_bc + y1 * istr[2] + x1 * istr[3]] * lx;
+                        out_buf[b * ostr[0] + c * ostr[1] + oh * ostr[2] + ow * ostr[3]] =
+                            top * (1.0 - ly) + bottom * ly;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn backward<I: Shape, O: Shape>(
+        &self,
+        op: Upsample2DOp,
+        grad_inp: &mut Self::Storage<I, f32>,
+        grad_out: &Self::Storage<O, f32>,
+    ) -> Result<(), Self::Err> {
+        let istr = make_4d::<I>(grad_inp.strides);
+        let ostr = make_4d::<O>(grad_out.strides);
+
+        let ginp_buf = Arc::make_mut(&mut grad_inp.data);
+        let buf = grad_out.data.as_ref();
+        for b in 0..op.batch {
+            for c in 0..op.chan {
+                let i_bc = b * istr[0] + c * istr[1];
+                for oh in 0..op.h_out {
+                    let (y0, y1, ly) = bilinear(oh, op.h_in, op.h_out);
+                    for ow in 0..op.w_out {
+                        let (x0, x1, lx) = bilinear(ow, op.w_in, op.w_out);
+                        let g = buf[b * ostr[0] + c * ostr[1] + oh * ostr[2] + ow * ostr[3]];
+                        ginp_buf[i_bc + y0 * istr[2] + x0 * istr[3]] += g * (1.0 - ly) * (1.0 - lx);
+                        ginp_buf[i_bc + y0 * istr[2] + x1 * istr[3]] += g * (1.0 - ly) * lx;
+                        ginp_buf[i_bc + y1 * istr[2] + x0 * istr[3]] += g * ly * (1.0 - lx);
+                        ginp_buf[i_bc + y1 * istr[2] + x1 * istr[3]] += g * ly * lx;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
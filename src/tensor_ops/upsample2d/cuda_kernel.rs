@@ -0,0 +1,80 @@
+use crate::{shapes::*, tensor::cuda::Cuda};
+
+use std::sync::Arc;
+
+use cudarc::driver::{AsKernelParam, LaunchAsync, LaunchConfig};
+
+use super::{Bilinear, NearestNeighbor};
+
+const MODULE_NAME: &str = "upsample2d";
+const NEAREST_FWD: &str = "nearest_upsample2d_forward";
+const NEAREST_BWD: &str = "nearest_upsample2d_backward";
+const BILINEAR_FWD: &str = "bilinear_upsample2d_forward";
+const BILINEAR_BWD: &str = "bilinear_upsample2d_backward";
+const ALL_FN_NAMES: [&str; 4] = [NEAREST_FWD, NEAREST_BWD, BILINEAR_FWD, BILINEAR_BWD];
+const PTX_SRC: &str = include_str!(concat!(env!("OUT_DIR"), "/upsample2d.ptx"));
+
+unsafe impl AsKernelParam for super::Upsample2DOp {}
+
+fn make_4d<S: Shape>(strides: S::Concrete) -> [usize; 4] {
+    match S::NUM_DIMS {
+        3 => [0, strides[0], strides[1], strides[2]],
+        4 => [strides[0], strides[1], strides[2], strides[3]],
+        _ => panic!("Only implemented for 3d & 4d arrays"),
+    }
+}
+
+macro_rules! upsample_impl {
+    ($Method:ty, Fwd=$FwdFn:ident, Bwd=$BwdFn:ident) => {
+        impl super::Upsample2DKernel<f32, $Method> for Cuda {
+            fn forward<I: Shape, O: Shape>(
+                &self,
+                op: super::Upsample2DOp,
+                inp: &Self::Storage<I, f32>,
+                out: &mut Self::Storage<O, f32>,
+            ) -> Result<(), Self::Err> {
+                if !self.dev.has_func(MODULE_NAME, $FwdFn) {
+                    self.dev
+                        .load_ptx(PTX_SRC.into(), MODULE_NAME, &ALL_FN_NAMES)?;
+                }
+
+                let inp_strides = self.dev.take_async(make_4d::<I>(inp.strides).into())?;
+                let out_strides = self.dev.take_async(make_4d::<O>(out.strides).into())?;
+                let fwd_fn = self.dev.get_func(MODULE_NAME, $FwdFn).unwrap();
+                let cfg = LaunchConfig::for_num_elems(out.shape().num_elements() as u32);
+                let params = (
+                    op,                           // const Upsample2dOp op,
+                    &inp_strides,                 // const size_t *inp_strides,
+                    &out_strides,                 // const size_t *out_strides,
+                    inp.data.as_ref(),            // const float *inp,
+                    Arc::make_mut(&mut out.data), // float *out
+                );
+                unsafe { fwd_fn.launch_async(cfg, params) }?;
+                Ok(())
+            }
+            fn backward<I: Shape, O: Shape>(
+                &self,
+                op: super::Upsample2DOp,
+                grad_inp: &mut Self::Storage<I, f32>,
+                grad_out: &Self::Storage<O, f32>,
+            ) -> Result<(), Self::Err> {
+                let inp_strides = self.dev.take_async(make_4d::<I>(grad_inp.strides).into())?;
+                let out_strides = self.dev.take_async(make_4d::<O>(grad_out.strides).into())?;
+                let bwd_fn = self.dev.get_func(MODULE_NAME, $BwdFn).unwrap();
+                let cfg = LaunchConfig::for_num_elems(grad_out.shape().num_elements() as u32);
+                let params = (
+                    op,                                // const Upsample2dOp op,
+                    &inp_strides,                      // const size_t *inp_strides,
+                    &out_strides,                      // const size_t *out_strides,
+                    Arc::make_mut(&mut grad_inp.data), // float *grad_inp,
+                    grad_out.data.as_ref(),            // const float *grad_out
+                );
+                unsafe { bwd_fn.launch_async(cfg, params) }?;
+                Ok(())
+            }
+        }
+    };
+}
+
+upsample_impl!(NearestNeighbor, Fwd = NEAREST_FWD, Bwd = NEAREST_BWD);
+upsample_impl!(Bilinear, Fwd = BILINEAR_FWD, Bwd = BILINEAR_BWD);
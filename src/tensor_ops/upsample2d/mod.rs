@@ -0,0 +1,293 @@
+mod cpu_kernel;
+
+#[cfg(feature = "cuda")]
+mod cuda_kernel;
+
+use crate::{
+    gradients::Tape,
+    shapes::*,
+    tensor::{DeviceStorage, HasErr, PutTape, SplitTape, Tensor, ZerosTensor},
+};
+
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct Upsample2DOp {
+    pub batch: usize,
+    pub chan: usize,
+    pub h_in: usize,
+    pub h_out: usize,
+    pub w_in: usize,
+    pub w_out: usize,
+}
+
+impl Upsample2DOp {
+    fn new([b, c, h_in, w_in]: [usize; 4], h_out: usize, w_out: usize) -> Self {
+        Self {
+            batch: b,
+            chan: c,
+            h_in,
+            h_out,
+            w_in,
+            w_out,
+        }
+    }
+}
+
+/// A method of computing the values of an upsampled image. See [NearestNeighbor] and [Bilinear].
+pub trait UpsampleMethod: Default {}
+
+/// Each output pixel is a copy of the single closest input pixel, and its gradient
+/// goes entirely to that pixel.
+///
+/// **Pytorch equivalent**: `F.interpolate(t, size, mode="nearest")`
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NearestNeighbor;
+impl UpsampleMethod for NearestNeighbor {}
+
+/// Each output pixel is a weighted average of the four closest input pixels, and its
+/// gradient is distributed to those pixels by the same weights. Corners are **not** aligned.
+///
+/// **Pytorch equivalent**: `F.interpolate(t, size, mode="bilinear", align_corners=False)`
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Bilinear;
+impl UpsampleMethod for Bilinear {}
+
+pub trait Upsample2DKernel<E: Unit, M: UpsampleMethod>: DeviceStorage {
+    fn forward<I: Shape, O: Shape>(
+        &self,
+        op: Upsample2DOp,
+        inp: &Self::Storage<I, E>,
+        out: &mut Self::Storage<O, E>,
+    ) -> Result<(), Self::Err>;
+
+    fn backward<I: Shape, O: Shape>(
+        &self,
+        op: Upsample2DOp,
+        grad_inp: &mut Self::Storage<I, E>,
+        grad_out: &Self::Storage<O, E>,
+    ) -> Result<(), Self::Err>;
+}
+
+pub trait GenericUpsample2D<M: UpsampleMethod>: HasErr {
+    type Output<OH: Dim, OW: Dim>;
+    fn generic_upsample2d_like<OH: Dim, OW: Dim>(
+        self,
+        method: M,
+        height: OH,
+        width: OW,
+    ) -> Result<Self::Output<OH, OW>, Self::Err>;
+}
+
+/// Resizes the last two dimensions of `(C, H, W)` and `(B, C, H, W)` tensors,
+/// with the [UpsampleMethod] `M`.
+///
+/// Despite the name, the output can also be smaller than the input.
+///
+/// ```rust
+/// # use dfdx::prelude::*;
+/// # let dev: Cpu = Default::default();
+/// let t: Tensor<Rank3<1, 2, 2>, f32, _> = dev.tensor([[[1.0, 2.0], [3.0, 4.0]]]);
+///
+/// let r = t.clone().upsample2d::<4, 4, _>(NearestNeighbor);
+/// assert_eq!(r.array()[0][1], [1.0, 1.0, 2.0, 2.0]);
+///
+/// let r = t.clone().upsample2d::<4, 4, _>(Bilinear);
+/// assert_eq!(r.array()[0][1], [1.5, 1.75, 2.25, 2.5]);
+///
+/// // the target size can also be known only at runtime
+/// let r: Tensor<(Const<1>, usize, usize), f32, _> = t.upsample2d_like(Bilinear, 3, 5);
+/// ```
+pub trait TryUpsample2D {
+    /// Resizes the image to the compile time size `OH x OW`.
+    fn upsample2d<const OH: usize, const OW: usize, M: UpsampleMethod>(
+        self,
+        method: M,
+    ) -> <Self as GenericUpsample2D<M>>::Output<Const<OH>, Const<OW>>
+    where
+        Self: GenericUpsample2D<M>,
+    {
+        self.try_upsample2d(method).unwrap()
+    }
+    /// Fallible version of [TryUpsample2D::upsample2d]
+    fn try_upsample2d<const OH: usize, const OW: usize, M: UpsampleMethod>(
+        self,
+        method: M,
+    ) -> Result<<Self as GenericUpsample2D<M>>::Output<Const<OH>, Const<OW>>, Self::Err>
+    where
+        Self: GenericUpsample2D<M>,
+    {
+        self.generic_upsample2d_like(method, Const, Const)
+    }
+    /// Resizes the image to `height x width`, which can be known at runtime.
+    fn upsample2d_like<OH: Dim, OW: Dim, M: UpsampleMethod>(
+        self,
+        method: M,
+        height: OH,
+        width: OW,
+    ) -> <Self as GenericUpsample2D<M>>::Output<OH, OW>
+    where
+        Self: GenericUpsample2D<M>,
+    {
+        self.generic_upsample2d_like(method, height, width).unwrap()
+    }
+    /// Fallible version of [TryUpsample2D::upsample2d_like]
+    fn try_upsample2d_like<OH: Dim, OW: Dim, M: UpsampleMethod>(
+        self,
+        method: M,
+        height: OH,
+        width: OW,
+    ) -> Result<<Self as GenericUpsample2D<M>>::Output<OH, OW>, Self::Err>
+    where
+        Self: GenericUpsample2D<M>,
+    {
+        self.generic_upsample2d_like(method, height, width)
+    }
+}
+impl<T> TryUpsample2D for T {}
+
+impl<
+        C: Dim,
+        H: Dim,
+        W: Dim,
+        M: UpsampleMethod,
+        D: Upsample2DKernel<f32, M> + ZerosTensor<f32>,
+        T: 'static + Tape<D>,
+    > GenericUpsample2D<M> for Tensor<(C, H, W), f32, D, T>
+{
+    type Output<OH: Dim, OW: Dim> = Tensor<(C, OH, OW), f32, D, T>;
+
+    fn generic_upsample2d_like<OH: Dim, OW: Dim>(
+        self,
+        _method: M,
+        height: OH,
+        width: OW,
+    ) -> Result<Self::Output<OH, OW>, Self::Err> {
+        let &(chan, h, w) = self.shape();
+        let op = Upsample2DOp::new(
+            [1, chan.size(), h.size(), w.size()],
+            height.size(),
+            width.size(),
+        );
+        let (inp, mut tape) = self.split_tape();
+        let mut out = inp.device.try_zeros_like(&(chan, height, width))?;
+        inp.device.forward(op, &inp.storage, &mut out.storage)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(op, grad_inp, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+impl<
+        B: Dim,
+        C: Dim,
+        H: Dim,
+        W: Dim,
+        M: UpsampleMethod,
+        D: Upsample2DKernel<f32, M> + ZerosTensor<f32>,
+        T: 'static + Tape<D>,
+    > GenericUpsample2D<M> for Tensor<(B, C, H, W), f32, D, T>
+{
+    type Output<OH: Dim, OW: Dim> = Tensor<(B, C, OH, OW), f32, D, T>;
+
+    fn generic_upsample2d_like<OH: Dim, OW: Dim>(
+        self,
+        _method: M,
+        height: OH,
+        width: OW,
+    ) -> Result<Self::Output<OH, OW>, Self::Err> {
+        let &(batch, chan, h, w) = self.shape();
+        let op = Upsample2DOp::new(
+            [batch.size(), chan.size(), h.size(), w.size()],
+            height.size(),
+            width.size(),
+        );
+        let (inp, mut tape) = self.split_tape();
+        let mut out = inp.device.try_zeros_like(&(batch, chan, height, width))?;
+        inp.device.forward(op, &inp.storage, &mut out.storage)?;
+        let phantom_out = out.clone();
+        tape.try_alloc_grad(&inp)?;
+        tape.try_alloc_grad(&out)?;
+        tape.add_backward_op(move |grads| {
+            let (grad_inp, grad_out) = grads.mut_and_ref(&inp, &phantom_out);
+            inp.device.backward(op, grad_inp, grad_out)
+        });
+        Ok(out.put_tape(tape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tensor::*, tensor_ops::*, tests::*};
+
+    #[test]
+    fn test_upsample2d_nearest_3d() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<1, 2, 2>, f32, _> = dev.tensor([[[1.0, 2.0], [3.0, 4.0]]]);
+        let r = x.trace().upsample2d::<4, 4, _>(NearestNeighbor);
+        assert_eq!(
+            r.array(),
+            [[
+                [1.0, 1.0, 2.0, 2.0],
+                [1.0, 1.0, 2.0, 2.0],
+                [3.0, 3.0, 4.0, 4.0],
+                [3.0, 3.0, 4.0, 4.0]
+            ]]
+        );
+        let g = r.sum().backward();
+        assert_eq!(g.get(&x).array(), [[[4.0, 4.0], [4.0, 4.0]]]);
+    }
+
+    #[test]
+    fn test_upsample2d_nearest_weighted_grads() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<1, 2, 2>, f32, _> = dev.tensor([[[1.0, 2.0], [3.0, 4.0]]]);
+        let r = x.trace().upsample2d::<2, 4, _>(NearestNeighbor);
+        let w = dev.tensor([[[1.0, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0]]]);
+        let g = (r * w).sum().backward();
+        assert_eq!(g.get(&x).array(), [[[3.0, 7.0], [11.0, 15.0]]]);
+    }
+
+    #[test]
+    fn test_upsample2d_bilinear_4d() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank4<2, 1, 2, 2>, f32, _> =
+            dev.tensor([[[[1.0, 2.0], [3.0, 4.0]]], [[[0.0, 0.0], [0.0, 4.0]]]]);
+        let r = x.trace().upsample2d::<4, 4, _>(Bilinear);
+        assert_close(
+            &r.array(),
+            &[
+                [[
+                    [1.0, 1.25, 1.75, 2.0],
+                    [1.5, 1.75, 2.25, 2.5],
+                    [2.5, 2.75, 3.25, 3.5],
+                    [3.0, 3.25, 3.75, 4.0],
+                ]],
+                [[
+                    [0.0, 0.0, 0.0, 0.0],
+                    [0.0, 0.25, 0.75, 1.0],
+                    [0.0, 0.75, 2.25, 3.0],
+                    [0.0, 1.0, 3.0, 4.0],
+                ]],
+            ],
+        );
+        let g = r.sum().backward();
+        assert_close(&g.get(&x).array(), &[[[[4.0; 2]; 2]], [[[4.0; 2]; 2]]]);
+    }
+
+    #[test]
+    fn test_upsample2d_bilinear_runtime_size() {
+        let dev: TestDevice = Default::default();
+        let x: Tensor<Rank3<1, 1, 2>, f32, _> = dev.tensor([[[1.0, 2.0]]]);
+        let r = x.trace().upsample2d_like(Bilinear, 1, 4);
+        assert_eq!(r.shape(), &(Const::<1>, 1, 4));
+        assert_eq!(r.as_vec(), [1.0, 1.25, 1.75, 2.0]);
+        let g = r.square().sum().backward();
+        assert_close(&g.get(&x).array(), &[[[4.75, 7.25]]]);
+    }
+}
@@ -0,0 +1,129 @@
+struct Upsample2dOp {
+    size_t batch;
+    size_t chan;
+    size_t h_in;
+    size_t h_out;
+    size_t w_in;
+    size_t w_out;
+};
+
+// Unravels the index `i` of the output into (batch, channel, row, column).
+__device__ void unravel_output(const Upsample2dOp op, unsigned int i, size_t *b, size_t *c, size_t *oh, size_t *ow) {
+    *ow = i % op.w_out;
+    i /= op.w_out;
+    *oh = i % op.h_out;
+    i /= op.h_out;
+    *c = i % op.chan;
+    i /= op.chan;
+    *b = i % op.batch;
+}
+
+__device__ size_t nearest(const size_t o, const size_t size_in, const size_t size_out) {
+    return min(o * size_in / size_out, size_in - 1);
+}
+
+__device__ void bilinear(const size_t o, const size_t size_in, const size_t size_out, size_t *i0, size_t *i1, float *l) {
+    float src = (static_cast<float>(o) + 0.5) * static_cast<float>(size_in) / static_cast<float>(size_out) - 0.5;
+    src = max(src, 0.0);
+    *i0 = min(static_cast<size_t>(src), size_in - 1);
+    *i1 = min(*i0 + 1, size_in - 1);
+    *l = src - static_cast<float>(*i0);
+}
+
+extern "C" __global__ void nearest_upsample2d_forward(
+    const Upsample2dOp op,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const float *inp, // 4d (Batch, Channels, Height, Width)
+    float *out // 4d (Batch, Channels, HeightOut, WidthOut)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.chan * op.h_out * op.w_out;
+    if (i >= numel) {
+        return;
+    }
+
+    size_t b, c, oh, ow;
+    unravel_output(op, i, &b, &c, &oh, &ow);
+    const size_t y = nearest(oh, op.h_in, op.h_out);
+    const size_t x = nearest(ow, op.w_in, op.w_out);
+    out[i] = inp[b * inp_strides[0] + c * inp_strides[1] + y * inp_strides[2] + x * inp_strides[3]];
+}
+
+extern "C" __global__ void nearest_upsample2d_backward(
+    const Upsample2dOp op,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    float *grad_inp,
+    const float *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.chan * op.h_out * op.w_out;
+    if (i >= numel) {
+        return;
+    }
+
+    size_t b, c, oh, ow;
+    unravel_output(op, i, &b, &c, &oh, &ow);
+    const size_t y = nearest(oh, op.h_in, op.h_out);
+    const size_t x = nearest(ow, op.w_in, op.w_out);
+    const float g = grad_out[b * out_strides[0] + c * out_strides[1] + oh * out_strides[2] + ow * out_strides[3]];
+    // multiple output pixels can copy the same input pixel
+    atomicAdd(grad_inp + b * inp_strides[0] + c * inp_strides[1] + y * inp_strides[2] + x * inp_strides[3], g);
+}
+
+extern "C" __global__ void bilinear_upsample2d_forward(
+    const Upsample2dOp op,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    const float *inp, // 4d (Batch, Channels, Height, Width)
+    float *out // 4d (Batch, Channels, HeightOut, WidthOut)
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.chan * op.h_out * op.w_out;
+    if (i >= numel) {
+        return;
+    }
+
+    size_t b, c, oh, ow;
+    unravel_output(op, i, &b, &c, &oh, &ow);
+    size_t y0, y1, x0, x1;
+    float ly, lx;
+    bilinear(oh, op.h_in, op.h_out, &y0, &y1, &ly);
+    bilinear(ow, op.w_in, op.w_out, &x0, &x1, &lx);
+
+    const float *inp_bc = inp + b * inp_strides[0] + c * inp_strides[1];
+    const float top = inp_bc[y0 * inp_strides[2] + x0 * inp_strides[3]] * (1.0 - lx)
+        + inp_bc[y0 * inp_strides[2] + x1 * inp_strides[3]] * lx;
+    const float bottom = inp_bc[y1 * inp_strides[2] + x0 * inp_strides[3]] * (1.0 - lx)
+        + inp_bc[y1 * inp_strides[2] + x1 * inp_strides[3]] * lx;
+    out[i] = top * (1.0 - ly) + bottom * ly;
+}
+
+extern "C" __global__ void bilinear_upsample2d_backward(
+    const Upsample2dOp op,
+    const size_t *inp_strides,
+    const size_t *out_strides,
+    float *grad_inp,
+    const float *grad_out
+) {
+    unsigned int i = blockIdx.x * blockDim.x + threadIdx.x;
+    const size_t numel = op.batch * op.chan * op.h_out * op.w_out;
+    if (i >= numel) {
+        return;
+    }
+
+    size_t b, c, oh, ow;
+    unravel_output(op, i, &b, &c, &oh, &ow);
+    size_t y0, y1, x0, x1;
+    float ly, lx;
+    bilinear(oh, op.h_in, op.h_out, &y0, &y1, &ly);
+    bilinear(ow, op.w_in, op.w_out, &x0, &x1, &lx);
+
+    const float g = grad_out[b * out_strides[0] + c * out_strides[1] + oh * out_strides[2] + ow * out_strides[3]];
+    float *grad_inp_bc = grad_inp + b * inp_strides[0] + c * inp_strides[1];
+    atomicAdd(grad_inp_bc + y0 * inp_strides[2] + x0 * inp_strides[3], g * (1.0 - ly) * (1.0 - lx));
+    atomicAdd(grad_inp_bc + y0 * inp_strides[2] + x1 * inp_strides[3], g * (1.0 - ly) * lx);
+    atomicAdd(grad_inp_bc + y1 * inp_strides[2] + x0 * inp_strides[3], g * ly * (1.0 - lx));
+    atomicAdd(grad_inp_bc + y1 * inp_strides[2] + x1 * inp_strides[3], g * ly * lx);
+}
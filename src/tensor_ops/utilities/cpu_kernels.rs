@@ -1,6 +1,6 @@
 use super::ops::{BinaryKernel, UnaryKernel};
 use crate::{
-    shapes::{Dtype, Shape},
+    shapes::Shape,
     tensor::cpu::{Cpu, LendingIterator, StridedArray},
 };
 
@@ -15,74 +15,87 @@ pub trait BinaryDerivative<E> {
     fn dfdy(&self, x: &E, y: &E) -> E;
 }
 
-impl<E: Dtype, Op: UnaryDerivative<E>> UnaryKernel<Op, E> for Cpu {
-    fn forward<S: Shape>(
-        &self,
-        op: Op,
-        inp: &Self::Storage<S, E>,
-    ) -> Result<Self::Storage<S, E>, Self::Err> {
-        let mut out: Self::Storage<S, E> = inp.clone();
-        for x in out.buf_iter_mut() {
-            *x = op.f(x);
-        }
-        Ok(out)
-    }
+/// Implements [UnaryKernel] and [BinaryKernel] for a single dtype. These are implemented
+/// per dtype instead of generically so that the dtype of a tensor can be inferred from
+/// the ops used on it.
+macro_rules! impl_cpu_kernels {
+    ($Dtype:ty) => {
+        impl<Op: UnaryDerivative<$Dtype>> UnaryKernel<Op, $Dtype> for Cpu {
+            fn forward<S: Shape>(
+                &self,
+                op: Op,
+                inp: &Self::Storage<S, $Dtype>,
+            ) -> Result<Self::Storage<S, $Dtype>, Self::Err> {
+                let mut out: Self::Storage<S, $Dtype> = inp.clone();
+                for x in out.buf_iter_mut() {
+                    *x = op.f(x);
+                }
+                Ok(out)
+            }
 
-    fn backward<S: Shape>(
-        &self,
-        op: Op,
-        inp: &Self::Storage<S, E>,
-        grad_inp: &mut Self::Storage<S, E>,
-        grad_out: &Self::Storage<S, E>,
-    ) -> Result<(), Self::Err> {
-        debug_assert_eq!(grad_inp.data.len(), grad_out.data.len());
-        debug_assert_eq!(inp.data.len(), grad_out.data.len());
-        for (i, x) in grad_inp.buf_iter_mut().enumerate() {
-            *x += op.df(&inp.data[i]) * grad_out.data[i];
+            fn backward<S: Shape>(
+                &self,
+                op: Op,
+                inp: &Self::Storage<S, $Dtype>,
+                grad_inp: &mut Self::Storage<S, $Dtype>,
+                grad_out: &Self::Storage<S, $Dtype>,
+            ) -> Result<(), Self::Err> {
+                debug_assert_eq!(grad_inp.data.len(), grad_out.data.len());
+                debug_assert_eq!(inp.data.len(), grad_out.data.len());
+                for (i, x) in grad_inp.buf_iter_mut().enumerate() {
+                    *x += op.df(&inp.data[i]) * grad_out.data[i];
+                }
+                Ok(())
+            }
         }
-        Ok(())
-    }
-}
 
-impl<E: Dtype, Op: BinaryDerivative<E>> BinaryKernel<Op, E> for Cpu {
-    fn forward<S: Shape>(
-        &self,
-        op: Op,
-        lhs: &Self::Storage<S, E>,
-        rhs: &Self::Storage<S, E>,
-    ) -> Result<Self::Storage<S, E>, Self::Err> {
-        let mut out: Self::Storage<S, E> = StridedArray::new(lhs.shape)?;
-        let mut lhs_iter = lhs.iter();
-        let mut rhs_iter = rhs.iter();
-        let mut out_iter = out.iter_mut();
-        while let Some((o, (l, r))) = out_iter.next().zip(lhs_iter.next().zip(rhs_iter.next())) {
-            *o = op.f(l, r);
+        impl<Op: BinaryDerivative<$Dtype>> BinaryKernel<Op, $Dtype> for Cpu {
+            fn forward<S: Shape>(
+                &self,
+                op: Op,
+                lhs: &Self::Storage<S, $Dtype>,
+                rhs: &Self::Storage<S, $Dtype>,
+            ) -> Result<Self::Storage<S, $Dtype>, Self::Err> {
+                let mut out: Self::Storage<S, $Dtype> = StridedArray::new(lhs.shape)?;
+                let mut lhs_iter = lhs.iter();
+                let mut rhs_iter = rhs.iter();
+                let mut out_iter = out.iter_mut();
+                while let Some((o, (l, r))) =
+                    out_iter.next().zip(lhs_iter.next().zip(rhs_iter.next()))
+                {
+                    *o = op.f(l, r);
+                }
+                Ok(out)
+            }
+            fn backward<S: Shape>(
+                &self,
+                op: Op,
+                lhs: &Self::Storage<S, $Dtype>,
+                grad_lhs: &mut Self::Storage<S, $Dtype>,
+                rhs: &Self::Storage<S, $Dtype>,
+                grad_rhs: &mut Self::Storage<S, $Dtype>,
+                grad_out: &Self::Storage<S, $Dtype>,
+            ) -> Result<(), Self::Err> {
+                let mut lhs_iter = lhs.iter();
+                let mut rhs_iter = rhs.iter();
+                let mut grad_lhs_iter = grad_lhs.iter_mut();
+                let mut grad_rhs_iter = grad_rhs.iter_mut();
+                let mut grad_out_iter = grad_out.iter();
+                for _ in 0..lhs.shape.num_elements() {
+                    let l = lhs_iter.next().unwrap();
+                    let r = rhs_iter.next().unwrap();
+                    let go = *grad_out_iter.next().unwrap();
+                    let gl = grad_lhs_iter.next().unwrap();
+                    *gl += op.dfdx(l, r) * go;
+                    let gr = grad_rhs_iter.next().unwrap();
+                    *gr += op.dfdy(l, r) * go;
+                }
+                Ok(())
+            }
         }
-        Ok(out)
-    }
-    fn backward<S: Shape>(
-        &self,
-        op: Op,
-        lhs: &Self::Storage<S, E>,
-        grad_lhs: &mut Self::Storage<S, E>,
-        rhs: &Self::Storage<S, E>,
-        grad_rhs: &mut Self::Storage<S, E>,
-        grad_out: &Self::Storage<S, E>,
-    ) -> Result<(), Self::Err> {
-        let mut lhs_iter = lhs.iter();
-        let mut rhs_iter = rhs.iter();
-        let mut grad_lhs_iter = grad_lhs.iter_mut();
-        let mut grad_rhs_iter = grad_rhs.iter_mut();
-        let mut grad_out_iter = grad_out.iter();
-        for _ in 0..lhs.shape.num_elements() {
-            let l = lhs_iter.next().unwrap();
-            let r = rhs_iter.next().unwrap();
-            let go = *grad_out_iter.next().unwrap();
-            let gl = grad_lhs_iter.next().unwrap();
-            *gl += op.dfdx(l, r) * go;
-            let gr = grad_rhs_iter.next().unwrap();
-            *gr += op.dfdy(l, r) * go;
-        }
-        Ok(())
-    }
+    };
 }
+
+impl_cpu_kernels!(f32);
+#[cfg(feature = "f64")]
+impl_cpu_kernels!(f64);
@@ -13,6 +13,7 @@ pub trait Device<E: Dtype>:
     + crate::tensor::ZerosTensor<E>
     + crate::tensor::OnesTensor<E>
     + crate::tensor::SampleTensor<E>
+    + crate::tensor::TensorFromVec<E>
     + crate::tensor::OneFillStorage<E>
     + crate::tensor::ZeroFillStorage<E>
 
@@ -21,17 +22,40 @@ pub trait Device<E: Dtype>:
     + super::super::sum_to::SumKernel<E>
     + super::super::max_to::MaxReduceKernel<E>
     + super::super::min_to::MinReduceKernel<E>
+    + super::super::prod::ProdKernel<E>
     + super::super::permute_to::PermuteKernel<E>
+    + super::super::transpose::TransposeKernel<E>
     + super::super::reshape_to::ReshapeKernel<E>
+    + super::super::split::SplitKernel<E>
+    + super::super::concat::ConcatKernel<E>
+    + super::super::one_hot::OneHotKernel<E>
+    + super::super::argreduce::ArgReduceKernel<E>
+    + super::super::top_k::TopKKernel<E>
+    + super::super::sort::SortKernel<E>
+    + super::super::cumsum::CumSumKernel<E>
+    + super::super::repeat::RepeatKernel<E>
+    + super::super::narrow::NarrowKernel<E>
+    + super::super::roll::RollKernel<E>
+    + super::super::stack::StackKernel<E>
+    + super::super::pad::PadKernel<E>
+    + super::super::flip::FlipKernel<E>
+    + super::super::tri::TriKernel<E>
+    + super::super::diag::DiagKernel<E>
+    + super::super::nan_to_num::FiniteCheckKernel<E>
 
     // indexing
     + super::super::select_and_gather::ReplaceDimKernel<E>
     + super::super::select_and_gather::RemoveDimKernel<E>
+    + super::super::select_and_gather::GatherRowsKernel<E>
+    + super::super::take_along_axis::TakeAlongAxisKernel<E>
+    + super::super::scatter::ScatterKernel<E>
     + super::super::choose::ChooseKernel<E>
+    + super::super::masked_fill::MaskedFillKernel<E>
 
     // matmuls
     + super::super::matmul::VecMatKernel<E>
     + super::super::matmul::MatMatKernel<E>
+    + super::super::matmul::MatMatBiasKernel<E>
     + super::super::matmul::VecVecKernel<E>
     + super::super::matmul::MatMatBrKernel<E>
     + super::super::matmul::MatMatBatch3Kernel<E>
@@ -51,6 +75,8 @@ pub trait Device<E: Dtype>:
 
     // boolean operations
     + super::super::boolean::BooleanKernel
+    + super::super::bool_reduce::BoolReduceKernel
+    + super::super::broadcast_to::BroadcastKernel<bool>
 
     // unary
     + UnaryKernel<super::super::abs::AbsKernelOp, E>
@@ -59,11 +85,14 @@ pub trait Device<E: Dtype>:
     + UnaryKernel<super::super::dropout::DropoutKernelOp, E>
     + UnaryKernel<super::super::exp::ExpKernelOp, E>
     + UnaryKernel<super::super::ln::LnKernelOp, E>
+    + UnaryKernel<super::super::nan_to_num::NanToNumKernelOp<E>, E>
     + UnaryKernel<super::super::nans_to::NansToKernelOp<E>, E>
     + UnaryKernel<super::super::negate::NegateKernelOp, E>
+    + UnaryKernel<super::super::recip::RecipKernelOp, E>
     + UnaryKernel<super::super::relu::ReLUKernelOp, E>
     + UnaryKernel<super::super::gelu::GeLUKernelOp, E>
     + UnaryKernel<super::super::sigmoid::SigmoidKernelOp, E>
+    + UnaryKernel<super::super::sign::SignKernelOp, E>
     + UnaryKernel<super::super::sin::SinKernelOp, E>
     + UnaryKernel<super::super::sqrt::SqrtKernelOp, E>
     + UnaryKernel<super::super::square::SquareKernelOp, E>
@@ -80,6 +109,8 @@ pub trait Device<E: Dtype>:
 }
 
 impl Device<f32> for crate::tensor::Cpu {}
+#[cfg(feature = "f64")]
+impl Device<f64> for crate::tensor::Cpu {}
 
 #[cfg(feature = "cuda")]
 impl Device<f32> for crate::tensor::Cuda {}
@@ -25,6 +25,36 @@ pub trait VarTo: HasErr + HasShape {
     fn try_var<Dst: Shape, Ax: Axes>(self) -> Result<Self::WithShape<Dst>, Self::Err>
     where
         Self::Shape: HasAxes<Ax> + ReduceShapeTo<Dst, Ax>;
+
+    /// Variance reduction with `ddof` delta degrees of freedom, so the sum of squared
+    /// differences is divided by `N - ddof` instead of `N`. Use `ddof = 1` for
+    /// Bessel's correction (the unbiased estimate).
+    ///
+    /// As in pytorch, the divisor is clamped to `max(N - ddof, 0)`, so if `ddof >= N`
+    /// the result is `inf` or `NaN`.
+    ///
+    /// **Pytorch equivalent**: `t.var(Axes, correction=ddof)`
+    ///
+    /// ```rust
+    /// # use dfdx::prelude::*;
+    /// # let dev: Cpu = Default::default();
+    /// let t = dev.tensor([[2.0, 3.0, 4.0], [3.0, 6.0, 9.0]]);
+    /// let r = t.var_with_ddof::<Rank1<2>, _>(1);
+    /// assert_eq!(r.array(), [1.0, 9.0]);
+    /// ```
+    fn var_with_ddof<Dst: Shape, Ax: Axes>(self, ddof: usize) -> Self::WithShape<Dst>
+    where
+        Self::Shape: HasAxes<Ax> + ReduceShapeTo<Dst, Ax>,
+    {
+        self.try_var_with_ddof(ddof).unwrap()
+    }
+    /// Fallible version of [VarTo::var_with_ddof]
+    fn try_var_with_ddof<Dst: Shape, Ax: Axes>(
+        self,
+        ddof: usize,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax> + ReduceShapeTo<Dst, Ax>;
 }
 
 impl<S: Shape, D: Device<f32>, T: Tape<D>> VarTo for Tensor<S, f32, D, T> {
@@ -32,18 +62,32 @@ impl<S: Shape, D: Device<f32>, T: Tape<D>> VarTo for Tensor<S, f32, D, T> {
     where
         Self::Shape: HasAxes<Ax> + ReduceShapeTo<Dst, Ax>,
     {
+        self.try_var_with_ddof(0)
+    }
+
+    fn try_var_with_ddof<Dst: Shape, Ax: Axes>(
+        self,
+        ddof: usize,
+    ) -> Result<Self::WithShape<Dst>, Self::Err>
+    where
+        Self::Shape: HasAxes<Ax> + ReduceShapeTo<Dst, Ax>,
+    {
+        let num_elements_reduced = <S as HasAxes<Ax>>::size(self.shape());
         let mean = self
             .retaped::<T>()
             .try_mean::<Dst, Ax>()?
             .try_broadcast_like(self.shape())?;
-        mean.try_sub(self)?.try_square()?.try_mean()
+        mean.try_sub(self)?
+            .try_square()?
+            .try_sum()?
+            .try_div(num_elements_reduced.saturating_sub(ddof) as f32)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tests::TestDevice;
+    use crate::tests::{assert_close, TestDevice};
 
     #[test]
     fn test_var_axis_0_2d() {
@@ -73,4 +117,35 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_var_with_ddof() {
+        let dev: TestDevice = Default::default();
+        let t = dev.tensor([1.0, 2.0, 3.0, 4.0]);
+
+        let r = t.trace().var_with_ddof::<Rank0, _>(0);
+        assert_eq!(r.array(), 1.25);
+        // 2 / (n - ddof) * (x_i - mean)
+        let g = r.backward();
+        assert_eq!(g.get(&t).array(), [-0.75, -0.25, 0.25, 0.75]);
+
+        let r = t.trace().var_with_ddof::<Rank0, _>(1);
+        assert_close(&r.array(), &(5.0 / 3.0));
+        let g = r.backward();
+        assert_close(&g.get(&t).array(), &[-1.0, -1.0 / 3.0, 1.0 / 3.0, 1.0]);
+    }
+
+    #[test]
+    fn test_var_with_ddof_too_large() {
+        let dev: TestDevice = Default::default();
+        let t = dev.tensor([1.0, 2.0]);
+        assert_eq!(
+            t.clone().var_with_ddof::<Rank0, _>(2).array(),
+            f32::INFINITY
+        );
+        assert_eq!(t.var_with_ddof::<Rank0, _>(3).array(), f32::INFINITY);
+
+        let t = dev.tensor([1.0, 1.0]);
+        assert!(t.var_with_ddof::<Rank0, _>(2).array().is_nan());
+    }
 }